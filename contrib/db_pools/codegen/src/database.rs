@@ -1,16 +1,34 @@
 use proc_macro::TokenStream;
 
-use devise::{DeriveGenerator, FromMeta, MapperBuild, Support, ValidatorBuild};
+use devise::{DeriveGenerator, FromMeta, MapperBuild, SpanWrapped, Support, ValidatorBuild};
 use devise::proc_macro2_diagnostics::SpanDiagnosticExt;
 use devise::syn::{self, spanned::Spanned};
 
 const ONE_DATABASE_ATTR: &str = "missing `#[database(\"name\")]` attribute";
 const ONE_UNNAMED_FIELD: &str = "struct must have exactly one unnamed field";
+const CONN_IDENT: &str = "`conn` must be a valid type identifier";
 
 #[derive(Debug, FromMeta)]
 struct DatabaseAttribute {
     #[meta(naked)]
     name: String,
+    conn: Option<SpanWrapped<String>>,
+    schema_version: Option<i64>,
+}
+
+impl DatabaseAttribute {
+    /// The identifier of the `Connection` type alias to generate, if any.
+    fn conn_alias(&self) -> devise::Result<Option<syn::Ident>> {
+        let Some(conn) = &self.conn else {
+            return Ok(None);
+        };
+
+        let mut ident = syn::parse_str::<syn::Ident>(&conn.value)
+            .map_err(|_| conn.span.error(CONN_IDENT))?;
+
+        ident.set_span(conn.span);
+        Ok(Some(ident))
+    }
 }
 
 pub fn derive_database(input: TokenStream) -> TokenStream {
@@ -25,6 +43,26 @@ pub fn derive_database(input: TokenStream) -> TokenStream {
                 }
             })
         )
+        .outer_mapper(MapperBuild::new()
+            .try_input_map(|_, input| {
+                // Errors in the attribute are reported by the inner mapper.
+                let alias = DatabaseAttribute::one_from_attrs("database", input.attrs())
+                    .ok()
+                    .flatten()
+                    .and_then(|attr| attr.conn_alias().ok().flatten());
+
+                let Some(alias) = alias else {
+                    return Ok(quote!());
+                };
+
+                let (vis, decorated_type) = (input.vis(), input.ident());
+                Ok(quote_spanned! { alias.span() =>
+                    #[doc = concat!("A [`Connection`](rocket_db_pools::Connection) to [`",
+                        stringify!(#decorated_type), "`].")]
+                    #vis type #alias = rocket_db_pools::Connection<#decorated_type>;
+                })
+            })
+        )
         .outer_mapper(MapperBuild::new()
             .struct_map(|_, s| {
                 let pool_type = match &s.fields {
@@ -84,10 +122,17 @@ pub fn derive_database(input: TokenStream) -> TokenStream {
         .outer_mapper(quote!(#[rocket::async_trait]))
         .inner_mapper(MapperBuild::new()
             .try_struct_map(|_, s| {
-                let db_name = DatabaseAttribute::one_from_attrs("database", &s.attrs)?
-                    .map(|attr| attr.name)
+                let attr = DatabaseAttribute::one_from_attrs("database", &s.attrs)?
                     .ok_or_else(|| s.span().error(ONE_DATABASE_ATTR))?;
 
+                attr.conn_alias()?;
+                let db_name = attr.name;
+                let schema_version = attr.schema_version.map(|version| quote! {
+                    fn expected_schema_version() -> Option<i64> {
+                        Some(#version)
+                    }
+                });
+
                 let fairing_name = format!("'{}' Database Pool", db_name);
 
                 let pool_type = match &s.fields {
@@ -103,6 +148,8 @@ pub fn derive_database(input: TokenStream) -> TokenStream {
                     fn init() -> rocket_db_pools::Initializer<Self> {
                         rocket_db_pools::Initializer::with_name(#fairing_name)
                     }
+
+                    #schema_version
                 })
             })
         )
@@ -34,6 +34,10 @@ mod database;
 /// * [`Database::Pool`] is set to the wrapped type: `PoolType` above. The type
 ///   must implement [`Pool`].
 ///
+/// * [`Database::expected_schema_version()`] returns `Some(N)` if the
+///   attribute contains a `schema_version = N` parameter and `None`
+///   otherwise.
+///
 /// To meet the required [`Database`] supertrait bounds, this derive also
 /// generates implementations for:
 ///
@@ -47,6 +51,23 @@ mod database;
 ///
 /// * `Sentinel for &Db`
 ///
+/// Optionally, the derive generates a type alias for the database's
+/// [`Connection`] request guard, with the visibility of the decorated type,
+/// when a `conn` parameter is present in the attribute:
+///
+/// ```rust
+/// use rocket_db_pools::Database;
+/// # type PoolType = rocket_db_pools::deadpool_postgres::Pool;
+///
+/// #[derive(Database)]
+/// #[database("database_name", conn = "DbConn")]
+/// struct Db(PoolType);
+///
+/// // Equivalent to: `type DbConn = Connection<Db>;`
+/// #[rocket::get("/")]
+/// fn index(conn: DbConn) { /* ... */ }
+/// ```
+///
 /// The `Deref` impls enable accessing the database pool directly from
 /// references `&Db` or `&mut Db`. To force a dereference to the underlying
 /// type, use `&db.0` or `&**db` or their `&mut` variants.
@@ -54,7 +75,9 @@ mod database;
 /// [`Database`]: ../rocket_db_pools/trait.Database.html
 /// [`Database::NAME`]: ../rocket_db_pools/trait.Database.html#associatedconstant.NAME
 /// [`Database::Pool`]: ../rocket_db_pools/trait.Database.html#associatedtype.Pool
+/// [`Database::expected_schema_version()`]: ../rocket_db_pools/trait.Database.html#method.expected_schema_version
 /// [`Pool`]: ../rocket_db_pools/trait.Pool.html
+/// [`Connection`]: ../rocket_db_pools/struct.Connection.html
 #[proc_macro_derive(Database, attributes(database))]
 pub fn derive_database(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     crate::database::derive_database(input)
@@ -20,6 +20,13 @@ use rocket::serde::{Deserialize, Serialize};
 /// max_connections = 1024
 /// connect_timeout = 5
 /// idle_timeout = 120
+/// connect_retries = 3
+/// connect_retry_base = 100
+/// test_on_checkout = true
+///
+/// # This option is only supported by the `sqlx_postgres`,
+/// # `deadpool_postgres`, and `diesel_postgres` drivers.
+/// statement_timeout = 30
 ///
 /// # This option is only supported by the `sqlx_sqlite` driver.
 /// extensions = ["memvfs", "rot13"]
@@ -39,6 +46,10 @@ use rocket::serde::{Deserialize, Serialize};
 ///             max_connections: 1024,
 ///             connect_timeout: 3,
 ///             idle_timeout: None,
+///             connect_retries: 0,
+///             connect_retry_base: 100,
+///             test_on_checkout: None,
+///             statement_timeout: None,
 ///             extensions: None,
 ///         }));
 ///
@@ -85,6 +96,63 @@ pub struct Config {
     ///
     /// _Default:_ `None`.
     pub idle_timeout: Option<u64>,
+    /// Number of times to retry retrieving a connection after a failure.
+    ///
+    /// When retrieving a connection, either via the
+    /// [`Connection`](crate::Connection) request guard or via
+    /// [`Database::get()`](crate::Database::get()), fails, the attempt is
+    /// retried up to `connect_retries` times with an exponential backoff, as
+    /// described in [`connect_retry_base`](Config::connect_retry_base). This
+    /// allows transient failures, such as a database restarting or failing
+    /// over, to go unnoticed by clients.
+    ///
+    /// _Default:_ `0`.
+    pub connect_retries: u32,
+    /// Number of milliseconds to wait before the first connection retry.
+    ///
+    /// The wait doubles with every subsequent retry: with a base of `100`, the
+    /// first retry occurs after `100ms`, the second after a further `200ms`,
+    /// the third after a further `400ms`, and so on.
+    ///
+    /// _Default:_ `100`.
+    pub connect_retry_base: u64,
+    /// Whether to verify that a pooled connection is alive before handing it
+    /// out.
+    ///
+    /// When enabled, a connection retrieved from the pool is first tested with
+    /// a round-trip to the database, such as a `SELECT 1` query or a `PING`
+    /// command. Connections which fail the test are discarded and replaced.
+    /// This avoids errors on the first use of stale connections, such as those
+    /// that outlived a database restart, at the cost of one round-trip per
+    /// checkout. When unset, the driver's default applies. Support is driver
+    /// specific:
+    ///
+    ///   * `sqlx`: connections are pinged when enabled. Enabled by default.
+    ///   * `deadpool_postgres`: connections are verified with a query when
+    ///     enabled. Otherwise, only closed connections are detected. Disabled
+    ///     by default.
+    ///   * `diesel`: connections are verified with a query when enabled.
+    ///     Otherwise, only closed connections are detected. Enabled by
+    ///     default.
+    ///   * `deadpool_redis`: connections are always pinged; ignored.
+    ///   * `mongodb`: the driver monitors connections itself; ignored.
+    ///
+    /// _Default:_ `None`.
+    pub test_on_checkout: Option<bool>,
+    /// Maximum number of seconds any single statement may run for.
+    ///
+    /// When set, the timeout is configured as a session parameter on every new
+    /// connection, and the database aborts statements which exceed it. This
+    /// guards against runaway queries pinning connections indefinitely.
+    ///
+    /// **Note:** Only the `sqlx_postgres`, `deadpool_postgres`, and
+    /// `diesel_postgres` drivers, which set Postgres' `statement_timeout`,
+    /// support this option at this time. All other drivers ignore this option
+    /// and emit a warning if it is set. For `deadpool_postgres`, the option is
+    /// appended to any `options` specified in the `url`.
+    ///
+    /// _Default:_ `None`.
+    pub statement_timeout: Option<u64>,
     /// A list of database extensions to load at run-time.
     ///
     /// **Note:** Only the `sqlx_sqlite` driver supports this option (for SQLite
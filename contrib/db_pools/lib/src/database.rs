@@ -1,13 +1,16 @@
+use std::future::Future;
 use std::marker::PhantomData;
 use std::ops::{Deref, DerefMut};
 
-use rocket::{error, info_, Build, Ignite, Phase, Rocket, Sentinel, Orbit};
+use rocket::{error, info, info_, warn, Build, Ignite, Phase, Rocket, Sentinel, Orbit};
 use rocket::fairing::{self, Fairing, Info, Kind};
 use rocket::request::{FromRequest, Outcome, Request};
 use rocket::http::Status;
 
 use rocket::yansi::Paint;
 use rocket::figment::providers::Serialized;
+use rocket::serde::Deserialize;
+use rocket::tokio::time::{sleep, Duration};
 
 use crate::Pool;
 
@@ -69,6 +72,42 @@ pub trait Database: From<Self::Pool> + DerefMut<Target = Self::Pool> + Send + Sy
         Initializer::new()
     }
 
+    /// The schema version the application expects the database to be at, if
+    /// any. The default implementation returns `None`.
+    ///
+    /// When this method returns `Some`, the [`Initializer`] fairing queries
+    /// the database's current schema version via [`Pool::schema_version()`]
+    /// on ignition and fails, thus aborting launch, if it doesn't match. This
+    /// prevents running an application against a database that has not been
+    /// migrated, or one that has been migrated past what it expects.
+    ///
+    /// Only drivers whose pool reports [`Pool::SUPPORTS_SCHEMA_VERSION`],
+    /// currently the `sqlx` drivers, support this check. For all others, the
+    /// expected version is ignored with a warning.
+    ///
+    /// When `Database` is derived, this returns the value `N` in a
+    /// `#[database("name", schema_version = N)]` attribute, if present.
+    ///
+    /// # Example
+    ///
+    /// With `sqlx`, the schema version is that of the latest successfully
+    /// applied migration:
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "sqlx_sqlite")] mod _inner {
+    /// use rocket_db_pools::{sqlx, Database};
+    ///
+    /// #[derive(Database)]
+    /// #[database("sqlite_db", schema_version = 20240115093000)]
+    /// struct Db(sqlx::SqlitePool);
+    ///
+    /// assert_eq!(Db::expected_schema_version(), Some(20240115093000));
+    /// # }
+    /// ```
+    fn expected_schema_version() -> Option<i64> {
+        None
+    }
+
     /// Returns a reference to the initialized database in `rocket`. The
     /// initializer fairing returned by `init()` must have already executed for
     /// `Option` to be `Some`. This is guaranteed to be the case if the fairing
@@ -128,8 +167,117 @@ pub trait Database: From<Self::Pool> + DerefMut<Target = Self::Pool> + Send + Sy
             dbtype.linger(), "::init()".resetting());
         None
     }
+
+    /// Retrieves a single [`Connection`] to the initialized database in
+    /// `rocket`, exactly as the `Connection` request guard does, but outside
+    /// of a request. This is useful in background tasks, such as those spawned
+    /// by a liftoff fairing, and in startup tasks.
+    ///
+    /// The returned future resolves to an `Err` if the database was not
+    /// initialized, with `None`, or if a connection could not be retrieved,
+    /// with the error in `Some`. The conditions under which the database is
+    /// initialized are those of [`Database::fetch()`]. Failures to retrieve a
+    /// connection are retried as configured by
+    /// [`connect_retries`](crate::Config::connect_retries).
+    ///
+    /// Note that this is an associated function, not a method: use
+    /// `Db::get(&rocket)`. Calling `db.get()` on an instance of `Db` calls
+    /// [`Pool::get()`], which returns the native connection type.
+    ///
+    /// # Example
+    ///
+    /// Periodically prune a table in a task spawned on liftoff:
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "sqlx_sqlite")] mod _inner {
+    /// # use rocket::launch;
+    /// use rocket::fairing::AdHoc;
+    /// use rocket::tokio::time::{self, Duration};
+    ///
+    /// use rocket_db_pools::{sqlx, Database};
+    ///
+    /// #[derive(Database)]
+    /// #[database("sqlite_db")]
+    /// struct Db(sqlx::SqlitePool);
+    ///
+    /// #[launch]
+    /// fn rocket() -> _ {
+    ///     rocket::build()
+    ///         .attach(Db::init())
+    ///         .attach(AdHoc::on_liftoff("Pruner", |rocket| Box::pin(async move {
+    ///             let mut db = Db::get(rocket).await.expect("database connection");
+    ///             rocket::tokio::spawn(async move {
+    ///                 let mut interval = time::interval(Duration::from_secs(3600));
+    ///                 loop {
+    ///                     interval.tick().await;
+    ///                     let query = sqlx::query("DELETE FROM logs WHERE old = 1");
+    ///                     let _ = query.execute(&mut **db).await;
+    ///                 }
+    ///             });
+    ///         })))
+    /// }
+    /// # }
+    /// ```
+    fn get<P: Phase>(
+        rocket: &Rocket<P>
+    ) -> impl Future<Output = Result<Connection<Self>, Option<<Self::Pool as Pool>::Error>>> + Send + '_ {
+        let db = Self::fetch(rocket);
+        let checkout = rocket.state::<Checkout<Self>>().copied().unwrap_or_default();
+        async move {
+            let Some(db) = db else { return Err(None) };
+            let mut retry = 0;
+            loop {
+                let delay = match Pool::get(&**db).await {
+                    Ok(conn) => return Ok(Connection(conn)),
+                    Err(e) if retry < checkout.connect_retries => {
+                        let delay = checkout.delay(retry);
+                        warn!("failed to retrieve `{}` connection: {}", Self::NAME, e);
+                        info_!("retrying in {}ms ({}/{})",
+                            delay.as_millis(), retry + 1, checkout.connect_retries);
+                        delay
+                    }
+                    Err(e) => return Err(Some(e)),
+                };
+
+                sleep(delay).await;
+                retry += 1;
+            }
+        }
+    }
+}
+
+/// Connection retrieval parameters for `D`, managed by the [`Initializer`].
+#[derive(Deserialize)]
+#[serde(crate = "rocket::serde", bound = "")]
+struct Checkout<D> {
+    connect_retries: u32,
+    connect_retry_base: u64,
+    #[serde(skip)]
+    _db: PhantomData<fn() -> D>,
+}
+
+impl<D> Checkout<D> {
+    /// The delay before retry number `retry`, starting at `0`.
+    fn delay(&self, retry: u32) -> Duration {
+        let factor = 2u64.saturating_pow(retry);
+        Duration::from_millis(self.connect_retry_base.saturating_mul(factor))
+    }
+}
+
+impl<D> Default for Checkout<D> {
+    fn default() -> Self {
+        Checkout { connect_retries: 0, connect_retry_base: 100, _db: PhantomData }
+    }
+}
+
+impl<D> Clone for Checkout<D> {
+    fn clone(&self) -> Self {
+        *self
+    }
 }
 
+impl<D> Copy for Checkout<D> { }
+
 /// A [`Fairing`] which initializes a [`Database`] and its connection pool.
 ///
 /// A value of this type can be created for any type `D` that implements
@@ -146,9 +294,17 @@ pub trait Database: From<Self::Pool> + DerefMut<Target = Self::Pool> + Send + Sy
 ///
 ///   3. Calls [`Pool::init()`].
 ///
-///   4. Stores the database instance in managed storage, retrievable via
+///   4. Checks that the schema version matches
+///      [`Database::expected_schema_version()`], if it returns `Some` and the
+///      pool [supports](Pool::SUPPORTS_SCHEMA_VERSION) schema versions.
+///
+///   5. Stores the database instance in managed storage, retrievable via
 ///      [`Database::fetch()`].
 ///
+/// On [shutdown](Fairing::on_shutdown()), the fairing closes the pool via
+/// [`Pool::close()`], allowing connections to be closed cleanly instead of
+/// being dropped abruptly when the process exits.
+///
 /// The name of the fairing itself is `Initializer<D>`, with `D` replaced with
 /// the type name `D` unless a name is explicitly provided via
 /// [`Self::with_name()`].
@@ -159,6 +315,8 @@ pub struct Initializer<D: Database>(Option<&'static str>, PhantomData<fn() -> D>
 /// For a database type of `Db`, a request guard of `Connection<Db>` retrieves a
 /// single connection to `Db`.
 ///
+/// Outside of a request, a connection can be retrieved via [`Database::get()`].
+///
 /// The request guard succeeds if the database was initialized by the
 /// [`Initializer`] fairing and a connection is available within
 /// [`connect_timeout`](crate::Config::connect_timeout) seconds.
@@ -245,6 +403,53 @@ impl<D: Database> Connection<D> {
     }
 }
 
+#[cfg(feature = "mongodb")]
+impl<D: Database<Pool = mongodb::Client>> Connection<D> {
+    /// Starts a new client session, runs `f` with it, and ends the session
+    /// once the future returned by `f` resolves.
+    ///
+    /// Operations in a session are causally consistent. A session is also
+    /// required to run operations in a transaction. Due to the lifetime of the
+    /// session borrow, `f` must return a boxed future, as with
+    /// [`ClientSession::with_transaction()`](mongodb::ClientSession::with_transaction()).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "mongodb")] mod _inner {
+    /// # use rocket::post;
+    /// use rocket_db_pools::{Database, Connection};
+    /// use rocket_db_pools::mongodb::{self, bson::{doc, Document}};
+    ///
+    /// #[derive(Database)]
+    /// #[database("mongo")]
+    /// struct Db(mongodb::Client);
+    ///
+    /// #[post("/transfer")]
+    /// async fn transfer(db: Connection<Db>) -> Option<()> {
+    ///     db.with_session(|session| Box::pin(async move {
+    ///         let accounts = session.client()
+    ///             .database("bank")
+    ///             .collection::<Document>("accounts");
+    ///
+    ///         session.start_transaction(None).await?;
+    ///         let (from, to) = (doc! { "_id": 1 }, doc! { "_id": 2 });
+    ///         accounts.update_one_with_session(from, doc! { "$inc": { "balance": -10 } }, None, session).await?;
+    ///         accounts.update_one_with_session(to, doc! { "$inc": { "balance": 10 } }, None, session).await?;
+    ///         session.commit_transaction().await
+    ///     })).await.ok()
+    /// }
+    /// # }
+    /// ```
+    pub async fn with_session<F, R>(&self, f: F) -> mongodb::error::Result<R>
+        where F: for<'s> FnOnce(&'s mut mongodb::ClientSession)
+            -> rocket::futures::future::BoxFuture<'s, mongodb::error::Result<R>>
+    {
+        let mut session = self.start_session(None).await?;
+        f(&mut session).await
+    }
+}
+
 #[rocket::async_trait]
 impl<D: Database> Fairing for Initializer<D> {
     fn info(&self) -> Info {
@@ -262,19 +467,57 @@ impl<D: Database> Fairing for Initializer<D> {
         let figment = rocket.figment()
             .focus(&format!("databases.{}", D::NAME))
             .join(Serialized::default("max_connections", workers * 4))
-            .join(Serialized::default("connect_timeout", 5));
+            .join(Serialized::default("connect_timeout", 5))
+            .join(Serialized::default("connect_retries", 0))
+            .join(Serialized::default("connect_retry_base", 100));
+
+        let checkout = match figment.extract::<Checkout<D>>() {
+            Ok(checkout) => checkout,
+            Err(e) => {
+                error!("invalid `{}` database configuration", D::NAME);
+                rocket::config::pretty_print_error(e);
+                return Err(rocket);
+            }
+        };
 
-        match <D::Pool>::init(&figment).await {
-            Ok(pool) => Ok(rocket.manage(D::from(pool))),
+        let pool = match <D::Pool>::init(&figment).await {
+            Ok(pool) => pool,
             Err(e) => {
                 error!("failed to initialize database: {}", e);
-                Err(rocket)
+                return Err(rocket);
             }
+        };
+
+        match D::expected_schema_version() {
+            Some(expected) if !<D::Pool>::SUPPORTS_SCHEMA_VERSION => {
+                let pool = std::any::type_name::<D::Pool>();
+                warn!("`{}` database pool `{}` doesn't report schema versions", D::NAME, pool);
+                info_!("ignoring expected schema version {}", expected);
+            }
+            Some(expected) => {
+                let error = match pool.schema_version().await {
+                    Ok(Some(version)) if version == expected => None,
+                    Ok(Some(version)) => Some(format!("found version {}", version)),
+                    Ok(None) => Some("version is unknown".into()),
+                    Err(e) => Some(format!("failed to query version: {}", e)),
+                };
+
+                if let Some(error) = error {
+                    error!("`{}` database schema version mismatch", D::NAME);
+                    info_!("expected version {}, but {}", expected, error);
+                    pool.close().await;
+                    return Err(rocket);
+                }
+            }
+            None => { /* no version expected */ },
         }
+
+        Ok(rocket.manage(D::from(pool)).manage(checkout))
     }
 
     async fn on_shutdown(&self, rocket: &Rocket<Orbit>) {
         if let Some(db) = D::fetch(rocket) {
+            info!("closing `{}` database connection pool", D::NAME);
             db.close().await;
         }
     }
@@ -285,12 +528,10 @@ impl<'r, D: Database> FromRequest<'r> for Connection<D> {
     type Error = Option<<D::Pool as Pool>::Error>;
 
     async fn from_request(req: &'r Request<'_>) -> Outcome<Self, Self::Error> {
-        match D::fetch(req.rocket()) {
-            Some(db) => match db.get().await {
-                Ok(conn) => Outcome::Success(Connection(conn)),
-                Err(e) => Outcome::Error((Status::ServiceUnavailable, Some(e))),
-            },
-            None => Outcome::Error((Status::InternalServerError, None)),
+        match <D as Database>::get(req.rocket()).await {
+            Ok(conn) => Outcome::Success(conn),
+            Err(Some(e)) => Outcome::Error((Status::ServiceUnavailable, Some(e))),
+            Err(None) => Outcome::Error((Status::InternalServerError, None)),
         }
     }
 }
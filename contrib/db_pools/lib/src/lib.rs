@@ -137,7 +137,8 @@
 //! |----------|-----------|----------------------------------------|
 //! | MongoDB  | `mongodb` | [`mongodb::Client`]                    |
 //!
-//! Graceful shutdown is not supported.
+//! On shutdown, the client is shut down. Shutdown waits for outstanding
+//! sessions and cursors to be dropped.
 //!
 //! ## `diesel` (v2)
 //!
@@ -185,6 +186,13 @@
 //! max_connections = 1024
 //! connect_timeout = 5
 //! idle_timeout = 120
+//! connect_retries = 3
+//! connect_retry_base = 100
+//! test_on_checkout = true
+//!
+//! # This option is only supported by the `sqlx_postgres`,
+//! # `deadpool_postgres`, and `diesel_postgres` drivers.
+//! statement_timeout = 30
 //!
 //! # This option is only supported by the `sqlx_sqlite` driver.
 //! extensions = ["memvfs", "rot13"]
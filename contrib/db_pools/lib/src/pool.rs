@@ -121,6 +121,15 @@ pub trait Pool: Sized + Send + Sync + 'static {
     /// The error type returned by [`Self::init()`] and [`Self::get()`].
     type Error: std::error::Error;
 
+    /// Whether [`Self::schema_version()`] reports the database's schema
+    /// version. Defaults to `false`.
+    ///
+    /// When `false`, the [`Initializer`](crate::Initializer) fairing doesn't
+    /// check the schema version, even when
+    /// [`Database::expected_schema_version()`](crate::Database::expected_schema_version())
+    /// returns `Some`, and instead warns that the expected version is ignored.
+    const SUPPORTS_SCHEMA_VERSION: bool = false;
+
     /// Constructs a pool from a [Value](rocket::figment::value::Value).
     ///
     /// It is up to each implementor of `Pool` to define its accepted
@@ -149,7 +158,47 @@ pub trait Pool: Sized + Send + Sync + 'static {
     ///
     /// The returned future may either resolve when all connections are known to
     /// have closed or at any point prior. Details are implementation specific.
+    ///
+    /// This method is called by the [`Initializer`](crate::Initializer)
+    /// fairing when Rocket shuts down, after all requests have completed or
+    /// the shutdown grace period has elapsed.
     async fn close(&self);
+
+    /// Returns the current schema version of the database, or `None` if it
+    /// is unknown. The default implementation returns `Ok(None)`.
+    ///
+    /// This method is called by the [`Initializer`](crate::Initializer)
+    /// fairing on ignition when
+    /// [`Database::expected_schema_version()`](crate::Database::expected_schema_version())
+    /// returns `Some` and [`Self::SUPPORTS_SCHEMA_VERSION`] is `true`.
+    /// Implementations provided by this crate support this method as follows:
+    ///
+    ///   * `sqlx`: the version of the latest successfully applied migration
+    ///     recorded by `sqlx::migrate!()` in the `_sqlx_migrations` table, or
+    ///     `None` if no migration has been applied.
+    ///   * All others: unsupported.
+    ///
+    /// ## Errors
+    ///
+    /// This method returns an error if querying the version failed, such as
+    /// when the versions table does not exist.
+    async fn schema_version(&self) -> Result<Option<i64>, Self::Error> {
+        Ok(None)
+    }
+}
+
+/// Warns that `driver` ignores [`Config::statement_timeout`] if it's set.
+#[cfg(any(
+    feature = "deadpool_redis",
+    feature = "diesel_mysql",
+    feature = "sqlx_mysql",
+    feature = "sqlx_sqlite",
+    feature = "mongodb",
+))]
+fn ignore_statement_timeout(config: &Config, driver: &str) {
+    if config.statement_timeout.is_some() {
+        rocket::warn!("`statement_timeout` is not supported by `{}`: ignoring", driver);
+    }
 }
 
 #[cfg(feature = "deadpool")]
@@ -158,6 +207,9 @@ mod deadpool_postgres {
     use super::{Duration, Error, Config, Figment};
     use rocket::Either;
 
+    #[cfg(feature = "deadpool_redis")]
+    use super::ignore_statement_timeout;
+
     pub trait DeadManager: Manager + Sized + Send + Sync + 'static {
         fn new(config: &Config) -> Result<Self, Self::Error>;
     }
@@ -165,13 +217,33 @@ mod deadpool_postgres {
     #[cfg(feature = "deadpool_postgres")]
     impl DeadManager for deadpool_postgres::Manager {
         fn new(config: &Config) -> Result<Self, Self::Error> {
-            Ok(Self::new(config.url.parse()?, deadpool_postgres::tokio_postgres::NoTls))
+            use deadpool_postgres::{ManagerConfig, RecyclingMethod};
+
+            let recycling_method = match config.test_on_checkout {
+                Some(true) => RecyclingMethod::Verified,
+                Some(false) | None => RecyclingMethod::Fast,
+            };
+
+            let mut pg_config: deadpool_postgres::tokio_postgres::Config = config.url.parse()?;
+            if let Some(timeout) = config.statement_timeout {
+                let options = match pg_config.get_options() {
+                    Some(options) => format!("{} -c statement_timeout={}", options, timeout * 1000),
+                    None => format!("-c statement_timeout={}", timeout * 1000),
+                };
+
+                pg_config.options(&options);
+            }
+
+            let tls = deadpool_postgres::tokio_postgres::NoTls;
+            let manager_config = ManagerConfig { recycling_method };
+            Ok(Self::from_config(pg_config, tls, manager_config))
         }
     }
 
     #[cfg(feature = "deadpool_redis")]
     impl DeadManager for deadpool_redis::Manager {
         fn new(config: &Config) -> Result<Self, Self::Error> {
+            ignore_statement_timeout(config, "deadpool_redis");
             Self::new(config.url.as_str())
         }
     }
@@ -212,10 +284,13 @@ mod deadpool_postgres {
 #[cfg(all(feature = "deadpool_09", any(feature = "diesel_postgres", feature = "diesel_mysql")))]
 mod deadpool_old {
     use deadpool_09::{managed::{Manager, Pool, PoolError, Object, BuildError}, Runtime};
-    use diesel_async::pooled_connection::AsyncDieselConnectionManager;
+    use diesel_async::pooled_connection::{AsyncDieselConnectionManager, ManagerConfig, RecyclingMethod};
 
     use super::{Duration, Error, Config, Figment};
 
+    #[cfg(feature = "diesel_mysql")]
+    use super::ignore_statement_timeout;
+
     pub trait DeadManager: Manager + Sized + Send + Sync + 'static {
         fn new(config: &Config) -> Result<Self, Self::Error>;
     }
@@ -223,17 +298,51 @@ mod deadpool_old {
     #[cfg(feature = "diesel_postgres")]
     impl DeadManager for AsyncDieselConnectionManager<diesel_async::AsyncPgConnection> {
         fn new(config: &Config) -> Result<Self, Self::Error> {
-            Ok(Self::new(config.url.as_str()))
+            use diesel::{sql_query, result::ConnectionError};
+            use diesel_async::{AsyncConnection, AsyncPgConnection, RunQueryDsl};
+
+            let mut manager_config = manager_config(config);
+            if let Some(timeout) = config.statement_timeout {
+                let millis = Duration::from_secs(timeout).as_millis();
+                manager_config.custom_setup = Box::new(move |url| {
+                    let url = url.to_owned();
+                    Box::pin(async move {
+                        let mut conn = AsyncPgConnection::establish(&url).await?;
+                        sql_query(format!("SET statement_timeout = {}", millis))
+                            .execute(&mut conn)
+                            .await
+                            .map_err(ConnectionError::CouldntSetupConfiguration)?;
+
+                        Ok(conn)
+                    })
+                });
+            }
+
+            Ok(Self::new_with_config(config.url.as_str(), manager_config))
         }
     }
 
     #[cfg(feature = "diesel_mysql")]
     impl DeadManager for AsyncDieselConnectionManager<diesel_async::AsyncMysqlConnection> {
         fn new(config: &Config) -> Result<Self, Self::Error> {
-            Ok(Self::new(config.url.as_str()))
+            ignore_statement_timeout(config, "diesel");
+            Ok(Self::new_with_config(config.url.as_str(), manager_config(config)))
         }
     }
 
+    fn manager_config<C>(config: &Config) -> ManagerConfig<C>
+        where C: diesel_async::AsyncConnection + 'static
+    {
+        let mut manager_config = ManagerConfig::default();
+        match config.test_on_checkout {
+            Some(true) => manager_config.recycling_method = RecyclingMethod::Verified,
+            Some(false) => manager_config.recycling_method = RecyclingMethod::Fast,
+            None => { /* use the driver's default */ },
+        }
+
+        manager_config
+    }
+
     #[rocket::async_trait]
     impl<M: DeadManager, C: From<Object<M>>> crate::Pool for Pool<M, C>
         where M::Type: Send, C: Send + Sync + 'static, M::Error: std::error::Error
@@ -287,6 +396,21 @@ mod sqlx {
                     *o = std::mem::take(o).extension(ext.clone());
                 }
             }
+
+            super::ignore_statement_timeout(__config, "sqlx_sqlite");
+        }
+
+        #[cfg(feature = "sqlx_postgres")]
+        if let Some(o) = __options.downcast_mut::<sqlx::postgres::PgConnectOptions>() {
+            if let Some(timeout) = __config.statement_timeout {
+                let millis = Duration::from_secs(timeout).as_millis();
+                *o = o.clone().options([("statement_timeout", millis)]);
+            }
+        }
+
+        #[cfg(feature = "sqlx_mysql")]
+        if __options.is::<sqlx::mysql::MySqlConnectOptions>() {
+            super::ignore_statement_timeout(__config, "sqlx_mysql");
         }
     }
 
@@ -296,6 +420,8 @@ mod sqlx {
 
         type Connection = sqlx::pool::PoolConnection<D>;
 
+        const SUPPORTS_SCHEMA_VERSION: bool = true;
+
         async fn init(figment: &Figment) -> Result<Self, Self::Error> {
             let config = figment.extract::<Config>()?;
             let mut opts = config.url.parse::<Options<D>>().map_err(Error::Init)?;
@@ -309,8 +435,12 @@ mod sqlx {
                 }
             }
 
-            sqlx::pool::PoolOptions::new()
-                .max_connections(config.max_connections as u32)
+            let mut options = sqlx::pool::PoolOptions::<D>::new();
+            if let Some(test) = config.test_on_checkout {
+                options = options.test_before_acquire(test);
+            }
+
+            options.max_connections(config.max_connections as u32)
                 .acquire_timeout(Duration::from_secs(config.connect_timeout))
                 .idle_timeout(config.idle_timeout.map(Duration::from_secs))
                 .min_connections(config.min_connections.unwrap_or_default())
@@ -326,6 +456,37 @@ mod sqlx {
         async fn close(&self) {
             <sqlx::Pool<D>>::close(self).await;
         }
+
+        async fn schema_version(&self) -> Result<Option<i64>, Self::Error> {
+            schema_version(self).await.map_err(Error::Get)
+        }
+    }
+
+    // Queries the version of the latest migration applied by `sqlx::migrate!`.
+    async fn schema_version(__pool: &(dyn std::any::Any + Sync)) -> Result<Option<i64>, sqlx::Error> {
+        #[cfg(any(
+            feature = "sqlx_postgres",
+            feature = "sqlx_mysql",
+            feature = "sqlx_sqlite",
+        ))]
+        const QUERY: &str = "SELECT MAX(version) FROM _sqlx_migrations WHERE success = TRUE";
+
+        #[cfg(feature = "sqlx_postgres")]
+        if let Some(pool) = __pool.downcast_ref::<sqlx::PgPool>() {
+            return sqlx::query_scalar(QUERY).fetch_one(pool).await;
+        }
+
+        #[cfg(feature = "sqlx_mysql")]
+        if let Some(pool) = __pool.downcast_ref::<sqlx::MySqlPool>() {
+            return sqlx::query_scalar(QUERY).fetch_one(pool).await;
+        }
+
+        #[cfg(feature = "sqlx_sqlite")]
+        if let Some(pool) = __pool.downcast_ref::<sqlx::SqlitePool>() {
+            return sqlx::query_scalar(QUERY).fetch_one(pool).await;
+        }
+
+        Ok(None)
     }
 }
 
@@ -342,6 +503,7 @@ mod mongodb {
 
         async fn init(figment: &Figment) -> Result<Self, Self::Error> {
             let config = figment.extract::<Config>()?;
+            super::ignore_statement_timeout(&config, "mongodb");
             let mut opts = ClientOptions::parse(&config.url).await.map_err(Error::Init)?;
             opts.min_pool_size = config.min_connections;
             opts.max_pool_size = Some(config.max_connections as u32);
@@ -356,7 +518,8 @@ mod mongodb {
         }
 
         async fn close(&self) {
-            // nothing to do for mongodb
+            // Waits for outstanding sessions and cursors to be dropped.
+            self.clone().shutdown().await;
         }
     }
 }
@@ -13,6 +13,21 @@ macro_rules! check_types_match {
             fn _db(conn: Connection<Db>) {
                 let _: &$Conn = &*conn;
             }
+
+            #[derive(Database)]
+            #[database("bar", conn = "BarConn", schema_version = 7)]
+            struct Bar($Pool);
+
+            #[get("/bar")]
+            fn _bar(conn: BarConn) {
+                let _: Connection<Bar> = conn;
+            }
+
+            #[test]
+            fn expected_schema_version() {
+                assert_eq!(Db::expected_schema_version(), None);
+                assert_eq!(Bar::expected_schema_version(), Some(7));
+            }
         }
     )
 }
@@ -0,0 +1,159 @@
+use std::fmt;
+use std::time::{Duration, Instant};
+use std::sync::atomic::{AtomicU32, Ordering};
+
+use rocket::{Build, Rocket};
+use rocket::figment::Figment;
+use rocket_db_pools::{Database, Pool};
+
+/// A pool whose first `failures` checkouts fail.
+struct MockPool {
+    failures: u32,
+    attempts: AtomicU32,
+}
+
+#[derive(Debug)]
+struct MockError(u32);
+
+impl fmt::Display for MockError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "checkout {} failed", self.0)
+    }
+}
+
+impl std::error::Error for MockError { }
+
+#[rocket::async_trait]
+impl Pool for MockPool {
+    type Connection = u32;
+
+    type Error = MockError;
+
+    async fn init(figment: &Figment) -> Result<Self, Self::Error> {
+        let failures = figment.extract_inner("failures").unwrap_or(0);
+        Ok(MockPool { failures, attempts: AtomicU32::new(0) })
+    }
+
+    async fn get(&self) -> Result<Self::Connection, Self::Error> {
+        let attempt = self.attempts.fetch_add(1, Ordering::SeqCst);
+        match attempt < self.failures {
+            true => Err(MockError(attempt)),
+            false => Ok(attempt),
+        }
+    }
+
+    async fn close(&self) { }
+}
+
+#[derive(Database)]
+#[database("mock")]
+struct Db(MockPool);
+
+fn mock_rocket(failures: u32, retries: u32, base: u64) -> Rocket<Build> {
+    let figment = rocket::Config::figment()
+        .merge(("databases.mock.url", "mock://"))
+        .merge(("databases.mock.failures", failures))
+        .merge(("databases.mock.connect_retries", retries))
+        .merge(("databases.mock.connect_retry_base", base));
+
+    rocket::custom(figment).attach(Db::init())
+}
+
+fn attempts<P: rocket::Phase>(rocket: &Rocket<P>) -> u32 {
+    Db::fetch(rocket).unwrap().attempts.load(Ordering::SeqCst)
+}
+
+#[rocket::async_test]
+async fn checkout_without_retries_fails_immediately() {
+    let rocket = mock_rocket(1, 0, 1).ignite().await.unwrap();
+    let error = Db::get(&rocket).await.err().expect("checkout fails");
+    assert_eq!(error.unwrap().0, 0);
+    assert_eq!(attempts(&rocket), 1);
+}
+
+#[rocket::async_test]
+async fn checkout_retries_transient_failures() {
+    let rocket = mock_rocket(2, 3, 1).ignite().await.unwrap();
+    let conn = Db::get(&rocket).await.ok().expect("checkout succeeds");
+    assert_eq!(*conn, 2);
+    assert_eq!(attempts(&rocket), 3);
+}
+
+#[rocket::async_test]
+async fn checkout_gives_up_after_retries() {
+    let rocket = mock_rocket(5, 2, 1).ignite().await.unwrap();
+    let error = Db::get(&rocket).await.err().expect("checkout fails");
+    assert_eq!(error.unwrap().0, 2);
+    assert_eq!(attempts(&rocket), 3);
+}
+
+#[rocket::async_test]
+async fn checkout_backs_off_exponentially() {
+    // Retries wait 20ms, then 40ms, then 80ms: at least 140ms in total.
+    let rocket = mock_rocket(3, 3, 20).ignite().await.unwrap();
+    let start = Instant::now();
+    let conn = Db::get(&rocket).await.ok().expect("checkout succeeds");
+    assert_eq!(*conn, 3);
+    assert!(start.elapsed() >= Duration::from_millis(140));
+
+    // Without a base delay, retries are immediate.
+    let rocket = mock_rocket(3, 3, 0).ignite().await.unwrap();
+    let start = Instant::now();
+    assert!(Db::get(&rocket).await.is_ok());
+    assert!(start.elapsed() < Duration::from_millis(140));
+}
+
+#[test]
+fn connection_guard_retries() {
+    use rocket::get;
+    use rocket::http::Status;
+    use rocket::local::blocking::Client;
+    use rocket_db_pools::Connection;
+
+    #[get("/")]
+    fn index(conn: Connection<Db>) -> String {
+        conn.to_string()
+    }
+
+    let client = Client::debug(mock_rocket(2, 2, 1).mount("/", rocket::routes![index])).unwrap();
+    let response = client.get("/").dispatch();
+    assert_eq!(response.status(), Status::Ok);
+    assert_eq!(response.into_string().unwrap(), "2");
+
+    let client = Client::debug(mock_rocket(3, 2, 1).mount("/", rocket::routes![index])).unwrap();
+    let response = client.get("/").dispatch();
+    assert_eq!(response.status(), Status::ServiceUnavailable);
+}
+
+#[rocket::async_test]
+async fn get_outside_of_request() {
+    let rocket = mock_rocket(0, 0, 1).ignite().await.unwrap();
+    let conn = Db::get(&rocket).await.ok().expect("checkout succeeds");
+    assert_eq!(*conn, 0);
+
+    let conn = Db::get(&rocket).await.ok().expect("checkout succeeds");
+    assert_eq!(*conn, 1);
+}
+
+#[rocket::async_test]
+async fn get_from_unattached_database_fails() {
+    let rocket = rocket::build().ignite().await.unwrap();
+    let error = Db::get(&rocket).await.err().expect("checkout fails");
+    assert!(error.is_none());
+
+    // A database initialized in another instance isn't found either.
+    let _other = mock_rocket(0, 0, 1).ignite().await.unwrap();
+    assert!(Db::get(&rocket).await.err().expect("checkout fails").is_none());
+}
+
+#[rocket::async_test]
+async fn unsupported_schema_version_is_ignored() {
+    #[derive(Database)]
+    #[database("mock", schema_version = 7)]
+    struct Versioned(MockPool);
+
+    assert!(!MockPool::SUPPORTS_SCHEMA_VERSION);
+    let figment = rocket::Config::figment().merge(("databases.mock.url", "mock://"));
+    let rocket = rocket::custom(figment).attach(Versioned::init()).ignite().await;
+    assert!(rocket.is_ok(), "launch aborted on unsupported schema version");
+}
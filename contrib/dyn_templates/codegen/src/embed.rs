@@ -0,0 +1,52 @@
+use std::path::{Path, PathBuf};
+use std::error::Error;
+
+use devise::ext::SpanDiagnosticExt;
+
+use crate::syn::{self, LitStr};
+use crate::proc_macro2::TokenStream;
+
+/// The extensions of all supported template engines.
+const ENGINE_EXTENSIONS: &[&str] = &["tera", "hbs", "j2"];
+
+pub fn include_templates(input: proc_macro::TokenStream) -> devise::Result<TokenStream> {
+    let dir = syn::parse::<LitStr>(input)?;
+    let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").expect("MANIFEST_DIR");
+    let root = Path::new(&manifest_dir).join(dir.value());
+
+    let mut files = vec![];
+    collect(&root, &mut files)
+        .map_err(|e| dir.span().error(format!("failed to read '{}': {}", root.display(), e)))?;
+
+    files.sort();
+    let entries = files.iter().map(|path| {
+        let name = path.strip_prefix(&root).unwrap()
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+
+        let full_path = path.display().to_string();
+        quote_spanned!(dir.span() => (#name, include_str!(#full_path)))
+    });
+
+    let root = root.display().to_string();
+    Ok(quote_spanned!(dir.span() => {
+        const FILES: &[(&str, &str)] = &[#(#entries),*];
+        ::rocket_dyn_templates::EmbeddedTemplates::new(#root, FILES)
+    }))
+}
+
+/// Recursively collects the paths to all template files in `dir`.
+fn collect(dir: &Path, files: &mut Vec<PathBuf>) -> Result<(), Box<dyn Error>> {
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect(&path, files)?;
+        } else if path.extension().map_or(false, |e| ENGINE_EXTENSIONS.iter().any(|x| e == *x)) {
+            files.push(path);
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,31 @@
+//! Code generation for rocket-dyn-templates.
+
+#![recursion_limit="256"]
+#![warn(rust_2018_idioms)]
+
+#[macro_use]
+extern crate quote;
+
+mod embed;
+
+use devise::{syn, proc_macro2};
+use proc_macro::TokenStream;
+
+/// Embeds every template in a directory into the binary.
+///
+/// The macro is invoked with a single string literal: the path to the template
+/// directory, relative to the crate's `Cargo.toml`. It expands to an
+/// expression of type `EmbeddedTemplates` containing the contents of every
+/// file in the directory, recursively, with a known template engine
+/// extension. Template names and content types are derived from the file
+/// paths exactly as they are for templates discovered at runtime.
+///
+/// Files are embedded via `include_str!`, so changes to existing files are
+/// tracked by `cargo`. Adding or removing files, however, is not detected;
+/// changing the crate's source or running `cargo clean` forces re-expansion.
+#[proc_macro]
+pub fn include_templates(input: TokenStream) -> TokenStream {
+    crate::embed::include_templates(input)
+        .unwrap_or_else(|diag| diag.emit_as_expr_tokens())
+        .into()
+}
@@ -5,6 +5,9 @@ use std::error::Error;
 use crate::engine::Engines;
 use crate::template::TemplateInfo;
 
+#[cfg(feature = "embedded")]
+use crate::EmbeddedTemplates;
+
 use rocket::http::ContentType;
 use normpath::PathExt;
 
@@ -18,6 +21,11 @@ pub(crate) struct Context {
     pub templates: HashMap<String, TemplateInfo>,
     /// Loaded template engines
     pub engines: Engines,
+    /// Whether the templates were embedded, and thus `root` is informational.
+    pub embedded: bool,
+    /// The configured `Content-Type` of every template, if any, in place of
+    /// the `Content-Type` inferred from a template's extension.
+    pub content_type: Option<ContentType>,
 }
 
 pub(crate) use self::manager::ContextManager;
@@ -50,30 +58,43 @@ impl Context {
                 };
 
                 let (name, data_type_str) = split_path(&root, entry.path());
-                if let Some(info) = templates.get(&*name) {
-                    warn_!("Template name '{}' does not have a unique source.", name);
-                    match info.path {
-                        Some(ref path) => info_!("Existing path: {:?}", path),
-                        None => info_!("Existing Content-Type: {}", info.data_type),
-                    }
-
-                    info_!("Additional path: {:?}", entry.path());
-                    warn_!("Keeping existing template '{}'.", name);
+                add_template(&mut templates, name, data_type_str, entry.into_path(), None, ext);
+            }
+        }
+
+        Context::finish(root, false, templates, callback)
+    }
+
+    /// Initialize the `embedded` templates using the relevant template engine
+    /// and store all of the initialized state in a `Context` structure, which
+    /// is returned if all goes well. Never touches the filesystem.
+    #[cfg(feature = "embedded")]
+    pub fn embedded(embedded: &EmbeddedTemplates, callback: &Callback) -> Option<Context> {
+        let mut templates: HashMap<String, TemplateInfo> = HashMap::new();
+        for &ext in Engines::ENABLED_EXTENSIONS {
+            for &(file, contents) in embedded.files {
+                let path = Path::new(file);
+                if path.extension().map_or(true, |e| e != ext) {
                     continue;
                 }
 
-                let data_type = data_type_str.as_ref()
-                    .and_then(|ext| ContentType::from_extension(ext))
-                    .unwrap_or(ContentType::Text);
-
-                templates.insert(name, TemplateInfo {
-                    path: Some(entry.into_path()),
-                    engine_ext: ext,
-                    data_type,
-                });
+                let (name, data_type_str) = split_path(Path::new(""), path);
+                let path = path.to_path_buf();
+                add_template(&mut templates, name, data_type_str, path, Some(contents), ext);
             }
         }
 
+        Context::finish(embedded.root.into(), true, templates, callback)
+    }
+
+    /// Initializes the engines with `templates`, runs the user's `callback`,
+    /// and records any templates registered by the callback.
+    fn finish(
+        root: PathBuf,
+        embedded: bool,
+        mut templates: HashMap<String, TemplateInfo>,
+        callback: &Callback,
+    ) -> Option<Context> {
         let mut engines = Engines::init(&templates)?;
         if let Err(e) = callback(&mut engines) {
             error_!("Template customization callback failed.");
@@ -88,15 +109,50 @@ impl Context {
                     .and_then(ContentType::from_extension)
                     .unwrap_or(ContentType::Text);
 
-                let info = TemplateInfo { path: None, engine_ext, data_type };
+                let info = TemplateInfo { path: None, contents: None, engine_ext, data_type };
                 templates.insert(name.to_string(), info);
             }
         }
 
-        Some(Context { root, templates, engines })
+        Some(Context { root, templates, engines, embedded, content_type: None })
+    }
+
+    /// The `Content-Type` of the template described by `info`: the configured
+    /// `Content-Type`, if any, or else the one inferred from its extension.
+    pub fn content_type(&self, info: &TemplateInfo) -> ContentType {
+        self.content_type.clone().unwrap_or_else(|| info.data_type.clone())
     }
 }
 
+/// Adds the template `name` at `path` to `templates` unless a template with
+/// the same name already exists, in which case a warning is emitted.
+fn add_template(
+    templates: &mut HashMap<String, TemplateInfo>,
+    name: String,
+    data_type_str: Option<String>,
+    path: PathBuf,
+    contents: Option<&'static str>,
+    engine_ext: &'static str,
+) {
+    if let Some(info) = templates.get(&*name) {
+        warn_!("Template name '{}' does not have a unique source.", name);
+        match info.path {
+            Some(ref path) => info_!("Existing path: {:?}", path),
+            None => info_!("Existing Content-Type: {}", info.data_type),
+        }
+
+        info_!("Additional path: {:?}", path);
+        warn_!("Keeping existing template '{}'.", name);
+        return;
+    }
+
+    let data_type = data_type_str.as_ref()
+        .and_then(|ext| ContentType::from_extension(ext))
+        .unwrap_or(ContentType::Text);
+
+    templates.insert(name, TemplateInfo { path: Some(path), contents, engine_ext, data_type });
+}
+
 #[cfg(not(debug_assertions))]
 mod manager {
     use std::ops::Deref;
@@ -142,6 +198,10 @@ mod manager {
 
     impl ContextManager {
         pub fn new(ctxt: Context) -> ContextManager {
+            if ctxt.embedded {
+                return ContextManager { watcher: None, context: RwLock::new(ctxt) };
+            }
+
             let (tx, rx) = channel();
             let watcher = recommended_watcher(tx).and_then(|mut watcher| {
                 watcher.watch(&ctxt.root.canonicalize()?, RecursiveMode::Recursive)?;
@@ -184,7 +244,8 @@ mod manager {
             if let Some(true) = templates_changes {
                 info_!("Change detected: reloading templates.");
                 let root = self.context().root.clone();
-                if let Some(new_ctxt) = Context::initialize(&root, callback) {
+                if let Some(mut new_ctxt) = Context::initialize(&root, callback) {
+                    new_ctxt.content_type = self.context().content_type.clone();
                     *self.context_mut() = new_ctxt;
                 } else {
                     warn_!("An error occurred while reloading templates.");
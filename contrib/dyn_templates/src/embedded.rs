@@ -0,0 +1,36 @@
+/// Templates embedded into the binary by [`include_templates!`].
+///
+/// A value of this type can only be created via [`include_templates!`]. It is
+/// passed to [`Template::embedded()`](crate::Template::embedded()) or
+/// [`Template::embedded_custom()`](crate::Template::embedded_custom()).
+///
+/// [`include_templates!`]: crate::include_templates!
+#[derive(Debug, Clone, Copy)]
+pub struct EmbeddedTemplates {
+    pub(crate) root: &'static str,
+    pub(crate) files: &'static [(&'static str, &'static str)],
+}
+
+impl EmbeddedTemplates {
+    #[doc(hidden)]
+    pub const fn new(root: &'static str, files: &'static [(&'static str, &'static str)]) -> Self {
+        EmbeddedTemplates { root, files }
+    }
+
+    /// Returns an iterator over the paths, relative to the embedded directory,
+    /// of the embedded template files.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "embedded")] {
+    /// use rocket_dyn_templates::include_templates;
+    ///
+    /// let templates = include_templates!("tests/templates");
+    /// assert!(templates.files().any(|f| f == "hbs/test.html.hbs"));
+    /// # }
+    /// ```
+    pub fn files(&self) -> impl Iterator<Item = &'static str> {
+        self.files.iter().map(|(path, _)| *path)
+    }
+}
@@ -1,21 +1,25 @@
-use std::path::Path;
-
-use handlebars::Handlebars;
+use handlebars::{Handlebars, Helper, HelperDef, HelperResult};
+use handlebars::{Context, RenderContext, Output, JsonRender};
 use rocket::serde::Serialize;
 
-use crate::engine::Engine;
+use crate::engine::{Engine, Source, RawHelper, EscapeFn};
 
 impl Engine for Handlebars<'static> {
     const EXT: &'static str = "hbs";
 
-    fn init<'a>(templates: impl Iterator<Item = (&'a str, &'a Path)>) -> Option<Self> {
+    fn init<'a>(templates: impl Iterator<Item = (&'a str, Source<'a>)>) -> Option<Self> {
         let mut hb = Handlebars::new();
         let mut ok = true;
-        for (name, path) in templates {
-            if let Err(e) = hb.register_template_file(name, path) {
+        for (name, source) in templates {
+            let result = match source {
+                Source::File(path) => hb.register_template_file(name, path),
+                Source::Embedded(_, contents) => hb.register_template_string(name, contents),
+            };
+
+            if let Err(e) = result {
                 error!("Handlebars template '{}' failed to register.", name);
                 error_!("{}", e);
-                info_!("Template path: '{}'.", path.to_string_lossy());
+                info_!("Template path: '{}'.", source.path().to_string_lossy());
                 ok = false;
             }
         }
@@ -33,4 +37,29 @@ impl Engine for Handlebars<'static> {
             .map_err(|e| error_!("Handlebars: {}", e))
             .ok()
     }
+
+    fn add_raw_helper(&mut self, name: &'static str, helper: RawHelper) {
+        self.register_helper(name, Box::new(RawHelperDef(helper)));
+    }
+
+    fn escape_with(&mut self, escape: EscapeFn) {
+        self.register_escape_fn(escape);
+    }
+}
+
+struct RawHelperDef(RawHelper);
+
+impl HelperDef for RawHelperDef {
+    fn call<'reg: 'rc, 'rc>(
+        &self,
+        h: &Helper<'rc>,
+        _: &'reg Handlebars<'reg>,
+        _: &'rc Context,
+        _: &mut RenderContext<'reg, 'rc>,
+        out: &mut dyn Output,
+    ) -> HelperResult {
+        let value = h.param(0).map(|p| p.value().render()).unwrap_or_default();
+        out.write(&(self.0)(&value))?;
+        Ok(())
+    }
 }
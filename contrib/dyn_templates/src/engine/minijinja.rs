@@ -1,27 +1,34 @@
 use std::sync::Arc;
-use std::path::Path;
 use std::collections::HashMap;
 
 use rocket::serde::Serialize;
 use minijinja::{Environment, Error, ErrorKind, AutoEscape};
+use minijinja::value::Value;
 
-use crate::engine::Engine;
+use crate::engine::{Engine, Source, RawHelper, EscapeFn};
 
 impl Engine for Environment<'static> {
     const EXT: &'static str = "j2";
 
-    fn init<'a>(templates: impl Iterator<Item = (&'a str, &'a Path)>) -> Option<Self> {
+    fn init<'a>(templates: impl Iterator<Item = (&'a str, Source<'a>)>) -> Option<Self> {
         let _templates = Arc::new(templates
-            .map(|(k, p)| (k.to_owned(), p.to_owned()))
+            .map(|(k, s)| match s {
+                Source::File(p) => (k.to_owned(), (p.to_owned(), None)),
+                Source::Embedded(p, contents) => (k.to_owned(), (p.to_owned(), Some(contents))),
+            })
             .collect::<HashMap<_, _>>());
 
         let templates = _templates.clone();
         let mut env = Environment::new();
         env.set_loader(move |name| {
-            let Some(path) = templates.get(name) else {
+            let Some((path, embedded)) = templates.get(name) else {
                 return Ok(None);
             };
 
+            if let Some(contents) = embedded {
+                return Ok(Some(contents.to_string()));
+            }
+
             match std::fs::read_to_string(path) {
                 Ok(result) => Ok(Some(result)),
                 Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
@@ -34,7 +41,7 @@ impl Engine for Environment<'static> {
         let templates = _templates.clone();
         env.set_auto_escape_callback(move |name| {
             templates.get(name)
-                .and_then(|path| path.to_str())
+                .and_then(|(path, _)| path.to_str())
                 .map(minijinja::default_auto_escape_callback)
                 .unwrap_or(AutoEscape::None)
         });
@@ -52,4 +59,22 @@ impl Engine for Environment<'static> {
             .map_err(|e| error_!("Minijinja: {}", e))
             .ok()
     }
+
+    fn add_raw_helper(&mut self, name: &'static str, helper: RawHelper) {
+        self.add_filter(name, move |value: Value| {
+            Value::from_safe_string(helper(&value.to_string()))
+        });
+    }
+
+    fn escape_with(&mut self, escape: EscapeFn) {
+        self.set_formatter(move |out, state, value| {
+            let escaped = !matches!(state.auto_escape(), AutoEscape::None);
+            if !escaped || value.is_safe() || value.is_undefined() || value.is_none() {
+                return minijinja::escape_formatter(out, state, value);
+            }
+
+            out.write_str(&escape(&value.to_string()))
+                .map_err(|e| Error::new(ErrorKind::WriteFailure, "write failed").with_source(e))
+        });
+    }
 }
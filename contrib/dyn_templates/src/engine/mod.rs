@@ -1,7 +1,9 @@
 use std::path::Path;
+use std::sync::Arc;
 use std::collections::HashMap;
 
 use rocket::serde::Serialize;
+use rocket::figment::{self, value::{Dict, Tag, Value}};
 
 use crate::template::TemplateInfo;
 
@@ -23,8 +25,35 @@ use ::minijinja::Environment;
 pub(crate) trait Engine: Send + Sync + Sized + 'static {
     const EXT: &'static str;
 
-    fn init<'a>(templates: impl Iterator<Item = (&'a str, &'a Path)>) -> Option<Self>;
+    fn init<'a>(templates: impl Iterator<Item = (&'a str, Source<'a>)>) -> Option<Self>;
     fn render<C: Serialize>(&self, name: &str, context: C) -> Option<String>;
+    fn add_raw_helper(&mut self, name: &'static str, helper: RawHelper);
+    fn escape_with(&mut self, escape: EscapeFn);
+}
+
+/// A helper which transforms a value into a string that is emitted unescaped.
+pub(crate) type RawHelper = Arc<dyn Fn(&str) -> String + Send + Sync + 'static>;
+
+/// A function which escapes a string for output.
+pub(crate) type EscapeFn = fn(&str) -> String;
+
+/// The source of a template's contents.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Source<'a> {
+    /// The template is read from the file at this path.
+    File(&'a Path),
+    /// The template was embedded from the file at this relative path and has
+    /// these contents.
+    Embedded(&'a Path, &'static str),
+}
+
+impl Source<'_> {
+    /// The path to the template's file.
+    pub fn path(&self) -> &Path {
+        match self {
+            Source::File(path) | Source::Embedded(path, _) => path,
+        }
+    }
 }
 
 /// A structure exposing access to templating engines.
@@ -89,6 +118,9 @@ pub struct Engines {
     /// mismatches.
     #[cfg(feature = "minijinja")]
     pub minijinja: Environment<'static>,
+
+    /// Global variables added to the context of every rendered template.
+    globals: Dict,
 }
 
 impl Engines {
@@ -102,8 +134,7 @@ impl Engines {
         fn inner<E: Engine>(templates: &HashMap<String, TemplateInfo>) -> Option<E> {
             let named_templates = templates.iter()
                 .filter(|&(_, i)| i.engine_ext == E::EXT)
-                .filter_map(|(k, i)| Some((k.as_str(), i.path.as_ref()?)))
-                .map(|(k, p)| (k, p.as_path()));
+                .filter_map(|(k, i)| Some((k.as_str(), i.source()?)));
 
             E::init(named_templates)
         }
@@ -124,15 +155,286 @@ impl Engines {
                 Some(hb) => hb,
                 None => return None
             },
+            globals: Dict::new(),
         })
     }
 
-    pub(crate) fn render<C: Serialize>(
+    /// Adds a global variable named `name` with value `value` to the context
+    /// of every template rendered by any engine.
+    ///
+    /// If a template's context is a map, globals are merged into it, with
+    /// values in the context taking precedence over globals of the same name.
+    /// If the context is empty, as with `()`, the globals form the context.
+    /// Otherwise, globals are ignored. Adding a global with the same name as an
+    /// existing global replaces it.
+    ///
+    /// Returns an error if `value` fails to serialize.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket_dyn_templates::{Template, Engines};
+    ///
+    /// # fn main() {
+    /// rocket::build()
+    ///     // ...
+    ///     .attach(Template::try_custom(|engines: &mut Engines| {
+    ///         engines.add_global("site_name", "My Site")?;
+    ///         engines.add_global("version", env!("CARGO_PKG_VERSION"))?;
+    ///         Ok(())
+    ///     }))
+    ///     // ...
+    ///     # ;
+    /// # }
+    /// ```
+    pub fn add_global<N, V>(&mut self, name: N, value: V) -> Result<&mut Self, figment::Error>
+        where N: Into<String>, V: Serialize
+    {
+        self.globals.insert(name.into(), Value::serialize(value)?);
+        Ok(self)
+    }
+
+    /// Registers the Tera function `function` as `name`. Equivalent to
+    /// `self.tera.register_function(name, function)`.
+    ///
+    /// This method is only available when the `tera` feature is enabled. See
+    /// [`register_handlebars_helper()`](Self::register_handlebars_helper()) and
+    /// [`register_minijinja_function()`](Self::register_minijinja_function())
+    /// for the equivalents for the other engines.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "tera")] {
+    /// use std::collections::HashMap;
+    ///
+    /// use rocket_dyn_templates::{Template, Engines};
+    /// use rocket_dyn_templates::tera::{self, Value};
+    ///
+    /// fn shout(args: &HashMap<String, Value>) -> tera::Result<Value> {
+    ///     let text = args.get("text").and_then(|v| v.as_str()).unwrap_or("");
+    ///     Ok(Value::from(text.to_uppercase()))
+    /// }
+    ///
+    /// fn main() {
+    ///     rocket::build()
+    ///         // ...
+    ///         .attach(Template::custom(|engines: &mut Engines| {
+    ///             engines.register_tera_function("shout", shout);
+    ///         }))
+    ///         // ...
+    ///         # ;
+    /// }
+    /// # }
+    /// ```
+    #[cfg(feature = "tera")]
+    #[cfg_attr(nightly, doc(cfg(feature = "tera")))]
+    pub fn register_tera_function<F>(&mut self, name: &str, function: F) -> &mut Self
+        where F: ::tera::Function + 'static
+    {
+        self.tera.register_function(name, function);
+        self
+    }
+
+    /// Registers the Handlebars helper `helper` as `name`. Equivalent to
+    /// `self.handlebars.register_helper(name, Box::new(helper))`.
+    ///
+    /// This method is only available when the `handlebars` feature is enabled.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "handlebars")] {
+    /// use rocket_dyn_templates::{Template, Engines};
+    /// use rocket_dyn_templates::handlebars::{
+    ///     Helper, Handlebars, Context, RenderContext, Output, HelperResult
+    /// };
+    ///
+    /// fn shout(
+    ///     h: &Helper<'_>,
+    ///     _: &Handlebars,
+    ///     _: &Context,
+    ///     _: &mut RenderContext<'_, '_>,
+    ///     out: &mut dyn Output,
+    /// ) -> HelperResult {
+    ///     if let Some(text) = h.param(0).and_then(|p| p.value().as_str()) {
+    ///         out.write(&text.to_uppercase())?;
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    ///
+    /// fn main() {
+    ///     rocket::build()
+    ///         // ...
+    ///         .attach(Template::custom(|engines: &mut Engines| {
+    ///             engines.register_handlebars_helper("shout", shout);
+    ///         }))
+    ///         // ...
+    ///         # ;
+    /// }
+    /// # }
+    /// ```
+    #[cfg(feature = "handlebars")]
+    #[cfg_attr(nightly, doc(cfg(feature = "handlebars")))]
+    pub fn register_handlebars_helper<H>(&mut self, name: &str, helper: H) -> &mut Self
+        where H: ::handlebars::HelperDef + Send + Sync + 'static
+    {
+        self.handlebars.register_helper(name, Box::new(helper));
+        self
+    }
+
+    /// Registers the minijinja function `function` as `name`. Equivalent to
+    /// `self.minijinja.add_function(name, function)`.
+    ///
+    /// This method is only available when the `minijinja` feature is enabled.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "minijinja")] {
+    /// use rocket_dyn_templates::{Template, Engines};
+    ///
+    /// fn shout(text: String) -> String {
+    ///     text.to_uppercase()
+    /// }
+    ///
+    /// fn main() {
+    ///     rocket::build()
+    ///         // ...
+    ///         .attach(Template::custom(|engines: &mut Engines| {
+    ///             engines.register_minijinja_function("shout", shout);
+    ///         }))
+    ///         // ...
+    ///         # ;
+    /// }
+    /// # }
+    /// ```
+    #[cfg(feature = "minijinja")]
+    #[cfg_attr(nightly, doc(cfg(feature = "minijinja")))]
+    pub fn register_minijinja_function<F, Rv, Args>(
+        &mut self,
+        name: &'static str,
+        function: F,
+    ) -> &mut Self
+        where F: ::minijinja::functions::Function<Rv, Args> + Send + Sync + 'static,
+              Rv: ::minijinja::value::FunctionResult,
+              Args: for<'a> ::minijinja::value::FunctionArgs<'a>,
+    {
+        self.minijinja.add_function(name, function);
+        self
+    }
+
+    /// Registers `helper` as a helper named `name` in every engine. The helper
+    /// is passed its argument as a string and returns a string which is
+    /// emitted _as-is_, without escaping, even in templates that are otherwise
+    /// autoescaped.
+    ///
+    /// Each engine invokes the helper in the way idiomatic for that engine:
+    ///
+    ///   * **Tera:** as a filter marked safe: `{{ value | name }}`
+    ///   * **Handlebars:** as a helper: `{{name value}}`
+    ///   * **MiniJinja:** as a filter returning a safe string: `{{ value | name }}`
+    ///
+    /// The helper must itself ensure that any untrusted data it emits is
+    /// appropriately escaped.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket_dyn_templates::{Template, Engines};
+    ///
+    /// # fn main() {
+    /// rocket::build()
+    ///     // ...
+    ///     .attach(Template::custom(|engines: &mut Engines| {
+    ///         // `{{ name | wow }}` or `{{wow name}}` renders `<b><i>name</i></b>`.
+    ///         engines.register_raw_helper("wow", |value| {
+    ///             format!("<b><i>{}</i></b>", value)
+    ///         });
+    ///     }))
+    ///     // ...
+    ///     # ;
+    /// # }
+    /// ```
+    pub fn register_raw_helper<F>(&mut self, name: &'static str, helper: F) -> &mut Self
+        where F: Fn(&str) -> String + Send + Sync + 'static
+    {
+        let _helper: RawHelper = Arc::new(helper);
+
+        #[cfg(feature = "tera")]
+        Engine::add_raw_helper(&mut self.tera, name, _helper.clone());
+
+        #[cfg(feature = "handlebars")]
+        Engine::add_raw_helper(&mut self.handlebars, name, _helper.clone());
+
+        #[cfg(feature = "minijinja")]
+        Engine::add_raw_helper(&mut self.minijinja, name, _helper.clone());
+
+        self
+    }
+
+    /// Sets the function used by every engine to escape values in autoescaped
+    /// templates to `escape`.
+    ///
+    /// By default, each engine uses its own HTML escaping function. The
+    /// functions differ slightly in which characters they escape; setting a
+    /// single function with this method makes escaping uniform across engines.
+    /// Values marked safe, including the output of helpers registered via
+    /// [`register_raw_helper()`](Self::register_raw_helper()), are never passed
+    /// to `escape`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket_dyn_templates::{Template, Engines};
+    ///
+    /// fn escape(string: &str) -> String {
+    ///     string.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;")
+    /// }
+    ///
+    /// # fn main() {
+    /// rocket::build()
+    ///     // ...
+    ///     .attach(Template::custom(|engines: &mut Engines| {
+    ///         engines.register_escape_fn(escape);
+    ///     }))
+    ///     // ...
+    ///     # ;
+    /// # }
+    /// ```
+    pub fn register_escape_fn(&mut self, escape: fn(&str) -> String) -> &mut Self {
+        #[cfg(feature = "tera")]
+        Engine::escape_with(&mut self.tera, escape);
+
+        #[cfg(feature = "handlebars")]
+        Engine::escape_with(&mut self.handlebars, escape);
+
+        #[cfg(feature = "minijinja")]
+        Engine::escape_with(&mut self.minijinja, escape);
+
+        self
+    }
+
+    pub(crate) fn render(
         &self,
         name: &str,
         info: &TemplateInfo,
-        context: C,
+        context: Value,
     ) -> Option<String> {
+        let context = match context {
+            _ if self.globals.is_empty() => context,
+            Value::Dict(tag, mut dict) => {
+                for (key, value) in &self.globals {
+                    dict.entry(key.clone()).or_insert_with(|| value.clone());
+                }
+
+                Value::Dict(tag, dict)
+            }
+            Value::Empty(..) => Value::Dict(Tag::Default, self.globals.clone()),
+            context => context,
+        };
+
         #[cfg(feature = "tera")] {
             if info.engine_ext == Tera::EXT {
                 return Engine::render(&self.tera, name, context);
@@ -1,26 +1,45 @@
-use std::path::Path;
 use std::error::Error;
+use std::collections::HashMap;
 
-use tera::{Context, Tera};
+use tera::{Context, Tera, Value, Filter};
 use rocket::serde::Serialize;
 
-use crate::engine::Engine;
+use crate::engine::{Engine, Source, RawHelper, EscapeFn};
 
 impl Engine for Tera {
     const EXT: &'static str = "tera";
 
-    fn init<'a>(templates: impl Iterator<Item = (&'a str, &'a Path)>) -> Option<Self> {
+    fn init<'a>(templates: impl Iterator<Item = (&'a str, Source<'a>)>) -> Option<Self> {
         // Create the Tera instance.
         let mut tera = Tera::default();
         let ext = [".html.tera", ".htm.tera", ".xml.tera", ".html", ".htm", ".xml"];
-        tera.autoescape_on(ext.to_vec());
+        let mut autoescape = ext.to_vec();
 
-        // Collect into a tuple of (name, path) for Tera. If we register one at
-        // a time, it will complain about unregistered base templates.
-        let files = templates.map(|(name, path)| (path, Some(name)));
+        // Collect into tuples of (name, path) and (name, contents) for Tera. If
+        // we register one at a time, it will complain about unregistered base
+        // templates.
+        let (mut files, mut raw) = (vec![], vec![]);
+        for (name, source) in templates {
+            match source {
+                Source::File(path) => files.push((path, Some(name))),
+                Source::Embedded(path, contents) => {
+                    // Tera autoescapes based on the path or, without one, the
+                    // name of a template, which lacks an extension. Register
+                    // the name of each embedded template that needs escaping.
+                    let path = path.to_string_lossy();
+                    if ext.iter().any(|ext| path.ends_with(ext)) {
+                        autoescape.push(&*Box::leak(name.to_owned().into_boxed_str()));
+                    }
+
+                    raw.push((name, contents));
+                }
+            }
+        }
 
         // Finally try to tell Tera about all of the templates.
-        if let Err(e) = tera.add_template_files(files) {
+        tera.autoescape_on(autoescape);
+        let result = tera.add_raw_templates(raw).and_then(|_| tera.add_template_files(files));
+        if let Err(e) = result {
             error!("Failed to initialize Tera templating.");
 
             let mut error = Some(&e as &dyn Error);
@@ -60,4 +79,29 @@ impl Engine for Tera {
             }
         }
     }
+
+    fn add_raw_helper(&mut self, name: &'static str, helper: RawHelper) {
+        self.register_filter(name, RawFilter(helper));
+    }
+
+    fn escape_with(&mut self, escape: EscapeFn) {
+        self.set_escape_fn(escape);
+    }
+}
+
+struct RawFilter(RawHelper);
+
+impl Filter for RawFilter {
+    fn filter(&self, value: &Value, _: &HashMap<String, Value>) -> tera::Result<Value> {
+        let string = match value {
+            Value::String(string) => (self.0)(string),
+            value => (self.0)(&value.to_string()),
+        };
+
+        Ok(Value::String(string))
+    }
+
+    fn is_safe(&self) -> bool {
+        true
+    }
 }
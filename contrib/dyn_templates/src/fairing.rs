@@ -1,5 +1,6 @@
 use rocket::{Rocket, Build, Orbit};
 use rocket::fairing::{self, Fairing, Info, Kind};
+use rocket::http::ContentType;
 
 use crate::context::{Callback, Context, ContextManager};
 use crate::template::DEFAULT_TEMPLATE_DIR;
@@ -14,6 +15,10 @@ pub struct TemplateFairing {
     /// functionality specific to individual template engines. In debug mode,
     /// this callback might be run multiple times as templates are reloaded.
     pub callback: Callback,
+    /// The embedded templates to use instead of those in `template_dir`, if
+    /// any. When set, templates are never read from disk.
+    #[cfg(feature = "embedded")]
+    pub embedded: Option<crate::EmbeddedTemplates>,
 }
 
 #[rocket::async_trait]
@@ -33,6 +38,37 @@ impl Fairing for TemplateFairing {
     async fn on_ignite(&self, rocket: Rocket<Build>) -> fairing::Result {
         use rocket::figment::value::magic::RelativePathBuf;
 
+        let content_type = rocket.figment().extract_inner::<String>("template_content_type");
+        let content_type = match content_type {
+            Ok(string) => match ContentType::parse_flexible(&string) {
+                Some(content_type) => Some(content_type),
+                None => {
+                    error_!("Invalid `template_content_type` configuration: {:?}.", string);
+                    info_!("Expected a media type like `text/html` or an extension like `html`.");
+                    return Err(rocket);
+                }
+            },
+            Err(e) if e.missing() => None,
+            Err(e) => {
+                rocket::config::pretty_print_error(e);
+                return Err(rocket);
+            }
+        };
+
+        #[cfg(feature = "embedded")]
+        if let Some(embedded) = &self.embedded {
+            return match Context::embedded(embedded, &self.callback) {
+                Some(mut ctxt) => {
+                    ctxt.content_type = content_type;
+                    Ok(rocket.manage(ContextManager::new(ctxt)))
+                }
+                None => {
+                    error_!("Template initialization failed. Aborting launch.");
+                    Err(rocket)
+                }
+            };
+        }
+
         let configured_dir = rocket.figment()
             .extract_inner::<RelativePathBuf>("template_dir")
             .map(|path| path.relative());
@@ -46,7 +82,8 @@ impl Fairing for TemplateFairing {
             }
         };
 
-        if let Some(ctxt) = Context::initialize(&path, &self.callback) {
+        if let Some(mut ctxt) = Context::initialize(&path, &self.callback) {
+            ctxt.content_type = content_type;
             Ok(rocket.manage(ContextManager::new(ctxt)))
         } else {
             error_!("Template initialization failed. Aborting launch.");
@@ -61,8 +98,16 @@ impl Fairing for TemplateFairing {
             .expect("Template ContextManager registered in on_ignite");
 
         info!("{}{}:", "📐 ".emoji(), "Templating".magenta());
-        info_!("directory: {}", Source::from(&*cm.context().root).primary());
+        let ctxt = cm.context();
+        match ctxt.embedded {
+            true => info_!("directory: {} (embedded)", Source::from(&*ctxt.root).primary()),
+            false => info_!("directory: {}", Source::from(&*ctxt.root).primary()),
+        }
+
         info_!("engines: {:?}", Engines::ENABLED_EXTENSIONS.primary());
+        if let Some(content_type) = &ctxt.content_type {
+            info_!("content-type: {}", content_type.primary());
+        }
     }
 
     #[cfg(debug_assertions)]
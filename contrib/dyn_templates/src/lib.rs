@@ -53,7 +53,8 @@
 //!
 //! ## Configuration
 //!
-//! This crate reads one configuration parameter from the configured figment:
+//! This crate reads the following configuration parameters from the
+//! configured figment:
 //!
 //!   * `template_dir` (**default: `templates/`**)
 //!
@@ -61,6 +62,12 @@
 //!      are considered relative to the configuration file, or there is no file,
 //!      the current working directory.
 //!
+//!   * `template_content_type` (**default: none**)
+//!
+//!      A media type, like `"text/html"`, or a known extension, like `"html"`.
+//!      When set, automatic [content-type inference](#template-naming-and-content-types)
+//!      is disabled and every template is served with this `Content-Type`.
+//!
 //! For example, to change the default and set `template_dir` to different
 //! values based on whether the application was compiled for debug or release
 //! from a `Rocket.toml` file (read by the default figment), you might write:
@@ -89,7 +96,10 @@
 //!
 //! The `Content-Type` of the response is automatically determined by the
 //! non-engine extension using [`ContentType::from_extension()`]. If there is no
-//! such extension or it is unknown, `text/plain` is used.
+//! such extension or it is unknown, `text/plain` is used. Inference can be
+//! overridden for a single response via [`Template::content_type()`] or
+//! disabled altogether via the `template_content_type` configuration
+//! parameter.
 //!
 //! The following table contains examples:
 //!
@@ -157,6 +167,10 @@
 //! `template_dir` ending with a known engine extension (as described in the
 //! [usage section](#usage)) can be rendered. The latter two fairings allow
 //! customizations such as registering helpers and templates from strings.
+//! Helpers are registered via [`Engines`] methods such as
+//! [`Engines::register_tera_function()`], while global variables available to
+//! every template, regardless of engine, are added via
+//! [`Engines::add_global()`].
 //!
 //! _**Note:** Templates that are registered directly via [`Template::custom()`],
 //! use whatever name provided during that registration; no extensions are
@@ -175,10 +189,44 @@
 //! metadata, such as whether a template is known to exist
 //! ([`Metadata::contains_template()`]), and to render templates to `String`
 //! ([`Metadata::render()`]).
+//!
+//! ### Embedded Templates
+//!
+//! With the `embedded` feature enabled, templates can be embedded into the
+//! binary at compile-time with [`include_templates!`] and served via the
+//! fairing returned by [`Template::embedded()`]. The application then never
+//! reads templates from disk, and a single binary can be deployed on its own:
+//!
+//! ```toml
+//! [dependencies.rocket_dyn_templates]
+//! version = "0.1.0"
+//! features = ["handlebars", "tera", "embedded"]
+//! ```
+//!
+//! ```rust
+//! # #[cfg(feature = "embedded")] {
+//! use rocket_dyn_templates::{Template, include_templates};
+//!
+//! fn main() {
+//!     // In debug, read and reload templates from disk. In release, embed.
+//!     #[cfg(debug_assertions)]
+//!     let templates = Template::fairing();
+//!     #[cfg(not(debug_assertions))]
+//!     let templates = Template::embedded(include_templates!("tests/templates"));
+//!
+//!     rocket::build()
+//!         // ...
+//!         .attach(templates)
+//!         // ...
+//!     # ;
+//! }
+//! # }
+//! ```
 
 #![doc(html_root_url = "https://api.rocket.rs/master/rocket_dyn_templates")]
 #![doc(html_favicon_url = "https://rocket.rs/images/favicon.ico")]
 #![doc(html_logo_url = "https://rocket.rs/images/logo-boxed.png")]
+#![cfg_attr(nightly, feature(doc_cfg))]
 
 #[macro_use] extern crate rocket;
 
@@ -205,7 +253,18 @@ mod fairing;
 mod context;
 mod metadata;
 mod template;
+#[cfg(feature = "embedded")]
+mod embedded;
 
 pub use engine::Engines;
 pub use metadata::Metadata;
 pub use template::Template;
+
+#[cfg(feature = "embedded")]
+#[cfg_attr(nightly, doc(cfg(feature = "embedded")))]
+pub use embedded::EmbeddedTemplates;
+
+#[doc(inline)]
+#[cfg(feature = "embedded")]
+#[cfg_attr(nightly, doc(cfg(feature = "embedded")))]
+pub use rocket_dyn_templates_codegen::include_templates;
@@ -123,6 +123,66 @@ impl Metadata<'_> {
     {
         Template::render(name.into(), context).finalize(&self.0.context()).ok()
     }
+
+    /// Directly render the template named `name` with the context `context`
+    /// into a `String`, returning it alongside `content_type` in place of the
+    /// template's detected `ContentType`. See [`Metadata::render()`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate rocket;
+    /// use rocket::http::ContentType;
+    /// use rocket_dyn_templates::{Metadata, context};
+    ///
+    /// // Serve `data.json` as HTML, ignoring its inferred JSON content-type.
+    /// #[get("/")]
+    /// fn describe(metadata: Metadata) -> Option<(ContentType, String)> {
+    ///     metadata.render_with_content_type("data.json", ContentType::HTML, context! {
+    ///         field: "Hello, world!"
+    ///     })
+    /// }
+    /// ```
+    pub fn render_with_content_type<S, C>(
+        &self,
+        name: S,
+        content_type: ContentType,
+        context: C
+    ) -> Option<(ContentType, String)>
+        where S: Into<Cow<'static, str>>, C: Serialize
+    {
+        Template::render(name.into(), context)
+            .content_type(content_type)
+            .finalize(&self.0.context())
+            .ok()
+    }
+
+    /// Returns the `ContentType` detected for the template named `name`, or
+    /// `None` if no such template is loaded.
+    ///
+    /// If `template_content_type` is configured, it is returned for every
+    /// template. Otherwise, the content-type is derived from the extension
+    /// preceding the engine extension in the template's file name:
+    /// `index.html.hbs` is `text/html` while `data.json.tera` is
+    /// `application/json`. Templates without such an extension, or with an
+    /// unknown one, are `text/plain`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate rocket;
+    /// use rocket::http::ContentType;
+    /// use rocket_dyn_templates::Metadata;
+    ///
+    /// #[get("/")]
+    /// fn handler(metadata: Metadata) {
+    ///     let is_html = metadata.content_type("index") == Some(ContentType::HTML);
+    /// }
+    /// ```
+    pub fn content_type(&self, name: &str) -> Option<ContentType> {
+        let ctxt = self.0.context();
+        ctxt.templates.get(name).map(|info| ctxt.content_type(info))
+    }
 }
 
 impl fmt::Debug for Metadata<'_> {
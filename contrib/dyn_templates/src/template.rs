@@ -11,9 +11,13 @@ use rocket::serde::Serialize;
 use rocket::yansi::Paint;
 
 use crate::Engines;
+use crate::engine::Source;
 use crate::fairing::TemplateFairing;
 use crate::context::{Context, ContextManager};
 
+#[cfg(feature = "embedded")]
+use crate::EmbeddedTemplates;
+
 pub(crate) const DEFAULT_TEMPLATE_DIR: &str = "templates";
 
 /// Responder that renders a dynamic template.
@@ -27,18 +31,35 @@ pub(crate) const DEFAULT_TEMPLATE_DIR: &str = "templates";
 pub struct Template {
     name: Cow<'static, str>,
     value: Result<Value, Error>,
+    content_type: Option<ContentType>,
+    status: Option<Status>,
 }
 
 #[derive(Debug)]
 pub(crate) struct TemplateInfo {
     /// The complete path, including `template_dir`, to this template, if any.
+    /// For embedded templates, the path relative to the embedded directory.
     pub(crate) path: Option<PathBuf>,
+    /// The contents of this template, if it was embedded.
+    pub(crate) contents: Option<&'static str>,
     /// The extension for the engine of this template.
     pub(crate) engine_ext: &'static str,
     /// The extension before the engine extension in the template, if any.
     pub(crate) data_type: ContentType
 }
 
+impl TemplateInfo {
+    /// The source of this template's contents, if it wasn't registered
+    /// directly with an engine.
+    pub(crate) fn source(&self) -> Option<Source<'_>> {
+        let path = self.path.as_deref()?;
+        match self.contents {
+            Some(contents) => Some(Source::Embedded(path, contents)),
+            None => Some(Source::File(path)),
+        }
+    }
+}
+
 impl Template {
     /// Returns a fairing that initializes and maintains templating state.
     ///
@@ -136,7 +157,81 @@ impl Template {
     pub fn try_custom<F: Send + Sync + 'static>(f: F) -> impl Fairing
         where F: Fn(&mut Engines) -> Result<(), Box<dyn std::error::Error>>
     {
-        TemplateFairing { callback: Box::new(f) }
+        TemplateFairing {
+            callback: Box::new(f),
+            #[cfg(feature = "embedded")]
+            embedded: None,
+        }
+    }
+
+    /// Returns a fairing that initializes and maintains templating state using
+    /// the `templates` embedded into the binary by
+    /// [`include_templates!`](crate::include_templates!).
+    ///
+    /// Unlike the other fairings, this fairing never reads templates from disk:
+    /// the `template_dir` configuration parameter is ignored, and templates are
+    /// never reloaded. This method is only available when the `embedded`
+    /// feature is enabled.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "embedded")] {
+    /// use rocket_dyn_templates::{Template, include_templates};
+    ///
+    /// fn main() {
+    ///     rocket::build()
+    ///         // ...
+    ///         .attach(Template::embedded(include_templates!("tests/templates")))
+    ///         // ...
+    ///     # ;
+    /// }
+    /// # }
+    /// ```
+    #[cfg(feature = "embedded")]
+    #[cfg_attr(nightly, doc(cfg(feature = "embedded")))]
+    pub fn embedded(templates: EmbeddedTemplates) -> impl Fairing {
+        Template::embedded_custom(templates, |_| {})
+    }
+
+    /// Returns a fairing that initializes and maintains templating state using
+    /// the `templates` embedded into the binary by
+    /// [`include_templates!`](crate::include_templates!), customizing the
+    /// engines via `f` as in [`Template::custom()`].
+    ///
+    /// See [`Template::embedded()`] for details. This method is only available
+    /// when the `embedded` feature is enabled.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "embedded")] {
+    /// use rocket_dyn_templates::{Template, include_templates};
+    ///
+    /// fn main() {
+    ///     let templates = include_templates!("tests/templates");
+    ///     rocket::build()
+    ///         // ...
+    ///         .attach(Template::embedded_custom(templates, |engines| {
+    ///             // engines.handlebars.register_helper ...
+    ///         }))
+    ///         // ...
+    ///     # ;
+    /// }
+    /// # }
+    /// ```
+    #[cfg(feature = "embedded")]
+    #[cfg_attr(nightly, doc(cfg(feature = "embedded")))]
+    pub fn embedded_custom<F: Send + Sync + 'static>(
+        templates: EmbeddedTemplates,
+        f: F
+    ) -> impl Fairing
+        where F: Fn(&mut Engines)
+    {
+        TemplateFairing {
+            callback: Box::new(move |engines| { f(engines); Ok(()) }),
+            embedded: Some(templates),
+        }
     }
 
     /// Render the template named `name` with the context `context`. The
@@ -178,9 +273,71 @@ impl Template {
         Template {
             name: name.into(),
             value: Value::serialize(context),
+            content_type: None,
+            status: None,
         }
     }
 
+    /// Sets the `Content-Type` of the response to `content_type`, overriding
+    /// the `Content-Type` inferred from the template's extension as well as
+    /// any configured `template_content_type`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate rocket;
+    /// use rocket::http::ContentType;
+    /// use rocket_dyn_templates::{Template, context};
+    ///
+    /// // Serve `data.json.tera`, an HTML page describing JSON, as HTML.
+    /// #[get("/")]
+    /// fn describe() -> Template {
+    ///     Template::render("data", context! { field: "Hello, world!" })
+    ///         .content_type(ContentType::HTML)
+    /// }
+    /// ```
+    #[inline]
+    pub fn content_type(mut self, content_type: ContentType) -> Template {
+        self.content_type = Some(content_type);
+        self
+    }
+
+    /// Sets the status of the response to `status`.
+    ///
+    /// By default, a `Template` does not set a status: the response has a
+    /// status of `200 OK` when returned from a route and the status of the
+    /// error being handled when returned from a catcher. A status set via this
+    /// method takes precedence in both cases. In particular, a catcher can use
+    /// it to respond with a status other than the one it was invoked with.
+    ///
+    /// # Example
+    ///
+    /// A default catcher that renders a template while preserving the incoming
+    /// status, and a `404` catcher that responds with a `410 Gone` instead:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate rocket;
+    /// use rocket::Request;
+    /// use rocket::http::Status;
+    /// use rocket_dyn_templates::{Template, context};
+    ///
+    /// #[catch(default)]
+    /// fn default_catcher(status: Status, req: &Request<'_>) -> Template {
+    ///     let context = context! { code: status.code, uri: req.uri() };
+    ///     Template::render("error", context).with_status(status)
+    /// }
+    ///
+    /// #[catch(404)]
+    /// fn not_found() -> Template {
+    ///     Template::render("gone", context! {}).with_status(Status::Gone)
+    /// }
+    /// ```
+    #[inline]
+    pub fn with_status(mut self, status: Status) -> Template {
+        self.status = Some(status);
+        self
+    }
+
     /// Render the template named `name` with the context `context` into a
     /// `String`. This method should **not** be used in any running Rocket
     /// application. This method should only be used during testing to validate
@@ -252,13 +409,17 @@ impl Template {
             Status::InternalServerError
         })?;
 
-        Ok((info.data_type.clone(), string))
+        let content_type = self.content_type.unwrap_or_else(|| ctxt.content_type(info));
+        Ok((content_type, string))
     }
 }
 
-/// Returns a response with the Content-Type derived from the template's
-/// extension and a fixed-size body containing the rendered template. If
-/// rendering fails, an `Err` of `Status::InternalServerError` is returned.
+/// Returns a response with the Content-Type set via [`Template::content_type()`],
+/// configured via `template_content_type`, or derived from the template's
+/// extension, in that order of preference, and a fixed-size body containing the rendered template. If
+/// a status was set via [`Template::with_status()`], the response has that
+/// status, including when returned from a catcher. If rendering fails, an
+/// `Err` of `Status::InternalServerError` is returned.
 impl<'r> Responder<'r, 'static> for Template {
     fn respond_to(self, req: &'r Request<'_>) -> response::Result<'static> {
         let ctxt = req.rocket()
@@ -270,7 +431,13 @@ impl<'r> Responder<'r, 'static> for Template {
                 Status::InternalServerError
             })?;
 
-        self.finalize(&ctxt.context())?.respond_to(req)
+        let status = self.status;
+        let mut response = self.finalize(&ctxt.context())?.respond_to(req)?;
+        if let Some(status) = status {
+            response.set_status(status);
+        }
+
+        Ok(response)
     }
 }
 
@@ -236,6 +236,175 @@ mod tera_tests {
         let md_rendered = metadata.render("tera/html_test", &map);
         assert_eq!(template, Some(ESCAPED_EXPECTED.into()));
         assert_eq!(md_rendered, Some((ContentType::HTML, ESCAPED_EXPECTED.into())));
+
+        // Query and override the detected content-type.
+        assert_eq!(metadata.content_type("tera/txt_test"), Some(ContentType::Text));
+        assert_eq!(metadata.content_type("tera/html_test"), Some(ContentType::HTML));
+        assert_eq!(metadata.content_type("tera/not_existing"), None);
+
+        let md_rendered = metadata.render_with_content_type("tera/html_test", ContentType::XML, &map);
+        assert_eq!(md_rendered, Some((ContentType::XML, ESCAPED_EXPECTED.into())));
+    }
+
+    #[test]
+    fn test_tera_functions_and_globals() {
+        use rocket::local::blocking::Client;
+        use rocket_dyn_templates::{Engines, tera};
+
+        fn shout(args: &HashMap<String, tera::Value>) -> tera::Result<tera::Value> {
+            let text = args.get("text").and_then(|v| v.as_str()).unwrap_or("");
+            Ok(tera::Value::from(text.to_uppercase()))
+        }
+
+        let rocket = rocket::custom(Config::figment().merge(("template_dir", template_root())))
+            .attach(Template::try_custom(|engines: &mut Engines| {
+                engines.register_tera_function("shout", shout)
+                    .add_global("site", "Rocket")?
+                    .add_global("title", "default")?;
+
+                Ok(())
+            }));
+
+        let client = Client::debug(rocket).unwrap();
+        let template = Template::show(client.rocket(), "tera/globals", context! { title: "hi" });
+        assert_eq!(template, Some("Rocket: HI".into()));
+
+        let template = Template::show(client.rocket(), "tera/globals", ());
+        assert_eq!(template, Some("Rocket: DEFAULT".into()));
+    }
+
+    #[test]
+    fn test_tera_raw_helpers_and_escape_fn() {
+        use rocket::local::blocking::Client;
+        use rocket_dyn_templates::Engines;
+
+        fn client(escape: Option<fn(&str) -> String>) -> Client {
+            let figment = Config::figment().merge(("template_dir", template_root()));
+            let rocket = rocket::custom(figment)
+                .attach(Template::custom(move |engines: &mut Engines| {
+                    engines.register_raw_helper("wow", |v| format!("<b>{}</b>", v));
+                    if let Some(escape) = escape {
+                        engines.register_escape_fn(escape);
+                    }
+                }));
+
+            Client::debug(rocket).unwrap()
+        }
+
+        let context = context! { content: "<i>" };
+        let client = client(None);
+        let template = Template::show(client.rocket(), "tera/raw", &context);
+        assert_eq!(template, Some("<b><i></b> &lt;i&gt;".into()));
+
+        let client = client(Some(|s| s.replace('<', "[").replace('>', "]")));
+        let template = Template::show(client.rocket(), "tera/raw", &context);
+        assert_eq!(template, Some("<b><i></b> [i]".into()));
+    }
+
+    #[test]
+    fn test_tera_content_type_override() {
+        use rocket::local::blocking::Client;
+
+        #[get("/<name>")]
+        fn render(name: &str) -> Template {
+            Template::render(format!("tera/{}", name), context! { title: "_test_" })
+        }
+
+        #[get("/<name>/xml")]
+        fn render_xml(name: &str) -> Template {
+            Template::render(format!("tera/{}", name), context! { title: "_test_" })
+                .content_type(ContentType::XML)
+        }
+
+        let client = Client::debug(rocket().mount("/", routes![render, render_xml])).unwrap();
+        let response = client.get("/txt_test").dispatch();
+        assert_eq!(response.content_type(), Some(ContentType::Text));
+
+        let response = client.get("/txt_test/xml").dispatch();
+        assert_eq!(response.content_type(), Some(ContentType::XML));
+
+        let figment = Config::figment()
+            .merge(("template_dir", template_root()))
+            .merge(("template_content_type", "html"));
+
+        let rocket = rocket::custom(figment)
+            .attach(Template::fairing())
+            .mount("/", routes![render, render_xml]);
+
+        let client = Client::debug(rocket).unwrap();
+        let response = client.get("/txt_test").dispatch();
+        assert_eq!(response.content_type(), Some(ContentType::HTML));
+
+        let response = client.get("/txt_test/xml").dispatch();
+        assert_eq!(response.content_type(), Some(ContentType::XML));
+
+        let req = client.get("/");
+        let metadata = rocket::async_test(Metadata::from_request(&req)).unwrap();
+        assert_eq!(metadata.content_type("tera/txt_test"), Some(ContentType::HTML));
+
+        let figment = Config::figment().merge(("template_content_type", "not a type"));
+        let rocket = rocket::custom(figment).attach(Template::fairing());
+        assert!(Client::debug(rocket).is_err());
+    }
+
+    #[test]
+    fn test_tera_template_status() {
+        use rocket::Request;
+        use rocket::local::blocking::Client;
+
+        #[get("/teapot")]
+        fn teapot() -> Template {
+            Template::render("tera/txt_test", context! { title: "teapot" })
+                .with_status(Status::ImATeapot)
+        }
+
+        #[get("/fail/<code>")]
+        fn fail(code: u16) -> Status {
+            Status::new(code)
+        }
+
+        #[catch(default)]
+        fn default_catcher(status: Status, _: &Request<'_>) -> Template {
+            Template::render("tera/txt_test", context! { title: status.code })
+                .with_status(status)
+        }
+
+        #[catch(404)]
+        fn not_found() -> Template {
+            Template::render("tera/txt_test", context! { title: "gone" })
+                .with_status(Status::Gone)
+        }
+
+        #[catch(500)]
+        fn internal_error() -> Template {
+            Template::render("tera/txt_test", context! { title: "oops" })
+        }
+
+        let rocket = rocket()
+            .mount("/", routes![teapot, fail])
+            .register("/", catchers![default_catcher, not_found, internal_error]);
+
+        let client = Client::debug(rocket).unwrap();
+        let response = client.get("/teapot").dispatch();
+        assert_eq!(response.status(), Status::ImATeapot);
+        assert_eq!(response.content_type(), Some(ContentType::Text));
+        assert!(response.into_string().unwrap().contains("title: teapot"));
+
+        let response = client.get("/fail/403").dispatch();
+        assert_eq!(response.status(), Status::Forbidden);
+        assert!(response.into_string().unwrap().contains("title: 403"));
+
+        let response = client.get("/fail/503").dispatch();
+        assert_eq!(response.status(), Status::ServiceUnavailable);
+        assert!(response.into_string().unwrap().contains("title: 503"));
+
+        let response = client.get("/does/not/exist").dispatch();
+        assert_eq!(response.status(), Status::Gone);
+        assert!(response.into_string().unwrap().contains("title: gone"));
+
+        let response = client.get("/fail/500").dispatch();
+        assert_eq!(response.status(), Status::InternalServerError);
+        assert!(response.into_string().unwrap().contains("title: oops"));
     }
 
     #[async_test]
@@ -484,3 +653,43 @@ mod j2_tests {
         assert_eq!(response.status(), Status::NotFound);
     }
 }
+
+#[cfg(all(feature = "embedded", feature = "tera"))]
+mod embedded_tests {
+    use super::*;
+    use std::collections::HashMap;
+    use rocket::http::Status;
+    use rocket::local::blocking::Client;
+    use rocket_dyn_templates::include_templates;
+    use pretty_assertions::assert_eq;
+
+    fn rocket() -> Rocket<Build> {
+        // The configured directory doesn't exist: it must be ignored.
+        rocket::custom(Config::figment().merge(("template_dir", "/does/not/exist")))
+            .attach(Template::embedded(include_templates!("tests/templates")))
+            .mount("/", routes![template_check, is_reloading])
+    }
+
+    #[test]
+    fn test_embedded_templates() {
+        let client = Client::debug(rocket()).unwrap();
+        let mut map = HashMap::new();
+        map.insert("title", "_test_");
+        map.insert("content", "<script />");
+
+        let template = Template::show(client.rocket(), "tera/txt_test", &map);
+        assert_eq!(template.unwrap(), "\nh_start\ntitle: _test_\nh_end\n\n\n<script />\n\nfoot");
+
+        let template = Template::show(client.rocket(), "tera/html_test", &map);
+        assert_eq!(template.unwrap(),
+            "\nh_start\ntitle: _test_\nh_end\n\n\n&lt;script &#x2F;&gt;\n\nfoot");
+    }
+
+    #[test]
+    fn test_embedded_metadata() {
+        let client = Client::debug(rocket()).unwrap();
+        assert_eq!(client.get("/tera/txt_test").dispatch().status(), Status::Ok);
+        assert_eq!(client.get("/tera/not_existing").dispatch().status(), Status::NotFound);
+        assert_eq!(client.get("/is_reloading").dispatch().status(), Status::NotFound);
+    }
+}
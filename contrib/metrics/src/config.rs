@@ -0,0 +1,80 @@
+use rocket::http::uri::Origin;
+use rocket::serde::{de, Deserialize, Deserializer, Serialize};
+
+/// Configuration for the [`Metrics`](crate::Metrics) fairing.
+///
+/// A dictionary matching this structure is extracted from the `metrics` key of
+/// the active [`Figment`](rocket::figment::Figment) on ignition. Every field
+/// is optional and defaults to the value in [`Config::default()`].
+///
+/// ```toml
+/// [default.metrics]
+/// enabled = true
+/// path = "/metrics"
+/// buckets = [0.01, 0.1, 1.0]
+/// ```
+///
+/// A custom provider can be used as well:
+///
+/// ```rust
+/// # use rocket::launch;
+/// use rocket_metrics::{Metrics, Config};
+///
+/// #[launch]
+/// fn rocket() -> _ {
+///     let figment = rocket::Config::figment()
+///         .merge(("metrics", Config {
+///             path: "/internal/metrics".into(),
+///             ..Config::default()
+///         }));
+///
+///     rocket::custom(figment).attach(Metrics::fairing())
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(crate = "rocket::serde")]
+pub struct Config {
+    /// Whether metrics are recorded and served.
+    ///
+    /// When `false`, the fairing neither records metrics nor mounts the
+    /// metrics endpoint.
+    ///
+    /// _Default:_ `true`.
+    pub enabled: bool,
+    /// The path at which metrics are served.
+    ///
+    /// Must be a valid, static origin URI path without a query. Extracting a
+    /// `Config` with any other `path` fails.
+    ///
+    /// _Default:_ `"/metrics"`.
+    #[serde(deserialize_with = "deserialize_path")]
+    pub path: String,
+    /// The upper bounds, in seconds, of the request latency histogram buckets.
+    ///
+    /// Bounds are sorted and deduplicated before use. An implicit `+Inf`
+    /// bucket is always present.
+    ///
+    /// _Default:_ `[0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0,
+    /// 10.0]`.
+    pub buckets: Vec<f64>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            enabled: true,
+            path: "/metrics".into(),
+            buckets: vec![0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0],
+        }
+    }
+}
+
+fn deserialize_path<'de, D: Deserializer<'de>>(de: D) -> Result<String, D::Error> {
+    const EXPECTED: &str = "an origin URI path without a query";
+
+    let path = String::deserialize(de)?;
+    match Origin::parse(&path) {
+        Ok(origin) if origin.query().is_none() => Ok(path),
+        _ => Err(de::Error::invalid_value(de::Unexpected::Str(&path), &EXPECTED)),
+    }
+}
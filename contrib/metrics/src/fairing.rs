@@ -0,0 +1,103 @@
+use std::time::Instant;
+
+use rocket::{info_, Rocket, Build, Request, Response, Data, State};
+use rocket::fairing::{self, Fairing, Info, Kind};
+use rocket::figment::providers::Serialized;
+use rocket::http::ContentType;
+
+use crate::{Config, Registry};
+
+/// The request metrics fairing.
+///
+/// When attached, this fairing:
+///
+///   * extracts a [`Config`] from the `metrics` key of the active figment;
+///   * if enabled, places a [`Registry`] in managed state and mounts a route
+///     at [`Config::path`] which renders it;
+///   * records the method, route, status, and latency of every request.
+///
+/// See the [crate docs](crate) for usage details.
+#[derive(Debug, Default)]
+pub struct Metrics {
+    _priv: (),
+}
+
+/// The time at which a request was received. Stored in request-local state.
+struct RequestStart(Instant);
+
+impl Metrics {
+    /// The route label used for requests that did not match any route.
+    pub const UNMATCHED: &'static str = "unmatched";
+
+    /// Returns the fairing which records and serves request metrics.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use rocket::launch;
+    /// use rocket_metrics::Metrics;
+    ///
+    /// #[launch]
+    /// fn rocket() -> _ {
+    ///     rocket::build().attach(Metrics::fairing())
+    /// }
+    /// ```
+    pub fn fairing() -> Metrics {
+        Metrics::default()
+    }
+}
+
+#[rocket::get("/")]
+fn render(registry: &State<Registry>) -> (ContentType, String) {
+    let content_type = ContentType::new("text", "plain")
+        .with_params([("version", "0.0.4"), ("charset", "utf-8")]);
+
+    (content_type, registry.render())
+}
+
+#[rocket::async_trait]
+impl Fairing for Metrics {
+    fn info(&self) -> Info {
+        Info {
+            name: "Metrics",
+            kind: Kind::Ignite | Kind::Request | Kind::Response | Kind::Singleton,
+        }
+    }
+
+    async fn on_ignite(&self, rocket: Rocket<Build>) -> fairing::Result {
+        let figment = rocket.figment()
+            .focus("metrics")
+            .join(Serialized::defaults(Config::default()));
+
+        let config: Config = match figment.extract() {
+            Ok(config) => config,
+            Err(e) => {
+                rocket::config::pretty_print_error(e);
+                return Err(rocket);
+            }
+        };
+
+        if !config.enabled {
+            info_!("Metrics are disabled.");
+            return Ok(rocket);
+        }
+
+        Ok(rocket.manage(Registry::new(config.buckets))
+            .mount(config.path, rocket::routes![render]))
+    }
+
+    async fn on_request(&self, req: &mut Request<'_>, _: &mut Data<'_>) {
+        req.local_cache(|| RequestStart(Instant::now()));
+    }
+
+    async fn on_response<'r>(&self, req: &'r Request<'_>, res: &mut Response<'r>) {
+        let Some(registry) = req.rocket().state::<Registry>() else {
+            return;
+        };
+
+        let start = req.local_cache(|| RequestStart(Instant::now()));
+        let route = req.route().map(|r| r.uri.to_string());
+        let route = route.as_deref().unwrap_or(Metrics::UNMATCHED);
+        registry.record(req.method(), route, res.status(), start.0.elapsed());
+    }
+}
@@ -0,0 +1,92 @@
+//! Prometheus-style request metrics for Rocket.
+//!
+//! This crate provides a [`Metrics`] fairing which records, for every request,
+//! the request method, the matched route, the response status, and the time
+//! taken to produce a response. The recorded values are served at a
+//! configurable endpoint in the [Prometheus text exposition format].
+//!
+//! [Prometheus text exposition format]: https://prometheus.io/docs/instrumenting/exposition_formats/
+//!
+//! # Usage
+//!
+//! Depend on the crate:
+//!
+//! ```toml
+//! [dependencies]
+//! rocket_metrics = "0.1.0"
+//! ```
+//!
+//! Then attach the fairing returned by [`Metrics::fairing()`]:
+//!
+//! ```rust
+//! # use rocket::launch;
+//! use rocket_metrics::Metrics;
+//!
+//! #[launch]
+//! fn rocket() -> _ {
+//!     rocket::build().attach(Metrics::fairing())
+//! }
+//! ```
+//!
+//! A `GET /metrics` request now responds with output resembling the following:
+//!
+//! ```text
+//! # HELP rocket_http_requests_total Total number of HTTP requests handled.
+//! # TYPE rocket_http_requests_total counter
+//! rocket_http_requests_total{method="GET",route="/",status="200"} 2
+//! # HELP rocket_http_request_duration_seconds HTTP request latencies in seconds.
+//! # TYPE rocket_http_request_duration_seconds histogram
+//! rocket_http_request_duration_seconds_bucket{method="GET",route="/",le="0.005"} 2
+//! ...
+//! rocket_http_request_duration_seconds_bucket{method="GET",route="/",le="+Inf"} 2
+//! rocket_http_request_duration_seconds_sum{method="GET",route="/"} 0.000113
+//! rocket_http_request_duration_seconds_count{method="GET",route="/"} 2
+//! ```
+//!
+//! Requests which do not match any route are recorded with a `route` label of
+//! `unmatched`.
+//!
+//! # Configuration
+//!
+//! The fairing reads its configuration from the `metrics` key of the active
+//! figment. With the default provider, it can be configured in `Rocket.toml`:
+//!
+//! ```toml
+//! [default.metrics]
+//! # Whether to record metrics and mount the endpoint.
+//! enabled = true
+//! # The path at which metrics are served.
+//! path = "/metrics"
+//! # The upper bounds, in seconds, of the latency histogram buckets.
+//! buckets = [0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0]
+//! ```
+//!
+//! All keys are optional. See [`Config`] for defaults.
+//!
+//! # Accessing the Registry
+//!
+//! When enabled, the fairing places a [`Registry`] in managed state. It can be
+//! retrieved to render metrics elsewhere:
+//!
+//! ```rust
+//! # use rocket::get;
+//! use rocket::State;
+//! use rocket_metrics::Registry;
+//!
+//! #[get("/admin/metrics")]
+//! fn admin_metrics(registry: &State<Registry>) -> String {
+//!     registry.render()
+//! }
+//! ```
+
+#![doc(html_root_url = "https://api.rocket.rs/master/rocket_metrics")]
+#![doc(html_favicon_url = "https://rocket.rs/images/favicon.ico")]
+#![doc(html_logo_url = "https://rocket.rs/images/logo-boxed.png")]
+
+mod config;
+mod registry;
+mod fairing;
+
+pub use self::config::Config;
+pub use self::registry::Registry;
+pub use self::fairing::Metrics;
@@ -0,0 +1,173 @@
+use std::fmt::{self, Write};
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use rocket::http::{Method, Status};
+
+/// A store of recorded request metrics.
+///
+/// A `Registry` is placed in managed state by the [`Metrics`](crate::Metrics)
+/// fairing when metrics are enabled. Cloning a `Registry` is cheap; all clones
+/// refer to the same underlying store.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+/// use rocket::http::{Method, Status};
+/// use rocket_metrics::Registry;
+///
+/// let registry = Registry::new(vec![0.1, 1.0]);
+/// registry.record(Method::Get, "/", Status::Ok, Duration::from_millis(50));
+///
+/// let output = registry.render();
+/// assert!(output.contains(r#"rocket_http_requests_total{method="GET",route="/",status="200"} 1"#));
+/// assert!(output.contains(r#"le="0.1"} 1"#));
+/// ```
+#[derive(Clone)]
+pub struct Registry {
+    buckets: Arc<[f64]>,
+    inner: Arc<Mutex<Inner>>,
+}
+
+#[derive(Default)]
+struct Inner {
+    requests: BTreeMap<(&'static str, String, u16), u64>,
+    durations: BTreeMap<(&'static str, String), Histogram>,
+}
+
+struct Histogram {
+    counts: Vec<u64>,
+    sum: f64,
+    count: u64,
+}
+
+/// Escapes a label value per the Prometheus text format.
+struct Label<'a>(&'a str);
+
+impl fmt::Display for Label<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for c in self.0.chars() {
+            match c {
+                '\\' => f.write_str("\\\\")?,
+                '"' => f.write_str("\\\"")?,
+                '\n' => f.write_str("\\n")?,
+                c => f.write_char(c)?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Registry {
+    const REQUESTS: &'static str = "rocket_http_requests_total";
+    const DURATIONS: &'static str = "rocket_http_request_duration_seconds";
+
+    /// Creates a new, empty registry with latency histogram bucket upper
+    /// bounds `buckets`, in seconds. Bounds are sorted and deduplicated; values
+    /// that aren't finite are discarded.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket_metrics::Registry;
+    ///
+    /// let registry = Registry::new(vec![0.5, 0.1, 1.0]);
+    /// ```
+    pub fn new(buckets: Vec<f64>) -> Self {
+        let mut buckets: Vec<f64> = buckets.into_iter().filter(|b| b.is_finite()).collect();
+        buckets.sort_by(|a, b| a.total_cmp(b));
+        buckets.dedup();
+
+        Registry { buckets: buckets.into(), inner: Arc::default() }
+    }
+
+    /// Records a single request with method `method` to route `route` which
+    /// resulted in a response with status `status` after `elapsed` time.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use rocket::http::{Method, Status};
+    /// use rocket_metrics::Registry;
+    ///
+    /// let registry = Registry::new(vec![1.0]);
+    /// registry.record(Method::Post, "/upload", Status::Created, Duration::from_secs(2));
+    /// ```
+    pub fn record(&self, method: Method, route: &str, status: Status, elapsed: Duration) {
+        let secs = elapsed.as_secs_f64();
+        let mut inner = self.inner.lock().unwrap_or_else(|e| e.into_inner());
+        *inner.requests.entry((method.as_str(), route.into(), status.code)).or_default() += 1;
+
+        let histogram = inner.durations.entry((method.as_str(), route.into()))
+            .or_insert_with(|| Histogram {
+                counts: vec![0; self.buckets.len()],
+                sum: 0.0,
+                count: 0,
+            });
+
+        for (bound, count) in self.buckets.iter().zip(histogram.counts.iter_mut()) {
+            if secs <= *bound {
+                *count += 1;
+            }
+        }
+
+        histogram.sum += secs;
+        histogram.count += 1;
+    }
+
+    /// Renders all recorded metrics in the Prometheus text exposition format.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket_metrics::Registry;
+    ///
+    /// let registry = Registry::new(vec![1.0]);
+    /// let output = registry.render();
+    /// assert!(output.contains("# TYPE rocket_http_requests_total counter"));
+    /// ```
+    pub fn render(&self) -> String {
+        let inner = self.inner.lock().unwrap_or_else(|e| e.into_inner());
+        let mut out = String::new();
+        let _ = self.write(&inner, &mut out);
+        out
+    }
+
+    fn write(&self, inner: &Inner, out: &mut String) -> fmt::Result {
+        writeln!(out, "# HELP {} Total number of HTTP requests handled.", Self::REQUESTS)?;
+        writeln!(out, "# TYPE {} counter", Self::REQUESTS)?;
+        for ((method, route, status), count) in &inner.requests {
+            writeln!(out, "{}{{method=\"{}\",route=\"{}\",status=\"{}\"}} {}",
+                Self::REQUESTS, method, Label(route), status, count)?;
+        }
+
+        writeln!(out, "# HELP {} HTTP request latencies in seconds.", Self::DURATIONS)?;
+        writeln!(out, "# TYPE {} histogram", Self::DURATIONS)?;
+        for ((method, route), histogram) in &inner.durations {
+            let labels = format!("method=\"{}\",route=\"{}\"", method, Label(route));
+            for (bound, count) in self.buckets.iter().zip(histogram.counts.iter()) {
+                writeln!(out, "{}_bucket{{{},le=\"{}\"}} {}",
+                    Self::DURATIONS, labels, bound, count)?;
+            }
+
+            writeln!(out, "{}_bucket{{{},le=\"+Inf\"}} {}",
+                Self::DURATIONS, labels, histogram.count)?;
+            writeln!(out, "{}_sum{{{}}} {}", Self::DURATIONS, labels, histogram.sum)?;
+            writeln!(out, "{}_count{{{}}} {}", Self::DURATIONS, labels, histogram.count)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Debug for Registry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Registry")
+            .field("buckets", &self.buckets)
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,73 @@
+#[macro_use] extern crate rocket;
+
+use rocket::Config;
+use rocket::figment::Figment;
+use rocket::http::Status;
+use rocket::local::blocking::Client;
+use rocket_metrics::Metrics;
+
+#[get("/")]
+fn index() -> &'static str {
+    "Hello, world!"
+}
+
+#[get("/fail")]
+fn fail() -> Status {
+    Status::InternalServerError
+}
+
+fn rocket() -> rocket::Rocket<rocket::Build> {
+    rocket_with(rocket::Config::figment())
+}
+
+fn rocket_with(figment: Figment) -> rocket::Rocket<rocket::Build> {
+    rocket::custom(figment)
+        .mount("/", routes![index, fail])
+        .attach(Metrics::fairing())
+}
+
+#[test]
+fn records_requests() {
+    let client = Client::debug(rocket()).unwrap();
+    client.get("/").dispatch();
+    client.get("/").dispatch();
+    client.get("/fail").dispatch();
+    client.get("/missing").dispatch();
+
+    let response = client.get("/metrics").dispatch();
+    assert_eq!(response.status(), Status::Ok);
+
+    let content_type = response.content_type().unwrap();
+    assert!(content_type.is_plain());
+
+    let body = response.into_string().unwrap();
+    assert!(body.contains(r#"rocket_http_requests_total{method="GET",route="/",status="200"} 2"#));
+    assert!(body.contains(r#"rocket_http_requests_total{method="GET",route="/fail",status="500"} 1"#));
+    assert!(body.contains(r#"rocket_http_requests_total{method="GET",route="unmatched",status="404"} 1"#));
+    assert!(body.contains(r#"rocket_http_request_duration_seconds_count{method="GET",route="/"} 2"#));
+    assert!(body.contains(r#"rocket_http_request_duration_seconds_bucket{method="GET",route="/",le="+Inf"} 2"#));
+}
+
+#[test]
+fn configurable_path() {
+    let rocket = rocket_with(Config::figment().merge(("metrics.path", "/internal/stats")));
+    let client = Client::debug(rocket).unwrap();
+    assert_eq!(client.get("/metrics").dispatch().status(), Status::NotFound);
+    assert_eq!(client.get("/internal/stats").dispatch().status(), Status::Ok);
+}
+
+#[test]
+fn can_be_disabled() {
+    let rocket = rocket_with(Config::figment().merge(("metrics.enabled", false)));
+    let client = Client::debug(rocket).unwrap();
+    assert_eq!(client.get("/metrics").dispatch().status(), Status::NotFound);
+    assert!(client.rocket().state::<rocket_metrics::Registry>().is_none());
+}
+
+#[test]
+fn invalid_path_fails_ignition() {
+    for path in ["metrics", "/metrics?format=text", "/metrics/<id>", ""] {
+        let rocket = rocket_with(Config::figment().merge(("metrics.path", path)));
+        assert!(Client::debug(rocket).is_err(), "{:?} should be rejected", path);
+    }
+}
@@ -0,0 +1,48 @@
+use rocket::serde::{Deserialize, Serialize};
+
+/// Configuration for the [`OpenTelemetry`](crate::OpenTelemetry) fairing.
+///
+/// A dictionary matching this structure is extracted from the `otel` key of
+/// the active [`Figment`](rocket::figment::Figment) on ignition. Every field is
+/// optional and defaults to the value in [`Config::default()`].
+///
+/// ```toml
+/// [default.otel]
+/// enabled = true
+/// service_name = "my-app"
+/// endpoint = "http://collector:4317"
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(crate = "rocket::serde")]
+pub struct Config {
+    /// Whether requests are traced.
+    ///
+    /// When `false`, the fairing neither starts spans nor installs an
+    /// exporter.
+    ///
+    /// _Default:_ `true`.
+    pub enabled: bool,
+    /// The value of the `service.name` resource attribute of exported spans.
+    ///
+    /// Only used by [`OpenTelemetry::otlp()`](crate::OpenTelemetry).
+    ///
+    /// _Default:_ `"rocket"`.
+    pub service_name: String,
+    /// The endpoint of the OTLP collector spans are exported to. When `None`,
+    /// the exporter's default, typically `http://localhost:4317`, is used.
+    ///
+    /// Only used by [`OpenTelemetry::otlp()`](crate::OpenTelemetry).
+    ///
+    /// _Default:_ `None`.
+    pub endpoint: Option<String>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            enabled: true,
+            service_name: "rocket".into(),
+            endpoint: None,
+        }
+    }
+}
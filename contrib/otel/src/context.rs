@@ -0,0 +1,106 @@
+use std::collections::HashMap;
+use std::convert::Infallible;
+
+use rocket::Request;
+use rocket::http::HeaderMap;
+use rocket::request::{FromRequest, Outcome};
+
+use opentelemetry::Context;
+use opentelemetry::propagation::{Extractor, TextMapPropagator};
+use opentelemetry_sdk::propagation::TraceContextPropagator;
+
+/// Request guard for the trace context of the current request.
+///
+/// When the [`OpenTelemetry`](crate::OpenTelemetry) fairing is attached and
+/// enabled, the context contains the request's server span. Otherwise, the
+/// context is empty. The guard never fails.
+///
+/// Use [`TraceContext::headers()`] to propagate the trace to outbound requests
+/// or [`TraceContext::context()`] to start child spans.
+///
+/// # Example
+///
+/// ```rust
+/// # use rocket::get;
+/// use rocket_otel::TraceContext;
+/// use rocket_otel::opentelemetry::global;
+/// use rocket_otel::opentelemetry::trace::Tracer;
+///
+/// #[get("/")]
+/// fn index(trace: TraceContext) -> &'static str {
+///     let tracer = global::tracer("my-app");
+///     let _span = tracer.start_with_context("compute", trace.context());
+///     "done"
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct TraceContext {
+    context: Context,
+}
+
+/// The context of a request's span. Stored in request-local state.
+pub(crate) struct RequestContext(pub(crate) Context);
+
+/// A propagation [`Extractor`] over a request's headers.
+pub(crate) struct HeaderExtractor<'a, 'h> {
+    headers: &'a HeaderMap<'h>,
+    keys: Vec<String>,
+}
+
+impl<'a, 'h> HeaderExtractor<'a, 'h> {
+    pub(crate) fn new(headers: &'a HeaderMap<'h>) -> Self {
+        let keys = headers.iter().map(|h| h.name().as_str().to_owned()).collect();
+        HeaderExtractor { headers, keys }
+    }
+}
+
+impl Extractor for HeaderExtractor<'_, '_> {
+    fn get(&self, key: &str) -> Option<&str> {
+        self.headers.get_one(key)
+    }
+
+    fn keys(&self) -> Vec<&str> {
+        self.keys.iter().map(|k| k.as_str()).collect()
+    }
+}
+
+impl TraceContext {
+    /// Returns the OpenTelemetry context of the request.
+    pub fn context(&self) -> &Context {
+        &self.context
+    }
+
+    /// Returns the [W3C Trace Context] headers, `traceparent` and, if
+    /// applicable, `tracestate`, which propagate the request's trace. Returns
+    /// an empty map if the context has no valid span.
+    ///
+    /// [W3C Trace Context]: https://www.w3.org/TR/trace-context/
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use rocket::get;
+    /// use rocket_otel::TraceContext;
+    ///
+    /// #[get("/")]
+    /// fn index(trace: TraceContext) -> String {
+    ///     let headers = trace.headers();
+    ///     headers.get("traceparent").cloned().unwrap_or_default()
+    /// }
+    /// ```
+    pub fn headers(&self) -> HashMap<String, String> {
+        let mut headers = HashMap::new();
+        TraceContextPropagator::new().inject_context(&self.context, &mut headers);
+        headers
+    }
+}
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for TraceContext {
+    type Error = Infallible;
+
+    async fn from_request(req: &'r Request<'_>) -> Outcome<Self, Infallible> {
+        let context = req.local_cache(|| RequestContext(Context::new())).0.clone();
+        Outcome::Success(TraceContext { context })
+    }
+}
@@ -0,0 +1,193 @@
+use rocket::{error, info_, Rocket, Build, Orbit, Request, Response, Data};
+use rocket::fairing::{self, Fairing, Info, Kind};
+use rocket::figment::providers::Serialized;
+use rocket::http::StatusClass;
+
+use opentelemetry::{global, Context, KeyValue};
+use opentelemetry::global::BoxedTracer;
+use opentelemetry::propagation::TextMapPropagator;
+use opentelemetry::trace::{SpanKind, Status, TraceContextExt, Tracer};
+use opentelemetry_sdk::propagation::TraceContextPropagator;
+
+use crate::Config;
+use crate::context::{HeaderExtractor, RequestContext};
+
+/// The request tracing fairing.
+///
+/// When attached, this fairing:
+///
+///   * extracts a [`Config`] from the `otel` key of the active figment;
+///   * if enabled and created via [`OpenTelemetry::otlp()`], installs an OTLP
+///     exporter as the global tracer provider, shutting it down, and thus
+///     flushing pending spans, when Rocket shuts down;
+///   * starts a span for every request, as a child of the span propagated via
+///     `traceparent`, if any, and ends it when the response is ready.
+///
+/// See the [crate docs](crate) for usage details.
+#[derive(Debug, Default)]
+pub struct OpenTelemetry {
+    otlp: bool,
+}
+
+/// The tracer used to start request spans. Placed in managed state.
+struct ServerTracer(BoxedTracer);
+
+impl OpenTelemetry {
+    /// The name of the tracer used to start request spans.
+    pub const TRACER_NAME: &'static str = "rocket";
+
+    /// Returns a fairing which traces requests using the globally installed
+    /// tracer provider.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use rocket::launch;
+    /// use rocket_otel::OpenTelemetry;
+    ///
+    /// #[launch]
+    /// fn rocket() -> _ {
+    ///     // Install a tracer provider via `global::set_tracer_provider()`.
+    ///     rocket::build().attach(OpenTelemetry::fairing())
+    /// }
+    /// ```
+    pub fn fairing() -> OpenTelemetry {
+        OpenTelemetry { otlp: false }
+    }
+
+    /// Returns a fairing which installs a batching OTLP exporter, configured
+    /// via [`Config::service_name`] and [`Config::endpoint`], as the global
+    /// tracer provider and traces requests with it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use rocket::launch;
+    /// use rocket_otel::OpenTelemetry;
+    ///
+    /// #[launch]
+    /// fn rocket() -> _ {
+    ///     rocket::build().attach(OpenTelemetry::otlp())
+    /// }
+    /// ```
+    #[cfg(feature = "otlp")]
+    pub fn otlp() -> OpenTelemetry {
+        OpenTelemetry { otlp: true }
+    }
+
+    #[cfg(feature = "otlp")]
+    fn install_otlp(config: &Config) -> Result<(), opentelemetry::trace::TraceError> {
+        use opentelemetry_otlp::WithExportConfig;
+        use opentelemetry_sdk::{trace, Resource};
+
+        let mut exporter = opentelemetry_otlp::new_exporter().tonic();
+        if let Some(endpoint) = &config.endpoint {
+            exporter = exporter.with_endpoint(endpoint);
+        }
+
+        let name = KeyValue::new("service.name", config.service_name.clone());
+        opentelemetry_otlp::new_pipeline()
+            .tracing()
+            .with_exporter(exporter)
+            .with_trace_config(trace::config().with_resource(Resource::new([name])))
+            .install_batch(opentelemetry_sdk::runtime::Tokio)?;
+
+        Ok(())
+    }
+
+    #[cfg(not(feature = "otlp"))]
+    fn install_otlp(_: &Config) -> Result<(), opentelemetry::trace::TraceError> {
+        Ok(())
+    }
+}
+
+#[rocket::async_trait]
+impl Fairing for OpenTelemetry {
+    fn info(&self) -> Info {
+        Info {
+            name: "OpenTelemetry",
+            kind: Kind::Ignite | Kind::Request | Kind::Response | Kind::Shutdown
+                | Kind::Singleton,
+        }
+    }
+
+    async fn on_ignite(&self, rocket: Rocket<Build>) -> fairing::Result {
+        let figment = rocket.figment()
+            .focus("otel")
+            .join(Serialized::defaults(Config::default()));
+
+        let config: Config = match figment.extract() {
+            Ok(config) => config,
+            Err(e) => {
+                rocket::config::pretty_print_error(e);
+                return Err(rocket);
+            }
+        };
+
+        if !config.enabled {
+            info_!("OpenTelemetry tracing is disabled.");
+            return Ok(rocket);
+        }
+
+        if self.otlp {
+            if let Err(e) = Self::install_otlp(&config) {
+                error!("failed to install OTLP exporter: {}", e);
+                return Err(rocket);
+            }
+        }
+
+        Ok(rocket.manage(ServerTracer(global::tracer(Self::TRACER_NAME))))
+    }
+
+    async fn on_request(&self, req: &mut Request<'_>, _: &mut Data<'_>) {
+        let Some(ServerTracer(tracer)) = req.rocket().state::<ServerTracer>() else {
+            return;
+        };
+
+        let parent = TraceContextPropagator::new().extract(&HeaderExtractor::new(req.headers()));
+        let mut attributes = vec![
+            KeyValue::new("http.request.method", req.method().as_str()),
+            KeyValue::new("url.path", req.uri().path().to_string()),
+        ];
+
+        if let Some(id) = req.request_id() {
+            attributes.push(KeyValue::new("rocket.request_id", id.to_string()));
+        }
+
+        let builder = tracer.span_builder(req.method().as_str())
+            .with_kind(SpanKind::Server)
+            .with_attributes(attributes);
+
+        let span = tracer.build_with_context(builder, &parent);
+        req.local_cache(|| RequestContext(parent.with_span(span)));
+    }
+
+    async fn on_response<'r>(&self, req: &'r Request<'_>, res: &mut Response<'r>) {
+        if req.rocket().state::<ServerTracer>().is_none() {
+            return;
+        }
+
+        let RequestContext(context) = req.local_cache(|| RequestContext(Context::new()));
+
+        let span = context.span();
+        if let Some(route) = req.route() {
+            let route = route.uri.to_string();
+            span.update_name(format!("{} {}", req.method(), route));
+            span.set_attribute(KeyValue::new("http.route", route));
+        }
+
+        let status = res.status();
+        span.set_attribute(KeyValue::new("http.response.status_code", status.code as i64));
+        if status.class() == StatusClass::ServerError {
+            span.set_status(Status::error(status.to_string()));
+        }
+
+        span.end();
+    }
+
+    async fn on_shutdown(&self, rocket: &Rocket<Orbit>) {
+        if self.otlp && rocket.state::<ServerTracer>().is_some() {
+            let _ = rocket::tokio::task::spawn_blocking(global::shutdown_tracer_provider).await;
+        }
+    }
+}
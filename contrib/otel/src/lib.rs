@@ -0,0 +1,101 @@
+//! OpenTelemetry request tracing for Rocket.
+//!
+//! This crate provides an [`OpenTelemetry`] fairing which starts a server
+//! [span] for every request and ends it once the response is ready. Spans are
+//! recorded with the following [semantic convention] attributes:
+//!
+//!   * `http.request.method`: the request method
+//!   * `url.path`: the request path
+//!   * `http.route`: the URI of the matched route, if any
+//!   * `http.response.status_code`: the response status code
+//!
+//! If the [`RequestId`](rocket::request_id::RequestId) fairing is attached,
+//! the request's ID is recorded as `rocket.request_id`. The span's start and
+//! end times record the request's latency. Responses with a `5xx` status mark
+//! the span as an error.
+//!
+//! [span]: https://opentelemetry.io/docs/concepts/signals/traces/#spans
+//! [semantic convention]: https://opentelemetry.io/docs/specs/semconv/http/http-spans/
+//!
+//! # Usage
+//!
+//! Depend on the crate. To export spans via OTLP, enable the `otlp` feature:
+//!
+//! ```toml
+//! [dependencies]
+//! rocket_otel = { version = "0.1.0", features = ["otlp"] }
+//! ```
+//!
+//! Then attach the fairing returned by [`OpenTelemetry::otlp()`], which
+//! installs a batching OTLP exporter as the global tracer provider:
+//!
+//! ```rust
+//! # #[cfg(feature = "otlp")] mod _m {
+//! # use rocket::launch;
+//! use rocket_otel::OpenTelemetry;
+//!
+//! #[launch]
+//! fn rocket() -> _ {
+//!     rocket::build().attach(OpenTelemetry::otlp())
+//! }
+//! # }
+//! ```
+//!
+//! Alternatively, install any tracer provider globally via
+//! [`opentelemetry::global::set_tracer_provider()`] and attach
+//! [`OpenTelemetry::fairing()`], which uses the global provider as-is.
+//!
+//! # Configuration
+//!
+//! The fairing reads its configuration from the `otel` key of the active
+//! figment. With the default provider, it can be configured in `Rocket.toml`:
+//!
+//! ```toml
+//! [default.otel]
+//! # Whether to trace requests.
+//! enabled = true
+//! # The `service.name` resource attribute of exported spans.
+//! service_name = "my-app"
+//! # The OTLP collector endpoint. Defaults to the exporter's default.
+//! endpoint = "http://localhost:4317"
+//! ```
+//!
+//! All keys are optional. See [`Config`] for defaults.
+//!
+//! # Trace Context Propagation
+//!
+//! Incoming requests carrying a [W3C Trace Context] `traceparent` header are
+//! traced as children of the propagated span. To continue the trace in
+//! outbound requests, use the [`TraceContext`] request guard to retrieve the
+//! headers to send:
+//!
+//! ```rust
+//! # use rocket::get;
+//! use rocket_otel::TraceContext;
+//!
+//! #[get("/")]
+//! fn index(trace: TraceContext) -> String {
+//!     for (name, value) in trace.headers() {
+//!         /* add `name: value` to an outbound request */
+//!     }
+//!
+//!     # "".into()
+//!     /* ... */
+//! }
+//! ```
+//!
+//! [W3C Trace Context]: https://www.w3.org/TR/trace-context/
+
+#![doc(html_root_url = "https://api.rocket.rs/master/rocket_otel")]
+#![doc(html_favicon_url = "https://rocket.rs/images/favicon.ico")]
+#![doc(html_logo_url = "https://rocket.rs/images/logo-boxed.png")]
+
+pub use opentelemetry;
+
+mod config;
+mod context;
+mod fairing;
+
+pub use self::config::Config;
+pub use self::context::TraceContext;
+pub use self::fairing::OpenTelemetry;
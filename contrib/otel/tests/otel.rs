@@ -0,0 +1,90 @@
+#[macro_use] extern crate rocket;
+
+use rocket::http::{Header, Status};
+use rocket::local::blocking::Client;
+use rocket_otel::{OpenTelemetry, TraceContext};
+
+use opentelemetry::{global, Key, Value};
+use opentelemetry::trace::{SpanKind, TraceId, SpanId};
+use opentelemetry_sdk::export::trace::SpanData;
+use opentelemetry_sdk::trace::TracerProvider;
+use opentelemetry_sdk::testing::trace::InMemorySpanExporter;
+
+const TRACE_ID: &str = "4bf92f3577b34da6a3ce929d0e0e4736";
+const PARENT_ID: &str = "00f067aa0ba902b7";
+
+#[get("/hello/<name>")]
+fn hello(name: &str, trace: TraceContext) -> String {
+    let traceparent = trace.headers().remove("traceparent").unwrap_or_default();
+    format!("{}:{}", name, traceparent)
+}
+
+#[get("/fail")]
+fn fail() -> Status {
+    Status::InternalServerError
+}
+
+fn attribute<'a>(span: &'a SpanData, key: &'static str) -> Option<&'a Value> {
+    span.attributes.iter().find(|kv| kv.key == Key::from_static_str(key)).map(|kv| &kv.value)
+}
+
+// The tracer provider is global, so all assertions live in one test.
+#[test]
+fn traces_requests() {
+    let exporter = InMemorySpanExporter::default();
+    let provider = TracerProvider::builder()
+        .with_simple_exporter(exporter.clone())
+        .build();
+
+    global::set_tracer_provider(provider.clone());
+
+    let rocket = rocket::build()
+        .mount("/", routes![hello, fail])
+        .attach(OpenTelemetry::fairing());
+
+    let client = Client::debug(rocket).unwrap();
+
+    // A propagated trace is continued and made available to handlers.
+    let traceparent = format!("00-{}-{}-01", TRACE_ID, PARENT_ID);
+    let response = client.get("/hello/bob")
+        .header(Header::new("traceparent", traceparent.clone()))
+        .dispatch();
+
+    let body = response.into_string().unwrap();
+    let (name, outbound) = body.split_once(':').unwrap();
+    assert_eq!(name, "bob");
+    assert!(outbound.starts_with(&format!("00-{}-", TRACE_ID)));
+    assert_ne!(outbound, traceparent);
+
+    client.get("/fail").dispatch();
+    client.get("/missing").dispatch();
+
+    for result in provider.force_flush() {
+        result.unwrap();
+    }
+
+    let spans = exporter.get_finished_spans().unwrap();
+    assert_eq!(spans.len(), 3);
+
+    let hello = &spans[0];
+    assert_eq!(hello.name, "GET /hello/<name>");
+    assert_eq!(hello.span_kind, SpanKind::Server);
+    assert_eq!(hello.span_context.trace_id(), TraceId::from_hex(TRACE_ID).unwrap());
+    assert_eq!(hello.parent_span_id, SpanId::from_hex(PARENT_ID).unwrap());
+    assert_eq!(attribute(hello, "http.request.method"), Some(&Value::from("GET")));
+    assert_eq!(attribute(hello, "url.path"), Some(&Value::from("/hello/bob")));
+    assert_eq!(attribute(hello, "http.route"), Some(&Value::from("/hello/<name>")));
+    assert_eq!(attribute(hello, "http.response.status_code"), Some(&Value::I64(200)));
+    assert_eq!(hello.status, opentelemetry::trace::Status::Unset);
+
+    let fail = &spans[1];
+    assert_eq!(fail.name, "GET /fail");
+    assert_ne!(fail.span_context.trace_id(), TraceId::from_hex(TRACE_ID).unwrap());
+    assert_eq!(attribute(fail, "http.response.status_code"), Some(&Value::I64(500)));
+    assert!(matches!(fail.status, opentelemetry::trace::Status::Error { .. }));
+
+    let missing = &spans[2];
+    assert_eq!(missing.name, "GET");
+    assert_eq!(attribute(missing, "http.route"), None);
+    assert_eq!(attribute(missing, "http.response.status_code"), Some(&Value::I64(404)));
+}
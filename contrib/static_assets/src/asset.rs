@@ -0,0 +1,98 @@
+use std::io;
+use std::sync::Arc;
+use std::borrow::Cow;
+use std::path::{Path, PathBuf};
+
+use rocket::http::ContentType;
+
+/// The contents of an asset: inline bytes or a file to read at ignition.
+///
+/// An `Asset` can be created from inline data via the `From` implementations
+/// for `&'static [u8]`, `&'static [u8; N]`, `Vec<u8>`, `&'static str`, and
+/// `String`, or from a file via [`Asset::file()`].
+///
+/// # Example
+///
+/// ```rust
+/// use rocket_static_assets::Asset;
+///
+/// let inline = Asset::from("User-agent: *\nDisallow:\n");
+/// let file = Asset::file("static/robots.txt");
+/// ```
+#[derive(Debug, Clone)]
+pub struct Asset(Source);
+
+#[derive(Debug, Clone)]
+enum Source {
+    Bytes(Cow<'static, [u8]>),
+    File(PathBuf),
+}
+
+impl Asset {
+    /// An asset read from the file at `path` when the fairing ignites. The
+    /// file must be readable at that time or ignition fails. Relative paths
+    /// are relative to the current working directory.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket_static_assets::Asset;
+    ///
+    /// let favicon = Asset::file("static/favicon.ico");
+    /// ```
+    pub fn file<P: AsRef<Path>>(path: P) -> Asset {
+        Asset(Source::File(path.as_ref().to_path_buf()))
+    }
+
+    /// The content-type of the asset: the one corresponding to the file's
+    /// extension, if it is known, or `default` otherwise.
+    pub(crate) fn content_type(&self, default: ContentType) -> ContentType {
+        match &self.0 {
+            Source::File(path) => path.extension()
+                .and_then(|ext| ext.to_str())
+                .and_then(ContentType::from_extension)
+                .unwrap_or(default),
+            Source::Bytes(_) => default,
+        }
+    }
+
+    /// Reads the contents of the asset.
+    pub(crate) async fn load(&self) -> io::Result<Arc<[u8]>> {
+        match &self.0 {
+            Source::Bytes(bytes) => Ok(bytes.as_ref().into()),
+            Source::File(path) => rocket::tokio::fs::read(path).await
+                .map(Arc::from)
+                .map_err(|e| io::Error::new(e.kind(), format!("{}: {}", path.display(), e))),
+        }
+    }
+}
+
+impl From<&'static [u8]> for Asset {
+    fn from(bytes: &'static [u8]) -> Self {
+        Asset(Source::Bytes(Cow::Borrowed(bytes)))
+    }
+}
+
+impl<const N: usize> From<&'static [u8; N]> for Asset {
+    fn from(bytes: &'static [u8; N]) -> Self {
+        Asset::from(bytes.as_slice())
+    }
+}
+
+impl From<Vec<u8>> for Asset {
+    fn from(bytes: Vec<u8>) -> Self {
+        Asset(Source::Bytes(Cow::Owned(bytes)))
+    }
+}
+
+impl From<&'static str> for Asset {
+    fn from(string: &'static str) -> Self {
+        Asset::from(string.as_bytes())
+    }
+}
+
+impl From<String> for Asset {
+    fn from(string: String) -> Self {
+        Asset::from(string.into_bytes())
+    }
+}
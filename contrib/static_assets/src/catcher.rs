@@ -0,0 +1,117 @@
+use std::path::{Path, PathBuf};
+
+use rocket::{Request, Catcher, catcher};
+use rocket::http::Status;
+use rocket::fs::NamedFile;
+use rocket::response::Responder;
+
+/// A catcher that serves static error pages from a directory.
+///
+/// For an error with status code `code`, `StaticCatcher` responds with the
+/// file `{code}.html` in its directory, if it exists, with the status code
+/// `code` and a `Content-Type` of HTML. If the file does not exist or cannot
+/// be opened, the catcher declines, and Rocket falls back to the next matching
+/// catcher, ultimately to its built-in error page.
+///
+/// Pages are read from disk on every error, so they can be changed without
+/// restarting the application.
+///
+/// # Example
+///
+/// Serve `errors/404.html`, `errors/500.html`, and so on, for every error:
+///
+/// ```rust
+/// # use rocket::launch;
+/// use rocket_static_assets::StaticCatcher;
+///
+/// #[launch]
+/// fn rocket() -> _ {
+///     rocket::build().register("/", StaticCatcher::from("errors"))
+/// }
+/// ```
+///
+/// Serve pages only for `404` and `500` errors:
+///
+/// ```rust
+/// # use rocket::launch;
+/// use rocket_static_assets::StaticCatcher;
+///
+/// #[launch]
+/// fn rocket() -> _ {
+///     rocket::build().register("/", StaticCatcher::from("errors").only([404, 500]))
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct StaticCatcher {
+    root: PathBuf,
+    codes: Vec<u16>,
+}
+
+impl StaticCatcher {
+    /// Creates a `StaticCatcher` serving error pages from the directory at
+    /// `path`. Relative paths are relative to the current working directory.
+    ///
+    /// The directory is not checked for existence. Missing pages cause the
+    /// catcher to decline.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket_static_assets::StaticCatcher;
+    ///
+    /// let catcher = StaticCatcher::from("static/errors");
+    /// ```
+    pub fn from<P: AsRef<Path>>(path: P) -> StaticCatcher {
+        StaticCatcher { root: path.as_ref().to_path_buf(), codes: vec![] }
+    }
+
+    /// Restricts the catcher to errors with status codes in `codes`. By
+    /// default, the catcher is registered as a `default` catcher and handles
+    /// errors of any status.
+    ///
+    /// # Panics
+    ///
+    /// Panics when converted into catchers if any code is not in the range
+    /// `[400, 600)`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket_static_assets::StaticCatcher;
+    ///
+    /// let catcher = StaticCatcher::from("static/errors").only([404, 422, 500]);
+    /// ```
+    pub fn only<I: IntoIterator<Item = u16>>(mut self, codes: I) -> Self {
+        self.codes = codes.into_iter().collect();
+        self
+    }
+}
+
+#[rocket::async_trait]
+impl catcher::Handler for StaticCatcher {
+    async fn handle<'r>(&self, status: Status, req: &'r Request<'_>) -> catcher::Result<'r> {
+        let path = self.root.join(format!("{}.html", status.code));
+        match NamedFile::open(&path).await {
+            Ok(file) => (status, file).respond_to(req),
+            Err(_) => Err(status),
+        }
+    }
+}
+
+impl From<StaticCatcher> for Vec<Catcher> {
+    fn from(catcher: StaticCatcher) -> Self {
+        let name = format!("StaticCatcher: {}", catcher.root.display());
+        let codes: Vec<Option<u16>> = match catcher.codes.is_empty() {
+            true => vec![None],
+            false => catcher.codes.iter().copied().map(Some).collect(),
+        };
+
+        codes.into_iter()
+            .map(|code| {
+                let mut catcher = Catcher::new(code, catcher.clone());
+                catcher.name = Some(name.clone().into());
+                catcher
+            })
+            .collect()
+    }
+}
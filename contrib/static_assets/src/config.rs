@@ -0,0 +1,47 @@
+use rocket::serde::{Deserialize, Serialize};
+use rocket::figment::value::magic::RelativePathBuf;
+
+/// Configuration for the [`StaticAssets`](crate::StaticAssets) fairing.
+///
+/// A dictionary matching this structure is extracted from the `static_assets`
+/// key of the active [`Figment`](rocket::figment::Figment) on ignition. Every
+/// field is optional and defaults to the value in [`Config::default()`].
+///
+/// ```toml
+/// [default.static_assets]
+/// favicon = "static/favicon.png"
+/// robots = "static/robots.txt"
+/// max_age = 3600
+/// ```
+///
+/// Relative paths are relative to the configuration file or, if there is
+/// none, the current working directory.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(crate = "rocket::serde")]
+pub struct Config {
+    /// The path to the file served at `/favicon.ico`, if any.
+    ///
+    /// When set, overrides the favicon set via
+    /// [`StaticAssets::favicon()`](crate::StaticAssets::favicon()).
+    ///
+    /// _Default:_ `None`.
+    pub favicon: Option<RelativePathBuf>,
+    /// The path to the file served at `/robots.txt`, if any.
+    ///
+    /// When set, overrides the file set via
+    /// [`StaticAssets::robots()`](crate::StaticAssets::robots()).
+    ///
+    /// _Default:_ `None`.
+    pub robots: Option<RelativePathBuf>,
+    /// The `max-age`, in seconds, of the `Cache-Control` header sent with
+    /// every asset.
+    ///
+    /// _Default:_ `86400` (one day).
+    pub max_age: u32,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config { favicon: None, robots: None, max_age: 86400 }
+    }
+}
@@ -0,0 +1,168 @@
+use std::sync::Arc;
+
+use rocket::{error, info_, Rocket, Build, Request, Data, Route};
+use rocket::fairing::{self, Fairing, Info, Kind};
+use rocket::figment::providers::Serialized;
+use rocket::http::{ContentType, Method};
+use rocket::response::Responder;
+use rocket::route::{Handler, Outcome};
+
+use crate::{Asset, Config};
+
+/// The favicon and `robots.txt` fairing.
+///
+/// When attached, this fairing:
+///
+///   * extracts a [`Config`] from the `static_assets` key of the active
+///     figment;
+///   * reads every provided asset into memory, failing ignition if any cannot
+///     be read;
+///   * mounts a route at `/favicon.ico` and `/robots.txt` for each asset that
+///     was provided, programmatically or via configuration.
+///
+/// See the [crate docs](crate) for usage details.
+#[derive(Debug, Default, Clone)]
+pub struct StaticAssets {
+    favicon: Option<Asset>,
+    robots: Option<Asset>,
+}
+
+/// Serves an asset's contents with a fixed content-type and caching policy.
+#[derive(Clone)]
+struct AssetHandler {
+    bytes: Arc<[u8]>,
+    content_type: ContentType,
+    cache_control: String,
+}
+
+impl StaticAssets {
+    /// Returns a fairing which serves no assets other than those configured.
+    /// Use [`StaticAssets::favicon()`] and [`StaticAssets::robots()`] to
+    /// provide assets programmatically.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use rocket::launch;
+    /// use rocket_static_assets::StaticAssets;
+    ///
+    /// #[launch]
+    /// fn rocket() -> _ {
+    ///     rocket::build().attach(StaticAssets::fairing())
+    /// }
+    /// ```
+    pub fn fairing() -> StaticAssets {
+        StaticAssets::default()
+    }
+
+    /// Serves `asset` at `/favicon.ico` unless a `favicon` path is configured.
+    ///
+    /// The `Content-Type` is `image/x-icon` unless `asset` is a file with a
+    /// known extension, such as `.png` or `.svg`, in which case the
+    /// corresponding `Content-Type` is used.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket_static_assets::{StaticAssets, Asset};
+    ///
+    /// let fairing = StaticAssets::fairing().favicon(Asset::file("static/icon.png"));
+    /// ```
+    pub fn favicon<A: Into<Asset>>(mut self, asset: A) -> Self {
+        self.favicon = Some(asset.into());
+        self
+    }
+
+    /// Serves `asset` at `/robots.txt` unless a `robots` path is configured.
+    ///
+    /// The `Content-Type` is always `text/plain`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket_static_assets::StaticAssets;
+    ///
+    /// let fairing = StaticAssets::fairing().robots("User-agent: *\nDisallow:\n");
+    /// ```
+    pub fn robots<A: Into<Asset>>(mut self, asset: A) -> Self {
+        self.robots = Some(asset.into());
+        self
+    }
+}
+
+#[rocket::async_trait]
+impl Handler for AssetHandler {
+    async fn handle<'r>(&self, req: &'r Request<'_>, _: Data<'r>) -> Outcome<'r> {
+        let mut response = match self.bytes.clone().respond_to(req) {
+            Ok(response) => response,
+            Err(status) => return Outcome::Error(status),
+        };
+
+        response.set_header(self.content_type.clone());
+        response.set_raw_header("Cache-Control", self.cache_control.clone());
+        Outcome::Success(response)
+    }
+}
+
+#[rocket::async_trait]
+impl Fairing for StaticAssets {
+    fn info(&self) -> Info {
+        Info { name: "Static Assets", kind: Kind::Ignite | Kind::Singleton }
+    }
+
+    async fn on_ignite(&self, rocket: Rocket<Build>) -> fairing::Result {
+        let figment = rocket.figment()
+            .focus("static_assets")
+            .join(Serialized::defaults(Config::default()));
+
+        let config: Config = match figment.extract() {
+            Ok(config) => config,
+            Err(e) => {
+                rocket::config::pretty_print_error(e);
+                return Err(rocket);
+            }
+        };
+
+        let favicon = config.favicon.map(|p| Asset::file(p.relative())).or(self.favicon.clone());
+        let robots = config.robots.map(|p| Asset::file(p.relative())).or(self.robots.clone());
+        let assets = [
+            ("favicon", "/favicon.ico", favicon, Some(ContentType::Icon)),
+            ("robots", "/robots.txt", robots, None),
+        ];
+
+        let mut routes = vec![];
+        for (name, path, asset, inferred_default) in assets {
+            let Some(asset) = asset else {
+                continue;
+            };
+
+            let bytes = match asset.load().await {
+                Ok(bytes) => bytes,
+                Err(e) => {
+                    error!("failed to read {} asset: {}", name, e);
+                    return Err(rocket);
+                }
+            };
+
+            let handler = AssetHandler {
+                bytes,
+                content_type: match inferred_default {
+                    Some(default) => asset.content_type(default),
+                    None => ContentType::Text,
+                },
+                cache_control: format!("public, max-age={}", config.max_age),
+            };
+
+            let mut route = Route::new(Method::Get, path, handler);
+            route.name = Some(format!("StaticAssets: {}", name).into());
+            routes.push(route);
+        }
+
+        if routes.is_empty() {
+            info_!("No static assets were provided.");
+            return Ok(rocket);
+        }
+
+        Ok(rocket.mount("/", routes))
+    }
+}
@@ -0,0 +1,84 @@
+//! Favicon, `robots.txt`, and static error page serving for Rocket.
+//!
+//! This crate provides a [`StaticAssets`] fairing which serves
+//! `GET /favicon.ico` and `GET /robots.txt` from inline bytes or files. Assets
+//! are read into memory once, at ignition, and served with an appropriate
+//! `Content-Type` and a `Cache-Control` header. This avoids mounting a
+//! [`FileServer`](rocket::fs::FileServer) or writing routes for two files
+//! every site is expected to have.
+//!
+//! # Usage
+//!
+//! Depend on the crate:
+//!
+//! ```toml
+//! [dependencies]
+//! rocket_static_assets = "0.1.0"
+//! ```
+//!
+//! Then attach the fairing returned by [`StaticAssets::fairing()`], providing
+//! the assets to serve as inline data, perhaps via `include_bytes!()`, or as
+//! files:
+//!
+//! ```rust
+//! # use rocket::launch;
+//! use rocket_static_assets::{StaticAssets, Asset};
+//!
+//! #[launch]
+//! fn rocket() -> _ {
+//!     rocket::build().attach(StaticAssets::fairing()
+//!         .favicon(Asset::file("static/favicon.ico"))
+//!         .robots("User-agent: *\nDisallow: /admin\n"))
+//! }
+//! ```
+//!
+//! Only the assets that are provided are served. Requests for missing assets
+//! are routed as usual.
+//!
+//! # Configuration
+//!
+//! The fairing reads its configuration from the `static_assets` key of the
+//! active figment. With the default provider, it can be configured in
+//! `Rocket.toml`:
+//!
+//! ```toml
+//! [default.static_assets]
+//! # Path to the favicon. Overrides any favicon set programmatically.
+//! favicon = "static/favicon.ico"
+//! # Path to `robots.txt`. Overrides any set programmatically.
+//! robots = "static/robots.txt"
+//! # The `max-age`, in seconds, of the `Cache-Control` header.
+//! max_age = 86400
+//! ```
+//!
+//! All keys are optional. See [`Config`] for defaults.
+//!
+//! # Error Pages
+//!
+//! The [`StaticCatcher`] catcher serves static error pages from a directory:
+//! `{code}.html` for an error with status `code`. When no page exists for an
+//! error, Rocket's built-in error page is served instead:
+//!
+//! ```rust
+//! # use rocket::launch;
+//! use rocket_static_assets::StaticCatcher;
+//!
+//! #[launch]
+//! fn rocket() -> _ {
+//!     rocket::build().register("/", StaticCatcher::from("static/errors"))
+//! }
+//! ```
+
+#![doc(html_root_url = "https://api.rocket.rs/master/rocket_static_assets")]
+#![doc(html_favicon_url = "https://rocket.rs/images/favicon.ico")]
+#![doc(html_logo_url = "https://rocket.rs/images/logo-boxed.png")]
+
+mod asset;
+mod catcher;
+mod config;
+mod fairing;
+
+pub use self::asset::Asset;
+pub use self::catcher::StaticCatcher;
+pub use self::config::Config;
+pub use self::fairing::StaticAssets;
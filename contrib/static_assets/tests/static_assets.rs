@@ -0,0 +1,81 @@
+#[macro_use] extern crate rocket;
+
+use std::path::{Path, PathBuf};
+
+use rocket::figment::Figment;
+use rocket::http::{ContentType, Status};
+use rocket::local::blocking::Client;
+use rocket_static_assets::{StaticAssets, Asset};
+
+const FAVICON: &[u8] = &[0, 0, 1, 0, 1, 0];
+
+#[get("/<path..>", rank = 20)]
+fn catch_all(path: PathBuf) -> String {
+    format!("route: {}", path.display())
+}
+
+fn static_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("static")
+}
+
+fn rocket_with(figment: Figment, assets: StaticAssets) -> rocket::Rocket<rocket::Build> {
+    rocket::custom(figment)
+        .mount("/", routes![catch_all])
+        .attach(assets)
+}
+
+#[test]
+fn serves_inline_assets() {
+    let assets = StaticAssets::fairing()
+        .favicon(FAVICON)
+        .robots("User-agent: *\nDisallow:\n");
+
+    let client = Client::debug(rocket_with(rocket::Config::figment(), assets)).unwrap();
+
+    let response = client.get("/favicon.ico").dispatch();
+    assert_eq!(response.status(), Status::Ok);
+    assert_eq!(response.content_type(), Some(ContentType::Icon));
+    assert_eq!(response.headers().get_one("Cache-Control"), Some("public, max-age=86400"));
+    assert_eq!(response.into_bytes().unwrap(), FAVICON);
+
+    let response = client.get("/robots.txt").dispatch();
+    assert_eq!(response.status(), Status::Ok);
+    assert_eq!(response.content_type(), Some(ContentType::Text));
+    assert_eq!(response.into_string().unwrap(), "User-agent: *\nDisallow:\n");
+
+    let response = client.get("/other.txt").dispatch();
+    assert_eq!(response.into_string().unwrap(), "route: other.txt");
+}
+
+#[test]
+fn serves_only_provided_assets() {
+    let assets = StaticAssets::fairing().favicon(FAVICON);
+    let client = Client::debug(rocket_with(rocket::Config::figment(), assets)).unwrap();
+
+    let response = client.get("/favicon.ico").dispatch();
+    assert_eq!(response.into_bytes().unwrap(), FAVICON);
+
+    let response = client.get("/robots.txt").dispatch();
+    assert_eq!(response.into_string().unwrap(), "route: robots.txt");
+}
+
+#[test]
+fn configured_paths_override_programmatic_assets() {
+    let figment = rocket::Config::figment()
+        .merge(("static_assets.robots", static_dir().join("robots.txt")))
+        .merge(("static_assets.max_age", 60));
+
+    let assets = StaticAssets::fairing().robots(Asset::from("ignored"));
+    let client = Client::debug(rocket_with(figment, assets)).unwrap();
+
+    let response = client.get("/robots.txt").dispatch();
+    assert_eq!(response.content_type(), Some(ContentType::Text));
+    assert_eq!(response.headers().get_one("Cache-Control"), Some("public, max-age=60"));
+    assert_eq!(response.into_string().unwrap(), "User-agent: *\nDisallow: /private\n");
+}
+
+#[test]
+fn missing_files_fail_ignition() {
+    let assets = StaticAssets::fairing().favicon(Asset::file(static_dir().join("missing.ico")));
+    assert!(Client::debug(rocket_with(rocket::Config::figment(), assets)).is_err());
+}
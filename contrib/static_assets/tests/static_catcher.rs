@@ -0,0 +1,63 @@
+#[macro_use] extern crate rocket;
+
+use std::path::{Path, PathBuf};
+
+use rocket::http::{ContentType, Status};
+use rocket::local::blocking::Client;
+use rocket_static_assets::StaticCatcher;
+
+#[get("/fail/<code>")]
+fn fail(code: u16) -> Status {
+    Status::new(code)
+}
+
+fn errors_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("static").join("errors")
+}
+
+#[test]
+fn serves_static_error_pages() {
+    let rocket = rocket::build()
+        .mount("/", routes![fail])
+        .register("/", StaticCatcher::from(errors_dir()));
+
+    let client = Client::debug(rocket).unwrap();
+    let response = client.get("/does/not/exist").dispatch();
+    assert_eq!(response.status(), Status::NotFound);
+    assert_eq!(response.content_type(), Some(ContentType::HTML));
+    assert!(response.into_string().unwrap().contains("This page does not exist."));
+
+    let response = client.get("/fail/404").dispatch();
+    assert_eq!(response.status(), Status::NotFound);
+    assert!(response.into_string().unwrap().contains("This page does not exist."));
+}
+
+#[test]
+fn falls_back_to_builtin_page() {
+    let rocket = rocket::build()
+        .mount("/", routes![fail])
+        .register("/", StaticCatcher::from(errors_dir()));
+
+    let client = Client::debug(rocket).unwrap();
+    let response = client.get("/fail/500").dispatch();
+    assert_eq!(response.status(), Status::InternalServerError);
+    assert!(!response.into_string().unwrap().contains("This page does not exist."));
+
+    let rocket = rocket::build().register("/", StaticCatcher::from("/no/such/dir"));
+    let client = Client::debug(rocket).unwrap();
+    let response = client.get("/").dispatch();
+    assert_eq!(response.status(), Status::NotFound);
+    assert!(!response.into_string().unwrap().contains("This page does not exist."));
+}
+
+#[test]
+fn only_serves_restricted_codes() {
+    let rocket = rocket::build()
+        .mount("/", routes![fail])
+        .register("/", StaticCatcher::from(errors_dir()).only([500]));
+
+    let client = Client::debug(rocket).unwrap();
+    let response = client.get("/fail/404").dispatch();
+    assert_eq!(response.status(), Status::NotFound);
+    assert!(!response.into_string().unwrap().contains("This page does not exist."));
+}
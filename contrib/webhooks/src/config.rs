@@ -0,0 +1,58 @@
+use std::fmt;
+
+use rocket::serde::{Deserialize, Serialize};
+
+/// Configuration for the [`Webhooks`](crate::Webhooks) fairing.
+///
+/// A dictionary matching this structure is extracted from the `webhooks` key
+/// of the active [`Figment`](rocket::figment::Figment) on ignition. All fields
+/// but `secret` are optional and default to the value in
+/// [`Config::default()`].
+///
+/// ```toml
+/// [default.webhooks]
+/// secret = "a-shared-secret"
+/// header = "X-Hub-Signature-256"
+/// prefix = "sha256="
+/// ```
+///
+/// The `Debug` implementation redacts the secret.
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+#[serde(crate = "rocket::serde")]
+pub struct Config {
+    /// The secret shared with the sender, used to key the HMAC.
+    ///
+    /// Must be non-empty.
+    ///
+    /// _Default:_ `""`.
+    pub secret: String,
+    /// The name of the header containing the signature.
+    ///
+    /// _Default:_ `"X-Signature"`.
+    pub header: String,
+    /// A prefix which must precede the hex-encoded signature in the header,
+    /// such as `"sha256="`. An empty prefix requires none.
+    ///
+    /// _Default:_ `""`.
+    pub prefix: String,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            secret: String::new(),
+            header: "X-Signature".into(),
+            prefix: String::new(),
+        }
+    }
+}
+
+impl fmt::Debug for Config {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Config")
+            .field("secret", &"[redacted]")
+            .field("header", &self.header)
+            .field("prefix", &self.prefix)
+            .finish()
+    }
+}
@@ -0,0 +1,69 @@
+use rocket::{error, Rocket, Build};
+use rocket::fairing::{self, Fairing, Info, Kind};
+use rocket::figment::providers::Serialized;
+
+use crate::Config;
+use crate::verifier::Verifier;
+
+/// The webhook signature verification fairing.
+///
+/// When attached, this fairing extracts a [`Config`] from the `webhooks` key
+/// of the active figment and places the state needed by
+/// [`SignedPayload`](crate::SignedPayload) in managed state. Ignition fails if
+/// the configuration is invalid or no secret is set.
+///
+/// See the [crate docs](crate) for usage details.
+#[derive(Debug, Default)]
+pub struct Webhooks {
+    _priv: (),
+}
+
+impl Webhooks {
+    /// Returns the fairing which enables webhook signature verification.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use rocket::launch;
+    /// use rocket_webhooks::Webhooks;
+    ///
+    /// #[launch]
+    /// fn rocket() -> _ {
+    ///     rocket::build().attach(Webhooks::fairing())
+    /// }
+    /// ```
+    pub fn fairing() -> Webhooks {
+        Webhooks::default()
+    }
+}
+
+#[rocket::async_trait]
+impl Fairing for Webhooks {
+    fn info(&self) -> Info {
+        Info {
+            name: "Webhooks",
+            kind: Kind::Ignite | Kind::Singleton,
+        }
+    }
+
+    async fn on_ignite(&self, rocket: Rocket<Build>) -> fairing::Result {
+        let figment = rocket.figment()
+            .focus("webhooks")
+            .join(Serialized::defaults(Config::default()));
+
+        let config: Config = match figment.extract() {
+            Ok(config) => config,
+            Err(e) => {
+                rocket::config::pretty_print_error(e);
+                return Err(rocket);
+            }
+        };
+
+        if config.secret.is_empty() {
+            error!("webhooks secret is not configured");
+            return Err(rocket);
+        }
+
+        Ok(rocket.manage(Verifier::new(config)))
+    }
+}
@@ -0,0 +1,91 @@
+//! HMAC-verified webhook payloads for Rocket.
+//!
+//! Services such as GitHub and Stripe sign the body of every webhook request
+//! they send with a secret shared with the receiver. This crate provides a
+//! [`SignedPayload<T>`] data guard which reads the raw request body, verifies
+//! its HMAC-SHA256 signature against the configured secret, and only then
+//! deserializes the body as JSON into `T`. Requests with a missing or invalid
+//! signature fail with a `401 Unauthorized`.
+//!
+//! # Usage
+//!
+//! Depend on the crate:
+//!
+//! ```toml
+//! [dependencies]
+//! rocket_webhooks = "0.1.0"
+//! ```
+//!
+//! Then attach the fairing returned by [`Webhooks::fairing()`] and use
+//! [`SignedPayload<T>`] as a data guard:
+//!
+//! ```rust
+//! # use rocket::{launch, post, routes};
+//! use rocket::serde::Deserialize;
+//! use rocket_webhooks::{Webhooks, SignedPayload};
+//!
+//! #[derive(Deserialize)]
+//! #[serde(crate = "rocket::serde")]
+//! struct Push {
+//!     r#ref: String,
+//! }
+//!
+//! #[post("/hooks/push", data = "<push>")]
+//! fn push(push: SignedPayload<Push>) -> String {
+//!     format!("pushed to {}", push.r#ref)
+//! }
+//!
+//! #[launch]
+//! fn rocket() -> _ {
+//!     rocket::build()
+//!         .attach(Webhooks::fairing())
+//!         .mount("/", routes![push])
+//! }
+//! ```
+//!
+//! # Signatures
+//!
+//! The signature is read from the configured [`Config::header`] and is
+//! expected to be the hex-encoded HMAC-SHA256 of the raw request body, keyed
+//! with [`Config::secret`], optionally preceded by [`Config::prefix`]. For
+//! GitHub, for instance, the header is `X-Hub-Signature-256` and the prefix is
+//! `sha256=`. Signatures are compared in constant time.
+//!
+//! The body is read into memory exactly once, up to the `webhook` data limit
+//! or, if it is not set, the `json` limit. The signature is verified over
+//! those bytes, and the same bytes are then deserialized. The raw bytes remain
+//! available via [`SignedPayload::raw()`].
+//!
+//! # Configuration
+//!
+//! The fairing reads its configuration from the `webhooks` key of the active
+//! figment. With the default provider, it can be configured in `Rocket.toml`:
+//!
+//! ```toml
+//! [default.webhooks]
+//! # The shared secret used to key the HMAC. Required.
+//! secret = "a-shared-secret"
+//! # The header containing the signature.
+//! header = "X-Hub-Signature-256"
+//! # A prefix which must precede the hex-encoded signature.
+//! prefix = "sha256="
+//! ```
+//!
+//! Since the secret is sensitive, consider reading it from a file instead by
+//! setting `secret_file = "/run/secrets/webhook"`; see [`FileValues`].
+//! Ignition fails if no secret is configured. See [`Config`] for defaults.
+//!
+//! [`FileValues`]: rocket::config::FileValues
+
+#![doc(html_root_url = "https://api.rocket.rs/master/rocket_webhooks")]
+#![doc(html_favicon_url = "https://rocket.rs/images/favicon.ico")]
+#![doc(html_logo_url = "https://rocket.rs/images/logo-boxed.png")]
+
+mod config;
+mod verifier;
+mod payload;
+mod fairing;
+
+pub use self::config::Config;
+pub use self::payload::{SignedPayload, Error};
+pub use self::fairing::Webhooks;
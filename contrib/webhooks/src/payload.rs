@@ -0,0 +1,196 @@
+use std::{io, fmt, error};
+use std::ops::{Deref, DerefMut};
+
+use rocket::Request;
+use rocket::data::{Limits, Data, FromData, Outcome};
+use rocket::http::Status;
+use rocket::serde::DeserializeOwned;
+use rocket::serde::json::serde_json;
+
+use crate::verifier::Verifier;
+
+/// A data guard for a JSON payload with a verified HMAC signature.
+///
+/// `SignedPayload<T>` reads the request body, verifies its signature as
+/// described in the [crate docs](crate#signatures), and deserializes it as
+/// JSON into `T`. The [`Webhooks`](crate::Webhooks) fairing must be attached.
+///
+/// The guard fails with:
+///
+///   * `500 Internal Server Error` if the fairing is not attached;
+///   * `401 Unauthorized` if the signature is missing, malformed, or invalid;
+///   * `413 Payload Too Large` if the body exceeds the data limit;
+///   * `422 Unprocessable Entity` if the body is well-formed JSON that
+///     doesn't deserialize into `T`;
+///   * `400 Bad Request` for all other I/O or parse errors.
+///
+/// The signature is always verified before any attempt to deserialize.
+///
+/// # Example
+///
+/// ```rust
+/// # use rocket::post;
+/// use rocket::serde::json::Value;
+/// use rocket_webhooks::SignedPayload;
+///
+/// #[post("/hook", data = "<event>")]
+/// fn hook(event: SignedPayload<Value>) -> String {
+///     format!("{} byte event: {}", event.raw().len(), *event)
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct SignedPayload<T> {
+    value: T,
+    raw: Vec<u8>,
+}
+
+/// An error returned by the [`SignedPayload`] data guard.
+#[derive(Debug)]
+pub enum Error {
+    /// The [`Webhooks`](crate::Webhooks) fairing is not attached.
+    Unconfigured,
+    /// The request is missing the signature header.
+    MissingSignature,
+    /// The signature header is missing its prefix or is not valid hex.
+    MalformedSignature,
+    /// The signature does not match the body.
+    InvalidSignature,
+    /// An I/O error occurred while reading the body.
+    Io(io::Error),
+    /// The body could not be deserialized.
+    Parse(serde_json::Error),
+}
+
+impl<T> SignedPayload<T> {
+    /// Consumes `self` and returns the deserialized value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket_webhooks::SignedPayload;
+    ///
+    /// fn value(payload: SignedPayload<String>) -> String {
+    ///     payload.into_inner()
+    /// }
+    /// ```
+    #[inline(always)]
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+
+    /// Returns the raw, verified bytes of the body.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket_webhooks::SignedPayload;
+    ///
+    /// fn len(payload: &SignedPayload<String>) -> usize {
+    ///     payload.raw().len()
+    /// }
+    /// ```
+    #[inline(always)]
+    pub fn raw(&self) -> &[u8] {
+        &self.raw
+    }
+
+    /// Consumes `self` and returns the deserialized value and the raw bytes
+    /// it was deserialized from.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket_webhooks::SignedPayload;
+    ///
+    /// fn parts(payload: SignedPayload<String>) -> (String, Vec<u8>) {
+    ///     payload.into_parts()
+    /// }
+    /// ```
+    #[inline(always)]
+    pub fn into_parts(self) -> (T, Vec<u8>) {
+        (self.value, self.raw)
+    }
+}
+
+impl<T: DeserializeOwned> SignedPayload<T> {
+    async fn from_data(req: &Request<'_>, data: Data<'_>) -> Result<Self, Error> {
+        let verifier = req.rocket().state::<Verifier>().ok_or(Error::Unconfigured)?;
+        let signature = verifier.signature(req)?;
+
+        let limits = req.limits();
+        let limit = limits.get("webhook").or_else(|| limits.get("json")).unwrap_or(Limits::JSON);
+        let raw = match data.open(limit).into_bytes().await {
+            Ok(bytes) if bytes.is_complete() => bytes.into_inner(),
+            Ok(_) => {
+                let eof = io::ErrorKind::UnexpectedEof;
+                return Err(Error::Io(io::Error::new(eof, "data limit exceeded")));
+            },
+            Err(e) => return Err(Error::Io(e)),
+        };
+
+        verifier.verify(&raw, &signature)?;
+        let value = serde_json::from_slice(&raw).map_err(Error::Parse)?;
+        Ok(SignedPayload { value, raw })
+    }
+}
+
+#[rocket::async_trait]
+impl<'r, T: DeserializeOwned> FromData<'r> for SignedPayload<T> {
+    type Error = Error;
+
+    async fn from_data(req: &'r Request<'_>, data: Data<'r>) -> Outcome<'r, Self> {
+        match Self::from_data(req, data).await {
+            Ok(value) => Outcome::Success(value),
+            Err(e@Error::Unconfigured) => Outcome::Error((Status::InternalServerError, e)),
+            Err(e@Error::MissingSignature)
+                | Err(e@Error::MalformedSignature)
+                | Err(e@Error::InvalidSignature) => Outcome::Error((Status::Unauthorized, e)),
+            Err(Error::Io(e)) if e.kind() == io::ErrorKind::UnexpectedEof => {
+                Outcome::Error((Status::PayloadTooLarge, Error::Io(e)))
+            },
+            Err(Error::Parse(e)) if e.classify() == serde_json::error::Category::Data => {
+                Outcome::Error((Status::UnprocessableEntity, Error::Parse(e)))
+            },
+            Err(e) => Outcome::Error((Status::BadRequest, e)),
+        }
+    }
+}
+
+impl<T> Deref for SignedPayload<T> {
+    type Target = T;
+
+    #[inline(always)]
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T> DerefMut for SignedPayload<T> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unconfigured => write!(f, "webhooks fairing is not attached"),
+            Self::MissingSignature => write!(f, "missing signature header"),
+            Self::MalformedSignature => write!(f, "malformed signature"),
+            Self::InvalidSignature => write!(f, "invalid signature"),
+            Self::Io(err) => write!(f, "i/o error: {}", err),
+            Self::Parse(err) => write!(f, "parse error: {}", err),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::Io(err) => Some(err),
+            Self::Parse(err) => Some(err),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use hmac::{Hmac, Mac};
+use rocket::Request;
+use sha2::Sha256;
+
+use crate::{Config, Error};
+
+/// Verifies signatures with a pre-keyed MAC. Placed in managed state by the
+/// fairing.
+pub(crate) struct Verifier {
+    header: String,
+    prefix: String,
+    mac: Hmac<Sha256>,
+}
+
+impl Verifier {
+    pub fn new(config: Config) -> Self {
+        let mac = Hmac::new_from_slice(config.secret.as_bytes())
+            .expect("HMAC accepts keys of any length");
+
+        Verifier { header: config.header, prefix: config.prefix, mac }
+    }
+
+    /// Returns the decoded signature in `req`'s signature header.
+    pub fn signature(&self, req: &Request<'_>) -> Result<Vec<u8>, Error> {
+        let value = req.headers().get_one(&self.header).ok_or(Error::MissingSignature)?;
+        let hex = value.trim().strip_prefix(&*self.prefix).ok_or(Error::MalformedSignature)?;
+        hex::decode(hex).map_err(|_| Error::MalformedSignature)
+    }
+
+    /// Verifies, in constant time, that `signature` is the MAC of `bytes`.
+    pub fn verify(&self, bytes: &[u8], signature: &[u8]) -> Result<(), Error> {
+        self.mac.clone()
+            .chain_update(bytes)
+            .verify_slice(signature)
+            .map_err(|_| Error::InvalidSignature)
+    }
+}
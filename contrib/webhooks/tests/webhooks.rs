@@ -0,0 +1,151 @@
+#[macro_use] extern crate rocket;
+
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+use rocket::{Rocket, Build, Config};
+use rocket::figment::Figment;
+use rocket::http::{Header, Status};
+use rocket::local::blocking::Client;
+use rocket::serde::{Deserialize, json::Value};
+use rocket_webhooks::{Webhooks, SignedPayload};
+
+const SECRET: &str = "It's a Secret to Everybody";
+
+#[derive(Deserialize)]
+#[serde(crate = "rocket::serde")]
+struct Event {
+    action: String,
+}
+
+#[post("/event", data = "<event>")]
+fn event(event: SignedPayload<Event>) -> String {
+    event.action.clone()
+}
+
+#[post("/raw", data = "<value>")]
+fn raw(value: SignedPayload<Value>) -> Vec<u8> {
+    value.raw().to_vec()
+}
+
+fn sign(body: &str) -> String {
+    let mut mac = Hmac::<Sha256>::new_from_slice(SECRET.as_bytes()).unwrap();
+    mac.update(body.as_bytes());
+    hex::encode(mac.finalize().into_bytes())
+}
+
+fn rocket_with(figment: Figment) -> Rocket<Build> {
+    rocket::custom(figment)
+        .mount("/", routes![event, raw])
+        .attach(Webhooks::fairing())
+}
+
+fn rocket() -> Rocket<Build> {
+    rocket_with(Config::figment().merge(("webhooks.secret", SECRET)))
+}
+
+#[test]
+fn verifies_and_deserializes() {
+    let client = Client::debug(rocket()).unwrap();
+    let body = r#"{ "action": "opened" }"#;
+
+    let response = client.post("/event")
+        .header(Header::new("X-Signature", sign(body)))
+        .body(body)
+        .dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+    assert_eq!(response.into_string().unwrap(), "opened");
+
+    let response = client.post("/raw")
+        .header(Header::new("X-Signature", sign(body)))
+        .body(body)
+        .dispatch();
+
+    assert_eq!(response.into_bytes().unwrap(), body.as_bytes());
+}
+
+#[test]
+fn rejects_bad_signatures() {
+    let client = Client::debug(rocket()).unwrap();
+    let body = r#"{ "action": "opened" }"#;
+
+    let response = client.post("/event").body(body).dispatch();
+    assert_eq!(response.status(), Status::Unauthorized);
+
+    let response = client.post("/event")
+        .header(Header::new("X-Signature", "not hex"))
+        .body(body)
+        .dispatch();
+
+    assert_eq!(response.status(), Status::Unauthorized);
+
+    let response = client.post("/event")
+        .header(Header::new("X-Signature", sign(r#"{ "action": "closed" }"#)))
+        .body(body)
+        .dispatch();
+
+    assert_eq!(response.status(), Status::Unauthorized);
+}
+
+#[test]
+fn verifies_before_parsing() {
+    let client = Client::debug(rocket()).unwrap();
+
+    let response = client.post("/event").body("not json").dispatch();
+    assert_eq!(response.status(), Status::Unauthorized);
+
+    let body = r#"{ "kind": "opened" }"#;
+    let response = client.post("/event")
+        .header(Header::new("X-Signature", sign(body)))
+        .body(body)
+        .dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+}
+
+#[test]
+fn configurable_header_and_prefix() {
+    let rocket = rocket_with(Config::figment()
+        .merge(("webhooks.secret", SECRET))
+        .merge(("webhooks.header", "X-Hub-Signature-256"))
+        .merge(("webhooks.prefix", "sha256=")));
+
+    let client = Client::debug(rocket).unwrap();
+    let body = r#"{ "action": "synchronize" }"#;
+
+    let response = client.post("/event")
+        .header(Header::new("X-Hub-Signature-256", format!("sha256={}", sign(body))))
+        .body(body)
+        .dispatch();
+
+    assert_eq!(response.into_string().unwrap(), "synchronize");
+
+    let response = client.post("/event")
+        .header(Header::new("X-Hub-Signature-256", sign(body)))
+        .body(body)
+        .dispatch();
+
+    assert_eq!(response.status(), Status::Unauthorized);
+}
+
+#[test]
+fn respects_data_limit() {
+    let rocket = rocket_with(Config::figment()
+        .merge(("webhooks.secret", SECRET))
+        .merge(("limits.webhook", 8)));
+
+    let client = Client::debug(rocket).unwrap();
+    let body = r#"{ "action": "opened" }"#;
+    let response = client.post("/event")
+        .header(Header::new("X-Signature", sign(body)))
+        .body(body)
+        .dispatch();
+
+    assert_eq!(response.status(), Status::PayloadTooLarge);
+}
+
+#[test]
+fn requires_secret() {
+    assert!(Client::debug(rocket_with(Config::figment())).is_err());
+}
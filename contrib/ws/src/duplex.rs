@@ -1,8 +1,11 @@
+use std::fmt;
 use std::pin::Pin;
 use std::task::{Context, Poll};
 
+use rocket::Shutdown;
 use rocket::data::IoStream;
-use rocket::futures::{StreamExt, SinkExt, Sink};
+use rocket::futures::{ready, FutureExt, StreamExt, SinkExt, Sink};
+use rocket::futures::future::poll_fn;
 use rocket::futures::stream::{Stream, FusedStream};
 
 use crate::frame::{Message, CloseFrame};
@@ -31,22 +34,211 @@ use crate::result::{Result, Error};
 /// }
 /// ```
 ///
+/// # Backpressure
+///
+/// Outgoing messages are buffered until they are written to the connection.
+/// To keep a slow client from causing the buffer to grow without bound, the
+/// buffer has a high-water mark, set via
+/// [`WebSocket::high_water_mark()`](crate::WebSocket::high_water_mark()). Once
+/// the number of buffered bytes reaches the mark, the stream refuses new
+/// messages until the buffer has been written out:
+///
+///   * [`DuplexStream::send_message()`], as well as any [`Sink`] method
+///     including [`SinkExt::send()`], waits until the buffer is below the mark
+///     before queueing the message.
+///   * [`DuplexStream::try_send()`] never waits; it returns the message in
+///     [`TrySendError::Full`] instead.
+///
+/// A message is always accepted when the buffer is below the mark, so the
+/// buffer may exceed the mark by at most one message.
+///
+/// # Graceful Shutdown
+///
+/// When Rocket begins a [graceful shutdown](rocket::Shutdown), the stream
+/// initiates a WebSocket close handshake by sending the close frame set via
+/// [`WebSocket::shutdown_frame()`](crate::WebSocket::shutdown_frame()), by
+/// default `1001 Going Away`. The frame is sent the next time the stream is
+/// read from or written to; a handler waiting on the next message, including
+/// in a `select!` loop, is woken to do so. Thereafter, reading yields any
+/// remaining messages until the client acknowledges the close, at which point
+/// the stream ends, and writing fails. The client thus receives a clean signal
+/// to reconnect well before the end of the grace period, when Rocket forcibly
+/// closes the connection.
+///
 /// [`StreamExt`]: rocket::futures::StreamExt
 /// [`SinkExt`]: rocket::futures::SinkExt
-pub struct DuplexStream(tokio_tungstenite::WebSocketStream<IoStream>);
+/// [`SinkExt::send()`]: rocket::futures::SinkExt::send()
+pub struct DuplexStream {
+    inner: tokio_tungstenite::WebSocketStream<IoStream>,
+    drain: Drain,
+    buffered: usize,
+    high_water_mark: usize,
+}
+
+/// An error returned by [`DuplexStream::try_send()`].
+#[derive(Debug)]
+pub enum TrySendError {
+    /// The send buffer is at or above its high-water mark. The unsent message
+    /// is returned.
+    Full(Message),
+    /// The message could not be sent due to a connection error.
+    Ws(Error),
+}
+
+/// The state of the close handshake initiated on shutdown.
+pub(crate) enum Drain {
+    Waiting(Shutdown, CloseFrame<'static>),
+    Sending(CloseFrame<'static>),
+    Flushing,
+    Done,
+}
 
 impl DuplexStream {
-    pub(crate) async fn new(stream: IoStream, config: crate::Config) -> Self {
+    pub(crate) async fn new(
+        stream: IoStream,
+        config: crate::Config,
+        drain: Drain,
+        high_water_mark: usize,
+    ) -> Self {
         use tokio_tungstenite::WebSocketStream;
         use crate::tungstenite::protocol::Role;
 
         let inner = WebSocketStream::from_raw_socket(stream, Role::Server, Some(config));
-        DuplexStream(inner.await)
+        DuplexStream { inner: inner.await, drain, buffered: 0, high_water_mark }
+    }
+
+    /// Send `message`, waiting for the send buffer to fall below its
+    /// high-water mark if necessary, and then for `message` to be written.
+    ///
+    /// This is equivalent to [`SinkExt::send()`] but doesn't require the trait
+    /// to be in scope. See [backpressure](#backpressure) for details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use rocket::get;
+    /// # use rocket_ws as ws;
+    /// #[get("/count")]
+    /// fn count(ws: ws::WebSocket) -> ws::Channel<'static> {
+    ///     ws.channel(move |mut stream| Box::pin(async move {
+    ///         for i in 0..1000 {
+    ///             // Waits for slow clients to catch up.
+    ///             stream.send_message(i.to_string().into()).await?;
+    ///         }
+    ///
+    ///         Ok(())
+    ///     }))
+    /// }
+    /// ```
+    ///
+    /// [`SinkExt::send()`]: rocket::futures::SinkExt::send()
+    pub async fn send_message(&mut self, message: Message) -> Result<()> {
+        SinkExt::send(self, message).await
+    }
+
+    /// Attempt to send `message` without waiting.
+    ///
+    /// The returned future completes the first time it is polled. If the send
+    /// buffer is at or above its high-water mark, it resolves to
+    /// [`TrySendError::Full`] with `message`. Otherwise, it queues `message`
+    /// and writes as much of the buffer as possible without waiting. Any
+    /// remainder is written by the next call to
+    /// [`send_message()`](Self::send_message()) or a [`Sink`] method such as
+    /// [`SinkExt::flush()`]. The stream is polled with the calling task's
+    /// context, so the task is woken as usual once the connection is writable.
+    ///
+    /// See [backpressure](#backpressure) for details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use rocket::get;
+    /// # use rocket_ws as ws;
+    /// use ws::stream::TrySendError;
+    ///
+    /// #[get("/ticks")]
+    /// fn ticks(ws: ws::WebSocket) -> ws::Channel<'static> {
+    ///     ws.channel(move |mut stream| Box::pin(async move {
+    ///         for i in 0..1000 {
+    ///             // Drop ticks for slow clients instead of waiting.
+    ///             match stream.try_send(i.to_string().into()).await {
+    ///                 Ok(()) | Err(TrySendError::Full(_)) => continue,
+    ///                 Err(TrySendError::Ws(e)) => return Err(e),
+    ///             }
+    ///         }
+    ///
+    ///         Ok(())
+    ///     }))
+    /// }
+    /// ```
+    ///
+    /// [`SinkExt::flush()`]: rocket::futures::SinkExt::flush()
+    pub async fn try_send(&mut self, message: Message) -> Result<(), TrySendError> {
+        let mut message = Some(message);
+        poll_fn(|cx| {
+            let message = message.take().expect("polled after completion");
+            Poll::Ready(self.poll_try_send(cx, message))
+        }).await
+    }
+
+    fn poll_try_send(&mut self, cx: &mut Context<'_>, msg: Message) -> Result<(), TrySendError> {
+        match Pin::new(&mut *self).poll_ready(cx) {
+            Poll::Ready(Ok(())) => {},
+            Poll::Ready(Err(e)) => return Err(TrySendError::Ws(e)),
+            Poll::Pending => return Err(TrySendError::Full(msg)),
+        }
+
+        Pin::new(&mut *self).start_send(msg).map_err(TrySendError::Ws)?;
+        match Pin::new(&mut *self).poll_flush(cx) {
+            Poll::Ready(Err(e)) => Err(TrySendError::Ws(e)),
+            _ => Ok(()),
+        }
+    }
+
+    /// Returns the number of bytes queued but not yet known to be written.
+    pub fn buffered(&self) -> usize {
+        self.buffered
     }
 
     /// Close the stream now. This does not typically need to be called.
     pub async fn close(&mut self, msg: Option<CloseFrame<'_>>) -> Result<()> {
-        self.0.close(msg).await
+        self.drain = Drain::Done;
+        self.inner.close(msg).await
+    }
+
+    /// Sends the shutdown close frame if shutdown has been requested and the
+    /// frame has yet to be sent. Returns `Ready` when there is nothing to do.
+    fn poll_drain(&mut self, cx: &mut Context<'_>) -> Poll<Result<()>> {
+        loop {
+            match std::mem::replace(&mut self.drain, Drain::Done) {
+                Drain::Waiting(mut shutdown, frame) => {
+                    if shutdown.poll_unpin(cx).is_pending() {
+                        self.drain = Drain::Waiting(shutdown, frame);
+                        return Poll::Ready(Ok(()));
+                    }
+
+                    self.drain = Drain::Sending(frame);
+                }
+                Drain::Sending(frame) => {
+                    if let Poll::Pending = self.inner.poll_ready_unpin(cx)? {
+                        self.drain = Drain::Sending(frame);
+                        return Poll::Pending;
+                    }
+
+                    self.inner.start_send_unpin(Message::Close(Some(frame)))?;
+                    self.drain = Drain::Flushing;
+                }
+                Drain::Flushing => {
+                    if let Poll::Pending = self.inner.poll_flush_unpin(cx)? {
+                        self.drain = Drain::Flushing;
+                        return Poll::Pending;
+                    }
+
+                    self.buffered = 0;
+                }
+                Drain::Done => return Poll::Ready(Ok(())),
+            }
+        }
     }
 }
 
@@ -54,17 +246,22 @@ impl Stream for DuplexStream {
     type Item = Result<Message>;
 
     fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
-        self.get_mut().0.poll_next_unpin(cx)
+        let this = self.get_mut();
+        if let Poll::Ready(Err(e)) = this.poll_drain(cx) {
+            return Poll::Ready(Some(Err(e)));
+        }
+
+        this.inner.poll_next_unpin(cx)
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        self.0.size_hint()
+        self.inner.size_hint()
     }
 }
 
 impl FusedStream for DuplexStream {
     fn is_terminated(&self) -> bool {
-        self.0.is_terminated()
+        self.inner.is_terminated()
     }
 }
 
@@ -72,18 +269,49 @@ impl Sink<Message> for DuplexStream {
     type Error = Error;
 
     fn poll_ready(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
-        self.get_mut().0.poll_ready_unpin(cx)
+        let this = self.get_mut();
+        ready!(this.poll_drain(cx))?;
+        if this.buffered >= this.high_water_mark {
+            ready!(Pin::new(&mut *this).poll_flush(cx))?;
+        }
+
+        this.inner.poll_ready_unpin(cx)
     }
 
     fn start_send(self: Pin<&mut Self>, item: Message) -> Result<(), Self::Error> {
-        self.get_mut().0.start_send_unpin(item)
+        let this = self.get_mut();
+        let len = item.len();
+        this.inner.start_send_unpin(item)?;
+        this.buffered = this.buffered.saturating_add(len);
+        Ok(())
     }
 
     fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
-        self.get_mut().0.poll_flush_unpin(cx)
+        let this = self.get_mut();
+        ready!(this.inner.poll_flush_unpin(cx))?;
+        this.buffered = 0;
+        Poll::Ready(Ok(()))
     }
 
     fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
-        self.get_mut().0.poll_close_unpin(cx)
+        self.get_mut().inner.poll_close_unpin(cx)
+    }
+}
+
+impl fmt::Display for TrySendError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TrySendError::Full(_) => write!(f, "send buffer is full"),
+            TrySendError::Ws(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for TrySendError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            TrySendError::Full(_) => None,
+            TrySendError::Ws(e) => Some(e),
+        }
     }
 }
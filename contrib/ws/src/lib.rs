@@ -69,10 +69,70 @@
 //!     }
 //! }
 //! ```
+//!
+//! # Authorization
+//!
+//! [`WebSocket`] is a request guard like any other, so it can be combined with
+//! any number of additional request guards, such as those which authenticate
+//! the client via cookies, headers, or the query string. All guards are
+//! resolved before the handler is called and thus before the connection is
+//! upgraded. If a guard fails, the handshake is rejected with the guard's
+//! error status, and the error catcher's response is sent instead: no
+//! WebSocket frames are ever exchanged.
+//!
+//! ```rust
+//! # use rocket::get;
+//! # use rocket_ws as ws;
+//! use rocket::http::Status;
+//! use rocket::request::{self, Request, FromRequest};
+//!
+//! struct User(String);
+//!
+//! # fn lookup_session(_: &str) -> Option<String> { None }
+//! #[rocket::async_trait]
+//! impl<'r> FromRequest<'r> for User {
+//!     type Error = ();
+//!
+//!     async fn from_request(req: &'r Request<'_>) -> request::Outcome<Self, ()> {
+//!         let session = req.cookies().get("session");
+//!         match session.and_then(|c| lookup_session(c.value())) {
+//!             Some(name) => request::Outcome::Success(User(name)),
+//!             None => request::Outcome::Error((Status::Unauthorized, ())),
+//!         }
+//!     }
+//! }
+//!
+//! // An unauthenticated handshake is rejected with a `401`.
+//! #[get("/chat?<room>")]
+//! fn chat(ws: ws::WebSocket, user: User, room: &str) -> ws::Stream!['_] {
+//!     ws::Stream! { ws =>
+//!         yield format!("{} joined {}", user.0, room).into();
+//!         for await message in ws {
+//!             yield message?;
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! The stream or channel may borrow data from the request, including from
+//! other guards, so long as its lifetime is declared accordingly.
+//!
+//! # Typed Messages
+//!
+//! A [`DuplexStream`](stream::DuplexStream) can be wrapped in a
+//! [`TypedChannel`](stream::TypedChannel) to send and receive values of any
+//! serializable type, encoded by a [`Codec`](codec::Codec). JSON and
+//! MessagePack codecs are available via the `json` and `msgpack` features:
+//!
+//! ```toml
+//! [dependencies]
+//! ws = { package = "rocket_ws", version = "0.1.0", features = ["json"] }
+//! ```
 
 #![doc(html_root_url = "https://api.rocket.rs/master/rocket_ws")]
 #![doc(html_favicon_url = "https://rocket.rs/images/favicon.ico")]
 #![doc(html_logo_url = "https://rocket.rs/images/logo-boxed.png")]
+#![cfg_attr(nightly, feature(doc_cfg))]
 
 mod tungstenite {
     #[doc(inline)] pub use tokio_tungstenite::tungstenite::*;
@@ -80,6 +140,7 @@ mod tungstenite {
 
 mod duplex;
 mod websocket;
+mod typed;
 
 pub use self::websocket::{WebSocket, Channel};
 
@@ -168,8 +229,17 @@ pub mod frame {
 
 /// Types representing incoming and/or outgoing `async` [`Message`] streams.
 pub mod stream {
-    pub use crate::duplex::DuplexStream;
+    pub use crate::duplex::{DuplexStream, TrySendError};
     pub use crate::websocket::MessageStream;
+    pub use crate::typed::TypedChannel;
+}
+
+/// Codecs for sending and receiving typed values via a
+/// [`TypedChannel`](crate::stream::TypedChannel).
+pub mod codec {
+    pub use crate::typed::{Codec, BoxError, Error};
+    #[cfg(feature = "json")] pub use crate::typed::Json;
+    #[cfg(feature = "msgpack")] pub use crate::typed::MsgPack;
 }
 
 /// Library [`Error`](crate::result::Error) and
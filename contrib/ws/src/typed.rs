@@ -0,0 +1,252 @@
+use std::fmt;
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use rocket::futures::{ready, StreamExt, SinkExt, Sink};
+use rocket::futures::stream::{Stream, FusedStream};
+use rocket::serde::{Serialize, DeserializeOwned};
+
+use crate::Message;
+use crate::stream::DuplexStream;
+
+/// A boxed error returned by a [`Codec`].
+pub type BoxError = Box<dyn std::error::Error + Send + Sync>;
+
+/// A message-framing codec: converts values to and from WebSocket [`Message`]s.
+///
+/// A codec is used as the type parameter `C` of a [`TypedChannel`] to
+/// determine how values are encoded into outgoing messages and decoded from
+/// incoming ones. The [`Json`] and [`MsgPack`] codecs are provided when the
+/// `json` and `msgpack` features, respectively, are enabled. Custom codecs can
+/// be implemented for other formats.
+///
+/// # Example
+///
+/// A codec which sends pretty-printed JSON and only accepts `Text` messages:
+///
+/// ```rust
+/// # #[cfg(feature = "json")] mod example {
+/// use rocket::serde::{Serialize, DeserializeOwned, json};
+/// use rocket_ws::Message;
+/// use rocket_ws::codec::{Codec, BoxError};
+///
+/// struct PrettyJson;
+///
+/// impl Codec for PrettyJson {
+///     fn encode<T: Serialize>(value: &T) -> Result<Message, BoxError> {
+///         Ok(Message::Text(json::to_pretty_string(value)?))
+///     }
+///
+///     fn decode<T: DeserializeOwned>(message: Message) -> Result<T, BoxError> {
+///         match message {
+///             Message::Text(text) => Ok(json::from_str(&text)?),
+///             _ => Err("expected a text message".into()),
+///         }
+///     }
+/// }
+/// # }
+/// ```
+pub trait Codec {
+    /// Encodes `value` into a message.
+    fn encode<T: Serialize>(value: &T) -> Result<Message, BoxError>;
+
+    /// Decodes a value from a `Text` or `Binary` `message`.
+    fn decode<T: DeserializeOwned>(message: Message) -> Result<T, BoxError>;
+}
+
+/// A [`Codec`] which encodes values as JSON in `Text` messages.
+///
+/// Both `Text` and `Binary` messages are decoded.
+#[cfg(feature = "json")]
+#[cfg_attr(nightly, doc(cfg(feature = "json")))]
+#[derive(Debug, Clone, Copy)]
+pub struct Json;
+
+#[cfg(feature = "json")]
+impl Codec for Json {
+    fn encode<T: Serialize>(value: &T) -> Result<Message, BoxError> {
+        Ok(Message::Text(rocket::serde::json::to_string(value)?))
+    }
+
+    fn decode<T: DeserializeOwned>(message: Message) -> Result<T, BoxError> {
+        Ok(rocket::serde::json::from_slice(&message.into_data())?)
+    }
+}
+
+/// A [`Codec`] which encodes values as MessagePack in `Binary` messages.
+///
+/// Both `Text` and `Binary` messages are decoded.
+#[cfg(feature = "msgpack")]
+#[cfg_attr(nightly, doc(cfg(feature = "msgpack")))]
+#[derive(Debug, Clone, Copy)]
+pub struct MsgPack;
+
+#[cfg(feature = "msgpack")]
+impl Codec for MsgPack {
+    fn encode<T: Serialize>(value: &T) -> Result<Message, BoxError> {
+        Ok(Message::Binary(rocket::serde::msgpack::to_vec(value)?))
+    }
+
+    fn decode<T: DeserializeOwned>(message: Message) -> Result<T, BoxError> {
+        Ok(rocket::serde::msgpack::from_slice(&message.into_data())?)
+    }
+}
+
+/// An error sending or receiving over a [`TypedChannel`].
+#[derive(Debug)]
+pub enum Error {
+    /// An error in the underlying WebSocket connection.
+    Ws(crate::result::Error),
+    /// An outgoing value could not be encoded. Nothing was sent.
+    Encode(BoxError),
+    /// An incoming message could not be decoded. The message was discarded;
+    /// the channel remains usable.
+    Decode(BoxError),
+}
+
+/// A [`DuplexStream`] which sends and receives values of type `T` encoded with
+/// the codec `C`.
+///
+/// `TypedChannel` implements [`Stream`] and [`Sink`]: it yields incoming
+/// `Text` and `Binary` messages decoded into `T` and accepts values of type `T`
+/// which it encodes into outgoing messages. Control messages are handled
+/// internally and never yielded. A message which fails to decode yields an
+/// [`Error::Decode`] but does not close the channel, allowing the handler to
+/// decide how to proceed.
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(feature = "json")] mod example {
+/// # use rocket::get;
+/// # use rocket_ws as ws;
+/// use rocket::futures::{SinkExt, StreamExt};
+/// use rocket::serde::{Serialize, Deserialize};
+/// use ws::stream::TypedChannel;
+/// use ws::codec::{self, Json};
+///
+/// #[derive(Serialize, Deserialize)]
+/// #[serde(crate = "rocket::serde")]
+/// struct Chat {
+///     user: String,
+///     text: String,
+/// }
+///
+/// #[get("/chat")]
+/// fn chat(ws: ws::WebSocket) -> ws::Channel<'static> {
+///     ws.channel(move |stream| Box::pin(async move {
+///         let mut chat = TypedChannel::<Chat, Json>::new(stream);
+///         while let Some(message) = chat.next().await {
+///             match message {
+///                 Ok(message) => { let _ = chat.send(message).await; },
+///                 Err(codec::Error::Decode(e)) => eprintln!("bad message: {}", e),
+///                 Err(codec::Error::Ws(e)) => return Err(e),
+///                 Err(codec::Error::Encode(_)) => unreachable!(),
+///             }
+///         }
+///
+///         Ok(())
+///     }))
+/// }
+/// # }
+/// ```
+pub struct TypedChannel<T, C> {
+    stream: DuplexStream,
+    _marker: PhantomData<fn(T) -> (T, C)>,
+}
+
+impl<T, C: Codec> TypedChannel<T, C> {
+    /// Wraps `stream` in a channel of `T` encoded with `C`.
+    pub fn new(stream: DuplexStream) -> Self {
+        TypedChannel { stream, _marker: PhantomData }
+    }
+
+    /// Returns a reference to the underlying stream.
+    pub fn get_ref(&self) -> &DuplexStream {
+        &self.stream
+    }
+
+    /// Returns a mutable reference to the underlying stream. This can be used
+    /// to send or receive raw messages.
+    pub fn get_mut(&mut self) -> &mut DuplexStream {
+        &mut self.stream
+    }
+
+    /// Consumes `self` and returns the underlying stream.
+    pub fn into_inner(self) -> DuplexStream {
+        self.stream
+    }
+}
+
+impl<T: DeserializeOwned, C: Codec> Stream for TypedChannel<T, C> {
+    type Item = Result<T, Error>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        loop {
+            let message = match ready!(this.stream.poll_next_unpin(cx)) {
+                Some(Ok(message)) => message,
+                Some(Err(e)) => return Poll::Ready(Some(Err(Error::Ws(e)))),
+                None => return Poll::Ready(None),
+            };
+
+            if message.is_text() || message.is_binary() {
+                return Poll::Ready(Some(C::decode(message).map_err(Error::Decode)));
+            }
+        }
+    }
+}
+
+impl<T: DeserializeOwned, C: Codec> FusedStream for TypedChannel<T, C> {
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+impl<T: Serialize, C: Codec> Sink<T> for TypedChannel<T, C> {
+    type Error = Error;
+
+    fn poll_ready(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.get_mut().stream.poll_ready_unpin(cx).map_err(Error::Ws)
+    }
+
+    fn start_send(self: Pin<&mut Self>, item: T) -> Result<(), Self::Error> {
+        let message = C::encode(&item).map_err(Error::Encode)?;
+        self.get_mut().stream.start_send_unpin(message).map_err(Error::Ws)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.get_mut().stream.poll_flush_unpin(cx).map_err(Error::Ws)
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.get_mut().stream.poll_close_unpin(cx).map_err(Error::Ws)
+    }
+}
+
+impl From<crate::result::Error> for Error {
+    fn from(error: crate::result::Error) -> Self {
+        Error::Ws(error)
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Ws(e) => write!(f, "websocket error: {}", e),
+            Error::Encode(e) => write!(f, "encode error: {}", e),
+            Error::Decode(e) => write!(f, "decode error: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Ws(e) => Some(e),
+            Error::Encode(e) => Some(&**e),
+            Error::Decode(e) => Some(&**e),
+        }
+    }
+}
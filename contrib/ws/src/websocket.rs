@@ -1,5 +1,6 @@
 use std::io;
 
+use rocket::Shutdown;
 use rocket::data::{IoHandler, IoStream};
 use rocket::futures::{self, StreamExt, SinkExt, future::BoxFuture, stream::SplitStream};
 use rocket::response::{self, Responder, Response};
@@ -7,6 +8,8 @@ use rocket::request::{FromRequest, Request, Outcome};
 use rocket::http::Status;
 
 use crate::{Config, Message};
+use crate::duplex::Drain;
+use crate::frame::{CloseFrame, CloseCode};
 use crate::stream::DuplexStream;
 use crate::result::{Result, Error};
 
@@ -26,6 +29,10 @@ use crate::result::{Result, Error};
 /// [`WebSocket::config()`]; see [`Config`] for details on configuring a
 /// connection.
 ///
+/// On shutdown, a close frame is sent to the client. See [`DuplexStream`]'s
+/// [graceful shutdown](DuplexStream#graceful-shutdown) docs and
+/// [`WebSocket::shutdown_frame()`] for details.
+///
 /// ### Forwarding
 ///
 /// If the incoming request is not a valid WebSocket request, the guard
@@ -33,9 +40,15 @@ use crate::result::{Result, Error};
 pub struct WebSocket {
     config: Config,
     key: String,
+    shutdown: Shutdown,
+    shutdown_frame: Option<CloseFrame<'static>>,
+    high_water_mark: usize,
 }
 
 impl WebSocket {
+    /// The default high-water mark of the send buffer: 128KiB.
+    pub const DEFAULT_HIGH_WATER_MARK: usize = 128 * 1024;
+
     /// Change the default connection configuration to `config`.
     ///
     /// # Example
@@ -63,6 +76,72 @@ impl WebSocket {
         self
     }
 
+    /// Change the close frame sent to the client when Rocket begins a graceful
+    /// shutdown to `frame`. If `frame` is `None`, no close frame is sent on
+    /// shutdown, and the connection is closed abruptly at the end of the grace
+    /// period.
+    ///
+    /// The default frame has a code of [`CloseCode::Away`] (`1001`) and a
+    /// reason of `"server shutting down"`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use rocket::get;
+    /// # use rocket_ws as ws;
+    /// use ws::frame::{CloseFrame, CloseCode};
+    ///
+    /// #[get("/echo")]
+    /// fn echo_stream(ws: ws::WebSocket) -> ws::Stream!['static] {
+    ///     let ws = ws.shutdown_frame(Some(CloseFrame {
+    ///         code: CloseCode::Restart,
+    ///         reason: "restarting".into(),
+    ///     }));
+    ///
+    ///     ws::Stream! { ws =>
+    ///         for await message in ws {
+    ///             yield message?;
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn shutdown_frame(mut self, frame: Option<CloseFrame<'static>>) -> Self {
+        self.shutdown_frame = frame;
+        self
+    }
+
+    /// Change the high-water mark of the send buffer to `bytes`.
+    ///
+    /// Once `bytes` bytes are buffered for sending, writes wait, or, via
+    /// [`DuplexStream::try_send()`], fail, until the buffer is written out.
+    /// See [`DuplexStream`]'s [backpressure](DuplexStream#backpressure) docs
+    /// for details.
+    ///
+    /// The default is [`WebSocket::DEFAULT_HIGH_WATER_MARK`], 128KiB.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use rocket::get;
+    /// # use rocket_ws as ws;
+    /// use rocket::data::ToByteUnit;
+    ///
+    /// #[get("/echo")]
+    /// fn echo_stream(ws: ws::WebSocket) -> ws::Stream!['static] {
+    ///     let ws = ws.high_water_mark(16.kibibytes().as_u64() as usize);
+    ///
+    ///     ws::Stream! { ws =>
+    ///         for await message in ws {
+    ///             yield message?;
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn high_water_mark(mut self, bytes: usize) -> Self {
+        self.high_water_mark = bytes;
+        self
+    }
+
     /// Create a read/write channel to the client and call `handler` with it.
     ///
     /// This method takes a `FnOnce`, `handler`, that consumes a read/write
@@ -187,6 +266,12 @@ impl WebSocket {
         &self.key
     }
 
+    fn drain(&self) -> Drain {
+        match &self.shutdown_frame {
+            Some(frame) => Drain::Waiting(self.shutdown.clone(), frame.clone()),
+            None => Drain::Done,
+        }
+    }
 }
 
 /// A streaming channel, returned by [`WebSocket::channel()`].
@@ -230,7 +315,16 @@ impl<'r> FromRequest<'r> for WebSocket {
         let key = headers.get_one("Sec-WebSocket-Key").map(|k| derive_accept_key(k.as_bytes()));
         match key {
             Some(key) if is_upgrade && is_ws && is_13 => {
-                Outcome::Success(WebSocket { key, config: Config::default() })
+                Outcome::Success(WebSocket {
+                    key,
+                    config: Config::default(),
+                    shutdown: req.rocket().shutdown(),
+                    shutdown_frame: Some(CloseFrame {
+                        code: CloseCode::Away,
+                        reason: "server shutting down".into(),
+                    }),
+                    high_water_mark: Self::DEFAULT_HIGH_WATER_MARK,
+                })
             },
             Some(_) | None => Outcome::Forward(Status::BadRequest)
         }
@@ -262,7 +356,8 @@ impl<'r, 'o: 'r, S> Responder<'r, 'o> for MessageStream<'o, S>
 #[rocket::async_trait]
 impl IoHandler for Channel<'_> {
     async fn io(self: Box<Self>, io: IoStream) -> io::Result<()> {
-        let stream = DuplexStream::new(io, self.ws.config).await;
+        let (drain, mark) = (self.ws.drain(), self.ws.high_water_mark);
+        let stream = DuplexStream::new(io, self.ws.config, drain, mark).await;
         let result = (self.handler)(stream).await;
         handle_result(result).map(|_| ())
     }
@@ -273,7 +368,9 @@ impl<'r, S> IoHandler for MessageStream<'r, S>
     where S: futures::Stream<Item = Result<Message>> + Send + 'r
 {
     async fn io(self: Box<Self>, io: IoStream) -> io::Result<()> {
-        let (mut sink, source) = DuplexStream::new(io, self.ws.config).await.split();
+        let (drain, mark) = (self.ws.drain(), self.ws.high_water_mark);
+        let (mut sink, source) = DuplexStream::new(io, self.ws.config, drain, mark).await
+            .split();
         let stream = (self.handler)(source);
         rocket::tokio::pin!(stream);
         while let Some(msg) = stream.next().await {
@@ -294,9 +391,13 @@ impl<'r, S> IoHandler for MessageStream<'r, S>
 /// Returns `Ok(true)` if processing should continue, `Ok(false)` if processing
 /// has terminated without error, and `Err(e)` if an error has occurred.
 fn handle_result(result: Result<()>) -> io::Result<bool> {
+    use crate::tungstenite::error::ProtocolError;
+
     match result {
         Ok(_) => Ok(true),
         Err(Error::ConnectionClosed) => Ok(false),
+        Err(Error::AlreadyClosed) => Ok(false),
+        Err(Error::Protocol(ProtocolError::SendAfterClosing)) => Ok(false),
         Err(Error::Io(e)) => Err(e),
         Err(e) => Err(io::Error::new(io::ErrorKind::Other, e))
     }
@@ -0,0 +1,76 @@
+#[macro_use] extern crate rocket;
+
+use rocket::http::{Header, Status};
+use rocket::local::blocking::{Client, LocalRequest};
+use rocket::request::{self, Request, FromRequest};
+use rocket_ws as ws;
+
+struct User(String);
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for User {
+    type Error = ();
+
+    async fn from_request(req: &'r Request<'_>) -> request::Outcome<Self, ()> {
+        match req.cookies().get("session").map(|c| c.value()) {
+            Some("s3cr3t") => request::Outcome::Success(User("bob".into())),
+            _ => request::Outcome::Error((Status::Unauthorized, ())),
+        }
+    }
+}
+
+#[get("/chat?<room>")]
+fn chat(ws: ws::WebSocket, user: User, room: &str) -> ws::Stream!['_] {
+    ws::Stream! { ws =>
+        yield format!("{} joined {}", user.0, room).into();
+        for await message in ws {
+            yield message?;
+        }
+    }
+}
+
+fn handshake(request: LocalRequest<'_>) -> LocalRequest<'_> {
+    request
+        .header(Header::new("Connection", "Upgrade"))
+        .header(Header::new("Upgrade", "websocket"))
+        .header(Header::new("Sec-WebSocket-Version", "13"))
+        .header(Header::new("Sec-WebSocket-Key", "dGhlIHNhbXBsZSBub25jZQ=="))
+}
+
+fn client() -> Client {
+    Client::debug_with(routes![chat]).unwrap()
+}
+
+#[test]
+fn authorized_handshake_is_accepted() {
+    let client = client();
+    let response = handshake(client.get("/chat?room=rust"))
+        .cookie(("session", "s3cr3t"))
+        .dispatch();
+
+    let accept = response.headers().get_one("Sec-WebSocket-Accept");
+    assert_eq!(accept, Some("s3pPLMBiTxaQ9kYGzzhZRbK+xOo="));
+}
+
+#[test]
+fn unauthorized_handshake_is_rejected() {
+    let client = client();
+
+    let response = handshake(client.get("/chat?room=rust")).dispatch();
+    assert_eq!(response.status(), Status::Unauthorized);
+    assert!(response.headers().get_one("Sec-WebSocket-Accept").is_none());
+
+    let response = handshake(client.get("/chat?room=rust"))
+        .cookie(("session", "guess"))
+        .dispatch();
+
+    assert_eq!(response.status(), Status::Unauthorized);
+    assert!(response.headers().get_one("Sec-WebSocket-Accept").is_none());
+}
+
+#[test]
+fn non_websocket_request_is_not_upgraded() {
+    let client = client();
+    let response = client.get("/chat?room=rust").cookie(("session", "s3cr3t")).dispatch();
+    assert_eq!(response.status(), Status::BadRequest);
+}
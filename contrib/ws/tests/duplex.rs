@@ -0,0 +1,200 @@
+#[macro_use] extern crate rocket;
+
+use std::net::SocketAddr;
+
+use rocket::{Build, Config, Rocket, Shutdown, State};
+use rocket::fairing::AdHoc;
+use rocket::futures::{SinkExt, StreamExt};
+use rocket::futures::channel::{mpsc, oneshot};
+use rocket::tokio::net::TcpStream;
+use rocket_ws::{self as ws, Message};
+use rocket_ws::frame::{CloseCode, CloseFrame};
+use rocket_ws::stream::TrySendError;
+use tokio_tungstenite::{client_async, WebSocketStream};
+
+type Client = WebSocketStream<TcpStream>;
+
+async fn launch(rocket: Rocket<Build>) -> (Shutdown, SocketAddr) {
+    let (tx, rx) = oneshot::channel();
+    let rocket = rocket
+        .reconfigure(Config { port: 0, ..Config::debug_default() })
+        .attach(AdHoc::on_liftoff("Send Address", move |rocket| Box::pin(async move {
+            let addr = rocket.endpoints().find_map(|e| e.tcp()).expect("tcp endpoint");
+            tx.send((rocket.shutdown(), addr)).expect("send okay");
+        })));
+
+    rocket::tokio::spawn(rocket.launch());
+    rx.await.expect("liftoff")
+}
+
+async fn connect(addr: SocketAddr, path: &str) -> Client {
+    let stream = TcpStream::connect(addr).await.expect("tcp connect");
+    let url = format!("ws://{}{}", addr, path);
+    let (client, _) = client_async(url, stream).await.expect("handshake");
+    client
+}
+
+#[get("/echo/channel")]
+fn echo_channel(ws: ws::WebSocket) -> ws::Channel<'static> {
+    ws.channel(move |mut stream| Box::pin(async move {
+        while let Some(message) = stream.next().await {
+            stream.send_message(message?).await?;
+        }
+
+        Ok(())
+    }))
+}
+
+#[get("/echo/stream")]
+fn echo_stream(ws: ws::WebSocket) -> ws::Stream!['static] {
+    ws::Stream! { ws =>
+        for await message in ws {
+            yield message?;
+        }
+    }
+}
+
+#[get("/idle")]
+fn idle(ws: ws::WebSocket) -> ws::Channel<'static> {
+    ws.channel(move |mut stream| Box::pin(async move {
+        while let Some(message) = stream.next().await {
+            message?;
+        }
+
+        Ok(())
+    }))
+}
+
+#[get("/idle/restart")]
+fn idle_restart(ws: ws::WebSocket) -> ws::Stream!['static] {
+    let ws = ws.shutdown_frame(Some(CloseFrame {
+        code: CloseCode::Restart,
+        reason: "restarting".into(),
+    }));
+
+    ws::Stream! { ws =>
+        for await message in ws {
+            message?;
+        }
+    }
+}
+
+async fn assert_echoes(client: &mut Client) {
+    let messages = [
+        Message::Text("hello".into()),
+        Message::Binary(vec![1, 2, 3]),
+        Message::Text("a".repeat(64 * 1024)),
+    ];
+
+    for message in messages {
+        client.send(message.clone()).await.expect("send okay");
+        let echoed = client.next().await.expect("message").expect("ok message");
+        assert_eq!(echoed, message);
+    }
+}
+
+async fn assert_closed_with(client: &mut Client, code: CloseCode, reason: &str) {
+    loop {
+        match client.next().await.expect("close frame").expect("ok message") {
+            Message::Close(Some(frame)) => {
+                assert_eq!(frame.code, code);
+                assert_eq!(frame.reason, reason);
+                break;
+            }
+            Message::Ping(_) | Message::Pong(_) => continue,
+            message => panic!("unexpected message: {:?}", message),
+        }
+    }
+
+    // Completing the close handshake ends the stream.
+    assert!(client.next().await.is_none());
+}
+
+#[rocket::async_test]
+async fn channel_round_trip() {
+    let (shutdown, addr) = launch(rocket::build().mount("/", routes![echo_channel])).await;
+    let mut client = connect(addr, "/echo/channel").await;
+    assert_echoes(&mut client).await;
+    client.close(None).await.expect("close okay");
+    shutdown.notify();
+}
+
+#[rocket::async_test]
+async fn stream_round_trip() {
+    let (shutdown, addr) = launch(rocket::build().mount("/", routes![echo_stream])).await;
+    let mut client = connect(addr, "/echo/stream").await;
+    assert_echoes(&mut client).await;
+    client.close(None).await.expect("close okay");
+    shutdown.notify();
+}
+
+#[rocket::async_test]
+async fn shutdown_sends_going_away() {
+    let (shutdown, addr) = launch(rocket::build().mount("/", routes![idle])).await;
+    let mut client = connect(addr, "/idle").await;
+    client.send("ping".into()).await.expect("send okay");
+
+    shutdown.notify();
+    assert_closed_with(&mut client, CloseCode::Away, "server shutting down").await;
+}
+
+#[rocket::async_test]
+async fn shutdown_sends_configured_frame() {
+    let (shutdown, addr) = launch(rocket::build().mount("/", routes![idle_restart])).await;
+    let mut client = connect(addr, "/idle/restart").await;
+    client.send("ping".into()).await.expect("send okay");
+
+    shutdown.notify();
+    assert_closed_with(&mut client, CloseCode::Restart, "restarting").await;
+}
+
+#[get("/flood")]
+fn flood(ws: ws::WebSocket, sent: &State<mpsc::UnboundedSender<usize>>) -> ws::Channel<'static> {
+    let sent_tx = sent.inner().clone();
+    ws.high_water_mark(1).channel(move |mut stream| Box::pin(async move {
+        let mut sent = 0;
+        while sent < 1024 {
+            match stream.try_send(Message::Binary(vec![0; 64 * 1024])).await {
+                Ok(()) => sent += 1,
+                Err(TrySendError::Full(message)) => {
+                    assert!(message.is_binary());
+                    break;
+                }
+                Err(TrySendError::Ws(e)) => return Err(e),
+            }
+        }
+
+        sent_tx.unbounded_send(sent).expect("send okay");
+        stream.send_message("done".into()).await
+    }))
+}
+
+#[rocket::async_test]
+async fn try_send_reports_full_and_send_message_waits() {
+    let (tx, mut rx) = mpsc::unbounded();
+    let (shutdown, addr) = launch(rocket::build().manage(tx).mount("/", routes![flood])).await;
+    let mut client = connect(addr, "/flood").await;
+
+    // The client hasn't read anything, so the buffer must eventually fill.
+    let sent = rx.next().await.expect("sent count");
+    assert!(sent < 1024, "send buffer never filled");
+
+    let mut received = 0;
+    loop {
+        match client.next().await.expect("message").expect("ok message") {
+            Message::Binary(data) => {
+                assert_eq!(data.len(), 64 * 1024);
+                received += 1;
+            }
+            Message::Text(text) => {
+                assert_eq!(text, "done");
+                break;
+            }
+            message => panic!("unexpected message: {:?}", message),
+        }
+    }
+
+    assert_eq!(received, sent);
+    let _ = client.close(None).await;
+    shutdown.notify();
+}
@@ -0,0 +1,172 @@
+#[macro_use] extern crate rocket;
+
+use std::net::SocketAddr;
+
+use rocket::{Build, Config, Rocket, Shutdown};
+use rocket::fairing::AdHoc;
+use rocket::futures::{SinkExt, StreamExt};
+use rocket::futures::channel::oneshot;
+use rocket::serde::{json, Serialize, Deserialize, DeserializeOwned};
+use rocket::tokio::net::TcpStream;
+use rocket_ws::{self as ws, Message};
+use rocket_ws::codec::{self, Codec, BoxError};
+use rocket_ws::stream::TypedChannel;
+use tokio_tungstenite::{client_async, WebSocketStream};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(crate = "rocket::serde")]
+struct Chat {
+    user: String,
+    text: String,
+}
+
+impl Chat {
+    fn new(user: &str, text: &str) -> Self {
+        Chat { user: user.into(), text: text.into() }
+    }
+}
+
+/// A JSON codec which only accepts `Text` messages.
+struct TextJson;
+
+impl Codec for TextJson {
+    fn encode<T: Serialize>(value: &T) -> Result<Message, BoxError> {
+        Ok(Message::Text(json::to_string(value)?))
+    }
+
+    fn decode<T: DeserializeOwned>(message: Message) -> Result<T, BoxError> {
+        match message {
+            Message::Text(text) => Ok(json::from_str(&text)?),
+            _ => Err("expected a text message".into()),
+        }
+    }
+}
+
+#[get("/chat")]
+fn chat(ws: ws::WebSocket) -> ws::Channel<'static> {
+    ws.channel(move |stream| Box::pin(async move {
+        let mut chat = TypedChannel::<Chat, TextJson>::new(stream);
+        while let Some(message) = chat.next().await {
+            match message {
+                Ok(mut message) => {
+                    message.text = message.text.to_uppercase();
+                    chat.send(message).await.map_err(|e| match e {
+                        codec::Error::Ws(e) => e,
+                        e => panic!("unexpected error: {}", e),
+                    })?;
+                }
+                Err(codec::Error::Decode(e)) => {
+                    let error = format!("bad message: {}", e);
+                    chat.get_mut().send_message(error.into()).await?;
+                }
+                Err(codec::Error::Ws(e)) => return Err(e),
+                Err(codec::Error::Encode(e)) => panic!("unexpected encode error: {}", e),
+            }
+        }
+
+        Ok(())
+    }))
+}
+
+async fn launch(rocket: Rocket<Build>) -> (Shutdown, SocketAddr) {
+    let (tx, rx) = oneshot::channel();
+    let rocket = rocket
+        .reconfigure(Config { port: 0, ..Config::debug_default() })
+        .attach(AdHoc::on_liftoff("Send Address", move |rocket| Box::pin(async move {
+            let addr = rocket.endpoints().find_map(|e| e.tcp()).expect("tcp endpoint");
+            tx.send((rocket.shutdown(), addr)).expect("send okay");
+        })));
+
+    rocket::tokio::spawn(rocket.launch());
+    rx.await.expect("liftoff")
+}
+
+async fn connect(addr: SocketAddr, path: &str) -> WebSocketStream<TcpStream> {
+    let stream = TcpStream::connect(addr).await.expect("tcp connect");
+    let url = format!("ws://{}{}", addr, path);
+    let (client, _) = client_async(url, stream).await.expect("handshake");
+    client
+}
+
+async fn exchange(client: &mut WebSocketStream<TcpStream>, message: Message) -> String {
+    client.send(message).await.expect("send okay");
+    match client.next().await.expect("message").expect("ok message") {
+        Message::Text(text) => text,
+        message => panic!("unexpected message: {:?}", message),
+    }
+}
+
+#[rocket::async_test]
+async fn typed_channel_round_trip() {
+    let (shutdown, addr) = launch(rocket::build().mount("/", routes![chat])).await;
+    let mut client = connect(addr, "/chat").await;
+
+    let message = TextJson::encode(&Chat::new("bob", "hi")).unwrap();
+    let response = exchange(&mut client, message).await;
+    let chat: Chat = json::from_str(&response).unwrap();
+    assert_eq!(chat, Chat::new("bob", "HI"));
+
+    // Control messages are handled internally and never decoded.
+    client.send(Message::Ping(vec![1, 2])).await.expect("send okay");
+    let pong = client.next().await.expect("message").expect("ok message");
+    assert_eq!(pong, Message::Pong(vec![1, 2]));
+
+    client.close(None).await.expect("close okay");
+    shutdown.notify();
+}
+
+#[rocket::async_test]
+async fn typed_channel_surfaces_decode_errors() {
+    let (shutdown, addr) = launch(rocket::build().mount("/", routes![chat])).await;
+    let mut client = connect(addr, "/chat").await;
+
+    let response = exchange(&mut client, Message::Text("{\"user\": ".into())).await;
+    assert!(response.starts_with("bad message: "), "{}", response);
+
+    let response = exchange(&mut client, Message::Text("{\"user\": \"bob\"}".into())).await;
+    assert!(response.starts_with("bad message: "), "{}", response);
+
+    let response = exchange(&mut client, Message::Binary(b"{}".to_vec())).await;
+    assert_eq!(response, "bad message: expected a text message");
+
+    // The channel remains usable after a decode error.
+    let message = TextJson::encode(&Chat::new("alice", "still here")).unwrap();
+    let response = exchange(&mut client, message).await;
+    let chat: Chat = json::from_str(&response).unwrap();
+    assert_eq!(chat, Chat::new("alice", "STILL HERE"));
+
+    client.close(None).await.expect("close okay");
+    shutdown.notify();
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn json_codec() {
+    use rocket_ws::codec::Json;
+
+    let message = Json::encode(&Chat::new("bob", "hi")).unwrap();
+    assert_eq!(message, Message::Text(r#"{"user":"bob","text":"hi"}"#.into()));
+
+    let chat: Chat = Json::decode(message).unwrap();
+    assert_eq!(chat, Chat::new("bob", "hi"));
+
+    let binary = Message::Binary(br#"{"user":"bob","text":"hi"}"#.to_vec());
+    assert_eq!(Json::decode::<Chat>(binary).unwrap(), Chat::new("bob", "hi"));
+
+    assert!(Json::decode::<Chat>(Message::Text("{\"user\": ".into())).is_err());
+    assert!(Json::decode::<Chat>(Message::Binary(vec![0xff, 0x00])).is_err());
+}
+
+#[cfg(feature = "msgpack")]
+#[test]
+fn msgpack_codec() {
+    use rocket_ws::codec::MsgPack;
+
+    let message = MsgPack::encode(&Chat::new("bob", "hi")).unwrap();
+    assert!(message.is_binary());
+
+    let chat: Chat = MsgPack::decode(message).unwrap();
+    assert_eq!(chat, Chat::new("bob", "hi"));
+
+    assert!(MsgPack::decode::<Chat>(Message::Binary(vec![0xc1])).is_err());
+}
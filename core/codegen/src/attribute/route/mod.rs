@@ -251,10 +251,18 @@ fn responder_outcome_expr(route: &Route) -> TokenStream {
     let _await = route.handler.sig.asyncness
         .map(|a| quote_spanned!(a.span() => .await));
 
-    define_spanned_export!(ret_span => __req, _route);
+    // A raw `impl Stream` isn't a `Responder`: respond with a `ByteStream`.
+    define_spanned_export!(ret_span => __req, _route, _response);
+    let responder = match route.handler.sig.output {
+        syn::ReturnType::Type(_, ref ty) if ty.is_impl_stream() => {
+            quote_spanned!(mixed(ret_span) => #_response::stream::ByteStream::from(___responder))
+        }
+        _ => quote_spanned!(mixed(ret_span) => ___responder),
+    };
+
     quote_spanned! { mixed(ret_span) =>
         let ___responder = #user_handler_fn_name(#(#parameter_names),*) #_await;
-        #_route::Outcome::from(#__req, ___responder)
+        #_route::Outcome::from(#__req, #responder)
     }
 }
 
@@ -332,8 +340,21 @@ fn codegen_route(route: Route) -> Result<TokenStream> {
     // Extract the sentinels from the route.
     let sentinels = sentinels_expr(&route);
 
-    // Gather info about the function.
-    let (vis, handler_fn) = (&route.handler.vis, &route.handler);
+    // Record the names and types of parameters for introspection.
+    let params = route.param_guards().chain(route.query_guards())
+        .map(|guard| {
+            let name = guard.source.name.as_str();
+            let ty = guard.ty.with_stripped_lifetimes();
+            let ty = quote!(#ty).to_string();
+            quote!((#name, #ty))
+        });
+
+    // Gather info about the function. `#[allow(unmounted_route)]` is an alias
+    // for `#[suppress(unmounted_route)]`; remove it as `rustc` doesn't know it.
+    let mut handler_fn = route.handler.clone();
+    let allow_unmounted = take_allowed_lint(&mut handler_fn.attrs, Lint::UnmountedRoute);
+    let declare = Lint::UnmountedRoute.enabled(handler_fn.span()) && !allow_unmounted;
+    let vis = &route.handler.vis;
     let deprecated = handler_fn.attrs.iter().find(|a| a.path().is_ident("deprecated"));
     let handler_fn_name = &handler_fn.sig.ident;
     let internal_uri_macro = internal_uri_macro_decl(&route);
@@ -343,6 +364,13 @@ fn codegen_route(route: Route) -> Result<TokenStream> {
     let uri = route.attr.uri.to_string();
     let rank = Optional(route.attr.rank);
     let format = Optional(route.attr.format.as_ref());
+    let timeout = Optional(route.attr.timeout);
+    let limit = Optional(route.attr.limit);
+    let metric = Optional(route.attr.metric.as_deref());
+
+    let declared_route = declare.then(|| {
+        quote!(#_route::declare_route!(#handler_fn_name, #method, #uri);)
+    });
 
     Ok(quote! {
         #handler_fn
@@ -377,7 +405,12 @@ fn codegen_route(route: Route) -> Result<TokenStream> {
                     handler: monomorphized_function,
                     format: #format,
                     rank: #rank,
+                    timeout: #timeout,
+                    limit: #limit,
+                    metric: #metric,
                     sentinels: #sentinels,
+                    params: &[#(#params),*],
+                    path: concat!(module_path!(), "::", stringify!(#handler_fn_name)),
                 }
             }
 
@@ -387,11 +420,44 @@ fn codegen_route(route: Route) -> Result<TokenStream> {
             }
         }
 
+        #declared_route
+
         /// Rocket code generated wrapping URI macro.
         #internal_uri_macro
     })
 }
 
+/// Removes `lint` from every `#[allow]` attribute in `attrs`, dropping any left
+/// empty. Returns `true` if `lint` was present.
+fn take_allowed_lint(attrs: &mut Vec<syn::Attribute>, lint: Lint) -> bool {
+    use syn::punctuated::Punctuated;
+
+    let mut found = false;
+    attrs.retain_mut(|attr| {
+        if !attr.path().is_ident("allow") {
+            return true;
+        }
+
+        let parser = Punctuated::<syn::Path, syn::Token![,]>::parse_terminated;
+        let Ok(lints) = attr.parse_args_with(parser) else {
+            return true;
+        };
+
+        let (matching, rest): (Vec<_>, Vec<_>) = lints.into_iter()
+            .partition(|path| path.is_ident(lint.as_str()));
+
+        if matching.is_empty() {
+            return true;
+        }
+
+        found = true;
+        *attr = syn::parse_quote!(#[allow(#(#rest),*)]);
+        !rest.is_empty()
+    });
+
+    found
+}
+
 fn complete_route(args: TokenStream, input: TokenStream) -> Result<TokenStream> {
     let function: syn::ItemFn = syn::parse2(input)
         .map_err(Diagnostic::from)
@@ -430,6 +496,9 @@ fn incomplete_route(
         data: method_attribute.data,
         format: method_attribute.format,
         rank: method_attribute.rank,
+        timeout: method_attribute.timeout,
+        limit: method_attribute.limit,
+        metric: method_attribute.metric,
     };
 
     codegen_route(Route::from(attribute, function)?)
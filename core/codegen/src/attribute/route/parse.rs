@@ -5,7 +5,7 @@ use proc_macro2::Span;
 
 use crate::attribute::suppress::Lint;
 use crate::proc_macro_ext::Diagnostics;
-use crate::http_codegen::{Method, MediaType};
+use crate::http_codegen::{Method, MediaType, Timeout, Limit};
 use crate::attribute::param::{Parameter, Dynamic, Guard};
 use crate::syn_ext::FnArgExt;
 use crate::name::Name;
@@ -48,6 +48,9 @@ pub struct Attribute {
     pub data: Option<SpanWrapped<Dynamic>>,
     pub format: Option<MediaType>,
     pub rank: Option<isize>,
+    pub timeout: Option<Timeout>,
+    pub limit: Option<Limit>,
+    pub metric: Option<String>,
 }
 
 /// The parsed `#[method(..)]` (e.g, `get`, `put`, etc.) attribute.
@@ -58,6 +61,9 @@ pub struct MethodAttribute {
     pub data: Option<SpanWrapped<Dynamic>>,
     pub format: Option<MediaType>,
     pub rank: Option<isize>,
+    pub timeout: Option<Timeout>,
+    pub limit: Option<Limit>,
+    pub metric: Option<String>,
 }
 
 #[derive(Debug)]
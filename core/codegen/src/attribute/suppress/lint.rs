@@ -22,7 +22,7 @@ macro_rules! declare_lints {
                 None
             }
 
-            fn as_str(&self) -> &'static str {
+            pub fn as_str(&self) -> &'static str {
                 match self {
                     $(Lint::$name => $string),*
                 }
@@ -41,6 +41,7 @@ declare_lints! {
     SegmentChars("segment_chars"),
     ArbitraryMain("arbitrary_main"),
     SyncSpawn("sync_spawn"),
+    UnmountedRoute("unmounted_route"),
 }
 
 thread_local! {
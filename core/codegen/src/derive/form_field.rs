@@ -23,11 +23,19 @@ pub enum FieldName {
     Uncased(Name),
 }
 
+/// The value of a `default` field attribute parameter: either a bare `default`
+/// or `default = expr`.
+#[derive(Debug)]
+pub enum FieldDefault {
+    Default(Span),
+    Expr(syn::Expr),
+}
+
 #[derive(FromMeta)]
 pub struct FieldAttr {
     pub name: Option<FieldName>,
     pub validate: Option<SpanWrapped<syn::Expr>>,
-    pub default: Option<syn::Expr>,
+    pub default: Option<FieldDefault>,
     pub default_with: Option<syn::Expr>,
 }
 
@@ -130,6 +138,15 @@ impl FromMeta for FieldName {
     }
 }
 
+impl FromMeta for FieldDefault {
+    fn from_meta(meta: &MetaItem) -> Result<Self> {
+        match meta {
+            MetaItem::Path(path) => Ok(FieldDefault::Default(path.span())),
+            _ => Ok(FieldDefault::Expr(syn::Expr::from_meta(meta)?)),
+        }
+    }
+}
+
 impl std::ops::Deref for FieldName {
     type Target = Name;
 
@@ -388,13 +405,21 @@ pub fn validators(field: Field<'_>) -> Result<impl Iterator<Item = syn::Expr> +
 }
 
 /// Take an $expr in `default = $expr` and turn it into a `Some($expr.into())`.
+/// A bare `default` becomes `Some(Default::default())`.
 ///
 /// As a result of calling `into()`, type inference fails for two common
 /// expressions: integer literals and the bare `None`. As a result, we cheat: if
 /// the expr matches either condition, we pass them through unchanged.
-fn default_expr(expr: &syn::Expr) -> TokenStream {
+fn default_expr(default: &FieldDefault) -> TokenStream {
     use syn::{Expr, Lit, ExprLit};
 
+    let expr = match default {
+        FieldDefault::Expr(expr) => expr,
+        FieldDefault::Default(span) => {
+            return quote_spanned!(*span => Some(::std::default::Default::default()));
+        }
+    };
+
     if matches!(expr, Expr::Path(e) if e.path.is_ident("None")) {
         quote!(#expr)
     } else if matches!(expr, Expr::Lit(ExprLit { lit: Lit::Int(_), .. })) {
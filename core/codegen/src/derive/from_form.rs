@@ -10,6 +10,11 @@ use crate::syn_ext::{GenericsExt as _, TypeExt as _};
 
 type WherePredicates = syn::punctuated::Punctuated<syn::WherePredicate, syn::Token![,]>;
 
+#[derive(Debug, Default, FromMeta)]
+struct FormAttr {
+    brackets: bool,
+}
+
 macro_rules! quote_spanned {
     ($span:expr => $($token:tt)*) => (
         quote::quote_spanned!(
@@ -171,7 +176,6 @@ pub fn derive_from_form(input: proc_macro::TokenStream) -> TokenStream {
 
                     fn init(__opts: #_form::Options) -> Self::Context {
                         Self::Context {
-                            __opts,
                             __errors: #_form::Errors::new(),
                             __parent: #_None,
                             #output
@@ -179,7 +183,18 @@ pub fn derive_from_form(input: proc_macro::TokenStream) -> TokenStream {
                     }
                 })
             })
-            .try_fields_map(|m, f| mapper::fields_null(m, f))
+            .try_fields_map(|m, fields| {
+                let attr = FormAttr::one_from_attrs("form", fields.parent.attrs())?
+                    .unwrap_or_default();
+
+                let opts = match attr.brackets {
+                    true => quote!(__opts.brackets(true)),
+                    false => quote!(__opts),
+                };
+
+                let fields = mapper::fields_null(m, fields)?;
+                Ok(quote!(__opts: #opts, #fields))
+            })
             .field_map(|_, field| {
                 let ident = field.context_ident();
                 let ty = field.ty.with_stripped_lifetimes();
@@ -2,4 +2,5 @@ mod form_field;
 pub mod from_form;
 pub mod from_form_field;
 pub mod responder;
+pub mod route_param;
 pub mod uri_display;
@@ -17,6 +17,14 @@ struct FieldAttr {
     ignore: bool,
 }
 
+/// Whether the first field, `field`, of an item with attribute `item` is used
+/// as the responder. It is unless it is ignored and the item has a `status`, in
+/// which case the item responds with only the status.
+fn is_responder(field: &Field<'_>, item: &ItemAttr) -> Result<bool> {
+    let attr = FieldAttr::one_from_attrs("response", &field.attrs)?.unwrap_or_default();
+    Ok(!attr.ignore || item.status.is_none())
+}
+
 pub fn derive_responder(input: proc_macro::TokenStream) -> TokenStream {
     let impl_tokens = quote!(impl<'r, 'o: 'r> #_response::Responder<'r, 'o>);
     DeriveGenerator::build_for(input, impl_tokens)
@@ -31,9 +39,12 @@ pub fn derive_responder(input: proc_macro::TokenStream) -> TokenStream {
                     .map(|f| (f, &f.field.inner.ty))
                     .map(|(f, ty)| (f, ty.with_replaced_lifetimes(lifetime(ty))));
 
+                let item = ItemAttr::one_from_attrs("response", fields.parent.attrs())?
+                    .unwrap_or_default();
+
                 let mut bounds = vec![];
-                if let Some((_, ty)) = types.next() {
-                    if !ty.is_concrete(&generic_idents) {
+                if let Some((f, ty)) = types.next() {
+                    if is_responder(&f, &item)? && !ty.is_concrete(&generic_idents) {
                         let span = ty.span();
                         bounds.push(quote_spanned!(span => #ty: #_response::Responder<'r, 'o>));
                     }
@@ -58,9 +69,15 @@ pub fn derive_responder(input: proc_macro::TokenStream) -> TokenStream {
                 true => Err(i.generics().span().error("only one lifetime is supported")),
                 false => Ok(())
             })
-            .fields_validate(|_, fields| match fields.is_empty() {
-                true => Err(fields.span().error("need at least one field")),
-                false => Ok(())
+            .fields_validate(|_, fields| {
+                let attr = ItemAttr::one_from_attrs("response", fields.parent.attrs())?
+                    .unwrap_or_default();
+
+                match !fields.is_empty() || attr.status.is_some() {
+                    true => Ok(()),
+                    false => Err(fields.span().error("need at least one field")
+                        .help("fields may be omitted when a `status` is specified")),
+                }
             })
         )
         .inner_mapper(MapperBuild::new()
@@ -77,14 +94,24 @@ pub fn derive_responder(input: proc_macro::TokenStream) -> TokenStream {
                 let attr = ItemAttr::one_from_attrs("response", fields.parent.attrs())?
                     .unwrap_or_default();
 
-                let responder = fields.iter().next().map(|f| {
-                    let (accessor, ty) = (f.accessor(), f.ty.with_stripped_lifetimes());
-                    quote_spanned! { f.span() =>
-                        let mut __res = <#ty as #_response::Responder>::respond_to(
-                            #accessor, __req
-                        )?;
+                let responder = match fields.iter().next() {
+                    Some(f) if is_responder(&f, &attr)? => {
+                        let (accessor, ty) = (f.accessor(), f.ty.with_stripped_lifetimes());
+                        quote_spanned! { f.span() =>
+                            let mut __res = <#ty as #_response::Responder>::respond_to(
+                                #accessor, __req
+                            )?;
+                        }
+                    }
+                    _ => {
+                        let status = attr.status.as_ref().expect("validated status");
+                        quote_spanned! { status.span() =>
+                            let mut __res = <#_http::Status as #_response::Responder>::respond_to(
+                                #status, __req
+                            )?;
+                        }
                     }
-                }).expect("have at least one field");
+                };
 
                 let mut headers = vec![];
                 for field in fields.iter().skip(1) {
@@ -0,0 +1,70 @@
+use proc_macro2::TokenStream;
+use devise::{*, ext::SpanDiagnosticExt};
+
+use crate::exports::*;
+use crate::http::uri::fmt;
+use crate::derive::uri_display::{from_uri_param, EXACTLY_ONE_FIELD, P_URI_DISPLAY, P_FORMATTER};
+
+fn one_field_validator() -> ValidatorBuild {
+    ValidatorBuild::new()
+        .fields_validate(|_, fields| match fields.count() {
+            1 => Ok(()),
+            _ => Err(fields.span().error(EXACTLY_ONE_FIELD))
+        })
+}
+
+pub fn derive_route_param(input: proc_macro::TokenStream) -> TokenStream {
+    let from_param = DeriveGenerator::build_for(input.clone(), quote!(impl<'__a> #_request::FromParam<'__a>))
+        .support(Support::TupleStruct)
+        .validator(one_field_validator())
+        .inner_mapper(MapperBuild::new()
+            .try_fields_map(|_, fields| {
+                let field = fields.iter().next().expect("exactly one field");
+                let ty = &field.ty;
+                Ok(quote_spanned! { field.span() =>
+                    type Error = <#ty as ::std::str::FromStr>::Err;
+
+                    #[inline]
+                    fn from_param(__param: &'__a str) -> #_Result<Self, Self::Error> {
+                        <#ty as ::std::str::FromStr>::from_str(__param).map(Self)
+                    }
+                })
+            })
+        )
+        .try_to_tokens::<TokenStream>();
+
+    let from_param = match from_param {
+        Ok(tokens) => tokens,
+        Err(diag) => return diag.emit_as_item_tokens()
+    };
+
+    let uri_display = DeriveGenerator::build_for(input.clone(), quote!(impl #P_URI_DISPLAY))
+        .support(Support::TupleStruct)
+        .validator(one_field_validator())
+        .inner_mapper(MapperBuild::new()
+            .with_output(|_, output| quote! {
+                fn fmt(&self, f: &mut #P_FORMATTER) -> ::std::fmt::Result {
+                    #output
+                    Ok(())
+                }
+            })
+            .field_map(|_, field| {
+                let accessor = field.accessor();
+                quote_spanned! { field.span() =>
+                    f.write_value(&::std::string::ToString::to_string(&#accessor))?;
+                }
+            })
+        )
+        .to_tokens();
+
+    let from_self = from_uri_param::<fmt::Path>(input.clone(), quote!(Self));
+    let from_ref = from_uri_param::<fmt::Path>(input.clone(), quote!(&'__r Self));
+    let from_mut = from_uri_param::<fmt::Path>(input, quote!(&'__r mut Self));
+
+    let mut ts = from_param;
+    ts.extend(uri_display);
+    ts.extend(from_self);
+    ts.extend(from_ref);
+    ts.extend(from_mut);
+    ts
+}
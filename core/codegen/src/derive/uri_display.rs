@@ -10,13 +10,13 @@ const NO_EMPTY_FIELDS: &str = "fieldless structs are not supported";
 const NO_NULLARY: &str = "nullary items are not supported";
 const NO_EMPTY_ENUMS: &str = "empty enums are not supported";
 const ONLY_ONE_UNNAMED: &str = "tuple structs or variants must have exactly one field";
-const EXACTLY_ONE_FIELD: &str = "struct must have exactly one field";
+pub(crate) const EXACTLY_ONE_FIELD: &str = "struct must have exactly one field";
 
 const Q_URI_DISPLAY: StaticTokens = quote_static!(#_fmt::UriDisplay<#_fmt::Query>);
 const Q_FORMATTER: StaticTokens = quote_static!(#_fmt::Formatter<#_fmt::Query>);
 
-const P_URI_DISPLAY: StaticTokens = quote_static!(#_fmt::UriDisplay<#_fmt::Path>);
-const P_FORMATTER: StaticTokens = quote_static!(#_fmt::Formatter<#_fmt::Path>);
+pub(crate) const P_URI_DISPLAY: StaticTokens = quote_static!(#_fmt::UriDisplay<#_fmt::Path>);
+pub(crate) const P_FORMATTER: StaticTokens = quote_static!(#_fmt::Formatter<#_fmt::Path>);
 
 fn generic_bounds_mapper(bound: StaticTokens) -> MapperBuild {
     MapperBuild::new()
@@ -150,7 +150,7 @@ pub fn derive_uri_display_path(input: proc_macro::TokenStream) -> TokenStream {
     ts
 }
 
-fn from_uri_param<P: fmt::Part>(input: proc_macro::TokenStream, ty: TokenStream) -> TokenStream {
+pub(crate) fn from_uri_param<P: fmt::Part>(input: proc_macro::TokenStream, ty: TokenStream) -> TokenStream {
     let part = match P::KIND {
         fmt::Kind::Path => quote!(#_fmt::Path),
         fmt::Kind::Query => quote!(#_fmt::Query),
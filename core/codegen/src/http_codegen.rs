@@ -16,6 +16,12 @@ pub struct MediaType(pub http::MediaType);
 #[derive(Debug, Clone)]
 pub struct Method(pub http::Method);
 
+#[derive(Debug, Clone, Copy)]
+pub struct Timeout(pub std::time::Duration);
+
+#[derive(Debug, Clone, Copy)]
+pub struct Limit(pub u64);
+
 #[derive(Clone, Debug)]
 pub struct Optional<T>(pub Option<T>);
 
@@ -48,7 +54,69 @@ impl FromMeta for Status {
 impl ToTokens for Status {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let code = self.0.code;
-        tokens.extend(quote!(rocket::http::Status { code: #code }));
+        tokens.extend(quote!(rocket::http::Status::new(#code)));
+    }
+}
+
+impl FromMeta for Timeout {
+    fn from_meta(meta: &MetaItem) -> Result<Self> {
+        let string = String::from_meta(meta)?;
+        let split = string.find(|c: char| !c.is_ascii_digit()).unwrap_or(string.len());
+        let (num, unit) = string.split_at(split);
+        let millis = match unit {
+            "ms" => Some(1),
+            "s" => Some(1_000),
+            "m" => Some(60_000),
+            "h" => Some(3_600_000),
+            _ => None,
+        };
+
+        let help = "expected a duration like \"500ms\", \"5s\", \"2m\", or \"1h\"";
+        let err = || meta.value_span().error("invalid timeout duration").help(help);
+        let millis = num.parse::<u64>().ok()
+            .zip(millis)
+            .and_then(|(n, m)| n.checked_mul(m))
+            .ok_or_else(err)?;
+
+        if millis == 0 {
+            return Err(meta.value_span().error("timeout must be non-zero"));
+        }
+
+        Ok(Timeout(std::time::Duration::from_millis(millis)))
+    }
+}
+
+impl ToTokens for Timeout {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let millis = self.0.as_millis() as u64;
+        tokens.extend(quote!(::std::time::Duration::from_millis(#millis)));
+    }
+}
+
+impl FromMeta for Limit {
+    fn from_meta(meta: &MetaItem) -> Result<Self> {
+        // Parse exactly as `ByteUnit` does in `Rocket.toml` limits.
+        let bytes = String::from_meta(meta)?
+            .parse::<ubyte::ByteUnit>()
+            .map_err(|e| meta.value_span()
+                .error(format!("invalid data limit: {}", e))
+                .help("expected a size like \"512KiB\", \"10MB\", or \"1GiB\""))?
+            .as_u64();
+
+        if bytes == 0 {
+            return Err(meta.value_span()
+                .error("data limit must be non-zero")
+                .help("a limit of zero bytes would reject every request body"));
+        }
+
+        Ok(Limit(bytes))
+    }
+}
+
+impl ToTokens for Limit {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let bytes = self.0;
+        tokens.extend(quote!(::rocket::data::ByteUnit::Byte(#bytes)));
     }
 }
 
@@ -157,12 +157,18 @@ macro_rules! route_attribute {
         /// parameter := 'rank' '=' INTEGER
         ///            | 'format' '=' '"' MEDIA_TYPE '"'
         ///            | 'data' '=' '"' SINGLE_PARAM '"'
+        ///            | 'timeout' '=' '"' DURATION '"'
+        ///            | 'limit' '=' '"' SIZE '"'
+        ///            | 'metric' '=' '"' STRING '"'
         ///
         /// SINGLE_PARAM := '<' IDENT '>'
         /// TRAILING_PARAM := '<' IDENT '..>'
         ///
         /// URI_SEG := valid, non-percent-encoded HTTP URI segment
         /// MEDIA_TYPE := valid HTTP media type or known shorthand
+        /// DURATION := INTEGER ('ms' | 's' | 'm' | 'h')
+        /// SIZE := byte size, as parsed by `ByteUnit`'s `FromStr` implementation
+        /// STRING := any string
         ///
         /// INTEGER := unsigned integer, as defined by Rust
         /// IDENT := valid identifier, as defined by Rust
@@ -254,18 +260,35 @@ macro_rules! route_attribute {
         ///      The returned value is used to generate a [`Response`] via the
         ///      type's [`Responder`] implementation.
         ///
+        ///      If a `timeout` is specified, the entire handler, including guard
+        ///      processing, must complete within the given duration. Otherwise,
+        ///      the handler is cancelled and the request fails with a `503
+        ///      Service Unavailable` status.
+        ///
+        ///      If a `limit` is specified, it replaces every configured data
+        ///      limit, as returned by [`Request::limits()`], while the route's
+        ///      guards are processed. This allows a single route to accept
+        ///      larger (or smaller) bodies without changing the global limits.
+        ///      The limit is parsed exactly as limits in `Rocket.toml` are, via
+        ///      [`ByteUnit`]'s `FromStr` implementation, and must be non-zero.
+        ///
         ///   2. A static structure used by [`routes!`] to generate a [`Route`].
         ///
         ///      The static structure (and resulting [`Route`]) is populated
-        ///      with the name (the function's name), path, query, rank, and
-        ///      format from the route attribute. The handler is set to the
-        ///      generated handler.
+        ///      with the name (the function's name), path, query, rank,
+        ///      format, timeout, limit, and metric from the route attribute. The
+        ///      handler is set to the generated handler. The `metric` is a stable
+        ///      label for the route, independent of the function's name, for
+        ///      use by metrics and logging fairings via [`Route::metric`].
         ///
         ///   3. A macro used by [`uri!`] to type-check and generate an
         ///      [`Origin`].
         ///
         /// [`Handler`]: ../rocket/route/trait.Handler.html
+        /// [`Request::limits()`]: ../rocket/struct.Request.html#method.limits
+        /// [`ByteUnit`]: ../rocket/data/struct.ByteUnit.html
         /// [`routes!`]: macro.routes.html
+        /// [`Route::metric`]: ../rocket/struct.Route.html#structfield.metric
         /// [`uri!`]: macro.uri.html
         /// [`Origin`]: ../rocket/http/uri/struct.Origin.html
         /// [`Outcome`]: ../rocket/outcome/enum.Outcome.html
@@ -364,6 +387,7 @@ pub fn catch(args: TokenStream, input: TokenStream) -> TokenStream {
 ///   * `segment_chars`
 ///   * `arbitrary_main`
 ///   * `sync_spawn`
+///   * `unmounted_route`
 ///
 /// # Example
 ///
@@ -630,11 +654,14 @@ pub fn derive_from_form_field(input: TokenStream) -> TokenStream {
 /// name_val :=  '"' FIELD_NAME '"'
 ///          | 'uncased(' '"' FIELD_NAME '"' ')
 ///
-/// default := 'default' '=' EXPR ','?
+/// default := 'default' ','?
+///          | 'default' '=' EXPR ','?
 ///          | 'default_with' '=' EXPR ','?
 ///
 /// validate := 'validate' '=' EXPR ','?
 ///
+/// form := 'brackets'
+///
 /// FIELD_NAME := valid field name, according to the HTML5 spec
 /// EXPR := valid expression, as defined by Rust
 /// ```
@@ -695,6 +722,24 @@ pub fn derive_from_form_field(input: TokenStream) -> TokenStream {
 ///     Except when `expr` is `None`, `expr` must be of type `T: Into<F>` where
 ///     `F` is the field's type.
 ///
+///   * **`default`**
+///
+///     A bare `default` sets the default value of the field to be
+///     `Default::default()`, which requires the field's type to implement
+///     [`Default`]. As with `default = expr`, the default value is only
+///     constructed if the attributed field is missing in the incoming form.
+///
+///     ```rust
+///     # #[macro_use] extern crate rocket;
+///     #[derive(FromForm)]
+///     struct MyForm {
+///         #[field(default)]
+///         count: usize,
+///         #[field(default)]
+///         tags: Vec<String>,
+///     }
+///     ```
+///
 ///   * **`default_with = expr`**
 ///
 ///     The parameter sets the default value of the field to be exactly `expr`
@@ -715,7 +760,34 @@ pub fn derive_from_form_field(input: TokenStream) -> TokenStream {
 ///     }
 ///     ```
 ///
+/// ## Container Attribute Parameters
+///
+///   * **`brackets`**
+///
+///     Enables [`Options::brackets()`] when parsing the structure and all of its
+///     fields. Fields with Rails-style empty bracket names, such as
+///     `addresses[][street]`, are grouped into the same item of a collection
+///     until a name within the item repeats. See [`FromForm#brackets`] for
+///     details.
+///
+///     ```rust
+///     # #[macro_use] extern crate rocket;
+///     #[derive(FromForm)]
+///     struct Address<'r> {
+///         street: &'r str,
+///         city: &'r str,
+///     }
+///
+///     #[derive(FromForm)]
+///     #[form(brackets)]
+///     struct User<'r> {
+///         addresses: Vec<Address<'r>>,
+///     }
+///     ```
+///
 /// [`FromForm`]: ../rocket/form/trait.FromForm.html
+/// [`FromForm#brackets`]: ../rocket/form/trait.FromForm.html#brackets
+/// [`Options::brackets()`]: ../rocket/form/struct.Options.html#method.brackets
 /// [`form::Errors`]: ../rocket/form/struct.Errors.html
 ///
 /// # Generics
@@ -834,7 +906,46 @@ pub fn derive_from_form(input: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
-/// Decorating the first field with `#[response(ignore)]` has no effect.
+/// Decorating the first field with `#[response(ignore)]` has no effect unless
+/// a `status` is specified, explained next.
+///
+/// # Status-Only Responses
+///
+/// Variants and structures with a `status` in their `response` attribute (see
+/// below) may omit a responder entirely, either by having no fields or by
+/// ignoring the first field. Such items respond as the specified [`Status`]
+/// does: error statuses are forwarded to the matching error catcher while
+/// other statuses produce an empty response. This makes it easy to map an
+/// application's error type to HTTP statuses and return it from handlers as
+/// the error in a `Result`:
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use rocket::serde::json::Json;
+///
+/// #[derive(Debug, Responder)]
+/// enum ApiError {
+///     #[response(status = 404)]
+///     NotFound,
+///     #[response(status = 400)]
+///     BadRequest(String),
+///     #[response(status = 500)]
+///     Database(#[response(ignore)] std::io::Error),
+/// }
+///
+/// #[get("/<id>")]
+/// fn item(id: usize) -> Result<Json<Vec<usize>>, ApiError> {
+///     match id {
+///         0 => Err(ApiError::NotFound),
+///         1 => Err(ApiError::BadRequest("bad id".into())),
+///         _ => Ok(Json(vec![id])),
+///     }
+/// }
+/// ```
+///
+/// Here, `ApiError::NotFound` and `ApiError::Database` are handled by the `404`
+/// and `500` catchers, respectively, while `ApiError::BadRequest` responds with
+/// a status of `400` and the contained `String` as the body.
 ///
 /// # Field Attribute
 ///
@@ -902,6 +1013,7 @@ pub fn derive_from_form(input: TokenStream) -> TokenStream {
 ///
 /// [`Responder`]: ../rocket/response/trait.Responder.html
 /// [`Response`]: ../rocket/struct.Response.html
+/// [`Status`]: ../rocket/http/struct.Status.html
 /// [`Response::set_header()`]: ../rocket/response/struct.Response.html#method.set_header
 /// [`ContentType::parse_flexible()`]: ../rocket/http/struct.ContentType.html#method.parse_flexible
 ///
@@ -1087,6 +1199,87 @@ pub fn derive_uri_display_path(input: TokenStream) -> TokenStream {
     emit!(derive::uri_display::derive_uri_display_path(input))
 }
 
+/// Derive for route parameter newtypes wrapping a `FromStr + Display` type.
+///
+/// The [`RouteParam`] derive can only be applied to tuple structs with one
+/// field and without generics. The field's type must implement both
+/// [`FromStr`] and [`Display`]. The derive generates implementations of:
+///
+///   * [`FromParam`], which parses the parameter with [`FromStr`]. The
+///     `Error` type is the field type's [`FromStr::Err`].
+///   * [`UriDisplay<Path>`], which writes the field's [`Display`] output,
+///     percent-encoded.
+///   * [`FromUriParam<Path, T>`] for `T`, `&T`, and `&mut T`, where `T` is
+///     the derived type.
+///
+/// The decorated type can thus be used as a dynamic path parameter in routes
+/// and as the corresponding argument to [`uri!`]:
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// #[derive(Debug, RouteParam)]
+/// struct UserId(u64);
+///
+/// #[get("/user/<id>")]
+/// fn user(id: UserId) -> String {
+///     format!("user #{}", id.0)
+/// }
+///
+/// assert_eq!(uri!(user(UserId(42))).to_string(), "/user/42");
+/// ```
+///
+/// Wrapping a custom type is what makes the derive useful: validation lives in
+/// the type's [`FromStr`] implementation.
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use std::fmt;
+/// use std::str::FromStr;
+///
+/// struct Slug(String);
+///
+/// impl FromStr for Slug {
+///     type Err = &'static str;
+///
+///     fn from_str(s: &str) -> Result<Self, Self::Err> {
+///         match s.bytes().all(|b| b.is_ascii_lowercase() || b == b'-') {
+///             true => Ok(Slug(s.into())),
+///             false => Err("invalid slug"),
+///         }
+///     }
+/// }
+///
+/// impl fmt::Display for Slug {
+///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+///         self.0.fmt(f)
+///     }
+/// }
+///
+/// #[derive(RouteParam)]
+/// struct PostSlug(Slug);
+///
+/// #[get("/post/<slug>")]
+/// fn post(slug: Result<PostSlug, &'static str>) -> String {
+///     match slug {
+///         Ok(slug) => format!("post {}", slug.0.0),
+///         Err(e) => e.to_string(),
+///     }
+/// }
+/// ```
+///
+/// [`RouteParam`]: ../rocket/derive.RouteParam.html
+/// [`FromParam`]: ../rocket/request/trait.FromParam.html
+/// [`UriDisplay<Path>`]: ../rocket/http/uri/fmt/trait.UriDisplay.html
+/// [`FromUriParam<Path, T>`]: ../rocket/http/uri/fmt/trait.FromUriParam.html
+/// [`FromStr`]: std::str::FromStr
+/// [`FromStr::Err`]: std::str::FromStr::Err
+/// [`Display`]: std::fmt::Display
+/// [`uri!`]: ../rocket/macro.uri.html
+#[proc_macro_derive(RouteParam)]
+pub fn derive_route_param(input: TokenStream) -> TokenStream {
+    emit!(derive::route_param::derive_route_param(input))
+}
+
 /// Generates a `Vec` of [`Route`]s from a set of route paths.
 ///
 /// The `routes!` macro expands a list of route paths into a `Vec` of their
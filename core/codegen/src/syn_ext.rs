@@ -29,6 +29,7 @@ pub trait FnArgExt {
 pub trait TypeExt {
     fn unfold_with_ty_macros(&self, names: &[&str], mapper: MacTyMapFn) -> Vec<Child<'_>>;
     fn is_concrete(&self, generic_ident: &[&Ident]) -> bool;
+    fn is_impl_stream(&self) -> bool;
 }
 
 pub trait GenericsExt {
@@ -210,6 +211,19 @@ impl TypeExt for syn::Type {
         visitor.visit_type(self);
         visitor.0
     }
+
+    fn is_impl_stream(&self) -> bool {
+        match self {
+            syn::Type::Group(t) => t.elem.is_impl_stream(),
+            syn::Type::Paren(t) => t.elem.is_impl_stream(),
+            syn::Type::ImplTrait(t) => t.bounds.iter().any(|bound| match bound {
+                syn::TypeParamBound::Trait(b) => b.path.segments.last()
+                    .map_or(false, |s| s.ident == "Stream"),
+                _ => false,
+            }),
+            _ => false,
+        }
+    }
 }
 
 impl GenericsExt for syn::Generics {
@@ -232,4 +246,21 @@ mod tests {
         let gen = &[&gen_ident];
         assert_eq!(children.iter().filter(|c| c.ty.is_concrete(gen)).count(), 3);
     }
+
+    #[test]
+    fn test_type_is_impl_stream() {
+        use super::TypeExt;
+
+        let ty: syn::Type = syn::parse_quote!(impl Stream<Item = String>);
+        assert!(ty.is_impl_stream());
+
+        let ty: syn::Type = syn::parse_quote!(impl futures::Stream<Item = u8> + Send + 'a);
+        assert!(ty.is_impl_stream());
+
+        let ty: syn::Type = syn::parse_quote!(impl Responder<'r, 'static>);
+        assert!(!ty.is_impl_stream());
+
+        let ty: syn::Type = syn::parse_quote!(ByteStream<impl Stream<Item = u8>>);
+        assert!(!ty.is_impl_stream());
+    }
 }
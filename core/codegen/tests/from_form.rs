@@ -887,6 +887,50 @@ fn test_defaults() {
     assert_eq!(form6, Ok(form));
 }
 
+#[test]
+fn test_bare_default() {
+    #[derive(Debug, Default, PartialEq, FromFormField)]
+    enum Kind {
+        #[default]
+        Basic,
+        Fancy,
+    }
+
+    #[derive(FromForm, Debug, PartialEq)]
+    struct Settings {
+        name: String,
+        #[field(default)]
+        count: usize,
+        #[field(default)]
+        tags: Vec<String>,
+        #[field(default)]
+        kind: Kind,
+        #[field(default)]
+        nickname: String,
+    }
+
+    let form: Settings = lenient("name=bob").unwrap();
+    assert_eq!(form, Settings {
+        name: "bob".into(),
+        count: 0,
+        tags: vec![],
+        kind: Kind::Basic,
+        nickname: "".into(),
+    });
+
+    let form: Settings = lenient("name=bob&count=7&tags=a&tags=b&kind=fancy&nickname=b").unwrap();
+    assert_eq!(form, Settings {
+        name: "bob".into(),
+        count: 7,
+        tags: vec!["a".into(), "b".into()],
+        kind: Kind::Fancy,
+        nickname: "b".into(),
+    });
+
+    // Defaults are never used in strict mode.
+    assert!(strict::<Settings>("name=bob").is_err());
+}
+
 #[test]
 fn test_lazy_default() {
     use std::sync::atomic::{AtomicUsize, Ordering};
@@ -1038,3 +1082,66 @@ fn range() {
         end: TodoTask { description: "yet more work".into(), completed: true, },
     });
 }
+
+#[test]
+fn brackets() {
+    #[derive(Debug, PartialEq, FromForm)]
+    struct Address<'r> {
+        street: &'r str,
+        city: &'r str,
+    }
+
+    #[derive(Debug, PartialEq, FromForm)]
+    #[form(brackets)]
+    struct User<'r> {
+        name: &'r str,
+        tags: Vec<&'r str>,
+        addresses: Vec<Address<'r>>,
+    }
+
+    #[derive(Debug, PartialEq, FromForm)]
+    struct PlainUser<'r> {
+        addresses: Vec<Address<'r>>,
+    }
+
+    let form = &[
+        "name=Bob",
+        "tags[]=a",
+        "addresses[][street]=Main",
+        "addresses[][city]=Springfield",
+        "tags[]=b",
+        "addresses[][street]=Elm",
+        "addresses[][city]=Shelbyville",
+    ].join("&");
+
+    let user: User<'_> = strict(form).unwrap();
+    assert_eq!(user, User {
+        name: "Bob",
+        tags: vec!["a", "b"],
+        addresses: vec![
+            Address { street: "Main", city: "Springfield" },
+            Address { street: "Elm", city: "Shelbyville" },
+        ],
+    });
+
+    // A repeated key begins a new item, even if the previous is incomplete.
+    let form = "name=Bob&addresses[][street]=Main&addresses[][street]=Elm&addresses[][city]=S";
+    assert!(lenient::<User<'_>>(form).is_err());
+
+    // Indexed items are unaffected.
+    let form = "name=Bob&addresses[0][street]=Main&addresses[1][street]=Elm\
+        &addresses[0][city]=Springfield&addresses[1][city]=Shelbyville";
+    let user: User<'_> = strict(form).unwrap();
+    assert_eq!(user.addresses, vec![
+        Address { street: "Main", city: "Springfield" },
+        Address { street: "Elm", city: "Shelbyville" },
+    ]);
+
+    // Without `#[form(brackets)]`, every empty index is a new item.
+    let form = "addresses[][street]=Main&addresses[][city]=Springfield";
+    assert!(lenient::<PlainUser<'_>>(form).is_err());
+
+    let form = "addresses[street]=Main&addresses[city]=Springfield";
+    let user: PlainUser<'_> = strict(form).unwrap();
+    assert_eq!(user.addresses, vec![Address { street: "Main", city: "Springfield" }]);
+}
@@ -145,3 +145,40 @@ async fn generic_responder() {
     assert_eq!(r.content_type().unwrap(), ContentType::Text);
     assert_eq!(r.body_mut().to_string().await.unwrap(), "beep beep");
 }
+
+#[derive(Debug, Responder)]
+enum ApiError {
+    #[response(status = 404)]
+    NotFound,
+    #[response(status = 400)]
+    BadRequest(String),
+    #[response(status = 503)]
+    Unavailable(#[response(ignore)] std::io::Error),
+    #[response(status = 204)]
+    Empty { #[response(ignore)] _reason: &'static str },
+}
+
+#[rocket::async_test]
+async fn status_only_responder() {
+    let client = Client::debug_with(vec![]).await.expect("valid rocket");
+    let local_req = client.get("/");
+    let req = local_req.inner();
+
+    let r = ApiError::NotFound.respond_to(req);
+    assert_eq!(r.unwrap_err(), Status::NotFound);
+
+    let error = std::io::Error::new(std::io::ErrorKind::Other, "down");
+    let r = ApiError::Unavailable(error).respond_to(req);
+    assert_eq!(r.unwrap_err(), Status::ServiceUnavailable);
+
+    let mut r = ApiError::BadRequest("bad".into()).respond_to(req).unwrap();
+    assert_eq!(r.status(), Status::BadRequest);
+    assert_eq!(r.body_mut().to_string().await.unwrap(), "bad");
+
+    let mut r = ApiError::Empty { _reason: "nothing" }.respond_to(req).unwrap();
+    assert_eq!(r.status(), Status::NoContent);
+    assert_eq!(r.body_mut().to_string().await.unwrap(), "");
+
+    let r: Result<Json<usize>, ApiError> = Err(ApiError::NotFound);
+    assert_eq!(r.respond_to(req).unwrap_err(), Status::NotFound);
+}
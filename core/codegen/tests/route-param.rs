@@ -0,0 +1,74 @@
+#[macro_use] extern crate rocket;
+
+use std::fmt;
+use std::str::FromStr;
+
+use rocket::http::uri::fmt::{UriDisplay, Path};
+use rocket::request::FromParam;
+use rocket::local::blocking::Client;
+
+#[derive(Debug, PartialEq, RouteParam)]
+struct UserId(u64);
+
+#[derive(Debug, PartialEq)]
+struct Name(String);
+
+impl FromStr for Name {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match !s.is_empty() && s.chars().all(|c| c.is_alphabetic() || c == ' ') {
+            true => Ok(Name(s.into())),
+            false => Err("bad name"),
+        }
+    }
+}
+
+impl fmt::Display for Name {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[derive(Debug, PartialEq, RouteParam)]
+struct UserName(Name);
+
+#[get("/user/<id>/<name>")]
+fn user(id: UserId, name: Result<UserName, &str>) -> String {
+    match name {
+        Ok(name) => format!("{}:{}", id.0, name.0),
+        Err(e) => format!("{}:{}", id.0, e),
+    }
+}
+
+#[test]
+fn route_param_from_param() {
+    assert_eq!(UserId::from_param("10"), Ok(UserId(10)));
+    assert!(UserId::from_param("-1").is_err());
+    assert_eq!(UserName::from_param("Bob"), Ok(UserName(Name("Bob".into()))));
+    assert_eq!(UserName::from_param("B0b"), Err("bad name"));
+}
+
+#[test]
+fn route_param_uri_display() {
+    let display = |v: &dyn UriDisplay<Path>| v.to_string();
+    assert_eq!(display(&UserId(7)), "7");
+    assert_eq!(display(&UserName(Name("Bob Ross".into()))), "Bob%20Ross");
+
+    let name = UserName(Name("Bob Ross".into()));
+    assert_eq!(uri!(user(UserId(3), &name)).to_string(), "/user/3/Bob%20Ross");
+    assert_eq!(uri!(user(UserId(3), name)).to_string(), "/user/3/Bob%20Ross");
+}
+
+#[test]
+fn route_param_in_routes() {
+    let client = Client::debug_with(routes![user]).unwrap();
+    let response = client.get("/user/5/Bob%20Ross").dispatch();
+    assert_eq!(response.into_string().unwrap(), "5:Bob Ross");
+
+    let response = client.get("/user/5/B0b").dispatch();
+    assert_eq!(response.into_string().unwrap(), "5:bad name");
+
+    let response = client.get("/user/x/Bob").dispatch();
+    assert_eq!(response.status(), rocket::http::Status::UnprocessableEntity);
+}
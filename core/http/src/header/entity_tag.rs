@@ -0,0 +1,268 @@
+use std::fmt;
+use std::borrow::Cow;
+
+use crate::Header;
+
+/// An HTTP entity tag: the value of an `ETag` header.
+///
+/// An entity tag is an opaque validator for a representation of a resource. It
+/// is either _strong_, written `"tag"`, or _weak_, written `W/"tag"`. Entity
+/// tags are compared by [`EntityTag::strong_eq()`] and
+/// [`EntityTag::weak_eq()`], as specified in [RFC 9110 §8.8.3.2].
+///
+/// [RFC 9110 §8.8.3.2]: https://www.rfc-editor.org/rfc/rfc9110#section-8.8.3.2
+///
+/// # Example
+///
+/// ```rust
+/// # extern crate rocket;
+/// use rocket::http::EntityTag;
+///
+/// let strong = EntityTag::strong("v1");
+/// assert_eq!(strong.to_string(), "\"v1\"");
+///
+/// let weak = EntityTag::parse("W/\"v1\"").unwrap();
+/// assert!(weak.is_weak());
+/// assert!(strong.weak_eq(&weak));
+/// assert!(!strong.strong_eq(&weak));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct EntityTag<'a> {
+    weak: bool,
+    tag: Cow<'a, str>,
+}
+
+/// Whether `c` is an `etagc`: `%x21 / %x23-7E / obs-text`.
+fn is_etagc(c: char) -> bool {
+    c == '!' || ('#'..='~').contains(&c) || c as u32 >= 0x80
+}
+
+impl<'a> EntityTag<'a> {
+    /// Returns a strong entity tag with the opaque value `tag`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `tag` contains a character not allowed in an entity tag: a
+    /// `"`, a control character, or whitespace.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # extern crate rocket;
+    /// use rocket::http::EntityTag;
+    ///
+    /// let etag = EntityTag::strong("abc123");
+    /// assert!(!etag.is_weak());
+    /// assert_eq!(etag.tag(), "abc123");
+    /// ```
+    pub fn strong<T: Into<Cow<'a, str>>>(tag: T) -> EntityTag<'a> {
+        EntityTag::new(false, tag)
+    }
+
+    /// Returns a weak entity tag with the opaque value `tag`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `tag` contains a character not allowed in an entity tag: a
+    /// `"`, a control character, or whitespace.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # extern crate rocket;
+    /// use rocket::http::EntityTag;
+    ///
+    /// let etag = EntityTag::weak("abc123");
+    /// assert!(etag.is_weak());
+    /// assert_eq!(etag.to_string(), "W/\"abc123\"");
+    /// ```
+    pub fn weak<T: Into<Cow<'a, str>>>(tag: T) -> EntityTag<'a> {
+        EntityTag::new(true, tag)
+    }
+
+    fn new<T: Into<Cow<'a, str>>>(weak: bool, tag: T) -> EntityTag<'a> {
+        let tag = tag.into();
+        assert!(tag.chars().all(is_etagc), "invalid entity tag: {:?}", tag);
+        EntityTag { weak, tag }
+    }
+
+    /// Parses a single entity tag, such as `"abc"` or `W/"abc"`, from `string`.
+    /// Returns `None` if `string` is not a valid entity tag.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # extern crate rocket;
+    /// use rocket::http::EntityTag;
+    ///
+    /// assert_eq!(EntityTag::parse("\"abc\""), Some(EntityTag::strong("abc")));
+    /// assert_eq!(EntityTag::parse("W/\"abc\""), Some(EntityTag::weak("abc")));
+    /// assert_eq!(EntityTag::parse("abc"), None);
+    /// ```
+    pub fn parse(string: &'a str) -> Option<EntityTag<'a>> {
+        match EntityTag::parse_prefix(string.trim()) {
+            Some((etag, "")) => Some(etag),
+            _ => None,
+        }
+    }
+
+    /// Parses a comma-separated list of entity tags, as found in `If-Match`
+    /// and `If-None-Match` headers. Returns `None` if `string` is not such a
+    /// list. The wildcard `*` is _not_ a list and must be checked separately.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # extern crate rocket;
+    /// use rocket::http::EntityTag;
+    ///
+    /// let tags = EntityTag::parse_list("\"a\", W/\"b,c\"").unwrap();
+    /// assert_eq!(tags, vec![EntityTag::strong("a"), EntityTag::weak("b,c")]);
+    ///
+    /// assert!(EntityTag::parse_list("\"a\", b").is_none());
+    /// ```
+    pub fn parse_list(string: &'a str) -> Option<Vec<EntityTag<'a>>> {
+        let mut tags = vec![];
+        let mut rest = string;
+        loop {
+            rest = rest.trim_start_matches(|c: char| c == ',' || c.is_ascii_whitespace());
+            if rest.is_empty() {
+                return Some(tags);
+            }
+
+            let (etag, remaining) = EntityTag::parse_prefix(rest)?;
+            if !remaining.is_empty() && !remaining.starts_with([',', ' ', '\t']) {
+                return None;
+            }
+
+            tags.push(etag);
+            rest = remaining;
+        }
+    }
+
+    /// Parses an entity tag at the start of `string`, returning it and the
+    /// remainder of `string`.
+    fn parse_prefix(string: &'a str) -> Option<(EntityTag<'a>, &'a str)> {
+        let (weak, string) = match string.strip_prefix("W/") {
+            Some(rest) => (true, rest),
+            None => (false, string),
+        };
+
+        let string = string.strip_prefix('"')?;
+        let end = string.find('"')?;
+        let tag = &string[..end];
+        if !tag.chars().all(is_etagc) {
+            return None;
+        }
+
+        Some((EntityTag { weak, tag: Cow::Borrowed(tag) }, &string[end + 1..]))
+    }
+
+    /// Returns `true` if `self` is a weak entity tag.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # extern crate rocket;
+    /// use rocket::http::EntityTag;
+    ///
+    /// assert!(EntityTag::weak("abc").is_weak());
+    /// assert!(!EntityTag::strong("abc").is_weak());
+    /// ```
+    pub fn is_weak(&self) -> bool {
+        self.weak
+    }
+
+    /// Returns the opaque value of the entity tag, without quotes or weakness
+    /// indicator.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # extern crate rocket;
+    /// use rocket::http::EntityTag;
+    ///
+    /// assert_eq!(EntityTag::weak("abc").tag(), "abc");
+    /// ```
+    pub fn tag(&self) -> &str {
+        &self.tag
+    }
+
+    /// Strong comparison: `true` if both tags are strong and their opaque
+    /// values are identical.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # extern crate rocket;
+    /// use rocket::http::EntityTag;
+    ///
+    /// assert!(EntityTag::strong("a").strong_eq(&EntityTag::strong("a")));
+    /// assert!(!EntityTag::strong("a").strong_eq(&EntityTag::weak("a")));
+    /// ```
+    pub fn strong_eq(&self, other: &EntityTag<'_>) -> bool {
+        !self.weak && !other.weak && self.tag == other.tag
+    }
+
+    /// Weak comparison: `true` if the opaque values of the tags are identical,
+    /// regardless of either tag's weakness.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # extern crate rocket;
+    /// use rocket::http::EntityTag;
+    ///
+    /// assert!(EntityTag::strong("a").weak_eq(&EntityTag::weak("a")));
+    /// assert!(!EntityTag::weak("a").weak_eq(&EntityTag::weak("b")));
+    /// ```
+    pub fn weak_eq(&self, other: &EntityTag<'_>) -> bool {
+        self.tag == other.tag
+    }
+
+    /// Returns an owned version of `self`.
+    pub fn into_owned(self) -> EntityTag<'static> {
+        EntityTag { weak: self.weak, tag: Cow::Owned(self.tag.into_owned()) }
+    }
+}
+
+impl fmt::Display for EntityTag<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.weak {
+            f.write_str("W/")?;
+        }
+
+        write!(f, "\"{}\"", self.tag)
+    }
+}
+
+impl From<EntityTag<'_>> for Header<'static> {
+    #[inline(always)]
+    fn from(etag: EntityTag<'_>) -> Self {
+        Header::new("ETag", etag.to_string())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::EntityTag;
+
+    #[test]
+    fn test_parse_list() {
+        let tags = EntityTag::parse_list(r#" "a" ,W/"b", "" "#).unwrap();
+        assert_eq!(tags, vec![EntityTag::strong("a"), EntityTag::weak("b"), EntityTag::strong("")]);
+
+        assert_eq!(EntityTag::parse_list("").unwrap(), vec![]);
+        assert!(EntityTag::parse_list("*").is_none());
+        assert!(EntityTag::parse_list(r#""a"b"#).is_none());
+        assert!(EntityTag::parse_list(r#""a"#).is_none());
+        assert!(EntityTag::parse_list(r#"w/"a""#).is_none());
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(EntityTag::parse(r#" "a" "#), Some(EntityTag::strong("a")));
+        assert_eq!(EntityTag::parse(r#""a b""#), None);
+        assert_eq!(EntityTag::parse(r#""a", "b""#), None);
+    }
+}
@@ -5,11 +5,13 @@ mod content_type;
 mod accept;
 mod header;
 mod proxy_proto;
+mod entity_tag;
 
 pub use self::content_type::ContentType;
 pub use self::accept::{Accept, QMediaType};
 pub use self::media_type::MediaType;
 pub use self::header::{Header, HeaderMap};
 pub use self::proxy_proto::ProxyProto;
+pub use self::entity_tag::EntityTag;
 
 pub(crate) use self::media_type::Source;
@@ -7,7 +7,9 @@ use ref_cast::RefCast;
 use stable_pattern::{Pattern, Searcher, ReverseSearcher, Split, SplitInternal};
 
 use crate::uncased::UncasedStr;
-use crate::uri::fmt::{DEFAULT_ENCODE_SET, percent_encode, percent_encode_bytes};
+use crate::uri::fmt::{
+    DEFAULT_ENCODE_SET, Encoding, percent_encode, percent_encode_bytes, percent_encode_with,
+};
 
 /// A reference to a string inside of a raw HTTP message.
 ///
@@ -277,6 +279,26 @@ impl RawStr {
         Self::from_cow_str(percent_encode::<DEFAULT_ENCODE_SET>(self))
     }
 
+    /// Returns a version of the string percent-encoded according to
+    /// `encoding`. With [`Encoding::DEFAULT`], this is identical to
+    /// [`RawStr::percent_encode()`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # extern crate rocket;
+    /// use rocket::http::RawStr;
+    /// use rocket::http::uri::fmt::Encoding;
+    ///
+    /// let raw_str = RawStr::new("docs/rocket guide");
+    /// assert_eq!(raw_str.percent_encode().as_str(), "docs%2Frocket%20guide");
+    /// assert_eq!(raw_str.percent_encode_with(Encoding::PATH).as_str(), "docs/rocket%20guide");
+    /// ```
+    #[inline(always)]
+    pub fn percent_encode_with(&self, encoding: Encoding) -> Cow<'_, RawStr> {
+        Self::from_cow_str(percent_encode_with(self.as_str(), encoding))
+    }
+
     /// Returns a percent-encoded version of `bytes`.
     ///
     /// # Example
@@ -398,6 +420,22 @@ impl RawStr {
     /// let escaped = raw_str.html_escape();
     /// assert_eq!(escaped, "大阪");
     /// ```
+    ///
+    /// Untrusted input, such as a request's URI, should always be escaped
+    /// before being interpolated into HTML outside of a template:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate rocket;
+    /// use rocket::Request;
+    /// use rocket::http::RawStr;
+    /// use rocket::response::content::RawHtml;
+    ///
+    /// #[catch(404)]
+    /// fn not_found(req: &Request<'_>) -> RawHtml<String> {
+    ///     let uri = req.uri().to_string();
+    ///     RawHtml(format!("<p>'{}' was not found.</p>", RawStr::new(&uri).html_escape()))
+    /// }
+    /// ```
     // NOTE: This is the ~fastest (a table-based implementation is slightly
     // faster) implementation benchmarked for dense-ish escaping. For sparser
     // texts, a regex-based-find solution is much faster.
@@ -46,8 +46,9 @@ impl StatusClass {
 ///
 /// A `Status` should rarely be created directly. Instead, an associated
 /// constant should be used; one is declared for every status defined in the
-/// HTTP standard. If a custom status code _must_ be created, note that it is
-/// not possible to set a custom reason phrase.
+/// HTTP standard. If a custom status code _must_ be created, use
+/// [`Status::new()`] or, to additionally set a custom reason phrase,
+/// [`Status::custom()`].
 ///
 /// ```rust
 /// # extern crate rocket;
@@ -73,6 +74,14 @@ impl StatusClass {
 /// assert_eq!(custom.reason(), None);
 /// ```
 ///
+/// # Reason Phrases
+///
+/// A `Status` is only a code: [`Status::reason()`] always returns the
+/// canonical reason phrase. [`Status::custom()`] pairs a `Status` with a custom
+/// reason phrase in a [`CustomStatus`] which, when used as a responder, emits
+/// the phrase in the status line of HTTP/1 responses. HTTP/2 and HTTP/3
+/// responses do not carry a reason phrase.
+///
 /// # Responding
 ///
 /// To set a custom `Status` on a response, use a [`response::status`]
@@ -347,6 +356,80 @@ impl Status {
     }
 }
 
+impl Status {
+    /// Returns a [`CustomStatus`] with `code` and the custom reason phrase
+    /// `reason`, which is emitted in the status line of HTTP/1 responses in
+    /// place of the canonical reason phrase, if any.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `reason` contains a character that is not allowed in a
+    /// reason phrase: a control character other than horizontal tab.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # extern crate rocket;
+    /// use rocket::http::{Status, CustomStatus};
+    ///
+    /// const STALE: CustomStatus = Status::custom(299, "Stale Data");
+    /// assert_eq!(STALE.status, Status::new(299));
+    /// assert_eq!(STALE.reason(), "Stale Data");
+    /// assert_eq!(STALE.to_string(), "299 Stale Data");
+    ///
+    /// // The canonical reason of the `Status` itself is unaffected.
+    /// let okay = Status::custom(200, "Okey Dokey");
+    /// assert_eq!(okay.status, Status::Ok);
+    /// assert_eq!(okay.status.reason(), Some("OK"));
+    /// ```
+    pub const fn custom(code: u16, reason: &'static str) -> CustomStatus {
+        let bytes = reason.as_bytes();
+        let mut i = 0;
+        while i < bytes.len() {
+            if (bytes[i] < 0x20 && bytes[i] != b'\t') || bytes[i] == 0x7F {
+                panic!("invalid character in status reason phrase");
+            }
+
+            i += 1;
+        }
+
+        CustomStatus { status: Status::new(code), reason }
+    }
+}
+
+/// A [`Status`] with a custom reason phrase.
+///
+/// A `CustomStatus` is created via [`Status::custom()`]. See [reason
+/// phrases](Status#reason-phrases) for details.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CustomStatus {
+    /// The status.
+    pub status: Status,
+    reason: &'static str,
+}
+
+impl CustomStatus {
+    /// Returns the custom reason phrase.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # extern crate rocket;
+    /// use rocket::http::Status;
+    ///
+    /// assert_eq!(Status::custom(404, "Gone Fishing").reason(), "Gone Fishing");
+    /// ```
+    pub const fn reason(&self) -> &'static str {
+        self.reason
+    }
+}
+
+impl fmt::Display for CustomStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.status.code, self.reason)
+    }
+}
+
 impl fmt::Display for Status {
     #[inline(always)]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
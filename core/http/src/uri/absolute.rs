@@ -343,6 +343,52 @@ impl<'a> Absolute<'a> {
         self
     }
 
+    /// [Normalizes](Absolute::normalize()) `self` and additionally removes dot
+    /// segments from its path as in [RFC 3986 §5.2.4]. See
+    /// [`Origin::canonicalize()`](crate::uri::Origin::canonicalize()) for
+    /// details.
+    ///
+    /// [RFC 3986 §5.2.4]: https://datatracker.ietf.org/doc/html/rfc3986#section-5.2.4
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate rocket;
+    /// use rocket::http::uri::Absolute;
+    ///
+    /// let mut uri = Absolute::parse("https://rocket.rs/a//b/../c/./d").unwrap();
+    /// uri.canonicalize();
+    /// assert_eq!(uri, uri!("https://rocket.rs/a/c/d"));
+    ///
+    /// let mut uri = Absolute::parse("https://rocket.rs/../..").unwrap();
+    /// uri.canonicalize();
+    /// assert_eq!(uri, uri!("https://rocket.rs/"));
+    /// ```
+    pub fn canonicalize(&mut self) {
+        self.normalize();
+        if self.path().has_dot_segments() {
+            let absolute = self.authority().is_some() && !self.path().is_empty();
+            self.path = self.path().to_canonical(absolute);
+        }
+    }
+
+    /// Consumes `self` and returns a [canonicalized](Absolute::canonicalize())
+    /// version.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate rocket;
+    /// use rocket::http::uri::Absolute;
+    ///
+    /// let uri = Absolute::parse("http://rocket.rs/a/./b/.").unwrap();
+    /// assert_eq!(uri.into_canonical(), uri!("http://rocket.rs/a/b/"));
+    /// ```
+    pub fn into_canonical(mut self) -> Self {
+        self.canonicalize();
+        self
+    }
+
     /// Sets the authority in `self` to `authority`.
     ///
     /// # Example
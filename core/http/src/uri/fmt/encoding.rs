@@ -81,6 +81,157 @@ impl EncodeSet for DEFAULT_ENCODE_SET {
         .add(b'=');
 }
 
+/// A set of ASCII characters to percent-encode when writing a string to a URI.
+///
+/// By default, [`RawStr::percent_encode()`] and the [`UriDisplay`]
+/// implementations for string types percent-encode every character that isn't
+/// safe in any single path segment or query value, including `/`, `&`, and
+/// `=`. Implementations of [`UriDisplay`] for types which require different
+/// rules, such as identifiers that should retain their `/` characters, can use
+/// [`RawStr::percent_encode_with()`] with a custom `Encoding` instead.
+///
+/// Non-ASCII characters, control characters, and characters that are never
+/// valid in a URI path, as well as `%` and `+`, are _always_ percent-encoded.
+///
+/// [`UriDisplay`]: crate::uri::fmt::UriDisplay
+///
+/// # Example
+///
+/// Preserve `/` characters in a `UriDisplay<Path>` implementation so that a
+/// single value spans several path segments:
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use std::fmt;
+///
+/// use rocket::http::RawStr;
+/// use rocket::http::uri::fmt::{Encoding, Formatter, UriDisplay, Path};
+///
+/// struct Key(String);
+///
+/// impl UriDisplay<Path> for Key {
+///     fn fmt(&self, f: &mut Formatter<'_, Path>) -> fmt::Result {
+///         f.write_raw(RawStr::new(&self.0).percent_encode_with(Encoding::PATH).as_str())
+///     }
+/// }
+///
+/// let key = Key("users/bob smith/avatar".into());
+/// let uri_string = format!("{}", &key as &dyn UriDisplay<Path>);
+/// assert_eq!(uri_string, "users/bob%20smith/avatar");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Encoding {
+    /// Bit `i` is set if the ASCII character `i` is written unencoded.
+    unencoded: u128,
+}
+
+impl Encoding {
+    /// The default encoding, used by [`RawStr::percent_encode()`]. Encodes
+    /// `/`, `&`, and `=`, among others.
+    pub const DEFAULT: Encoding = Encoding::from_table(&PATH_CHARS)
+        .encode(b'/')
+        .encode(b'&')
+        .encode(b'=');
+
+    /// The [default](Encoding::DEFAULT) encoding but with `/` unencoded.
+    pub const PATH: Encoding = Encoding::DEFAULT.preserve(b'/');
+
+    const fn from_table(table: &'static [u8; 256]) -> Encoding {
+        let mut encoding = Encoding { unencoded: 0 };
+        let mut i: u8 = 0;
+        while i < 0x80 {
+            if table[i as usize] != 0 {
+                encoding.unencoded |= 1 << i;
+            }
+
+            i += 1;
+        }
+
+        encoding.encode(b'%').encode(b'+')
+    }
+
+    /// Returns a copy of `self` which leaves `byte` unencoded.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `byte` must always be percent-encoded.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # extern crate rocket;
+    /// use rocket::http::RawStr;
+    /// use rocket::http::uri::fmt::Encoding;
+    ///
+    /// const ENCODING: Encoding = Encoding::DEFAULT.preserve(b'=');
+    ///
+    /// let encoded = RawStr::new("a=b&c d").percent_encode_with(ENCODING);
+    /// assert_eq!(encoded.as_str(), "a=b%26c%20d");
+    /// ```
+    pub const fn preserve(self, byte: u8) -> Encoding {
+        let safe = Encoding::from_table(&PATH_CHARS);
+        if !safe.is_unencoded(byte) {
+            panic!("character must always be percent-encoded");
+        }
+
+        Encoding { unencoded: self.unencoded | 1 << byte }
+    }
+
+    /// Returns a copy of `self` which percent-encodes `byte`. Non-ASCII bytes
+    /// are always encoded.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # extern crate rocket;
+    /// use rocket::http::RawStr;
+    /// use rocket::http::uri::fmt::Encoding;
+    ///
+    /// const ENCODING: Encoding = Encoding::PATH.encode(b':');
+    ///
+    /// let encoded = RawStr::new("a/b:c").percent_encode_with(ENCODING);
+    /// assert_eq!(encoded.as_str(), "a/b%3Ac");
+    /// ```
+    pub const fn encode(self, byte: u8) -> Encoding {
+        if byte >= 0x80 {
+            return self;
+        }
+
+        Encoding { unencoded: self.unencoded & !(1 << byte) }
+    }
+
+    /// Returns `true` if `byte` is written unencoded.
+    #[inline(always)]
+    pub const fn is_unencoded(&self, byte: u8) -> bool {
+        byte < 0x80 && self.unencoded & (1 << byte) != 0
+    }
+}
+
+impl Default for Encoding {
+    fn default() -> Self {
+        Encoding::DEFAULT
+    }
+}
+
+pub fn percent_encode_with(string: &str, encoding: Encoding) -> Cow<'_, str> {
+    use std::fmt::Write;
+
+    if string.bytes().all(|b| encoding.is_unencoded(b)) {
+        return Cow::Borrowed(string);
+    }
+
+    let mut encoded = String::with_capacity(string.len() + string.len() / 2);
+    for byte in string.bytes() {
+        if encoding.is_unencoded(byte) {
+            encoded.push(byte as char);
+        } else {
+            let _ = write!(encoded, "%{:02X}", byte);
+        }
+    }
+
+    Cow::Owned(encoded)
+}
+
 pub fn percent_encode<S: EncodeSet + Default>(string: &RawStr) -> Cow<'_, str> {
     percent_encoding::utf8_percent_encode(string.as_str(), &S::SET).into()
 }
@@ -88,3 +239,37 @@ pub fn percent_encode<S: EncodeSet + Default>(string: &RawStr) -> Cow<'_, str> {
 pub fn percent_encode_bytes<S: EncodeSet + Default>(bytes: &[u8]) -> Cow<'_, str> {
     percent_encoding::percent_encode(bytes, &S::SET).into()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_encoding_matches_default_encode_set() {
+        for byte in 0..=255u8 {
+            let bytes = [byte];
+            let expected = percent_encode_bytes::<DEFAULT_ENCODE_SET>(&bytes);
+            let actual = match Encoding::DEFAULT.is_unencoded(byte) {
+                true => Cow::Owned((byte as char).to_string()),
+                false => Cow::Owned(format!("%{:02X}", byte)),
+            };
+
+            assert_eq!(expected, actual, "byte {}", byte);
+        }
+    }
+
+    #[test]
+    fn path_encoding() {
+        assert_eq!(percent_encode_with("a/b", Encoding::PATH), "a/b");
+        assert_eq!(percent_encode_with("a/b", Encoding::DEFAULT), "a%2Fb");
+        assert_eq!(percent_encode_with("/a b/", Encoding::PATH), "/a%20b/");
+        assert_eq!(percent_encode_with("a%2F?+", Encoding::PATH), "a%252F%3F%2B");
+        assert_eq!(percent_encode_with("ü/&=", Encoding::PATH), "%C3%BC/%26%3D");
+    }
+
+    #[test]
+    #[should_panic]
+    fn preserve_unsafe() {
+        let _ = Encoding::DEFAULT.preserve(b'%');
+    }
+}
@@ -298,7 +298,7 @@ impl_from_uri_param_identity! {
     IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6,
     NonZeroIsize, NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128,
     NonZeroUsize, NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128,
-    time::Date, time::Time, time::PrimitiveDateTime,
+    time::Date, time::Time, time::PrimitiveDateTime, time::OffsetDateTime,
 }
 
 impl_from_uri_param_identity! {
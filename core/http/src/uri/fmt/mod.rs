@@ -10,5 +10,6 @@ pub use self::formatter::*;
 pub use self::uri_display::*;
 pub use self::from_uri_param::*;
 pub use self::part::*;
+pub use self::encoding::Encoding;
 
 pub(crate) use self::encoding::*;
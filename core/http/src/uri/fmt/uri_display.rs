@@ -3,6 +3,7 @@ use std::{fmt, path};
 use std::borrow::Cow;
 
 use time::{macros::format_description, format_description::FormatItem};
+use time::format_description::well_known::Rfc3339;
 
 use crate::RawStr;
 use crate::uri::fmt::{Part, Path, Query, Formatter};
@@ -221,7 +222,11 @@ use crate::uri::fmt::{Part, Path, Query, Formatter};
 /// existing implementations of `UriDisplay` as much as possible. In the example
 /// below, for instance, `Name`'s implementation defers to `String`'s
 /// implementation. To percent-encode a string, use
-/// [`Uri::percent_encode()`](crate::uri::Uri::percent_encode()).
+/// [`Uri::percent_encode()`](crate::uri::Uri::percent_encode()). To use
+/// different encoding rules, such as retaining `/` characters in a value that
+/// spans several path segments, use
+/// [`RawStr::percent_encode_with()`](crate::RawStr::percent_encode_with())
+/// with a custom [`Encoding`](crate::uri::fmt::Encoding).
 ///
 /// ## Example
 ///
@@ -381,6 +386,7 @@ impl_with_string! {
     time::Date => |d| d.format(&DATE_FMT).map_err(|_| fmt::Error),
     time::Time => |d| d.format(&TIME_FMT).map_err(|_| fmt::Error),
     time::PrimitiveDateTime => |d| d.format(&DATE_TIME_FMT).map_err(|_| fmt::Error),
+    time::OffsetDateTime => |d| d.format(&Rfc3339).map_err(|_| fmt::Error),
     SocketAddr => |a| Ok(a.to_string()),
     SocketAddrV4 => |a| Ok(a.to_string()),
     SocketAddrV6 => |a| Ok(a.to_string()),
@@ -0,0 +1,37 @@
+use std::borrow::Cow;
+
+/// A matrix parameter, `;name=value`, in a segment of a URI path.
+///
+/// Matrix parameters are a legacy convention for attaching parameters to
+/// individual path segments, as in `/cars;color=red/models;year=2020`. They
+/// are not otherwise interpreted by Rocket: `cars;color=red` is a single path
+/// segment when routing. Matrix parameters are retrieved via
+/// [`Path::matrix()`](crate::uri::Path::matrix()).
+///
+/// # Example
+///
+/// ```rust
+/// # extern crate rocket;
+/// use rocket::http::uri::Origin;
+///
+/// let uri = Origin::parse("/cars;color=red;used/models;year=2020").unwrap();
+/// let params: Vec<_> = uri.path().matrix()
+///     .map(|p| (p.segment, p.name, p.value))
+///     .collect();
+///
+/// assert_eq!(params, [
+///     (0, "color".into(), "red".into()),
+///     (0, "used".into(), "".into()),
+///     (1, "year".into(), "2020".into()),
+/// ]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MatrixParam<'a> {
+    /// The index of the path segment containing the parameter, matching the
+    /// index of the segment in [`Path::segments()`](crate::uri::Path::segments()).
+    pub segment: usize,
+    /// The percent-decoded name of the parameter.
+    pub name: Cow<'a, str>,
+    /// The percent-decoded value of the parameter, empty if there is none.
+    pub value: Cow<'a, str>,
+}
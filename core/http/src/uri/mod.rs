@@ -8,6 +8,7 @@ mod authority;
 mod absolute;
 mod segments;
 mod path_query;
+mod matrix;
 mod asterisk;
 mod host;
 
@@ -24,5 +25,6 @@ pub use self::absolute::*;
 pub use self::segments::*;
 pub use self::reference::*;
 pub use self::path_query::*;
+pub use self::matrix::*;
 pub use self::asterisk::*;
 pub use self::host::*;
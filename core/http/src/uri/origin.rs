@@ -438,6 +438,64 @@ impl<'a> Origin<'a> {
         self
     }
 
+    /// [Normalizes](Origin::normalize()) `self` and additionally removes dot
+    /// segments from its path as in [RFC 3986 §5.2.4]: `.` segments are
+    /// removed, and each `..` segment is removed along with the segment
+    /// preceding it, if any. Dot segments are recognized even when
+    /// percent-encoded. A path ending in a dot segment retains a trailing
+    /// slash. This is a no-op if `self` is normalized and has no dot segments.
+    ///
+    /// Canonicalizing a URI composed from untrusted input prevents `//` and
+    /// traversal artifacts from appearing in the result. `..` segments can
+    /// never traverse beyond the root.
+    ///
+    /// [RFC 3986 §5.2.4]: https://datatracker.ietf.org/doc/html/rfc3986#section-5.2.4
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate rocket;
+    /// use rocket::http::uri::Origin;
+    ///
+    /// let mut uri = Origin::parse("/a/./b/../c//d?x&&y").unwrap();
+    /// uri.canonicalize();
+    /// assert_eq!(uri, uri!("/a/c/d?x&y"));
+    ///
+    /// let mut uri = Origin::parse("/../../etc/%2e%2E/passwd").unwrap();
+    /// uri.canonicalize();
+    /// assert_eq!(uri, uri!("/passwd"));
+    ///
+    /// let mut uri = Origin::parse("/a/b/..").unwrap();
+    /// uri.canonicalize();
+    /// assert_eq!(uri, uri!("/a/"));
+    /// ```
+    pub fn canonicalize(&mut self) {
+        self.normalize();
+        if self.path().has_dot_segments() {
+            self.path = self.path().to_canonical(true);
+        }
+    }
+
+    /// Consumes `self` and returns a [canonicalized](Origin::canonicalize())
+    /// version.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate rocket;
+    /// use rocket::http::uri::Origin;
+    ///
+    /// let uri = Origin::parse("/a/b/./../c/.").unwrap();
+    /// assert_eq!(uri.into_canonical(), uri!("/a/c/"));
+    ///
+    /// let uri = Origin::parse("/a/b/c").unwrap();
+    /// assert_eq!(uri.into_canonical(), uri!("/a/b/c"));
+    /// ```
+    pub fn into_canonical(mut self) -> Self {
+        self.canonicalize();
+        self
+    }
+
     /// Returns `true` if `self` has a _trailing_ slash.
     ///
     /// This is defined as `path.len() > 1` && `path.ends_with('/')`. This
@@ -699,4 +757,35 @@ mod tests {
         test_query("/?", Some(""));
         test_query("/?hi", Some("hi"));
     }
+
+    fn canonical(uri: &str, expected: &str) {
+        let actual = Origin::parse(uri).unwrap().into_canonical();
+        assert_eq!(actual.to_string(), expected, "canonicalizing {}", uri);
+    }
+
+    #[test]
+    fn canonicalize() {
+        canonical("/", "/");
+        canonical("/a/b/c", "/a/b/c");
+        canonical("/a/b/c/", "/a/b/c/");
+        canonical("//a///b//", "/a/b/");
+        canonical("/a/./b", "/a/b");
+        canonical("/a/../b", "/b");
+        canonical("/a/b/../../c", "/c");
+        canonical("/..", "/");
+        canonical("/../..", "/");
+        canonical("/../a", "/a");
+        canonical("/a/../..", "/");
+        canonical("/a/.", "/a/");
+        canonical("/a/./", "/a/");
+        canonical("/a/b/..", "/a/");
+        canonical("/a/b/../", "/a/");
+        canonical("/.", "/");
+        canonical("/a/%2e/b/%2E%2e", "/a/");
+        canonical("/a/.../b", "/a/.../b");
+        canonical("/a/.b/..c", "/a/.b/..c");
+        canonical("/a/b./c..", "/a/b./c..");
+        canonical("/a/../b?x&&y", "/b?x&y");
+        canonical("/a/./b?..", "/a/b?..");
+    }
 }
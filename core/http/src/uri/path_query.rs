@@ -4,7 +4,7 @@ use std::borrow::Cow;
 use state::InitCell;
 
 use crate::{RawStr, ext::IntoOwned};
-use crate::uri::Segments;
+use crate::uri::{Segments, MatrixParam};
 use crate::uri::fmt::{self, Part};
 use crate::parse::{IndexedStr, Extent};
 
@@ -64,6 +64,10 @@ fn decode_to_indexed_str<P: fmt::Part>(
     }
 }
 
+fn is_dot_segment(segment: &RawStr) -> bool {
+    matches!(&*segment.percent_decode_lossy(), "." | "..")
+}
+
 impl<'a> Path<'a> {
     /// Returns the raw path value.
     ///
@@ -133,6 +137,44 @@ impl<'a> Path<'a> {
         }
     }
 
+    /// Whether `self` contains a `.` or `..` segment, percent-encoded or not.
+    pub(crate) fn has_dot_segments(&self) -> bool {
+        self.raw_segments().any(|s| is_dot_segment(s))
+    }
+
+    /// Normalizes `self` as in [`Path::to_normalized()`], allowing a trailing
+    /// slash, and removes `.` and `..` segments as in RFC 3986 §5.2.4. A `..`
+    /// segment removes the segment preceding it, if any. If the last segment
+    /// is a dot segment, the path has a trailing slash.
+    pub(crate) fn to_canonical(self, absolute: bool) -> Data<'static, fmt::Path> {
+        let raw = self.raw().trim();
+        let mut segments: Vec<&str> = vec![];
+        let mut trail = raw.len() > 1 && raw.ends_with('/');
+        for segment in self.raw_segments().filter(|s| !s.is_empty()) {
+            trail = is_dot_segment(segment) || raw.ends_with('/');
+            match &*segment.percent_decode_lossy() {
+                "." => continue,
+                ".." => { segments.pop(); },
+                _ => segments.push(segment.as_str()),
+            }
+        }
+
+        let mut path = String::with_capacity(raw.len());
+        if absolute || raw.starts_with('/') {
+            path.push('/');
+        }
+
+        path.push_str(&segments.join("/"));
+        if trail && !segments.is_empty() {
+            path.push('/');
+        }
+
+        Data {
+            value: IndexedStr::from(Cow::Owned(path)),
+            decoded_segments: InitCell::new(),
+        }
+    }
+
     /// Returns an iterator over the raw, undecoded segments, potentially empty
     /// segments.
     ///
@@ -232,6 +274,50 @@ impl<'a> Path<'a> {
 
         Segments::new(raw, cached)
     }
+
+    /// Returns an iterator over the percent-decoded matrix parameters in the
+    /// path, that is, the `;`-separated `name=value` pairs following the first
+    /// `;` in each segment. A parameter without a `=` has an empty value.
+    ///
+    /// The [`segment`](MatrixParam::segment) of each parameter is the index,
+    /// in [`Path::segments()`], of the segment containing the parameter.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # extern crate rocket;
+    /// use rocket::http::uri::Origin;
+    ///
+    /// let uri = Origin::parse("/a;x=1;y=2//b/c;z=hi%20there?q=1").unwrap();
+    /// let params: Vec<_> = uri.path().matrix()
+    ///     .map(|p| (p.segment, p.name, p.value))
+    ///     .collect();
+    ///
+    /// assert_eq!(params, [
+    ///     (0, "x".into(), "1".into()),
+    ///     (0, "y".into(), "2".into()),
+    ///     (2, "z".into(), "hi there".into()),
+    /// ]);
+    ///
+    /// // Matrix parameters remain part of the segment.
+    /// let segments: Vec<_> = uri.path().segments().collect();
+    /// assert_eq!(segments, ["a;x=1;y=2", "b", "c;z=hi there"]);
+    /// ```
+    pub fn matrix(&self) -> impl Iterator<Item = MatrixParam<'a>> {
+        self.raw_segments()
+            .filter(|segment| !segment.is_empty())
+            .enumerate()
+            .flat_map(|(i, segment)| segment.split(';').skip(1).map(move |param| (i, param)))
+            .filter(|(_, param)| !param.is_empty())
+            .map(|(segment, param)| {
+                let (name, value) = param.split_at_byte(b'=');
+                MatrixParam {
+                    segment,
+                    name: name.percent_decode_lossy(),
+                    value: value.percent_decode_lossy(),
+                }
+            })
+    }
 }
 
 impl<'a> Query<'a> {
@@ -7,6 +7,7 @@ use crate::response::Response;
 use crate::request::Request;
 use crate::http::{Status, ContentType, uri};
 use crate::catcher::{Handler, BoxFuture};
+use crate::config::ErrorFormat;
 
 use yansi::Paint;
 
@@ -49,6 +50,52 @@ use yansi::Paint;
 /// [`Outcome`]: crate::request::Outcome
 /// [`Rocket::register()`]: crate::Rocket::register()
 ///
+/// ## Scopes and Declining
+///
+/// Catchers registered at a base apply to every request under that base, so
+/// nested bases form scopes that inherit from broader ones: an error not
+/// handled by a catcher under `/hello/Sergio` is handled by one under
+/// `/hello`, then by one under `/`, and finally by the [built-in
+/// default](#built-in-default). At each base, a catcher for the specific
+/// status takes precedence over a `default` catcher.
+///
+/// A catcher can _decline_ to handle an error by failing with the very status
+/// it was invoked with. Rocket then invokes the next catcher in order of
+/// precedence, possibly the built-in default. Failing with any other status is
+/// a catcher error, which Rocket handles with a `500` catcher as usual. Note
+/// that a `404` catcher returning `None` declines.
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use rocket::Request;
+/// use rocket::http::Status;
+///
+/// // Handles errors only for requests that accept JSON. Others are handled by
+/// // the next matching catcher, here, `fallback`.
+/// #[catch(default)]
+/// fn json(status: Status, req: &Request<'_>) -> Result<String, Status> {
+///     match req.format() {
+///         Some(f) if f.is_json() => Ok(format!("{{ \"code\": {} }}", status.code)),
+///         _ => Err(status),
+///     }
+/// }
+///
+/// #[catch(default)]
+/// fn fallback(status: Status) -> String {
+///     format!("{} error", status)
+/// }
+///
+/// #[launch]
+/// fn rocket() -> _ {
+///     rocket::build()
+///         .register("/", catchers![fallback])
+///         .register("/api", catchers![json])
+/// }
+/// ```
+///
+/// The catchers Rocket tries for a given status and request, in order, can be
+/// queried with [`Rocket::catchers_for()`](crate::Rocket::catchers_for()).
+///
 /// ## Collisions
 ///
 /// Two catchers are said to _collide_ if there exists an error that matches
@@ -61,7 +108,9 @@ use yansi::Paint;
 /// ## Built-In Default
 ///
 /// Rocket's provides a built-in default catcher that can handle all errors. It
-/// produces HTML or JSON, depending on the value of the `Accept` header. As
+/// produces HTML or JSON, depending on the value of the `Accept` header. The
+/// format can be fixed application-wide via the
+/// [`error_format`](crate::Config::error_format) configuration parameter. As
 /// such, catchers only need to be registered if an error needs to be handled in
 /// a custom fashion. The built-in default never conflicts with any
 /// user-registered catchers.
@@ -430,8 +479,13 @@ macro_rules! default_handler_fn {
             status: Status,
             req: &'r Request<'_>
         ) -> Response<'r> {
-            let preferred = req.accept().map(|a| a.preferred());
-            let (mime, text) = if preferred.map_or(false, |a| a.is_json()) {
+            let json = match req.rocket().config().error_format {
+                ErrorFormat::Auto => req.accept().map_or(false, |a| a.preferred().is_json()),
+                ErrorFormat::Html => false,
+                ErrorFormat::Json => true,
+            };
+
+            let (mime, text) = if json {
                 let json: Cow<'_, str> = match status.code {
                     $($code => json_error_template!($code, $reason, $description).into(),)*
                     code => format!(json_error_fmt_template!("{}", "Unknown Error",
@@ -6,6 +6,7 @@ use yansi::{Paint, Style, Color::Primary};
 
 use crate::log::PaintExt;
 use crate::config::{LogLevel, ShutdownConfig, Ident, CliColors};
+use crate::config::{TrailingSlash, ErrorFormat, FileValues, SerdeLimits};
 use crate::request::{self, Request, FromRequest};
 use crate::http::uncased::Uncased;
 use crate::data::Limits;
@@ -70,6 +71,10 @@ pub struct Config {
     pub max_blocking: usize,
     /// How, if at all, to identify the server via the `Server` header.
     /// **(default: `"Rocket"`)**
+    ///
+    /// The header is added to every response, including error responses,
+    /// that doesn't already contain a `Server` header. Set to `false` to
+    /// never add the header.
     pub ident: Ident,
     /// The name of a header, whose value is typically set by an intermediary
     /// server or proxy, which contains the real IP address of the connecting
@@ -106,12 +111,93 @@ pub struct Config {
     pub proxy_proto_header: Option<Uncased<'static>>,
     /// Streaming read size limits. **(default: [`Limits::default()`])**
     pub limits: Limits,
+    /// Nesting and element count limits for deserialized JSON and
+    /// MessagePack data. **(default: [`SerdeLimits::default()`])**
+    pub serde_limits: SerdeLimits,
     /// Directory to store temporary files in. **(default:
     /// [`std::env::temp_dir()`])**
     #[serde(serialize_with = "RelativePathBuf::serialize_relative")]
     pub temp_dir: RelativePathBuf,
     /// Keep-alive timeout in seconds; disabled when `0`. **(default: `5`)**
+    ///
+    /// Over HTTP/2, idle connections are pinged every quarter of this period
+    /// and closed if a ping isn't acknowledged within it.
+    ///
+    /// Over HTTP/1, this value only enables or disables keep-alive: there is
+    /// no separate idle timeout. Idle keep-alive connections are instead closed
+    /// by [`read_timeout`](Config::read_timeout) when the next request's
+    /// headers don't arrive in time.
     pub keep_alive: u32,
+    /// Timeout in seconds for receiving the complete headers of a request;
+    /// disabled when `0`. **(default: `15`)**
+    ///
+    /// Connections which do not transmit a request's headers in full within
+    /// this period are closed, defending against clients which hold
+    /// connections open by sending headers slowly ("slowloris"). Over HTTP/1,
+    /// the period begins when the server starts waiting for a request, so it
+    /// also bounds how long an idle keep-alive connection is kept open.
+    pub read_timeout: u32,
+    /// Maximum number of requests processed concurrently; unlimited when `0`.
+    /// **(default: `0`)**
+    ///
+    /// When the limit is reached, new requests are immediately answered with a
+    /// `503 Service Unavailable` error carrying a `Retry-After` header instead
+    /// of being queued.
+    pub max_concurrent_requests: usize,
+    /// Maximum length, in bytes, of a request's URI, that is, of its path and
+    /// query, or `0` for no limit. **(default: `8192`)**
+    ///
+    /// Requests with longer URIs are answered with a `414 URI Too Long` error
+    /// without being routed or having their body read.
+    pub max_uri_length: usize,
+    /// Whether to accept HTTP/2 over plaintext connections ("h2c") with prior
+    /// knowledge. **(default: `false`)**
+    ///
+    /// HTTP/2 is always available over TLS when the `http2` feature is enabled.
+    /// Enabling `h2c` additionally allows clients, typically a proxy which
+    /// terminates TLS, to speak HTTP/2 over connections that are not secured
+    /// with TLS. When disabled, such connections are served with HTTP/1 only:
+    /// a client which attempts to speak HTTP/2 over one has its connection
+    /// closed.
+    pub h2c: bool,
+    /// How trailing slashes in request paths affect routing. **(default:
+    /// [`TrailingSlash::Strict`])**
+    pub trailing_slash: TrailingSlash,
+    /// Whether static path segments in routes match request path segments
+    /// case-insensitively. **(default: `false`)**
+    ///
+    /// When enabled, a route for `/api/users` matches a request to
+    /// `/API/Users`. Dynamic parameters are unaffected: their values are
+    /// passed to routes exactly as they appear in the request. Routes which
+    /// differ only in the case of static segments are not considered to
+    /// collide and are tried in order of rank.
+    pub case_insensitive_paths: bool,
+    /// Whether to reject requests whose `Expect` header can't be met before
+    /// their body is transferred. **(default: `false`)**
+    ///
+    /// When enabled, a request with an `Expect` header other than
+    /// `100-continue` is answered with a `417 Expectation Failed` error. A
+    /// request with `Expect: 100-continue` and a `Content-Length` exceeding
+    /// every configured [`limit`](Config::limits) is answered with a `413
+    /// Payload Too Large` error. In either case, the body is never requested
+    /// from the client, saving the bandwidth of transferring it.
+    ///
+    /// Because the check happens before routing, it only considers the
+    /// configured limits. Do not enable this option if any handler reads
+    /// bodies larger than every configured limit, for instance, via a larger
+    /// limit passed to [`Data::open()`](crate::Data::open()) or a route's
+    /// `limit` parameter: such uploads would be rejected.
+    ///
+    /// Regardless of this setting, the `100 Continue` interim response is only
+    /// sent when the body is first read, typically by a data guard after all
+    /// request guards have succeeded. Requests which fail before the body is
+    /// read are answered without one. Note that request fairings and the
+    /// `_method` override of form submissions may read the body earlier.
+    pub expect_continue: bool,
+    /// The format of responses from the default catcher, used when no
+    /// registered catcher handles an error. **(default:
+    /// [`ErrorFormat::Auto`])**
+    pub error_format: ErrorFormat,
     /// The secret key for signing and encrypting. **(default: `0`)**
     ///
     /// _**Note:** This field _always_ serializes as a 256-bit array of `0`s to
@@ -163,9 +249,14 @@ impl Default for Config {
 }
 
 impl Config {
+    /// Keys whose values can be read from files by [`Config::figment()`]:
+    /// `secret_key` and `databases.*.url`. Additional keys can be allowed via
+    /// [`Config::figment_with_file_keys()`].
+    pub const FILE_KEYS: &'static [&'static str] = &["secret_key", "databases.*.url"];
+
     const DEPRECATED_KEYS: &'static [(&'static str, Option<&'static str>)] = &[
         ("env", Some(Self::PROFILE)), ("log", Some(Self::LOG_LEVEL)),
-        ("read_timeout", None), ("write_timeout", None),
+        ("write_timeout", None),
     ];
 
     const DEPRECATED_PROFILES: &'static [(&'static str, Option<&'static str>)] = &[
@@ -196,8 +287,17 @@ impl Config {
             ip_header: Some(Uncased::from_borrowed("X-Real-IP")),
             proxy_proto_header: None,
             limits: Limits::default(),
+            serde_limits: SerdeLimits::default(),
             temp_dir: std::env::temp_dir().into(),
             keep_alive: 5,
+            read_timeout: 15,
+            max_concurrent_requests: 0,
+            max_uri_length: 8192,
+            h2c: false,
+            trailing_slash: TrailingSlash::Strict,
+            case_insensitive_paths: false,
+            expect_continue: false,
+            error_format: ErrorFormat::Auto,
             #[cfg(feature = "secrets")]
             secret_key: SecretKey::zero(),
             shutdown: ShutdownConfig::default(),
@@ -239,6 +339,11 @@ impl Config {
     ///   2. `Rocket.toml` _or_ filename in `ROCKET_CONFIG` environment variable
     ///   3. `ROCKET_` prefixed environment variables
     ///
+    /// In the latter two sources, the values of the [`Config::FILE_KEYS`] can
+    /// instead be read from the file named by the same key suffixed with
+    /// `_file`. See [`FileValues`] for details and
+    /// [`Config::figment_with_file_keys()`] to allow additional keys.
+    ///
     /// The profile selected is the value set in the `ROCKET_PROFILE`
     /// environment variable. If it is not set, it defaults to `debug` when
     /// compiled in debug mode and `release` when compiled in release mode.
@@ -259,12 +364,47 @@ impl Config {
     /// let my_config = Config::figment().extract::<MyConfig>();
     /// ```
     pub fn figment() -> Figment {
+        Self::figment_with_file_keys(None::<String>)
+    }
+
+    /// Returns the default provider figment, as returned by
+    /// [`Config::figment()`], which additionally allows the values of `keys`
+    /// to be read from files.
+    ///
+    /// Each key in `keys` is a dotted path, relative to a profile, as accepted
+    /// by [`FileValues::allow()`]. The [`Config::FILE_KEYS`] are always
+    /// allowed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::Config;
+    ///
+    /// // Read `my_app.password` from the file named by `my_app.password_file`.
+    /// let figment = Config::figment_with_file_keys(["my_app.password"]);
+    /// let rocket = rocket::custom(figment);
+    /// ```
+    pub fn figment_with_file_keys<I>(keys: I) -> Figment
+        where I: IntoIterator, I::Item: Into<String>
+    {
+        let keys: Vec<String> = Self::FILE_KEYS.iter()
+            .map(|key| key.to_string())
+            .chain(keys.into_iter().map(Into::into))
+            .collect();
+
+        let toml = Toml::file(Env::var_or("ROCKET_CONFIG", "Rocket.toml")).nested();
+        let env = Env::prefixed("ROCKET_").ignore(&["PROFILE"]).global();
         Figment::from(Config::default())
-            .merge(Toml::file(Env::var_or("ROCKET_CONFIG", "Rocket.toml")).nested())
-            .merge(Env::prefixed("ROCKET_").ignore(&["PROFILE"]).global())
+            .merge(Self::file_values(toml, &keys))
+            .merge(Self::file_values(env, &keys))
             .select(Profile::from_env_or("ROCKET_PROFILE", Self::DEFAULT_PROFILE))
     }
 
+    /// Wraps `provider` in a [`FileValues`] allowing `keys`.
+    fn file_values<P: Provider>(provider: P, keys: &[String]) -> FileValues<P> {
+        keys.iter().fold(FileValues::new(provider), |values, key| values.allow(key.as_str()))
+    }
+
     /// Attempts to extract a `Config` from `provider`, returning the result.
     ///
     /// # Example
@@ -364,14 +504,36 @@ impl Config {
         }
 
         launch_meta_!("limits: {}", self.limits.paint(VAL));
+        launch_meta_!("serde limits: {}", self.serde_limits.paint(VAL));
         launch_meta_!("temp dir: {}", self.temp_dir.relative().display().paint(VAL));
         launch_meta_!("http/2: {}", (cfg!(feature = "http2").paint(VAL)));
+        launch_meta_!("h2c: {}", (cfg!(feature = "http2") && self.h2c).paint(VAL));
 
         match self.keep_alive {
             0 => launch_meta_!("keep-alive: {}", "disabled".paint(VAL)),
             ka => launch_meta_!("keep-alive: {}{}", ka.paint(VAL), "s".paint(VAL)),
         }
 
+        match self.read_timeout {
+            0 => launch_meta_!("read timeout: {}", "disabled".paint(VAL)),
+            t => launch_meta_!("read timeout: {}{}", t.paint(VAL), "s".paint(VAL)),
+        }
+
+        match self.max_concurrent_requests {
+            0 => launch_meta_!("max concurrent requests: {}", "unlimited".paint(VAL)),
+            n => launch_meta_!("max concurrent requests: {}", n.paint(VAL)),
+        }
+
+        match self.max_uri_length {
+            0 => launch_meta_!("max uri length: {}", "unlimited".paint(VAL)),
+            n => launch_meta_!("max uri length: {}{}", n.paint(VAL), "B".paint(VAL)),
+        }
+
+        launch_meta_!("trailing slash: {}", self.trailing_slash.paint(VAL));
+        launch_meta_!("case-insensitive paths: {}", self.case_insensitive_paths.paint(VAL));
+        launch_meta_!("expect continue: {}", self.expect_continue.paint(VAL));
+        launch_meta_!("error format: {}", self.error_format.paint(VAL));
+
         launch_meta_!("shutdown: {}", self.shutdown.paint(VAL));
         launch_meta_!("log level: {}", self.log_level.paint(VAL));
         launch_meta_!("cli colors: {}", self.cli_colors.paint(VAL));
@@ -450,6 +612,36 @@ impl Config {
     /// The stringy parameter name for setting/extracting [`Config::keep_alive`].
     pub const KEEP_ALIVE: &'static str = "keep_alive";
 
+    /// The stringy parameter name for setting/extracting [`Config::read_timeout`].
+    pub const READ_TIMEOUT: &'static str = "read_timeout";
+
+    /// The stringy parameter name for setting/extracting
+    /// [`Config::max_concurrent_requests`].
+    pub const MAX_CONCURRENT_REQUESTS: &'static str = "max_concurrent_requests";
+
+    /// The stringy parameter name for setting/extracting
+    /// [`Config::max_uri_length`].
+    pub const MAX_URI_LENGTH: &'static str = "max_uri_length";
+
+    /// The stringy parameter name for setting/extracting [`Config::h2c`].
+    pub const H2C: &'static str = "h2c";
+
+    /// The stringy parameter name for setting/extracting
+    /// [`Config::trailing_slash`].
+    pub const TRAILING_SLASH: &'static str = "trailing_slash";
+
+    /// The stringy parameter name for setting/extracting
+    /// [`Config::case_insensitive_paths`].
+    pub const CASE_INSENSITIVE_PATHS: &'static str = "case_insensitive_paths";
+
+    /// The stringy parameter name for setting/extracting
+    /// [`Config::expect_continue`].
+    pub const EXPECT_CONTINUE: &'static str = "expect_continue";
+
+    /// The stringy parameter name for setting/extracting
+    /// [`Config::error_format`].
+    pub const ERROR_FORMAT: &'static str = "error_format";
+
     /// The stringy parameter name for setting/extracting [`Config::ident`].
     pub const IDENT: &'static str = "ident";
 
@@ -462,6 +654,10 @@ impl Config {
     /// The stringy parameter name for setting/extracting [`Config::limits`].
     pub const LIMITS: &'static str = "limits";
 
+    /// The stringy parameter name for setting/extracting
+    /// [`Config::serde_limits`].
+    pub const SERDE_LIMITS: &'static str = "serde_limits";
+
     /// The stringy parameter name for setting/extracting [`Config::secret_key`].
     pub const SECRET_KEY: &'static str = "secret_key";
 
@@ -479,10 +675,12 @@ impl Config {
 
     /// An array of all of the stringy parameter names.
     pub const PARAMETERS: &'static [&'static str] = &[
-        Self::WORKERS, Self::MAX_BLOCKING, Self::KEEP_ALIVE, Self::IDENT,
-        Self::IP_HEADER, Self::PROXY_PROTO_HEADER, Self::LIMITS,
-        Self::SECRET_KEY, Self::TEMP_DIR, Self::LOG_LEVEL, Self::SHUTDOWN,
-        Self::CLI_COLORS,
+        Self::WORKERS, Self::MAX_BLOCKING, Self::KEEP_ALIVE, Self::READ_TIMEOUT,
+        Self::MAX_CONCURRENT_REQUESTS, Self::MAX_URI_LENGTH, Self::H2C,
+        Self::TRAILING_SLASH, Self::CASE_INSENSITIVE_PATHS, Self::EXPECT_CONTINUE,
+        Self::ERROR_FORMAT, Self::IDENT, Self::IP_HEADER, Self::PROXY_PROTO_HEADER,
+        Self::LIMITS, Self::SERDE_LIMITS, Self::SECRET_KEY, Self::TEMP_DIR,
+        Self::LOG_LEVEL, Self::SHUTDOWN, Self::CLI_COLORS,
     ];
 }
 
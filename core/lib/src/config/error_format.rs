@@ -0,0 +1,82 @@
+use std::fmt;
+
+use serde::{de, Deserialize, Serialize};
+
+/// The format of error responses generated by Rocket's default catcher.
+///
+/// The default catcher responds to errors that no registered
+/// [`Catcher`](crate::Catcher) handles. Valid configuration values are:
+///
+///   * `"auto"` - [`ErrorFormat::Auto`] _(default)_
+///   * `"html"` - [`ErrorFormat::Html`]
+///   * `"json"` - [`ErrorFormat::Json`]
+///
+/// To respond in a custom format, such as with a template, register a
+/// `default` catcher at `/` instead: it takes precedence over the default
+/// catcher for every status.
+///
+/// # Example
+///
+/// ```rust
+/// use rocket::config::{Config, ErrorFormat};
+///
+/// let config = Config {
+///     error_format: ErrorFormat::Json,
+///     ..Config::default()
+/// };
+/// ```
+#[derive(Debug, Copy, Clone, Default, Serialize, PartialEq, Eq, Hash)]
+pub enum ErrorFormat {
+    /// Respond with JSON if the request's preferred media type, as determined
+    /// by its `Accept` header, is JSON, and with HTML otherwise.
+    ///
+    /// Case-insensitive string values of `"auto"` parse as this value.
+    #[default]
+    Auto,
+
+    /// Always respond with HTML.
+    ///
+    /// Case-insensitive string values of `"html"` parse as this value.
+    Html,
+
+    /// Always respond with JSON. Useful for API-only applications which
+    /// should never emit HTML error pages.
+    ///
+    /// Case-insensitive string values of `"json"` parse as this value.
+    Json,
+}
+
+impl fmt::Display for ErrorFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorFormat::Auto => write!(f, "auto"),
+            ErrorFormat::Html => write!(f, "html"),
+            ErrorFormat::Json => write!(f, "json"),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for ErrorFormat {
+    fn deserialize<D: de::Deserializer<'de>>(de: D) -> Result<Self, D::Error> {
+        struct Visitor;
+
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ErrorFormat;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("auto, html, or json")
+            }
+
+            fn visit_str<E: de::Error>(self, val: &str) -> Result<ErrorFormat, E> {
+                match val.to_lowercase().as_str() {
+                    "auto" => Ok(ErrorFormat::Auto),
+                    "html" => Ok(ErrorFormat::Html),
+                    "json" => Ok(ErrorFormat::Json),
+                    _ => Err(E::invalid_value(de::Unexpected::Str(val), &self)),
+                }
+            }
+        }
+
+        de.deserialize_str(Visitor)
+    }
+}
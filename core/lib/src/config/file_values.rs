@@ -0,0 +1,180 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use figment::{Error, Metadata, Profile, Provider, Source};
+use figment::value::{Dict, Map, Value};
+
+/// A [`Provider`] adapter which reads the values of allowed keys from files.
+///
+/// For every [allowed](FileValues::allow()) key `key` for which the wrapped
+/// provider provides a string value for `key_file`, `FileValues` reads the file
+/// at the path given by the value and provides its contents as the value of
+/// `key` instead. The `key_file` key is removed. This follows the `*_FILE`
+/// convention used by container orchestrators to provide secrets as mounted
+/// files. Keys which are not allowed, including application keys which happen
+/// to end in `_file`, are left untouched.
+///
+/// A single trailing newline is stripped from the contents, which are then
+/// parsed as a [`Value`] exactly as environment variable values are: `8000`
+/// becomes an integer, `true` a boolean, and so on.
+///
+/// It is an error for the wrapped provider to provide both `key` and
+/// `key_file` or for the file to be unreadable. Relative paths are resolved
+/// against the directory containing the provider's source file, if it has one,
+/// as is the case for [`Toml::file()`], and against the current working
+/// directory otherwise.
+///
+/// [`Config::figment()`](crate::Config::figment()) wraps Rocket's TOML and
+/// environment variable providers in `FileValues`, allowing `secret_key` and
+/// `databases.*.url`. As such, setting `ROCKET_SECRET_KEY_FILE=/run/secrets/key`
+/// sets `secret_key` to the contents of `/run/secrets/key`. Additional keys can
+/// be allowed via
+/// [`Config::figment_with_file_keys()`](crate::Config::figment_with_file_keys()).
+///
+/// [`Toml::file()`]: figment::providers::Format::file()
+///
+/// # Example
+///
+/// ```rust
+/// use rocket::config::FileValues;
+/// use rocket::figment::{Figment, providers::Env};
+///
+/// // Allow `APP_PASSWORD_FILE` to provide `password`.
+/// let provider = FileValues::new(Env::prefixed("APP_")).allow("password");
+/// let figment = Figment::from(provider);
+/// ```
+#[derive(Debug, Clone)]
+pub struct FileValues<P> {
+    provider: P,
+    allowed: Vec<String>,
+}
+
+impl<P: Provider> FileValues<P> {
+    /// The suffix which marks a key as naming a file: `_file`.
+    pub const SUFFIX: &'static str = "_file";
+
+    /// Wraps `provider` so that the values of [allowed](FileValues::allow())
+    /// keys can be read from files. Initially, no keys are allowed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::config::FileValues;
+    /// use rocket::figment::providers::{Format, Toml};
+    ///
+    /// let provider = FileValues::new(Toml::file("App.toml").nested());
+    /// ```
+    pub fn new(provider: P) -> Self {
+        FileValues { provider, allowed: vec![] }
+    }
+
+    /// Allows the value of `key` to be read from the file named by `key_file`.
+    ///
+    /// `key` is a dotted path to a key relative to a profile. A path segment of
+    /// `*` matches any key, so `databases.*.url` allows both
+    /// `databases.users.url_file` and `databases.cache.url_file`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::config::FileValues;
+    /// use rocket::figment::providers::{Format, Toml};
+    ///
+    /// let provider = FileValues::new(Toml::file("App.toml").nested())
+    ///     .allow("password")
+    ///     .allow("services.*.token");
+    /// ```
+    pub fn allow<K: Into<String>>(mut self, key: K) -> Self {
+        self.allowed.push(key.into());
+        self
+    }
+
+    fn is_allowed(&self, path: &[&str]) -> bool {
+        self.allowed.iter().any(|allowed| {
+            let segments = allowed.split('.');
+            segments.clone().count() == path.len()
+                && segments.zip(path).all(|(a, p)| a == "*" || a == *p)
+        })
+    }
+
+    fn resolve(
+        &self,
+        dict: &mut Dict,
+        base: Option<&Path>,
+        path: &mut Vec<String>,
+    ) -> Result<(), Error> {
+        for (key, value) in dict.iter_mut() {
+            if let Value::Dict(_, dict) = value {
+                path.push(key.clone());
+                self.resolve(dict, base, path)?;
+                path.pop();
+            }
+        }
+
+        let file_keys: Vec<String> = dict.keys()
+            .filter(|k| k.len() > Self::SUFFIX.len() && k.ends_with(Self::SUFFIX))
+            .filter(|k| {
+                let key = &k[..k.len() - Self::SUFFIX.len()];
+                let mut full: Vec<&str> = path.iter().map(|s| s.as_str()).collect();
+                full.push(key);
+                self.is_allowed(&full)
+            })
+            .cloned()
+            .collect();
+
+        for file_key in file_keys {
+            let file = match dict.get(&file_key).and_then(|v| v.as_str()) {
+                Some(file) => base.map_or_else(|| PathBuf::from(file), |base| base.join(file)),
+                None => continue,
+            };
+
+            let key = &file_key[..file_key.len() - Self::SUFFIX.len()];
+            if dict.contains_key(key) {
+                return Err(format!("`{}` and `{}` cannot both be set", key, file_key).into());
+            }
+
+            let contents = fs::read_to_string(&file).map_err(|e| {
+                Error::from(format!("failed to read `{}` file {:?}: {}", file_key, file, e))
+            })?;
+
+            let contents = contents.strip_suffix('\n')
+                .map(|c| c.strip_suffix('\r').unwrap_or(c))
+                .unwrap_or(&contents);
+
+            let value = contents.parse::<Value>().expect("infallible");
+            dict.remove(&file_key);
+            dict.insert(key.to_owned(), value);
+        }
+
+        Ok(())
+    }
+}
+
+impl<P: Provider> Provider for FileValues<P> {
+    fn metadata(&self) -> Metadata {
+        self.provider.metadata()
+    }
+
+    fn data(&self) -> Result<Map<Profile, Dict>, Error> {
+        let mut map = self.provider.data()?;
+        if self.allowed.is_empty() {
+            return Ok(map);
+        }
+
+        let source = self.provider.metadata().source;
+        let base = match &source {
+            Some(Source::File(path)) => path.parent(),
+            _ => None,
+        };
+
+        for dict in map.values_mut() {
+            self.resolve(dict, base, &mut vec![])?;
+        }
+
+        Ok(map)
+    }
+
+    fn profile(&self) -> Option<Profile> {
+        self.provider.profile()
+    }
+}
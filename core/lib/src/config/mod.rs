@@ -40,6 +40,38 @@
 //! property of `Rocket.toml` will be considered - all other `workers` values
 //! are ignored.
 //!
+//! ## Secrets From Files
+//!
+//! The `secret_key` parameter and database URLs, `databases.*.url`, can be
+//! read from a file by setting the parameter name suffixed with `_file` to the
+//! file's path, following the `*_FILE` convention used by Docker and Kubernetes
+//! to mount secrets. For example, the following sets `secret_key` to the
+//! contents of `/run/secrets/rocket_key`:
+//!
+//! ```sh
+//! ROCKET_SECRET_KEY_FILE=/run/secrets/rocket_key
+//! ```
+//!
+//! This applies to `Rocket.toml`, where relative paths are resolved against the
+//! directory containing the file, and `ROCKET_` environment variables as read
+//! by [`Config::figment()`]. Other keys, including application keys ending in
+//! `_file`, are left untouched. Application keys can opt-in by using the
+//! figment returned by [`Config::figment_with_file_keys()`] in place of the
+//! default:
+//!
+//! ```rust
+//! # #[macro_use] extern crate rocket;
+//! use rocket::Config;
+//!
+//! #[launch]
+//! fn rocket() -> _ {
+//!     // `ROCKET_MY_APP_PASSWORD_FILE` reads `my_app_password` from a file.
+//!     rocket::custom(Config::figment_with_file_keys(["my_app_password"]))
+//! }
+//! ```
+//!
+//! Custom providers can opt-in via [`FileValues`].
+//!
 //! ## Custom Providers
 //!
 //! A custom provider can be set via [`rocket::custom()`], which replaces calls to
@@ -114,13 +146,21 @@
 mod ident;
 mod config;
 mod cli_colors;
+mod trailing_slash;
+mod error_format;
+mod serde_limits;
 mod http_header;
+mod file_values;
 #[cfg(test)]
 mod tests;
 
 pub use ident::Ident;
 pub use config::Config;
 pub use cli_colors::CliColors;
+pub use trailing_slash::TrailingSlash;
+pub use error_format::ErrorFormat;
+pub use serde_limits::{SerdeLimits, SerdeLimitError};
+pub use file_values::FileValues;
 
 pub use crate::log::LogLevel;
 pub use crate::shutdown::ShutdownConfig;
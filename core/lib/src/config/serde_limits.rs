@@ -0,0 +1,295 @@
+use std::{fmt, error, io};
+
+use serde::{Deserialize, Serialize};
+
+use crate::http::Status;
+
+/// Structural limits on data deserialized by the [`Json`] and [`MsgPack`] data
+/// guards.
+///
+/// [`Limits`](crate::data::Limits) bound the _size_ of incoming data. These
+/// limits additionally bound its _structure_, defending against documents
+/// which are small but expensive to deserialize, such as deeply nested arrays.
+/// Incoming data is checked against the limits before it is deserialized:
+///
+///   * `depth` - the maximum nesting depth of arrays and objects (maps). Data
+///     exceeding the limit is rejected with a `400 Bad Request` error.
+///
+///     **default: `128`**
+///
+///   * `elements` - the maximum number of elements in any single array or of
+///     members in any single object (map). Data exceeding the limit is
+///     rejected with a `413 Payload Too Large` error.
+///
+///     **default: `0`**
+///
+/// A limit of `0` disables the respective check. Note that `serde_json` also
+/// enforces a nesting depth of `128` of its own, irrespective of `depth`.
+///
+/// [`Json`]: crate::serde::json::Json
+/// [`MsgPack`]: crate::serde::msgpack::MsgPack
+///
+/// # Example
+///
+/// To restrict nesting to 16 levels and arrays and objects to 1000 elements,
+/// you might add the following to your `Rocket.toml`:
+///
+/// ```toml
+/// [default.serde_limits]
+/// depth = 16
+/// elements = 1000
+/// ```
+///
+/// Or, programmatically:
+///
+/// ```rust
+/// use rocket::config::{Config, SerdeLimits};
+///
+/// let config = Config {
+///     serde_limits: SerdeLimits { depth: 16, elements: 1000 },
+///     ..Config::default()
+/// };
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct SerdeLimits {
+    /// The maximum nesting depth, or `0` for no limit.
+    pub depth: usize,
+    /// The maximum number of elements in an array or object, or `0` for no
+    /// limit.
+    pub elements: usize,
+}
+
+/// Error returned when incoming data exceeds a configured [`SerdeLimits`].
+///
+/// The [`Json`](crate::serde::json::Json) and
+/// [`MsgPack`](crate::serde::msgpack::MsgPack) data guards wrap this error in
+/// an [`io::Error`] of kind [`io::ErrorKind::InvalidData`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SerdeLimitError {
+    /// The nesting depth exceeded the contained limit.
+    Depth(usize),
+    /// The number of elements in an array or object exceeded the contained
+    /// limit.
+    Elements(usize),
+}
+
+impl Default for SerdeLimits {
+    fn default() -> Self {
+        SerdeLimits { depth: 128, elements: 0 }
+    }
+}
+
+impl fmt::Display for SerdeLimits {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.depth {
+            0 => write!(f, "depth = unlimited, ")?,
+            n => write!(f, "depth = {}, ", n)?,
+        }
+
+        match self.elements {
+            0 => write!(f, "elements = unlimited"),
+            n => write!(f, "elements = {}", n),
+        }
+    }
+}
+
+impl SerdeLimits {
+    #[cfg(any(feature = "json", feature = "msgpack"))]
+    fn is_unlimited(&self) -> bool {
+        self.depth == 0 && self.elements == 0
+    }
+
+    #[cfg(any(feature = "json", feature = "msgpack"))]
+    fn check_depth(&self, depth: usize) -> Result<(), SerdeLimitError> {
+        match self.depth {
+            limit if limit != 0 && depth > limit => Err(SerdeLimitError::Depth(limit)),
+            _ => Ok(())
+        }
+    }
+
+    #[cfg(any(feature = "json", feature = "msgpack"))]
+    fn check_elements(&self, elements: u64) -> Result<(), SerdeLimitError> {
+        match self.elements {
+            limit if limit != 0 && elements > limit as u64 => Err(SerdeLimitError::Elements(limit)),
+            _ => Ok(())
+        }
+    }
+
+    /// Checks the JSON document `bytes` against `self`. Malformed documents
+    /// are not rejected: they are left for the deserializer to report.
+    #[cfg(feature = "json")]
+    pub(crate) fn check_json(&self, bytes: &[u8]) -> Result<(), SerdeLimitError> {
+        if self.is_unlimited() {
+            return Ok(());
+        }
+
+        // The number of `,` seen so far in each open array or object.
+        let mut commas: Vec<u64> = vec![];
+        let (mut in_string, mut escaped) = (false, false);
+        for &byte in bytes {
+            if in_string {
+                match byte {
+                    _ if escaped => escaped = false,
+                    b'\\' => escaped = true,
+                    b'"' => in_string = false,
+                    _ => {}
+                }
+
+                continue;
+            }
+
+            match byte {
+                b'"' => in_string = true,
+                b'[' | b'{' => {
+                    commas.push(0);
+                    self.check_depth(commas.len())?;
+                }
+                b']' | b'}' => { commas.pop(); },
+                b',' => if let Some(n) = commas.last_mut() {
+                    *n += 1;
+                    self.check_elements(*n + 1)?;
+                },
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Checks the MessagePack value at the start of `bytes` against `self`.
+    /// Malformed data is not rejected: it is left for the deserializer to
+    /// report.
+    #[cfg(feature = "msgpack")]
+    pub(crate) fn check_msgpack(&self, bytes: &[u8]) -> Result<(), SerdeLimitError> {
+        fn be(bytes: &[u8], at: usize, n: usize) -> Option<u64> {
+            let slice = bytes.get(at..at.checked_add(n)?)?;
+            Some(slice.iter().fold(0, |acc, &b| acc << 8 | b as u64))
+        }
+
+        if self.is_unlimited() {
+            return Ok(());
+        }
+
+        // The number of items remaining in each open array or map.
+        let mut remaining: Vec<u64> = vec![];
+        let mut i = 0;
+        while let Some(&marker) = bytes.get(i) {
+            i += 1;
+
+            // Either (a) the number of elements in an array or map and the
+            // number of items they contain or (b) the size of a scalar.
+            let (container, size): (Option<(u64, u64)>, Option<u64>) = match marker {
+                0x00..=0x7f | 0xe0..=0xff | 0xc0..=0xc3 => (None, Some(0)),
+                0x80..=0x8f => (Some(((marker & 0x0f) as u64, 2 * (marker & 0x0f) as u64)), None),
+                0x90..=0x9f => (Some(((marker & 0x0f) as u64, (marker & 0x0f) as u64)), None),
+                0xa0..=0xbf => (None, Some((marker & 0x1f) as u64)),
+                0xc4 | 0xd9 => (None, be(bytes, i, 1).map(|n| n + 1)),
+                0xc5 | 0xda => (None, be(bytes, i, 2).map(|n| n + 2)),
+                0xc6 | 0xdb => (None, be(bytes, i, 4).map(|n| n + 4)),
+                0xc7 => (None, be(bytes, i, 1).map(|n| n + 2)),
+                0xc8 => (None, be(bytes, i, 2).map(|n| n + 3)),
+                0xc9 => (None, be(bytes, i, 4).map(|n| n + 5)),
+                0xcc | 0xd0 => (None, Some(1)),
+                0xcd | 0xd1 | 0xd4 => (None, Some(2)),
+                0xd5 => (None, Some(3)),
+                0xca | 0xce | 0xd2 => (None, Some(4)),
+                0xd6 => (None, Some(5)),
+                0xcb | 0xcf | 0xd3 => (None, Some(8)),
+                0xd7 => (None, Some(9)),
+                0xd8 => (None, Some(17)),
+                0xdc => (be(bytes, i, 2).map(|n| (n, n)), Some(2)),
+                0xdd => (be(bytes, i, 4).map(|n| (n, n)), Some(4)),
+                0xde => (be(bytes, i, 2).map(|n| (n, 2 * n)), Some(2)),
+                0xdf => (be(bytes, i, 4).map(|n| (n, 2 * n)), Some(4)),
+            };
+
+            match (container, size) {
+                (Some((elements, items)), size) => {
+                    i += size.unwrap_or(0) as usize;
+                    self.check_elements(elements)?;
+                    self.check_depth(remaining.len() + 1)?;
+                    if items != 0 {
+                        remaining.push(items);
+                        continue;
+                    }
+                }
+                (None, Some(size)) => match i.checked_add(size as usize) {
+                    Some(end) if end <= bytes.len() => i = end,
+                    _ => break,
+                },
+                (None, None) => break,
+            }
+
+            // A complete value was read: it closes every container for which
+            // it was the final item.
+            while let Some(n) = remaining.last_mut() {
+                *n -= 1;
+                if *n != 0 { break; }
+                remaining.pop();
+            }
+
+            if remaining.is_empty() {
+                break;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl SerdeLimitError {
+    /// Returns the `SerdeLimitError` wrapped in `error`, if any.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::io;
+    /// use rocket::config::SerdeLimitError;
+    ///
+    /// let error = io::Error::new(io::ErrorKind::InvalidData, SerdeLimitError::Depth(16));
+    /// assert_eq!(SerdeLimitError::from_io(&error), Some(&SerdeLimitError::Depth(16)));
+    ///
+    /// let error = io::Error::new(io::ErrorKind::InvalidData, "bad data");
+    /// assert_eq!(SerdeLimitError::from_io(&error), None);
+    /// ```
+    pub fn from_io(error: &io::Error) -> Option<&SerdeLimitError> {
+        error.get_ref().and_then(|e| e.downcast_ref())
+    }
+
+    /// The status with which requests exceeding the limit are rejected:
+    /// `400 Bad Request` for [`SerdeLimitError::Depth`] and `413 Payload Too
+    /// Large` for [`SerdeLimitError::Elements`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::http::Status;
+    /// use rocket::config::SerdeLimitError;
+    ///
+    /// assert_eq!(SerdeLimitError::Depth(16).status(), Status::BadRequest);
+    /// assert_eq!(SerdeLimitError::Elements(100).status(), Status::PayloadTooLarge);
+    /// ```
+    pub fn status(&self) -> Status {
+        match self {
+            SerdeLimitError::Depth(_) => Status::BadRequest,
+            SerdeLimitError::Elements(_) => Status::PayloadTooLarge,
+        }
+    }
+}
+
+impl From<SerdeLimitError> for io::Error {
+    fn from(e: SerdeLimitError) -> Self {
+        io::Error::new(io::ErrorKind::InvalidData, e)
+    }
+}
+
+impl fmt::Display for SerdeLimitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Depth(n) => write!(f, "nesting depth exceeds limit of {}", n),
+            Self::Elements(n) => write!(f, "number of elements exceeds limit of {}", n),
+        }
+    }
+}
+
+impl error::Error for SerdeLimitError { }
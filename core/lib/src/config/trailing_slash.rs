@@ -0,0 +1,83 @@
+use std::fmt;
+
+use serde::{de, Deserialize, Serialize};
+
+/// How requests with and without a trailing slash in their path are routed.
+///
+/// Valid configuration values are:
+///
+///   * `"strict"` - [`TrailingSlash::Strict`] _(default)_
+///   * `"redirect"` - [`TrailingSlash::Redirect`]
+///   * `"ignore"` - [`TrailingSlash::Ignore`]
+///
+/// # Example
+///
+/// ```rust
+/// use rocket::config::{Config, TrailingSlash};
+///
+/// let config = Config {
+///     trailing_slash: TrailingSlash::Redirect,
+///     ..Config::default()
+/// };
+/// ```
+#[derive(Debug, Copy, Clone, Default, Serialize, PartialEq, Eq, Hash)]
+pub enum TrailingSlash {
+    /// Paths with and without a trailing slash are distinct: a request to
+    /// `/foo/` does not match a route for `/foo` and vice versa.
+    ///
+    /// Case-insensitive string values of `"strict"` parse as this value.
+    #[default]
+    Strict,
+
+    /// Like [`TrailingSlash::Strict`], but a request whose path ends in a
+    /// slash that would otherwise fail with a `404 Not Found` is instead
+    /// redirected with a `301 Moved Permanently` to the same path without
+    /// trailing slashes, preserving the query. That is, if no route handles
+    /// `/foo/?a=b`, the response redirects to `/foo?a=b`.
+    ///
+    /// Case-insensitive string values of `"redirect"` parse as this value.
+    Redirect,
+
+    /// Paths with and without a trailing slash are equivalent when routing: a
+    /// request to `/foo/` matches a route for `/foo` and vice versa. Routes
+    /// whose path ends with a trailing parameter, like `/foo/<path..>`, are
+    /// unaffected.
+    ///
+    /// Case-insensitive string values of `"ignore"` parse as this value.
+    Ignore,
+}
+
+impl fmt::Display for TrailingSlash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TrailingSlash::Strict => write!(f, "strict"),
+            TrailingSlash::Redirect => write!(f, "redirect"),
+            TrailingSlash::Ignore => write!(f, "ignore"),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for TrailingSlash {
+    fn deserialize<D: de::Deserializer<'de>>(de: D) -> Result<Self, D::Error> {
+        struct Visitor;
+
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = TrailingSlash;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("strict, redirect, or ignore")
+            }
+
+            fn visit_str<E: de::Error>(self, val: &str) -> Result<TrailingSlash, E> {
+                match val.to_lowercase().as_str() {
+                    "strict" => Ok(TrailingSlash::Strict),
+                    "redirect" => Ok(TrailingSlash::Redirect),
+                    "ignore" => Ok(TrailingSlash::Ignore),
+                    _ => Err(E::invalid_value(de::Unexpected::Str(val), &self)),
+                }
+            }
+        }
+
+        de.deserialize_str(Visitor)
+    }
+}
@@ -244,7 +244,8 @@ macro_rules! impl_strict_from_form_field_from_capped {
 }
 
 macro_rules! impl_strict_from_data_from_capped {
-    ($T:ty) => (
+    ($T:ty) => (impl_strict_from_data_from_capped!($T, Status::BadRequest););
+    ($T:ty, $limit_status:expr) => (
         #[crate::async_trait]
         impl<'r> $crate::data::FromData<'r> for $T {
             type Error = <$crate::data::Capped<Self> as $crate::data::FromData<'r>>::Error;
@@ -260,7 +261,7 @@ macro_rules! impl_strict_from_data_from_capped {
                     Success(p) if p.is_complete() => Success(p.into_inner()),
                     Success(_) => {
                         let e = Error::new(UnexpectedEof, "data limit exceeded");
-                        Error((Status::BadRequest, e.into()))
+                        Error(($limit_status, e.into()))
                     },
                     Forward(d) => Forward(d),
                     Error((s, e)) => Error((s, e)),
@@ -1,7 +1,14 @@
 use std::io;
 use std::pin::Pin;
 
-use crate::data::ByteUnit;
+use tokio::io::AsyncWrite;
+#[cfg(feature = "decompression")] use std::io::Cursor;
+#[cfg(feature = "decompression")] use tokio::io::AsyncRead;
+#[cfg(feature = "decompression")] use tokio_util::io::ReaderStream;
+#[cfg(feature = "decompression")] use crate::util::Chain;
+
+use crate::data::{ByteUnit, Bytes, Capped, N};
+use crate::fs::TempFile;
 use crate::data::data_stream::{DataStream, RawReader, RawStream};
 use crate::data::peekable::Peekable;
 use crate::data::transform::{Transform, TransformBuf, Inspect, InPlaceMap};
@@ -84,6 +91,80 @@ impl<'r> Data<'r> {
         DataStream::new(self.transforms, self.stream, limit.into())
     }
 
+    /// Streams at most `limit` bytes of the body data into a new temporary
+    /// file in the system's temporary directory, returning the resulting
+    /// [`TempFile`].
+    ///
+    /// This is a convenience for simple upload handlers which would otherwise
+    /// have to create a file and stream to it via [`Data::open()`]. If an I/O
+    /// error occurs, the temporary file is deleted and the error is returned.
+    /// Reaching `limit` is _not_ an error; use [`Capped::is_complete()`] to
+    /// determine whether all of the body data was written.
+    ///
+    /// To stream into the configured [`temp_dir`](crate::Config::temp_dir)
+    /// with limits determined by the content-type of the request, use the
+    /// `Capped<TempFile>` data guard instead.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate rocket;
+    /// use std::io;
+    /// use rocket::data::{Data, ToByteUnit};
+    ///
+    /// #[post("/upload", data = "<data>")]
+    /// async fn upload(data: Data<'_>) -> io::Result<String> {
+    ///     let mut file = data.into_temp_file(2.mebibytes()).await?;
+    ///     if !file.is_complete() {
+    ///         return Err(io::Error::new(io::ErrorKind::Other, "upload too large"));
+    ///     }
+    ///
+    ///     file.persist_to("/tmp/upload").await?;
+    ///     Ok(format!("uploaded {} bytes", file.len()))
+    /// }
+    /// ```
+    ///
+    /// [`TempFile`]: crate::fs::TempFile
+    pub async fn into_temp_file(self, limit: ByteUnit) -> io::Result<Capped<TempFile<'static>>> {
+        TempFile::stream_from(self, limit, std::env::temp_dir(), None, None).await
+    }
+
+    /// Streams at most `limit` bytes of the body data into `writer`, returning
+    /// an [`N`] which indicates how many bytes were written and whether all of
+    /// the body data was written. The writer is flushed once streaming
+    /// completes.
+    ///
+    /// This is a shorthand for `data.open(limit).stream_to(writer)` and allows
+    /// piping uploads directly to an arbitrary sink, such as a network upload,
+    /// without buffering them in memory or on disk. Reaching `limit` is _not_
+    /// an error; check [`N::complete`] to determine whether all of the body
+    /// data was written.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate rocket;
+    /// use std::io;
+    /// use rocket::data::{Data, ToByteUnit};
+    ///
+    /// #[post("/upload", data = "<data>")]
+    /// async fn upload(data: Data<'_>) -> io::Result<String> {
+    ///     // Any `AsyncWrite` sink works: a socket, a compressor, an uploader.
+    ///     let sink = rocket::tokio::io::sink();
+    ///     let n = data.stream_to_writer(sink, 10.mebibytes()).await?;
+    ///     if !n.complete {
+    ///         return Err(io::Error::new(io::ErrorKind::Other, "upload too large"));
+    ///     }
+    ///
+    ///     Ok(format!("uploaded {} bytes", n.written))
+    /// }
+    /// ```
+    pub async fn stream_to_writer<W>(self, writer: W, limit: ByteUnit) -> io::Result<N>
+        where W: AsyncWrite + Unpin
+    {
+        self.open(limit).stream_to(writer).await
+    }
+
     /// Fills the peek buffer with body data until it contains at least `num`
     /// bytes (capped to 512), or the complete body data, whichever is less, and
     /// returns it. If the buffer already contains either at least `num` bytes
@@ -162,6 +243,45 @@ impl<'r> Data<'r> {
         self.stream.peek(num).await
     }
 
+    /// Reads at most `limit` bytes of the body data into memory and returns a
+    /// copy of them _without_ consuming the data: the body can be read again,
+    /// in full, from `self`. Use [`Capped::is_complete()`] to determine
+    /// whether the returned bytes are the complete body data.
+    ///
+    /// Unlike [`Data::peek()`], the amount of data buffered is bounded only by
+    /// `limit`. This allows middleware, such as a request fairing which logs
+    /// requests or verifies request signatures, to inspect the raw body while
+    /// the handler parses it via its usual data guard. Transforms chained via
+    /// [`Data::chain_transform()`] are _not_ applied to the returned bytes.
+    ///
+    /// Subsequent calls only perform I/O when they request more data than has
+    /// already been buffered. To make the buffered bytes available to request
+    /// guards, use [`Tee::buffer()`](crate::data::Tee::buffer()).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::data::ToByteUnit;
+    /// use rocket::fairing::AdHoc;
+    ///
+    /// let fairing = AdHoc::on_request("Body Logger", |req, data| Box::pin(async move {
+    ///     match data.tee(64.kibibytes()).await {
+    ///         Ok(body) if body.is_complete() => println!("body: {:?}", body.value),
+    ///         Ok(body) => println!("body is larger than {} bytes", body.len()),
+    ///         Err(e) => println!("failed to read body: {}", e),
+    ///     }
+    /// }));
+    /// ```
+    pub async fn tee(&mut self, limit: ByteUnit) -> io::Result<Capped<Bytes>> {
+        let limit = usize::try_from(limit.as_u64()).unwrap_or(usize::MAX);
+        self.stream.fill(limit).await?;
+
+        let buffer = &self.stream.buffer;
+        let len = std::cmp::min(buffer.len(), limit);
+        let n = N { written: len as u64, complete: self.stream.complete && buffer.len() <= limit };
+        Ok(Capped::new(Bytes::copy_from_slice(&buffer[..len]), n))
+    }
+
     /// Returns true if the `peek` buffer contains all of the data in the body
     /// of the request. Returns `false` if it does not or it is not known.
     ///
@@ -181,6 +301,22 @@ impl<'r> Data<'r> {
         self.stream.complete
     }
 
+    /// Replaces the raw body data with that read from the reader returned by
+    /// `decoder`, which is passed a reader of all of the current raw body
+    /// data, including any that has been peeked. Limits and transforms apply
+    /// to the decoded data.
+    #[cfg(feature = "decompression")]
+    pub(crate) fn decode_with<F, R>(&mut self, decoder: F)
+        where F: FnOnce(Chain<Cursor<Vec<u8>>, RawReader<'r>>) -> R,
+              R: AsyncRead + Send + Sync + 'r
+    {
+        let empty = Peekable::new(RawReader::new(RawStream::Empty));
+        let Peekable { buffer, reader, .. } = std::mem::replace(&mut self.stream, empty);
+        let decoded = decoder(Chain::new(Cursor::new(buffer), reader));
+        let stream = RawStream::Decoded(Box::pin(ReaderStream::new(decoded)));
+        self.stream = Peekable::new(RawReader::new(stream));
+    }
+
     /// Chains the [`Transform`] `transform` to `self`.
     ///
     /// Note that transforms do nothing until the data is
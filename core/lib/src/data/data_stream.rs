@@ -72,6 +72,8 @@ pub enum RawStream<'r> {
     #[cfg(feature = "http3-preview")]
     H3Body(crate::listener::Cancellable<crate::listener::quic::QuicRx>),
     Multipart(multer::Field<'r>),
+    #[cfg(feature = "decompression")]
+    Decoded(Pin<Box<dyn Stream<Item = io::Result<Bytes>> + Send + Sync + 'r>>),
 }
 
 impl<'r> TransformReader<'r> {
@@ -349,6 +351,8 @@ impl Stream for RawStream<'_> {
             #[cfg(feature = "http3-preview")]
             RawStream::H3Body(stream) => Pin::new(stream).poll_next(cx),
             RawStream::Multipart(s) => Pin::new(s).poll_next(cx).map_err(io::Error::other),
+            #[cfg(feature = "decompression")]
+            RawStream::Decoded(s) => s.as_mut().poll_next(cx),
             RawStream::Empty => Poll::Ready(None),
         }
     }
@@ -363,6 +367,8 @@ impl Stream for RawStream<'_> {
             #[cfg(feature = "http3-preview")]
             RawStream::H3Body(_) => (0, Some(0)),
             RawStream::Multipart(mp) => mp.size_hint(),
+            #[cfg(feature = "decompression")]
+            RawStream::Decoded(s) => (s.size_hint().0, None),
             RawStream::Empty => (0, Some(0)),
         }
     }
@@ -376,6 +382,8 @@ impl std::fmt::Display for RawStream<'_> {
             #[cfg(feature = "http3-preview")]
             RawStream::H3Body(_) => f.write_str("http3 quic stream"),
             RawStream::Multipart(_) => f.write_str("multipart form field"),
+            #[cfg(feature = "decompression")]
+            RawStream::Decoded(_) => f.write_str("decoded request body"),
         }
     }
 }
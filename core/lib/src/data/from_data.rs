@@ -1,6 +1,6 @@
 use crate::http::{RawStr, Status};
 use crate::request::{Request, local_cache};
-use crate::data::{Data, Limits};
+use crate::data::{Data, Limits, Bytes};
 use crate::outcome::{self, IntoOutcome, try_outcome, Outcome::*};
 
 /// Type alias for the `Outcome` of [`FromData`].
@@ -72,6 +72,22 @@ pub type Outcome<'r, T, E = <T as FromData<'r>>::Error>
 ///
 ///     - **Forwards:** Never.
 ///
+///   * [`Bytes`]
+///
+///     _Limited by the `bytes` [data limit]._
+///
+///     Reads the body data into memory via [`DataStream::into_bytes()`].
+///
+///     - **Fails:** On I/O errors while reading or if the limit is exceeded.
+///     When the limit is exceeded, the error status is `413 Payload Too
+///     Large`. The error type is [`io::Error`].
+///
+///     - **Succeeds:** As long as no I/O error occurs and the limit is not
+///     exceeded. Use `Capped<Bytes>` to instead receive data truncated to the
+///     limit.
+///
+///     - **Forwards:** Never.
+///
 ///   * [`TempFile`](crate::fs::TempFile)
 ///
 ///     _Limited by the `file` and/or `file/$ext` [data limit]._
@@ -391,6 +407,18 @@ impl<'r> FromData<'r> for Capped<Vec<u8>> {
 
 impl_strict_from_data_from_capped!(Vec<u8>);
 
+#[crate::async_trait]
+impl<'r> FromData<'r> for Capped<Bytes> {
+    type Error = std::io::Error;
+
+    async fn from_data(req: &'r Request<'_>, data: Data<'r>) -> Outcome<'r, Self> {
+        let capped = try_outcome!(<Capped<Vec<u8>>>::from_data(req, data).await);
+        Success(capped.map(Bytes::from))
+    }
+}
+
+impl_strict_from_data_from_capped!(Bytes, Status::PayloadTooLarge);
+
 #[crate::async_trait]
 impl<'r> FromData<'r> for Data<'r> {
     type Error = std::convert::Infallible;
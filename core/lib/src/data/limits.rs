@@ -303,6 +303,19 @@ impl Limits {
         None
     }
 
+    /// Returns a `Limits` with every limit in `self` and in
+    /// [`Limits::default()`] set to `limit`.
+    pub(crate) fn replaced_with(&self, limit: ByteUnit) -> Limits {
+        Limits::default().limits.into_iter()
+            .chain(self.limits.iter().cloned())
+            .fold(Limits::new(), |limits, (name, _)| limits.limit(name, limit))
+    }
+
+    /// Returns the largest limit in `self`, if any.
+    pub(crate) fn max(&self) -> Option<ByteUnit> {
+        self.limits.iter().map(|(_, limit)| *limit).max()
+    }
+
     /// Deserialize a `Limits` vector from a map. Ensures that the resulting
     /// vector is properly sorted for futures lookups via binary search.
     fn deserialize<'de, D>(de: D) -> Result<Vec<(Uncased<'static>, ByteUnit)>, D::Error>
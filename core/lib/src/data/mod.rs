@@ -9,6 +9,7 @@ mod limits;
 mod io_stream;
 mod transform;
 mod peekable;
+mod tee;
 
 pub use self::data::Data;
 pub use self::data_stream::DataStream;
@@ -16,7 +17,13 @@ pub use self::from_data::{FromData, Outcome};
 pub use self::limits::Limits;
 pub use self::capped::{N, Capped};
 pub use self::io_stream::{IoHandler, IoStream};
+pub use self::tee::Tee;
 pub use ubyte::{ByteUnit, ToByteUnit};
+
+/// A cheaply cloneable, contiguous buffer of bytes. Re-exported from the
+/// [`bytes`](https://docs.rs/bytes) crate.
+#[doc(inline)]
+pub use bytes::Bytes;
 pub use self::transform::{Transform, TransformBuf};
 
 pub(crate) use self::data_stream::RawStream;
@@ -45,4 +45,18 @@ impl<const N: usize, R: AsyncRead + Unpin> Peekable<N, R> {
 
         self.buffer.as_slice()
     }
+
+    /// Reads into the buffer, regardless of `N`, until it contains more than
+    /// `num` bytes or the complete data.
+    pub async fn fill(&mut self, num: usize) -> std::io::Result<()> {
+        while !self.complete && self.buffer.len() <= num {
+            // Reserving guarantees spare capacity, so `0` can only mean EOF.
+            self.buffer.reserve(N);
+            if self.reader.read_buf::<Vec<u8>>(&mut self.buffer).await? == 0 {
+                self.complete = true;
+            }
+        }
+
+        Ok(())
+    }
 }
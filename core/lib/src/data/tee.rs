@@ -0,0 +1,181 @@
+use crate::request::{FromRequest, Outcome, Request};
+use crate::data::{ByteUnit, Bytes, Capped, Data};
+use crate::http::Status;
+
+/// A copy of the raw body data of a request, buffered before routing.
+///
+/// Typically, the body of a request can only be read once: by the data guard
+/// of the handler which serves it. A `Tee` allows middleware, such as a
+/// request fairing which logs requests or verifies request signatures, to read
+/// the raw body data _as well_. The handler still reads the body as usual.
+///
+/// A `Tee` is created in a request fairing via [`Tee::buffer()`], which reads
+/// the body up to a limit via [`Data::tee()`] and stores the result in
+/// [request-local cache](Request::local_cache()). Once buffered, `&Tee` can be
+/// used as a request guard to retrieve it.
+///
+/// # Request Guard
+///
+/// The `&Tee` request guard succeeds if the body was buffered with
+/// [`Tee::buffer()`]. Otherwise, it fails with a status of `500`: this is an
+/// application error, typically a missing fairing.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use rocket::data::{Tee, ToByteUnit};
+/// use rocket::fairing::AdHoc;
+///
+/// #[post("/", data = "<body>")]
+/// fn hook(tee: &Tee, body: String) -> String {
+///     assert_eq!(tee.as_bytes(), body.as_bytes());
+///     body
+/// }
+///
+/// #[launch]
+/// fn rocket() -> _ {
+///     rocket::build()
+///         .mount("/", routes![hook])
+///         .attach(AdHoc::on_request("Tee", |req, data| Box::pin(async move {
+///             if let Some(tee) = Tee::buffer(req, data, 1.mebibytes()).await {
+///                 println!("received {} bytes", tee.len());
+///             }
+///         })))
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Tee(Capped<Bytes>);
+
+impl Tee {
+    /// Buffers at most `limit` bytes of `data` via [`Data::tee()`] and stores
+    /// the result in `req`'s request-local cache, returning it. If `data` was
+    /// already buffered for `req`, the previously stored value is returned
+    /// without reading any data.
+    ///
+    /// Returns `None` if reading the body failed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::data::{Tee, ToByteUnit};
+    /// use rocket::fairing::AdHoc;
+    ///
+    /// let fairing = AdHoc::on_request("Tee", |req, data| Box::pin(async move {
+    ///     if let Some(tee) = Tee::buffer(req, data, 1.mebibytes()).await {
+    ///         if !tee.is_complete() {
+    ///             println!("body is larger than 1MiB");
+    ///         }
+    ///     }
+    /// }));
+    /// ```
+    pub async fn buffer<'r>(
+        req: &'r Request<'_>,
+        data: &mut Data<'_>,
+        limit: ByteUnit,
+    ) -> Option<&'r Tee> {
+        let tee = req.local_cache_async(async {
+            match data.tee(limit).await {
+                Ok(body) => Some(Tee(body)),
+                Err(e) => {
+                    error_!("Failed to buffer request body: {}.", e);
+                    None
+                }
+            }
+        });
+
+        tee.await.as_ref()
+    }
+
+    /// Returns the buffered body data.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::data::Tee;
+    ///
+    /// fn log(tee: &Tee) {
+    ///     println!("body: {:?}", tee.as_bytes());
+    /// }
+    /// ```
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0.value
+    }
+
+    /// Returns a cheap clone of the buffered body data.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::data::{Bytes, Tee};
+    ///
+    /// fn keep(tee: &Tee) -> Bytes {
+    ///     tee.bytes()
+    /// }
+    /// ```
+    pub fn bytes(&self) -> Bytes {
+        self.0.value.clone()
+    }
+
+    /// Returns the number of bytes buffered.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::data::Tee;
+    ///
+    /// fn is_small(tee: &Tee) -> bool {
+    ///     tee.len() < 512
+    /// }
+    /// ```
+    pub fn len(&self) -> usize {
+        self.0.value.len()
+    }
+
+    /// Returns `true` if no bytes were buffered.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::data::Tee;
+    ///
+    /// fn has_body(tee: &Tee) -> bool {
+    ///     !tee.is_empty()
+    /// }
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.0.value.is_empty()
+    }
+
+    /// Returns `true` if the buffered bytes are the complete body data, that
+    /// is, the body was no larger than the limit it was buffered with.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::data::Tee;
+    ///
+    /// fn can_verify(tee: &Tee) -> bool {
+    ///     tee.is_complete()
+    /// }
+    /// ```
+    pub fn is_complete(&self) -> bool {
+        self.0.is_complete()
+    }
+}
+
+#[crate::async_trait]
+impl<'r> FromRequest<'r> for &'r Tee {
+    type Error = ();
+
+    async fn from_request(req: &'r Request<'_>) -> Outcome<Self, ()> {
+        match req.local_cache(|| None::<Tee>) {
+            Some(tee) => Outcome::Success(tee),
+            None => {
+                error_!("The request body was not buffered with `Tee::buffer()`.");
+                info_!("Ensure a request fairing calls `Tee::buffer()` for this request.");
+                Outcome::Error((Status::InternalServerError, ()))
+            }
+        }
+    }
+}
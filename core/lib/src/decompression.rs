@@ -0,0 +1,171 @@
+//! Transparent decompression of request bodies via `Content-Encoding`.
+//!
+//! The [`Decompression`] fairing decompresses the body of every request whose
+//! `Content-Encoding` header names one or more supported encodings before any
+//! data guard reads it. The `Content-Encoding` and `Content-Length` headers,
+//! which describe the compressed body, are removed from such requests. As a
+//! result, handlers and data guards are oblivious to the compression.
+//!
+//! The supported encodings are:
+//!
+//!   * `gzip` (or `x-gzip`)
+//!   * `deflate`, the zlib format
+//!   * `identity`, the absence of an encoding
+//!
+//! A request with any other encoding is rejected, before it is routed, with a
+//! `415 Unsupported Media Type` response generated by the `415` error catcher.
+//! As suggested by [RFC 7694], the response includes an `Accept-Encoding`
+//! header listing the supported encodings.
+//!
+//! [RFC 7694]: https://www.rfc-editor.org/rfc/rfc7694#section-3
+//!
+//! # Limits
+//!
+//! Data [limits](crate::data::Limits) apply to the _decompressed_ body data:
+//! decompression proceeds lazily, as data is read, and stops as soon as a data
+//! guard's limit is reached. A small compressed body that decompresses to an
+//! enormous one (a "zip bomb") is thus never decompressed beyond the limit.
+//!
+//! # Errors
+//!
+//! A body which fails to decompress results in an I/O error when the body is
+//! read, which data guards typically report as a `400 Bad Request` or `500
+//! Internal Server Error`.
+//!
+//! # Usage
+//!
+//! Enable the `decompression` feature and attach an instance of
+//! [`Decompression`]:
+//!
+//! ```rust
+//! # #[macro_use] extern crate rocket;
+//! use rocket::decompression::Decompression;
+//!
+//! #[post("/", data = "<body>")]
+//! fn index(body: String) -> String {
+//!     body
+//! }
+//!
+//! #[launch]
+//! fn rocket() -> _ {
+//!     rocket::build()
+//!         .mount("/", routes![index])
+//!         .attach(Decompression::new())
+//! }
+//! ```
+
+use async_compression::tokio::bufread::{GzipDecoder, ZlibDecoder};
+use tokio::io::BufReader;
+
+use crate::{Request, Response, Data};
+use crate::fairing::{Fairing, Info, Kind};
+use crate::http::Status;
+
+/// Fairing which decompresses request bodies.
+///
+/// See the [module level docs](crate::decompression) for details.
+#[derive(Debug, Default, Clone)]
+pub struct Decompression {
+    _priv: (),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Encoding {
+    Gzip,
+    Deflate,
+    Identity,
+}
+
+impl Encoding {
+    fn parse(name: &str) -> Option<Encoding> {
+        let name = name.trim();
+        if name.eq_ignore_ascii_case("gzip") || name.eq_ignore_ascii_case("x-gzip") {
+            Some(Encoding::Gzip)
+        } else if name.eq_ignore_ascii_case("deflate") {
+            Some(Encoding::Deflate)
+        } else if name.eq_ignore_ascii_case("identity") {
+            Some(Encoding::Identity)
+        } else {
+            None
+        }
+    }
+}
+
+impl Decompression {
+    /// The value of the `Accept-Encoding` header sent with responses to
+    /// requests with unsupported encodings.
+    const ACCEPT_ENCODING: &'static str = "gzip, deflate, identity";
+
+    /// Returns a new `Decompression` fairing.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::decompression::Decompression;
+    ///
+    /// let fairing = Decompression::new();
+    /// ```
+    pub fn new() -> Self {
+        Decompression::default()
+    }
+
+    /// Returns the encodings of the request in the order they were applied,
+    /// or `None` if the request has no `Content-Encoding` or uses an
+    /// unsupported encoding.
+    fn encodings(req: &Request<'_>) -> Option<Vec<Encoding>> {
+        let mut encodings = vec![];
+        for value in req.headers().get("Content-Encoding") {
+            for name in value.split(',').filter(|n| !n.trim().is_empty()) {
+                encodings.push(Encoding::parse(name)?);
+            }
+        }
+
+        (!encodings.is_empty()).then_some(encodings)
+    }
+}
+
+#[crate::async_trait]
+impl Fairing for Decompression {
+    fn info(&self) -> Info {
+        Info { name: "Decompression", kind: Kind::Request | Kind::Intercept }
+    }
+
+    async fn on_request(&self, req: &mut Request<'_>, data: &mut Data<'_>) {
+        // Requests with unsupported encodings keep their `Content-Encoding`
+        // and are rejected in `on_intercept`.
+        let Some(encodings) = Self::encodings(req) else {
+            return;
+        };
+
+        // Encodings are listed in the order they were applied: undo in reverse.
+        for encoding in encodings.into_iter().rev() {
+            match encoding {
+                Encoding::Gzip => data.decode_with(|raw| {
+                    let mut decoder = GzipDecoder::new(BufReader::new(raw));
+                    decoder.multiple_members(true);
+                    decoder
+                }),
+                Encoding::Deflate => data.decode_with(|raw| ZlibDecoder::new(BufReader::new(raw))),
+                Encoding::Identity => continue,
+            };
+        }
+
+        req.remove_header("Content-Encoding");
+        req.remove_header("Content-Length");
+    }
+
+    async fn on_intercept<'r>(&self, req: &'r Request<'_>) -> Option<Response<'r>> {
+        // Supported encodings were removed in `on_request`: any left over are
+        // unsupported. An empty header names no encoding at all.
+        let mut names = req.headers().get("Content-Encoding").flat_map(|v| v.split(','));
+        if names.all(|name| name.trim().is_empty()) {
+            return None;
+        }
+
+        info_!("Request body has an unsupported encoding. Responding with 415.");
+        let status = Status::UnsupportedMediaType;
+        let mut response = req.rocket().dispatch_error(status, req).await;
+        response.set_raw_header("Accept-Encoding", Self::ACCEPT_ENCODING);
+        Some(response)
+    }
+}
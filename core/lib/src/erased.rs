@@ -152,6 +152,27 @@ impl ErasedResponse {
 
         io.map(|io| ErasedIoHandler { _request: parent, io })
     }
+
+    /// Takes the trailers currently set on the response, if there are any.
+    /// Invalid trailers are logged and skipped.
+    pub fn take_trailers(&mut self) -> Option<http::HeaderMap> {
+        let trailers = self.inner().trailers()?.take();
+        if trailers.is_empty() {
+            return None;
+        }
+
+        let mut map = http::HeaderMap::with_capacity(trailers.len());
+        for header in trailers.iter() {
+            let name = http::HeaderName::from_bytes(header.name().as_str().as_bytes());
+            let value = http::HeaderValue::from_str(header.value());
+            match (name, value) {
+                (Ok(name), Ok(value)) => { map.append(name, value); },
+                _ => warn_!("Ignoring invalid response trailer: {}", header),
+            }
+        }
+
+        Some(map)
+    }
 }
 
 impl ErasedIoHandler {
@@ -12,9 +12,10 @@ use crate::fairing::{Fairing, Kind, Info, Result};
 ///
 /// # Usage
 ///
-/// Use [`AdHoc::on_ignite`], [`AdHoc::on_liftoff`], [`AdHoc::on_request()`], or
-/// [`AdHoc::on_response()`] to create an `AdHoc` structure from a function or
-/// closure. Then, simply attach the structure to the `Rocket` instance.
+/// Use [`AdHoc::on_ignite`], [`AdHoc::on_liftoff`], [`AdHoc::on_request()`],
+/// [`AdHoc::on_intercept()`], or [`AdHoc::on_response()`] to create an `AdHoc`
+/// structure from a function or closure. Then, simply attach the structure to
+/// the `Rocket` instance.
 ///
 /// # Example
 ///
@@ -62,6 +63,11 @@ enum AdHocKind {
     Request(Box<dyn for<'a> Fn(&'a mut Request<'_>, &'a mut Data<'_>)
         -> BoxFuture<'a, ()> + Send + Sync + 'static>),
 
+    /// An ad-hoc **intercept** fairing. Called just before a request is
+    /// routed; may respond in place of the router.
+    Intercept(Box<dyn for<'r> Fn(&'r Request<'_>)
+        -> BoxFuture<'r, Option<Response<'r>>> + Send + Sync + 'static>),
+
     /// An ad-hoc **response** fairing. Called when a response is ready to be
     /// sent to a client.
     Response(Box<dyn for<'r, 'b> Fn(&'r Request<'_>, &'b mut Response<'r>)
@@ -158,6 +164,34 @@ impl AdHoc {
         AdHoc { name, kind: AdHocKind::Request(Box::new(f)) }
     }
 
+    /// Constructs an `AdHoc` intercept fairing named `name`. The function `f`
+    /// will be called and the returned `Future` will be `await`ed by Rocket
+    /// just before a request is routed. If the `Future` resolves to
+    /// `Some(response)`, the request is answered with `response` instead of
+    /// being routed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::Response;
+    /// use rocket::fairing::AdHoc;
+    /// use rocket::http::Status;
+    ///
+    /// // Respond to all requests to `/admin` with a `403 Forbidden`.
+    /// let fairing = AdHoc::on_intercept("Admin Gate", |req| Box::pin(async move {
+    ///     if !req.uri().path().starts_with("/admin") {
+    ///         return None;
+    ///     }
+    ///
+    ///     Some(Response::build().status(Status::Forbidden).finalize())
+    /// }));
+    /// ```
+    pub fn on_intercept<F: Send + Sync + 'static>(name: &'static str, f: F) -> AdHoc
+        where F: for<'r> Fn(&'r Request<'_>) -> BoxFuture<'r, Option<Response<'r>>>
+    {
+        AdHoc { name, kind: AdHocKind::Intercept(Box::new(f)) }
+    }
+
     // FIXME(rustc): We'd like to allow passing `async fn` to these methods...
     // https://github.com/rust-lang/rust/issues/64552#issuecomment-666084589
 
@@ -209,6 +243,9 @@ impl AdHoc {
     /// type `T` from the configured provider and stores it in managed state. If
     /// extractions fails, pretty-prints the error message and aborts launch.
     ///
+    /// To reload the configuration at runtime, use
+    /// [`ReloadableState`](crate::state::ReloadableState) instead.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -397,6 +434,107 @@ impl AdHoc {
 
         Normalizer::default()
     }
+
+    /// Constructs an `AdHoc` fairing that rewrites `Location` headers to
+    /// account for the public base of an application served behind a reverse
+    /// proxy and enables the [`ProxyBase`] request guard.
+    ///
+    /// When a reverse proxy serves an application under a path prefix, say
+    /// `https://example.com/app`, redirects such as `Redirect::to("/login")`
+    /// point outside of the application. This fairing rewrites every
+    /// `Location` header containing an origin URI, one beginning with a
+    /// single `/`, so that it includes the external base:
+    ///
+    ///   * The path is prefixed with the value of the `X-Forwarded-Prefix`
+    ///     request header if there is one and with `base` otherwise. An empty
+    ///     `base` adds no prefix.
+    ///   * If the request contains an `X-Forwarded-Host` header, the URI is
+    ///     made absolute with that host. The scheme is `https` if the request
+    ///     is [likely in a secure context] and `http` otherwise; configure
+    ///     [`proxy_proto_header`] so that it reflects the proxy's scheme.
+    ///
+    /// A prefix, whether from `base` or the header, that begins with `//` or
+    /// contains a `\`, `?`, `#`, or a control character could redirect clients
+    /// to another host. Such a header is ignored in favor of `base`, while such
+    /// a `base` is replaced with an empty prefix.
+    ///
+    /// Absolute URIs in `Location` headers are left unchanged. URIs in
+    /// response bodies, such as those generated by [`uri!`], are not rewritten
+    /// automatically; resolve them with the [`ProxyBase`] request guard, which
+    /// applies the same rules, instead.
+    ///
+    /// **Note:** The `X-Forwarded-*` headers are set by the client unless
+    /// overwritten by a proxy. Only attach this fairing when Rocket is exclusively
+    /// reachable through a proxy that sets or removes these headers.
+    ///
+    /// [likely in a secure context]: Request::context_is_likely_secure()
+    /// [`proxy_proto_header`]: crate::Config::proxy_proto_header
+    /// [`ProxyBase`]: crate::request::ProxyBase
+    /// [`uri!`]: crate::uri!
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate rocket;
+    /// use rocket::local::blocking::Client;
+    /// use rocket::response::Redirect;
+    /// use rocket::fairing::AdHoc;
+    ///
+    /// #[get("/")]
+    /// fn index() -> Redirect {
+    ///     Redirect::to(uri!("/login"))
+    /// }
+    ///
+    /// #[launch]
+    /// fn rocket() -> _ {
+    ///     rocket::build()
+    ///         .mount("/", routes![index])
+    ///         .attach(AdHoc::proxy_base("/app"))
+    /// }
+    ///
+    /// # let client = Client::debug(rocket()).unwrap();
+    /// let response = client.get("/").dispatch();
+    /// assert_eq!(response.headers().get_one("Location"), Some("/app/login"));
+    ///
+    /// let response = client.get("/")
+    ///     .header(rocket::http::Header::new("X-Forwarded-Host", "example.com"))
+    ///     .dispatch();
+    ///
+    /// let location = response.headers().get_one("Location");
+    /// assert_eq!(location, Some("http://example.com/app/login"));
+    /// ```
+    pub fn proxy_base<B: Into<String>>(base: B) -> AdHoc {
+        use crate::request::{ProxyBase, ConfiguredBase};
+
+        let base = base.into();
+        AdHoc::on_ignite("Proxy Base", |rocket| async move {
+            let base = ProxyBase::normalize(&base).unwrap_or_else(|| {
+                warn!("Ignoring unsafe proxy base {:?}.", base);
+                String::new()
+            });
+
+            let locations = AdHoc::on_response("Proxy Base Locations", |req, res| {
+                Box::pin(async move {
+                    let location = match res.headers().get_one("Location") {
+                        Some(l) if l.starts_with('/') && !l.starts_with("//") => l,
+                        _ => return,
+                    };
+
+                    let base = match req.rocket().state::<ConfiguredBase>() {
+                        Some(base) => ProxyBase::compute(req, &base.0),
+                        None => return,
+                    };
+
+                    let rewritten = base.resolve_str(location);
+                    if rewritten != location {
+                        res.set_raw_header("Location", rewritten);
+                    }
+                })
+            });
+
+            rocket.manage(ConfiguredBase(base)).attach(locations)
+        })
+    }
 }
 
 #[crate::async_trait]
@@ -406,6 +544,7 @@ impl Fairing for AdHoc {
             AdHocKind::Ignite(_) => Kind::Ignite,
             AdHocKind::Liftoff(_) => Kind::Liftoff,
             AdHocKind::Request(_) => Kind::Request,
+            AdHocKind::Intercept(_) => Kind::Intercept,
             AdHocKind::Response(_) => Kind::Response,
             AdHocKind::Shutdown(_) => Kind::Shutdown,
         };
@@ -432,6 +571,13 @@ impl Fairing for AdHoc {
         }
     }
 
+    async fn on_intercept<'r>(&self, req: &'r Request<'_>) -> Option<Response<'r>> {
+        match self.kind {
+            AdHocKind::Intercept(ref f) => f(req).await,
+            _ => None
+        }
+    }
+
     async fn on_response<'r>(&self, req: &'r Request<'_>, res: &mut Response<'r>) {
         if let AdHocKind::Response(ref f) = self.kind {
             f(req, res).await
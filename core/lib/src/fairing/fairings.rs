@@ -18,6 +18,7 @@ pub struct Fairings {
     ignite: Vec<usize>,
     liftoff: Vec<usize>,
     request: Vec<usize>,
+    intercept: Vec<usize>,
     response: Vec<usize>,
     shutdown: Vec<usize>,
 }
@@ -46,6 +47,7 @@ impl Fairings {
         self.ignite.iter()
             .chain(self.liftoff.iter())
             .chain(self.request.iter())
+            .chain(self.intercept.iter())
             .chain(self.response.iter())
             .chain(self.shutdown.iter())
     }
@@ -101,6 +103,7 @@ impl Fairings {
         if this_info.kind.is(Kind::Ignite) { self.ignite.push(index); }
         if this_info.kind.is(Kind::Liftoff) { self.liftoff.push(index); }
         if this_info.kind.is(Kind::Request) { self.request.push(index); }
+        if this_info.kind.is(Kind::Intercept) { self.intercept.push(index); }
         if this_info.kind.is(Kind::Response) { self.response.push(index); }
         if this_info.kind.is(Kind::Shutdown) { self.shutdown.push(index); }
     }
@@ -150,6 +153,18 @@ impl Fairings {
         }
     }
 
+    #[inline(always)]
+    pub async fn handle_intercept<'r>(&self, req: &'r Request<'_>) -> Option<Response<'r>> {
+        for fairing in iter!(self.intercept) {
+            if let Some(response) = fairing.on_intercept(req).await {
+                info_!("Request intercepted by {}.", fairing.info().name.primary().bold());
+                return Some(response);
+            }
+        }
+
+        None
+    }
+
     #[inline(always)]
     pub async fn handle_response<'r>(&self, req: &'r Request<'_>, res: &mut Response<'r>) {
         for fairing in iter!(self.response) {
@@ -193,6 +208,7 @@ impl std::fmt::Debug for Fairings {
             .field("launch", &debug_info(iter!(self.ignite)))
             .field("liftoff", &debug_info(iter!(self.liftoff)))
             .field("request", &debug_info(iter!(self.request)))
+            .field("intercept", &debug_info(iter!(self.intercept)))
             .field("response", &debug_info(iter!(self.response)))
             .field("shutdown", &debug_info(iter!(self.shutdown)))
             .finish()
@@ -74,6 +74,9 @@ impl Kind {
     /// [singleton](crate::fairing::Fairing#singletons) fairing.
     pub const Singleton: Kind = Kind(1 << 5);
 
+    /// `Kind` flag representing a request for an 'intercept' callback.
+    pub const Intercept: Kind = Kind(1 << 6);
+
     /// Returns `true` if `self` is a superset of `other`. In other words,
     /// returns `true` if all of the kinds in `other` are also in `self`.
     ///
@@ -144,6 +147,7 @@ impl std::fmt::Display for Kind {
         write("ignite", Kind::Ignite)?;
         write("liftoff", Kind::Liftoff)?;
         write("request", Kind::Request)?;
+        write("intercept", Kind::Intercept)?;
         write("response", Kind::Response)?;
         write("shutdown", Kind::Shutdown)?;
         write("singleton", Kind::Singleton)
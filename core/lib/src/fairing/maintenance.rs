@@ -0,0 +1,268 @@
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+use crate::{Rocket, Request, Response, Build};
+use crate::fairing::{self, Fairing, Info, Kind};
+use crate::http::Status;
+
+/// Fairing which responds to requests with `503 Service Unavailable` while
+/// maintenance mode is enabled.
+///
+/// When enabled, the fairing responds to every request with
+/// `503 Service Unavailable` and a `Retry-After` header _before_ the request is
+/// routed. Requests whose path is in an allow-list, such as a health check
+/// endpoint, are routed as usual. Maintenance mode is enabled if either:
+///
+///   * The `maintenance` configuration parameter is `true`.
+///
+///     The parameter is read once, at ignition. It defaults to `false`.
+///
+///   * A sentinel file, if one is configured via [`Maintenance::sentinel()`],
+///     exists.
+///
+///     Maintenance mode can thus be toggled at runtime by creating and
+///     removing the file. To avoid a filesystem lookup on every request, the
+///     result of a check is reused for [`Maintenance::SENTINEL_TTL`], or as
+///     configured via [`Maintenance::sentinel_ttl()`], so a change takes
+///     effect within that duration.
+///
+/// # Response
+///
+/// The body of the response is generated by the `503` error catcher. To
+/// customize the response, for instance to render a template, register a
+/// catcher for `503`:
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use rocket::fairing::Maintenance;
+/// use rocket::response::content::RawHtml;
+///
+/// #[catch(503)]
+/// fn unavailable() -> RawHtml<&'static str> {
+///     RawHtml("<h1>We'll be right back!</h1>")
+/// }
+///
+/// #[get("/healthz")]
+/// fn health() -> &'static str {
+///     "ok"
+/// }
+///
+/// #[launch]
+/// fn rocket() -> _ {
+///     rocket::build()
+///         .mount("/", routes![health])
+///         .register("/", catchers![unavailable])
+///         .attach(Maintenance::new().sentinel("/var/run/app/maintenance").allow("/healthz"))
+/// }
+/// ```
+///
+/// # Configuration
+///
+/// Maintenance mode can be enabled via `Rocket.toml` or the environment, as
+/// with any other configuration parameter:
+///
+/// ```toml
+/// [default]
+/// maintenance = true
+/// ```
+///
+/// Or, equivalently, `ROCKET_MAINTENANCE=true`.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use std::time::Duration;
+/// use rocket::fairing::Maintenance;
+///
+/// #[launch]
+/// fn rocket() -> _ {
+///     let maintenance = Maintenance::new()
+///         .sentinel("maintenance.lock")
+///         .retry_after(Duration::from_secs(600))
+///         .allow("/healthz")
+///         .allow("/status");
+///
+///     rocket::build().attach(maintenance)
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Maintenance {
+    configured: AtomicBool,
+    sentinel: Option<PathBuf>,
+    sentinel_ttl: Duration,
+    sentinel_check: Mutex<Option<(Instant, bool)>>,
+    retry_after: Duration,
+    allowed: Vec<String>,
+}
+
+impl Maintenance {
+    /// The name of the configuration parameter which enables maintenance mode:
+    /// `maintenance`.
+    pub const CONFIG: &'static str = "maintenance";
+
+    /// The default value of the `Retry-After` header: 60 seconds.
+    pub const RETRY_AFTER: Duration = Duration::from_secs(60);
+
+    /// The default duration for which the presence of the sentinel file is
+    /// cached: 1 second.
+    pub const SENTINEL_TTL: Duration = Duration::from_secs(1);
+
+    /// Returns a new `Maintenance` fairing enabled only by the `maintenance`
+    /// configuration parameter, with a `Retry-After` of
+    /// [`Maintenance::RETRY_AFTER`] and an empty allow-list.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::fairing::Maintenance;
+    ///
+    /// let fairing = Maintenance::new();
+    /// ```
+    pub fn new() -> Self {
+        Maintenance {
+            configured: AtomicBool::new(false),
+            sentinel: None,
+            sentinel_ttl: Self::SENTINEL_TTL,
+            sentinel_check: Mutex::new(None),
+            retry_after: Self::RETRY_AFTER,
+            allowed: vec![],
+        }
+    }
+
+    /// Additionally enables maintenance mode whenever a file exists at `path`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::fairing::Maintenance;
+    ///
+    /// let fairing = Maintenance::new().sentinel("/var/run/app/maintenance");
+    /// ```
+    pub fn sentinel<P: Into<PathBuf>>(mut self, path: P) -> Self {
+        self.sentinel = Some(path.into());
+        self
+    }
+
+    /// Sets the duration for which the result of checking for the sentinel
+    /// file is reused. A `ttl` of zero checks for the file on every request.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use rocket::fairing::Maintenance;
+    ///
+    /// let fairing = Maintenance::new()
+    ///     .sentinel("/var/run/app/maintenance")
+    ///     .sentinel_ttl(Duration::from_secs(5));
+    /// ```
+    pub fn sentinel_ttl(mut self, ttl: Duration) -> Self {
+        self.sentinel_ttl = ttl;
+        self
+    }
+
+    /// Sets the duration sent in the `Retry-After` header, in whole seconds.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use rocket::fairing::Maintenance;
+    ///
+    /// let fairing = Maintenance::new().retry_after(Duration::from_secs(300));
+    /// ```
+    pub fn retry_after(mut self, duration: Duration) -> Self {
+        self.retry_after = duration;
+        self
+    }
+
+    /// Allows requests to `path`, and to any path nested under it, to be
+    /// routed as usual while maintenance mode is enabled.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::fairing::Maintenance;
+    ///
+    /// // Allows `/healthz`, `/healthz/`, and `/healthz/db` but not `/healthzz`.
+    /// let fairing = Maintenance::new().allow("/healthz");
+    /// ```
+    pub fn allow<P: Into<String>>(mut self, path: P) -> Self {
+        let path = path.into();
+        let path = path.trim_end_matches('/');
+        self.allowed.push(format!("/{}", path.trim_start_matches('/')));
+        self
+    }
+
+    fn is_allowed(&self, path: &str) -> bool {
+        self.allowed.iter().any(|allowed| match path.strip_prefix(allowed.as_str()) {
+            Some(rest) => rest.is_empty() || rest.starts_with('/') || allowed == "/",
+            None => false,
+        })
+    }
+
+    async fn is_enabled(&self) -> bool {
+        if self.configured.load(Ordering::Acquire) {
+            return true;
+        }
+
+        let Some(ref path) = self.sentinel else {
+            return false;
+        };
+
+        let cached = *self.sentinel_check.lock().expect("sentinel lock");
+        match cached {
+            Some((checked, exists)) if checked.elapsed() < self.sentinel_ttl => exists,
+            _ => {
+                let exists = tokio::fs::metadata(path).await.is_ok();
+                let check = Some((Instant::now(), exists));
+                *self.sentinel_check.lock().expect("sentinel lock") = check;
+                exists
+            }
+        }
+    }
+}
+
+impl Default for Maintenance {
+    fn default() -> Self {
+        Maintenance::new()
+    }
+}
+
+#[crate::async_trait]
+impl Fairing for Maintenance {
+    fn info(&self) -> Info {
+        Info {
+            name: "Maintenance",
+            kind: Kind::Ignite | Kind::Intercept | Kind::Singleton,
+        }
+    }
+
+    async fn on_ignite(&self, rocket: Rocket<Build>) -> fairing::Result {
+        let configured = match rocket.figment().extract_inner::<bool>(Self::CONFIG) {
+            Ok(configured) => configured,
+            Err(e) if e.missing() => false,
+            Err(e) => {
+                crate::config::pretty_print_error(e);
+                return Err(rocket);
+            }
+        };
+
+        self.configured.store(configured, Ordering::Release);
+        Ok(rocket)
+    }
+
+    async fn on_intercept<'r>(&self, req: &'r Request<'_>) -> Option<Response<'r>> {
+        if self.is_allowed(req.uri().path().as_str()) || !self.is_enabled().await {
+            return None;
+        }
+
+        info_!("Maintenance mode is enabled. Responding with 503.");
+        let mut response = req.rocket().dispatch_error(Status::ServiceUnavailable, req).await;
+        response.set_raw_header("Retry-After", self.retry_after.as_secs().to_string());
+        Some(response)
+    }
+}
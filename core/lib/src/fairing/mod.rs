@@ -56,9 +56,11 @@ use crate::{Rocket, Request, Response, Data, Build, Orbit};
 mod fairings;
 mod ad_hoc;
 mod info_kind;
+mod maintenance;
 
 pub(crate) use self::fairings::Fairings;
 pub use self::ad_hoc::AdHoc;
+pub use self::maintenance::Maintenance;
 pub use self::info_kind::{Info, Kind};
 
 /// A type alias for the return `Result` type of [`Fairing::on_ignite()`].
@@ -101,11 +103,11 @@ pub type Result<T = Rocket<Build>, E = Rocket<Build>> = std::result::Result<T, E
 ///
 /// ## Fairing Callbacks
 ///
-/// There are five kinds of fairing callbacks: launch, liftoff, request,
-/// response, and shutdown. A fairing can request any combination of these
-/// callbacks through the `kind` field of the [`Info`] structure returned from
-/// the `info` method. Rocket will only invoke the callbacks identified in the
-/// fairing's [`Kind`].
+/// There are six kinds of fairing callbacks: launch, liftoff, request,
+/// intercept, response, and shutdown. A fairing can request any combination of
+/// these callbacks through the `kind` field of the [`Info`] structure returned
+/// from the `info` method. Rocket will only invoke the callbacks identified in
+/// the fairing's [`Kind`].
 ///
 /// The callback kinds are as follows:
 ///
@@ -127,6 +129,11 @@ pub type Result<T = Rocket<Build>, E = Rocket<Build>> = std::result::Result<T, E
 ///     ignite fairing returns `Err`, launch will be aborted. All ignite
 ///     fairings are executed even if one or more signal an error.
 ///
+///     In particular, an ignite callback may add routes via
+///     [`Rocket::mount()`] and remove them via [`Rocket::retain_routes()`].
+///     The router is built from the final set of routes after all ignite
+///     callbacks have executed.
+///
 ///   * **<a name="liftoff">Liftoff</a> (`on_liftoff`)**
 ///
 ///     A liftoff callback, represented by the [`Fairing::on_liftoff()`] method,
@@ -149,9 +156,23 @@ pub type Result<T = Rocket<Build>, E = Rocket<Build>> = std::result::Result<T, E
 ///     [`Request`] and [`Data`] structures but has not routed the request. A
 ///     request callback can modify the request at will and [`Data::peek()`]
 ///     into the incoming data. It may not, however, abort or respond directly
-///     to the request; these issues are better handled via [request guards] or
-///     via response callbacks. Any modifications to a request are persisted and
-///     can potentially alter how a request is routed.
+///     to the request; these issues are better handled via [request guards],
+///     intercept callbacks, or response callbacks. Any modifications to a
+///     request are persisted and can potentially alter how a request is routed.
+///
+///   * **<a name="intercept">Intercept</a> (`on_intercept`)**
+///
+///     An intercept callback, represented by the [`Fairing::on_intercept()`]
+///     method, is called after all request callbacks have run, just before
+///     the request is routed. An intercept callback can inspect, but not
+///     modify, the request and may respond to it directly by returning
+///     `Some(response)`. When it does, the router, and any remaining intercept
+///     callbacks, are skipped, and the response is used as if it had been
+///     returned by a route: cookies and Rocket-specific headers are added,
+///     response callbacks are run, and the body is stripped for `HEAD`
+///     requests. Intercept callbacks are run in `attach()` order. They are
+///     well-suited for gates that apply to every route, such as maintenance
+///     windows or authentication requirements.
 ///
 ///   * **<a name="response">Response</a> (`on_response`)**
 ///
@@ -208,10 +229,11 @@ pub type Result<T = Rocket<Build>, E = Rocket<Build>> = std::result::Result<T, E
 /// # Implementing
 ///
 /// A `Fairing` implementation has one required method: [`info`]. A `Fairing`
-/// can also implement any of the available callbacks: `on_ignite`, `on_liftoff`,
-/// `on_request`, and `on_response`. A `Fairing` _must_ set the appropriate
-/// callback kind in the `kind` field of the returned `Info` structure from
-/// [`info`] for a callback to actually be called by Rocket.
+/// can also implement any of the available callbacks: `on_ignite`,
+/// `on_liftoff`, `on_request`, `on_intercept`, `on_response`, and
+/// `on_shutdown`. A `Fairing` _must_ set the appropriate callback kind in the
+/// `kind` field of the returned `Info` structure from [`info`] for a callback
+/// to actually be called by Rocket.
 ///
 /// ## Fairing `Info`
 ///
@@ -275,6 +297,11 @@ pub type Result<T = Rocket<Build>, E = Rocket<Build>> = std::result::Result<T, E
 ///         # unimplemented!()
 ///     }
 ///
+///     async fn on_intercept<'r>(&self, req: &'r Request<'_>) -> Option<Response<'r>> {
+///         /* ... */
+///         # unimplemented!()
+///     }
+///
 ///     async fn on_response<'r>(&self, req: &'r Request<'_>, res: &mut Response<'r>) {
 ///         /* ... */
 ///         # unimplemented!()
@@ -503,6 +530,51 @@ pub trait Fairing: Send + Sync + Any + 'static {
     /// The default implementation of this method does nothing.
     async fn on_request(&self, _req: &mut Request<'_>, _data: &mut Data<'_>) {}
 
+    /// The intercept callback. Returns `Some(response)` to respond to the
+    /// request with `response` instead of routing it and `None` to continue
+    /// processing the request normally.
+    ///
+    /// See [Fairing Callbacks](#intercept) for complete semantics.
+    ///
+    /// This method is called just before a request is routed if
+    /// `Kind::Intercept` is in the `kind` field of the `Info` structure for
+    /// this fairing. The `&Request` parameter is the request to be routed.
+    ///
+    /// ## Default Implementation
+    ///
+    /// The default implementation of this method returns `None`.
+    ///
+    /// ## Example
+    ///
+    /// A fairing that responds with `401 Unauthorized` to requests without an
+    /// `Authorization` header:
+    ///
+    /// ```rust
+    /// use rocket::{Request, Response};
+    /// use rocket::fairing::{Fairing, Info, Kind};
+    /// use rocket::http::Status;
+    ///
+    /// struct RequireAuth;
+    ///
+    /// #[rocket::async_trait]
+    /// impl Fairing for RequireAuth {
+    ///     fn info(&self) -> Info {
+    ///         Info { name: "Require Authorization", kind: Kind::Intercept }
+    ///     }
+    ///
+    ///     async fn on_intercept<'r>(&self, req: &'r Request<'_>) -> Option<Response<'r>> {
+    ///         if req.headers().contains("Authorization") {
+    ///             return None;
+    ///         }
+    ///
+    ///         Some(Response::build().status(Status::Unauthorized).finalize())
+    ///     }
+    /// }
+    /// ```
+    async fn on_intercept<'r>(&self, _req: &'r Request<'_>) -> Option<Response<'r>> {
+        None
+    }
+
     /// The response callback.
     ///
     /// See [Fairing Callbacks](#response) for complete semantics.
@@ -555,6 +627,11 @@ impl<T: Fairing + ?Sized> Fairing for std::sync::Arc<T> {
         (self as &T).on_request(req, data).await
     }
 
+    #[inline]
+    async fn on_intercept<'r>(&self, req: &'r Request<'_>) -> Option<Response<'r>> {
+        (self as &T).on_intercept(req).await
+    }
+
     #[inline]
     async fn on_response<'r>(&self, req: &'r Request<'_>, res: &mut Response<'r>) {
         (self as &T).on_response(req, res).await
@@ -0,0 +1,98 @@
+use std::ops::{Deref, DerefMut};
+
+use crate::form::prelude::*;
+
+/// A form field guard for parsing a comma-separated list of values.
+///
+/// A `Vec<T>` form field is parsed from repeated fields, as in
+/// `ids=1&ids=2&ids=3`. A `CommaSeparated<T>`, where `T` is any
+/// [`FromFormField`] type, is instead parsed from a _single_ field whose value
+/// is a list of values separated by commas, as in `ids=1,2,3`. Whitespace
+/// around each value is trimmed, and an empty value parses as an empty list.
+///
+/// Every value is parsed as a `T`. If any value fails to parse, the errors
+/// for all failing values are reported. As with any form field, values are
+/// percent-decoded _before_ they are split, so an encoded comma (`%2C`) also
+/// separates values.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use rocket::form::CommaSeparated;
+///
+/// // Handles `/items?ids=1,2,3` and `/items?ids=7`.
+/// #[get("/items?<ids>")]
+/// fn items(ids: CommaSeparated<usize>) -> String {
+///     format!("{:?}", *ids)
+/// }
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CommaSeparated<T>(Vec<T>);
+
+impl<T> CommaSeparated<T> {
+    /// Consumes `self` and returns the inner vector.
+    ///
+    /// Note that since `CommaSeparated` implements [`Deref`] and [`DerefMut`]
+    /// with target `Vec<T>`, reading and writing the inner vector can be
+    /// accomplished transparently.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate rocket;
+    /// use rocket::form::CommaSeparated;
+    ///
+    /// #[get("/tags?<tags>")]
+    /// fn tags(tags: CommaSeparated<&str>) -> String {
+    ///     tags.into_inner().join(" ")
+    /// }
+    /// ```
+    pub fn into_inner(self) -> Vec<T> {
+        self.0
+    }
+}
+
+impl<'v, T: FromFormField<'v>> FromFormField<'v> for CommaSeparated<T> {
+    fn from_value(field: ValueField<'v>) -> Result<'v, Self> {
+        if field.value.trim().is_empty() {
+            return Ok(CommaSeparated(vec![]));
+        }
+
+        let mut values = vec![];
+        let mut errors = Errors::new();
+        for value in field.value.split(',') {
+            let field = ValueField { name: field.name.clone(), value: value.trim() };
+            match T::from_value(field) {
+                Ok(value) => values.push(value),
+                Err(e) => errors.extend(e),
+            }
+        }
+
+        match errors.is_empty() {
+            true => Ok(CommaSeparated(values)),
+            false => Err(errors),
+        }
+    }
+}
+
+impl<T> Deref for CommaSeparated<T> {
+    type Target = Vec<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for CommaSeparated<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T> From<Vec<T>> for CommaSeparated<T> {
+    #[inline]
+    fn from(values: Vec<T>) -> CommaSeparated<T> {
+        CommaSeparated(values)
+    }
+}
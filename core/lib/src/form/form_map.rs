@@ -0,0 +1,218 @@
+use std::collections::HashMap;
+
+use crate::Request;
+use crate::data::{Data, FromData, Outcome};
+use crate::form::prelude::*;
+
+/// A data guard for the fields of a URL-encoded form as a map.
+///
+/// `FormMap` reads an `application/x-www-form-urlencoded` request body into a
+/// map from each field name to the percent-decoded values of all fields with
+/// that name. It is useful when the fields of a form aren't known at compile
+/// time, for instance, for dynamic forms or when proxying a form.
+///
+/// A field may appear more than once. [`FormMap::get()`] returns the _last_
+/// value for a name while [`FormMap::get_all()`] returns every value in the
+/// order received. Similarly, [`FormMap::into_map()`] converts into a
+/// `HashMap<String, String>` where the last value wins while
+/// [`FormMap::into_multimap()`] preserves every value. To inspect fields in
+/// wire order instead, use [`RawForm`].
+///
+/// # Errors
+///
+/// `FormMap` behaves exactly like [`RawForm`]: if the request's content-type
+/// is not [`ContentType::Form`], the guard **forwards** with a status of `415
+/// Unsupported Media Type`. The body is limited by the `form` data limit. If
+/// reading the body fails or the limit is exceeded, the guard **fails** with
+/// the [`Errors`].
+///
+/// [`ContentType::Form`]: crate::http::ContentType::Form
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use rocket::form::FormMap;
+///
+/// #[post("/settings", data = "<form>")]
+/// fn settings(form: FormMap<'_>) -> String {
+///     let theme = form.get("theme").unwrap_or("light");
+///     let tags = form.get_all("tag").join(",");
+///     format!("theme={}, tags={}", theme, tags)
+/// }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FormMap<'r> {
+    map: HashMap<&'r str, Vec<&'r str>>,
+}
+
+impl<'r> FormMap<'r> {
+    /// Returns the last value of the field named `name`, if there is one.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use rocket::post;
+    /// use rocket::form::FormMap;
+    ///
+    /// #[post("/", data = "<form>")]
+    /// fn name(form: FormMap<'_>) -> Option<&str> {
+    ///     form.get("name")
+    /// }
+    /// ```
+    pub fn get(&self, name: &str) -> Option<&'r str> {
+        self.map.get(name).and_then(|values| values.last()).copied()
+    }
+
+    /// Returns every value of the field named `name` in the order they were
+    /// received. The slice is empty if there is no such field.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use rocket::post;
+    /// use rocket::form::FormMap;
+    ///
+    /// #[post("/", data = "<form>")]
+    /// fn tags(form: FormMap<'_>) -> String {
+    ///     form.get_all("tag").join(" ")
+    /// }
+    /// ```
+    pub fn get_all(&self, name: &str) -> &[&'r str] {
+        self.map.get(name).map(|values| &values[..]).unwrap_or(&[])
+    }
+
+    /// Returns `true` if the form contains a field named `name`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use rocket::post;
+    /// use rocket::form::FormMap;
+    ///
+    /// #[post("/", data = "<form>")]
+    /// fn subscribed(form: FormMap<'_>) -> &'static str {
+    ///     if form.contains("subscribe") { "yes" } else { "no" }
+    /// }
+    /// ```
+    pub fn contains(&self, name: &str) -> bool {
+        self.map.contains_key(name)
+    }
+
+    /// Returns the number of distinct field names in the form.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use rocket::post;
+    /// use rocket::form::FormMap;
+    ///
+    /// #[post("/", data = "<form>")]
+    /// fn count(form: FormMap<'_>) -> String {
+    ///     form.len().to_string()
+    /// }
+    /// ```
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Returns `true` if the form contains no fields.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use rocket::post;
+    /// use rocket::form::FormMap;
+    ///
+    /// #[post("/", data = "<form>")]
+    /// fn empty(form: FormMap<'_>) -> &'static str {
+    ///     if form.is_empty() { "empty" } else { "not empty" }
+    /// }
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Returns an iterator over the distinct field names and their values, in
+    /// arbitrary order.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use rocket::post;
+    /// use rocket::form::FormMap;
+    ///
+    /// #[post("/", data = "<form>")]
+    /// fn total(form: FormMap<'_>) -> String {
+    ///     form.iter().map(|(_, values)| values.len()).sum::<usize>().to_string()
+    /// }
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = (&'r str, &[&'r str])> + '_ {
+        self.map.iter().map(|(name, values)| (*name, &values[..]))
+    }
+
+    /// Converts `self` into a map from each field name to its last value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use rocket::post;
+    /// use std::collections::HashMap;
+    /// use rocket::form::FormMap;
+    ///
+    /// #[post("/", data = "<form>")]
+    /// fn fields(form: FormMap<'_>) -> String {
+    ///     let map: HashMap<String, String> = form.into_map();
+    ///     format!("{:?}", map)
+    /// }
+    /// ```
+    pub fn into_map(self) -> HashMap<String, String> {
+        self.map.into_iter()
+            .filter_map(|(name, values)| Some((name.into(), values.last()?.to_string())))
+            .collect()
+    }
+
+    /// Converts `self` into a map from each field name to all of its values in
+    /// the order they were received.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use rocket::post;
+    /// use std::collections::HashMap;
+    /// use rocket::form::FormMap;
+    ///
+    /// #[post("/", data = "<form>")]
+    /// fn fields(form: FormMap<'_>) -> String {
+    ///     let map: HashMap<String, Vec<String>> = form.into_multimap();
+    ///     format!("{:?}", map)
+    /// }
+    /// ```
+    pub fn into_multimap(self) -> HashMap<String, Vec<String>> {
+        self.map.into_iter()
+            .map(|(name, values)| {
+                (name.into(), values.into_iter().map(|v| v.to_string()).collect())
+            })
+            .collect()
+    }
+}
+
+impl<'r> From<RawForm<'r>> for FormMap<'r> {
+    fn from(form: RawForm<'r>) -> Self {
+        let mut map: HashMap<&'r str, Vec<&'r str>> = HashMap::new();
+        for (name, value) in form.iter() {
+            map.entry(name).or_default().push(value);
+        }
+
+        FormMap { map }
+    }
+}
+
+#[crate::async_trait]
+impl<'r> FromData<'r> for FormMap<'r> {
+    type Error = Errors<'r>;
+
+    async fn from_data(req: &'r Request<'_>, data: Data<'r>) -> Outcome<'r, Self> {
+        RawForm::from_data(req, data).await.map(FormMap::from)
+    }
+}
@@ -90,6 +90,51 @@ use crate::http::uncased::AsUncased;
 /// }
 /// ```
 ///
+/// # Brackets
+///
+/// Form field names may use bracket notation: `user[name]=Bob` is equivalent
+/// to `user.name=Bob`, and every field with an empty index, as in
+/// `items[]=a&items[]=b`, is pushed to a new item of a sequence such as a
+/// `Vec`. As such, PHP-style forms are parsed without further effort.
+///
+/// Rails-style forms additionally group fields for sequences of structures:
+/// consecutive fields with an empty index refer to the _same_ item until a
+/// key within the item repeats. Because this changes how existing forms
+/// parse, this is opt-in via [`Options::brackets()`], typically enabled by
+/// deriving `FromForm` with `#[form(brackets)]`. The option is inherited by
+/// all nested form guards:
+///
+/// ```rust
+/// use rocket::form::{Form, FromForm};
+///
+/// #[derive(FromForm, Debug, PartialEq)]
+/// struct Address<'r> {
+///     street: &'r str,
+///     city: &'r str,
+/// }
+///
+/// #[derive(FromForm, Debug, PartialEq)]
+/// #[form(brackets)]
+/// struct User<'r> {
+///     name: &'r str,
+///     addresses: Vec<Address<'r>>,
+/// }
+///
+/// let form = "name=Bob\
+///     &addresses[][street]=Main St&addresses[][city]=Springfield\
+///     &addresses[][street]=Elm St&addresses[][city]=Shelbyville";
+///
+/// let user: User<'_> = Form::parse(form).unwrap();
+/// assert_eq!(user.addresses, [
+///     Address { street: "Main St", city: "Springfield" },
+///     Address { street: "Elm St", city: "Shelbyville" },
+/// ]);
+/// ```
+///
+/// Without `#[form(brackets)]`, each of the four `addresses` fields above
+/// would be pushed to a new `Address`, resulting in errors for every missing
+/// `street` and `city`.
+///
 /// # Defaults
 ///
 /// A form guard may have a _default_ which is used in case of a missing field
@@ -129,6 +174,7 @@ use crate::http::uncased::AsUncased;
 /// | [`time::Date`]         | _inherit_   | **no default**    | No     | Yes    | `%F` (`YYYY-MM-DD`). HTML "date" input.            |
 /// | [`time::DateTime`]     | _inherit_   | **no default**    | No     | Yes    | `%FT%R` or `%FT%T` (`YYYY-MM-DDTHH:MM[:SS]`)       |
 /// | [`time::Time`]         | _inherit_   | **no default**    | No     | Yes    | `%R` or `%T` (`HH:MM[:SS]`)                        |
+/// | [`time::OffsetDateTime`]| _inherit_  | **no default**    | No     | Yes    | RFC 3339 (`YYYY-MM-DDTHH:MM:SS[.f](Z\|±HH:MM)`)    |
 ///
 /// [`Result<T>`]: crate::form::Result
 /// [`Strict<T>`]: crate::form::Strict
@@ -199,6 +245,14 @@ use crate::http::uncased::AsUncased;
 ///     This is the `"time"` HTML input type without support for the millisecond
 ///     variant.
 ///
+///   * **[`time::OffsetDateTime`]**
+///
+///     Parses an [RFC 3339] date and time with an offset, the ISO-8601 profile
+///     commonly used in APIs, such as `2012-10-12T13:30:00Z` or
+///     `2012-10-12T13:30:00.5+02:00`.
+///
+///     [RFC 3339]: https://www.rfc-editor.org/rfc/rfc3339
+///
 /// # Push Parsing
 ///
 /// `FromForm` describes a push-based parser for Rocket's [field wire format].
@@ -568,6 +622,7 @@ pub struct VecContext<'v, T: FromForm<'v>> {
     opts: Options,
     last_key: Option<&'v Key>,
     current: Option<T::Context>,
+    group: Vec<&'v Key>,
     errors: Errors<'v>,
     items: Vec<T>
 }
@@ -578,6 +633,7 @@ impl<'v, T: FromForm<'v>> VecContext<'v, T> {
             opts,
             last_key: None,
             current: None,
+            group: vec![],
             items: vec![],
             errors: Errors::new(),
         }
@@ -594,14 +650,30 @@ impl<'v, T: FromForm<'v>> VecContext<'v, T> {
 
     fn context(&mut self, name: &NameView<'v>) -> &mut T::Context {
         let this_key = name.key();
+        let next_key = || {
+            let mut next = *name;
+            next.shift();
+            next.key()
+        };
+
         let keys_match = match (self.last_key, this_key) {
             (Some(k1), Some(k2)) => k1 == k2,
+            // With `brackets`, consecutive empty keys refer to the same item
+            // until a key _within_ the item repeats.
+            (None, None) if self.opts.brackets && self.current.is_some() => {
+                next_key().map_or(false, |k| !self.group.contains(&k))
+            }
             _ => false
         };
 
         if !keys_match {
             self.shift();
             self.current = Some(T::init(self.opts));
+            self.group.clear();
+        }
+
+        if self.opts.brackets && this_key.is_none() {
+            self.group.extend(next_key());
         }
 
         self.last_key = name.key();
@@ -818,8 +890,8 @@ impl<'v, K, V> FromForm<'v> for BTreeMap<K, V>
 impl<'v, T: FromForm<'v>> FromForm<'v> for Option<T> {
     type Context = <T as FromForm<'v>>::Context;
 
-    fn init(_: Options) -> Self::Context {
-        T::init(Options { strict: true })
+    fn init(opts: Options) -> Self::Context {
+        T::init(Options { strict: true, ..opts })
     }
 
     fn push_value(ctxt: &mut Self::Context, field: ValueField<'v>) {
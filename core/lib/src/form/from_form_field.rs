@@ -5,8 +5,9 @@ use std::num::{
     NonZeroUsize, NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128,
 };
 
-use time::{Date, Time, PrimitiveDateTime};
+use time::{Date, Time, PrimitiveDateTime, OffsetDateTime};
 use time::{macros::format_description, format_description::FormatItem};
+use time::format_description::well_known::Rfc3339;
 
 use crate::data::Capped;
 use crate::http::uncased::AsUncased;
@@ -400,13 +401,16 @@ impl_with_parse!(
     Ipv4Addr, IpAddr, Ipv6Addr, SocketAddrV4, SocketAddrV6, SocketAddr
 );
 
-// Keep formats in sync with 'FromFormField' impls.
-static DATE_FMT: &[FormatItem<'_>] = format_description!("[year padding:none]-[month]-[day]");
-static TIME_FMT1: &[FormatItem<'_>] = format_description!("[hour padding:none]:[minute]:[second]");
-static TIME_FMT2: &[FormatItem<'_>] = format_description!("[hour padding:none]:[minute]");
-static DATE_TIME_FMT1: &[FormatItem<'_>] =
+// Keep formats in sync with 'FromFormField' and 'FromParam' impls.
+pub(crate) static DATE_FMT: &[FormatItem<'_>] =
+    format_description!("[year padding:none]-[month]-[day]");
+pub(crate) static TIME_FMT1: &[FormatItem<'_>] =
+    format_description!("[hour padding:none]:[minute]:[second]");
+pub(crate) static TIME_FMT2: &[FormatItem<'_>] =
+    format_description!("[hour padding:none]:[minute]");
+pub(crate) static DATE_TIME_FMT1: &[FormatItem<'_>] =
     format_description!("[year padding:none]-[month]-[day]T[hour padding:none]:[minute]:[second]");
-static DATE_TIME_FMT2: &[FormatItem<'_>] =
+pub(crate) static DATE_TIME_FMT2: &[FormatItem<'_>] =
     format_description!("[year padding:none]-[month]-[day]T[hour padding:none]:[minute]");
 
 impl<'v> FromFormField<'v> for Date {
@@ -437,3 +441,12 @@ impl<'v> FromFormField<'v> for PrimitiveDateTime {
         Ok(dt)
     }
 }
+
+impl<'v> FromFormField<'v> for OffsetDateTime {
+    fn from_value(field: ValueField<'v>) -> Result<'v, Self> {
+        let dt = Self::parse(field.value, &Rfc3339)
+            .map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send>)?;
+
+        Ok(dt)
+    }
+}
@@ -0,0 +1,157 @@
+use std::ops::{Deref, DerefMut};
+use std::num::ParseIntError;
+
+use crate::form::prelude::*;
+use crate::http::uri::fmt::{Query, FromUriParam};
+
+/// A form field guard for parsing integers written as Rust integer literals.
+///
+/// The [`FromFormField`] implementations for integer types parse only plain
+/// decimal integers: `1000`, `-15`. An `IntLiteral<T>`, where `T` is any
+/// primitive integer type, additionally accepts:
+///
+///   * `_` digit separators: `1_000_000`, `0xFF_FF`
+///   * a `0x` hexadecimal, `0o` octal, or `0b` binary prefix: `0xFF`, `0o17`,
+///     `0b1010`, `-0x10`
+///
+/// Prefixes are case-insensitive. A leading `+` or `-` sign is permitted before
+/// the prefix. Parse failures, including overflow, are reported as
+/// [`ErrorKind::Int`](crate::form::error::ErrorKind::Int) errors.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use rocket::form::{Form, IntLiteral};
+///
+/// #[derive(FromForm)]
+/// struct Quota {
+///     // Parses `1_000`, `0x3E8`, `0o1750`, and `0b1111101000` as `1000`.
+///     bytes: IntLiteral<u64>,
+///     mask: IntLiteral<u8>,
+/// }
+///
+/// #[post("/quota", data = "<form>")]
+/// fn quota(form: Form<Quota>) -> String {
+///     format!("{} bytes, mask {:08b}", *form.bytes, *form.mask)
+/// }
+/// ```
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct IntLiteral<T>(T);
+
+/// Integer types which can be parsed from a string in a given radix.
+#[doc(hidden)]
+pub trait FromStrRadix: Sized {
+    fn from_str_radix(src: &str, radix: u32) -> std::result::Result<Self, ParseIntError>;
+}
+
+macro_rules! impl_from_str_radix {
+    ($($T:ident),+ $(,)?) => ($(
+        impl FromStrRadix for $T {
+            #[inline(always)]
+            fn from_str_radix(src: &str, radix: u32) -> std::result::Result<Self, ParseIntError> {
+                $T::from_str_radix(src, radix)
+            }
+        }
+    )+)
+}
+
+impl_from_str_radix!(isize, i8, i16, i32, i64, i128, usize, u8, u16, u32, u64, u128);
+
+impl<T: FromStrRadix> IntLiteral<T> {
+    /// Parses `string` as an integer literal. See the [type-level
+    /// docs](IntLiteral) for the accepted syntax.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::form::IntLiteral;
+    ///
+    /// assert_eq!(*IntLiteral::<u32>::parse("1_000").unwrap(), 1000);
+    /// assert_eq!(*IntLiteral::<i16>::parse("-0xFF").unwrap(), -255);
+    /// assert_eq!(*IntLiteral::<u8>::parse("0b1000_0001").unwrap(), 129);
+    /// assert!(IntLiteral::<u8>::parse("0x100").is_err());
+    /// assert!(IntLiteral::<u8>::parse("0x").is_err());
+    /// ```
+    pub fn parse(string: &str) -> std::result::Result<Self, ParseIntError> {
+        let (sign, rest) = match string.as_bytes().first() {
+            Some(b'-') => ("-", &string[1..]),
+            Some(b'+') => ("", &string[1..]),
+            _ => ("", string),
+        };
+
+        let prefix = rest.get(..2).map(|p| p.to_ascii_lowercase());
+        let (radix, digits) = match prefix.as_deref() {
+            Some("0x") => (16, &rest[2..]),
+            Some("0o") => (8, &rest[2..]),
+            Some("0b") => (2, &rest[2..]),
+            _ => (10, rest),
+        };
+
+        // A second sign, as in `0x-1` or `--1`, is invalid. `from_str_radix()`
+        // rejects a lone sign with an `InvalidDigit` error, so we defer to it.
+        let digits: String = digits.chars().filter(|&c| c != '_').collect();
+        if digits.starts_with(['+', '-']) {
+            return T::from_str_radix("-+", radix).map(IntLiteral);
+        }
+
+        T::from_str_radix(&format!("{}{}", sign, digits), radix).map(IntLiteral)
+    }
+}
+
+impl<T> IntLiteral<T> {
+    /// Consumes `self` and returns the inner value.
+    ///
+    /// Note that since `IntLiteral` implements [`Deref`] and [`DerefMut`] with
+    /// target `T`, reading and writing an inner value can be accomplished
+    /// transparently.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::form::IntLiteral;
+    ///
+    /// let value = IntLiteral::<u16>::parse("0xFF_FF").unwrap();
+    /// assert_eq!(value.into_inner(), u16::MAX);
+    /// ```
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<'v, T: FromStrRadix + Send> FromFormField<'v> for IntLiteral<T> {
+    #[inline]
+    fn from_value(field: ValueField<'v>) -> Result<'v, Self> {
+        Ok(IntLiteral::parse(field.value)?)
+    }
+}
+
+impl<T> Deref for IntLiteral<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for IntLiteral<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T> From<T> for IntLiteral<T> {
+    #[inline]
+    fn from(val: T) -> IntLiteral<T> {
+        IntLiteral(val)
+    }
+}
+
+impl<A, T: FromUriParam<Query, A>> FromUriParam<Query, A> for IntLiteral<T> {
+    type Target = T::Target;
+
+    #[inline(always)]
+    fn from_uri_param(param: A) -> Self::Target {
+        T::from_uri_param(param)
+    }
+}
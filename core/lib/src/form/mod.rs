@@ -369,6 +369,12 @@ mod form;
 mod context;
 mod strict;
 mod lenient;
+mod query;
+mod raw_form;
+mod form_map;
+mod int_literal;
+mod comma_separated;
+mod multipart;
 mod parser;
 mod buffer;
 pub mod validate;
@@ -393,11 +399,18 @@ pub use self::buffer::{SharedStack, Shareable};
 pub use field::*;
 pub use options::*;
 pub use from_form_field::*;
+pub(crate) use from_form_field::{DATE_FMT, TIME_FMT1, TIME_FMT2, DATE_TIME_FMT1, DATE_TIME_FMT2};
 pub use from_form::*;
 pub use form::*;
 pub use context::*;
 pub use strict::*;
 pub use lenient::*;
+pub use query::*;
+pub use raw_form::*;
+pub use form_map::*;
+pub use int_literal::*;
+pub use comma_separated::*;
+pub use multipart::*;
 
 #[doc(hidden)]
 pub mod prelude {
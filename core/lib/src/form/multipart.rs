@@ -0,0 +1,228 @@
+use std::io;
+use std::fmt;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures::stream::{self, BoxStream, Stream, StreamExt, TryStreamExt};
+use tokio::io::{AsyncRead, ReadBuf};
+use tokio_util::io::StreamReader;
+
+use crate::Request;
+use crate::data::{Data, FromData, Outcome};
+use crate::http::{ContentType, Header, HeaderMap, Status};
+use crate::form::prelude::{*, parser::multipart};
+
+/// A data guard that streams the parts of a `multipart/form-data` body.
+///
+/// Unlike [`Form`], which parses every field of a form before a handler runs,
+/// `MultipartStream` is a [`Stream`] of [`Part`]s, yielded as they arrive.
+/// Each part's body is read incrementally via [`AsyncRead`], so a handler can
+/// process uploads of many large files in bounded memory, for instance, by
+/// copying each part directly to its final destination.
+///
+/// Parts must be read in order, and a [`Part`] must be dropped before the next
+/// part is requested: while a previous part is alive, the stream yields an
+/// error instead of the next part. Any data left unread in a dropped part is
+/// discarded, so a part can be skipped, in whole or in part, by dropping it.
+///
+/// # Limits
+///
+/// The entire body, and thus every part, is limited by the `data-form` limit,
+/// exactly as with [`Form`]. Exceeding the limit results in an error being
+/// yielded by the stream or by a part's reader.
+///
+/// # Errors
+///
+/// If the request's content-type is not [`ContentType::FormData`], the guard
+/// **forwards** with a status of `415 Unsupported Media Type`. If the
+/// content-type lacks a boundary, the guard **fails** with the [`Errors`].
+/// Malformed multipart data is reported as an `Err` item in the stream.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use rocket::form::MultipartStream;
+/// use rocket::futures::TryStreamExt;
+/// use rocket::tokio::io;
+///
+/// #[post("/upload", data = "<parts>")]
+/// async fn upload(mut parts: MultipartStream<'_>) -> io::Result<String> {
+///     let mut summary = vec![];
+///     while let Some(mut part) = parts.try_next().await.map_err(io::Error::other)? {
+///         let name = part.name().unwrap_or("?").to_string();
+///         let bytes = io::copy(&mut part, &mut io::sink()).await?;
+///         summary.push(format!("{}: {} bytes", name, bytes));
+///     }
+///
+///     Ok(summary.join("\n"))
+/// }
+/// ```
+pub struct MultipartStream<'r> {
+    parts: BoxStream<'r, Result<'r, Part<'r>>>,
+}
+
+/// A single part of a `multipart/form-data` body, yielded by
+/// [`MultipartStream`].
+///
+/// A `Part` exposes the part's headers and implements [`AsyncRead`] to read
+/// its body incrementally.
+pub struct Part<'r> {
+    name: Option<String>,
+    file_name: Option<String>,
+    content_type: Option<ContentType>,
+    headers: HeaderMap<'static>,
+    body: Pin<Box<dyn AsyncRead + Send + 'r>>,
+}
+
+impl<'r> MultipartStream<'r> {
+    fn new(multipart: multer::Multipart<'r>) -> Self {
+        let parts = stream::unfold(Some(multipart), |multipart| async move {
+            let mut multipart = multipart?;
+            match multipart.next_field().await {
+                Ok(Some(field)) => Some((Ok(Part::from(field)), Some(multipart))),
+                Ok(None) => None,
+                Err(e) => Some((Err(Error::from(e)), None)),
+            }
+        });
+
+        MultipartStream { parts: parts.boxed() }
+    }
+}
+
+impl<'r> Part<'r> {
+    /// The name of the form field this part corresponds to, if any.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::form::Part;
+    ///
+    /// fn is_avatar(part: &Part<'_>) -> bool {
+    ///     part.name() == Some("avatar")
+    /// }
+    /// ```
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// The file name of this part, _exactly_ as sent by the client, if any.
+    ///
+    /// This value is not sanitized and must not be used as a file system path
+    /// directly. To sanitize it, use [`FileName`](crate::fs::FileName).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::form::Part;
+    /// use rocket::fs::FileName;
+    ///
+    /// fn safe_name<'a>(part: &'a Part<'_>) -> Option<&'a str> {
+    ///     FileName::new(part.file_name()?).as_str()
+    /// }
+    /// ```
+    pub fn file_name(&self) -> Option<&str> {
+        self.file_name.as_deref()
+    }
+
+    /// The content-type of this part, if it has a valid one.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::form::Part;
+    /// use rocket::http::ContentType;
+    ///
+    /// fn is_png(part: &Part<'_>) -> bool {
+    ///     part.content_type() == Some(&ContentType::PNG)
+    /// }
+    /// ```
+    pub fn content_type(&self) -> Option<&ContentType> {
+        self.content_type.as_ref()
+    }
+
+    /// All of the headers of this part.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::form::Part;
+    ///
+    /// fn content_id<'a>(part: &'a Part<'_>) -> Option<&'a str> {
+    ///     part.headers().get_one("Content-ID")
+    /// }
+    /// ```
+    pub fn headers(&self) -> &HeaderMap<'static> {
+        &self.headers
+    }
+}
+
+impl<'r> From<multer::Field<'r>> for Part<'r> {
+    fn from(field: multer::Field<'r>) -> Self {
+        let mut headers = HeaderMap::new();
+        for (name, value) in field.headers() {
+            if let Ok(value) = value.to_str() {
+                headers.add(Header::new(name.as_str().to_string(), value.to_string()));
+            }
+        }
+
+        Part {
+            name: field.name().map(|s| s.to_string()),
+            file_name: field.file_name().map(|s| s.to_string()),
+            content_type: field.content_type().and_then(|m| m.as_ref().parse().ok()),
+            headers,
+            body: Box::pin(StreamReader::new(field.map_err(io::Error::other))),
+        }
+    }
+}
+
+impl<'r> Stream for MultipartStream<'r> {
+    type Item = Result<'r, Part<'r>>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.parts.poll_next_unpin(cx)
+    }
+}
+
+impl AsyncRead for Part<'_> {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        self.body.as_mut().poll_read(cx, buf)
+    }
+}
+
+#[crate::async_trait]
+impl<'r> FromData<'r> for MultipartStream<'r> {
+    type Error = Errors<'r>;
+
+    async fn from_data(req: &'r Request<'_>, data: Data<'r>) -> Outcome<'r, Self> {
+        if !req.content_type().map_or(false, |ct| ct.is_form_data()) {
+            return Outcome::Forward((data, Status::UnsupportedMediaType));
+        }
+
+        match multipart(req, data) {
+            Ok(multipart) => Outcome::Success(MultipartStream::new(multipart)),
+            Err(e) => Outcome::Error((e.status(), e.into())),
+        }
+    }
+}
+
+impl fmt::Debug for MultipartStream<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MultipartStream").finish_non_exhaustive()
+    }
+}
+
+impl fmt::Debug for Part<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Part")
+            .field("name", &self.name)
+            .field("file_name", &self.file_name)
+            .field("content_type", &self.content_type)
+            .field("headers", &self.headers)
+            .finish_non_exhaustive()
+    }
+}
@@ -5,13 +5,32 @@
 pub struct Options {
     /// Whether parsing should be strict (no extra parameters) or not.
     pub strict: bool,
+    /// Whether bracketed sequences are enabled. See [`Options::brackets()`].
+    pub(crate) brackets: bool,
 }
 
 #[allow(non_upper_case_globals, dead_code)]
 impl Options {
-    /// `Options` with `strict` set to `false`.
-    pub const Lenient: Self = Options { strict: false };
+    /// `Options` with `strict` and `brackets` set to `false`.
+    pub const Lenient: Self = Options { strict: false, brackets: false };
 
-    /// `Options` with `strict` set to `true`.
-    pub const Strict: Self = Options { strict: true };
+    /// `Options` with `strict` set to `true` and `brackets` set to `false`.
+    pub const Strict: Self = Options { strict: true, brackets: false };
+
+    /// Sets whether consecutive fields with an empty index (`[]`) refer to the
+    /// same item of a sequence until one of the item's keys repeats, as in
+    /// Rails-style forms. See [`FromForm#brackets`](crate::form::FromForm#brackets).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::form::Options;
+    ///
+    /// let opts = Options::Lenient.brackets(true);
+    /// assert!(!opts.strict);
+    /// ```
+    pub const fn brackets(mut self, brackets: bool) -> Self {
+        self.brackets = brackets;
+        self
+    }
 }
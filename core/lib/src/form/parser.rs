@@ -58,26 +58,10 @@ impl<'r, 'i> Parser<'r, 'i> {
     }
 
     async fn from_multipart(req: &'r Request<'i>, data: Data<'r>) -> Result<'r, Parser<'r, 'i>> {
-        let boundary = req.content_type()
-            .ok_or(multer::Error::NoMultipart)?
-            .param("boundary")
-            .ok_or(multer::Error::NoBoundary)?;
-
-        let form_limit = req.limits()
-            .get("data-form")
-            .unwrap_or(Limits::DATA_FORM);
-
-        // Increase internal limit by 1 so multer can limit to `form_limit`.
-        let stream = data.open(form_limit + 1);
-        let constraints = multer::Constraints::new()
-            .size_limit(multer::SizeLimit::new()
-                .whole_stream(form_limit.into())
-                .per_field(form_limit.into()));
-
         Ok(Parser::Multipart(MultipartParser {
             request: req,
             buffer: local_cache_once!(req, SharedStack::new()),
-            source: Multipart::with_reader_with_constraints(stream, boundary, constraints),
+            source: multipart(req, data)?,
             done: false,
         }))
     }
@@ -90,6 +74,28 @@ impl<'r, 'i> Parser<'r, 'i> {
     }
 }
 
+/// Returns a `multer` parser for the `multipart/form-data` body `data` of
+/// `req`, limited by the `data-form` limit.
+pub fn multipart<'r>(req: &'r Request<'_>, data: Data<'r>) -> Result<'r, Multipart<'r>> {
+    let boundary = req.content_type()
+        .ok_or(multer::Error::NoMultipart)?
+        .param("boundary")
+        .ok_or(multer::Error::NoBoundary)?;
+
+    let form_limit = req.limits()
+        .get("data-form")
+        .unwrap_or(Limits::DATA_FORM);
+
+    // Increase internal limit by 1 so multer can limit to `form_limit`.
+    let stream = data.open(form_limit + 1);
+    let constraints = multer::Constraints::new()
+        .size_limit(multer::SizeLimit::new()
+            .whole_stream(form_limit.into())
+            .per_field(form_limit.into()));
+
+    Ok(Multipart::with_reader_with_constraints(stream, boundary, constraints))
+}
+
 impl<'r> RawStrParser<'r> {
     pub fn new(buffer: &'r SharedStack<String>, source: &'r RawStr) -> Self {
         RawStrParser { buffer, source }
@@ -0,0 +1,100 @@
+use std::ops::{Deref, DerefMut};
+
+use crate::Request;
+use crate::request::{FromRequest, Outcome};
+use crate::form::prelude::*;
+
+/// A request guard for parsing the entire query string as a [`FromForm`] type.
+///
+/// Query parameters are typically declared in a route's URI, as in
+/// `/items?<filter..>`. `Query<T>` instead parses every field in the request's
+/// query string into `T` without the route declaring any query parameters,
+/// which is convenient for routes that accept many optional filters.
+///
+/// Parsing is lenient, exactly as with [`Form`]: missing fields use the field
+/// type's default, if any, and extra fields are ignored. For strict parsing,
+/// use `Query<Strict<T>>`. Because route URIs don't mention the query, a route
+/// with a `Query<T>` guard matches requests regardless of their query string.
+///
+/// # Errors
+///
+/// If `T` fails to parse, the guard fails with the [`Errors`] and the status
+/// returned by [`Errors::status()`], typically `422 Unprocessable Entity`. To
+/// handle the errors directly, use `Result<Query<T>, Errors<'_>>`.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use rocket::form::Query;
+///
+/// #[derive(FromForm)]
+/// struct Filters<'r> {
+///     color: Option<&'r str>,
+///     #[field(default = 10)]
+///     limit: usize,
+///     tags: Vec<&'r str>,
+/// }
+///
+/// // Handles `/items`, `/items?color=red`, `/items?tags=a&tags=b&limit=5`...
+/// #[get("/items")]
+/// fn items(filters: Query<Filters<'_>>) -> String {
+///     format!("{:?} {} {:?}", filters.color, filters.limit, filters.tags)
+/// }
+/// ```
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Query<T>(T);
+
+impl<T> Query<T> {
+    /// Consumes `self` and returns the inner value.
+    ///
+    /// Note that since `Query` implements [`Deref`] and [`DerefMut`] with
+    /// target `T`, reading and writing an inner value can be accomplished
+    /// transparently.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate rocket;
+    /// use rocket::form::Query;
+    ///
+    /// #[derive(FromForm)]
+    /// struct Search {
+    ///     q: String,
+    /// }
+    ///
+    /// #[get("/search")]
+    /// fn search(search: Query<Search>) -> String {
+    ///     search.into_inner().q
+    /// }
+    /// ```
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> Deref for Query<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Query<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+#[crate::async_trait]
+impl<'r, T: FromForm<'r>> FromRequest<'r> for Query<T> {
+    type Error = Errors<'r>;
+
+    async fn from_request(req: &'r Request<'_>) -> Outcome<Self, Self::Error> {
+        match Form::<T>::parse_iter(req.query_fields()) {
+            Ok(value) => Outcome::Success(Query(value)),
+            Err(e) => Outcome::Error((e.status(), e)),
+        }
+    }
+}
@@ -0,0 +1,190 @@
+use crate::Request;
+use crate::request::local_cache_once;
+use crate::data::{Data, FromData, Limits, Outcome};
+use crate::http::{RawStr, Status};
+use crate::form::prelude::{*, parser::RawStrParser};
+
+/// A data guard for the fields of a URL-encoded form in wire order.
+///
+/// `RawForm` reads an `application/x-www-form-urlencoded` request body and
+/// exposes its percent-decoded fields as `(name, value)` pairs in exactly the
+/// order they were sent, duplicates included, along with the raw body. This
+/// is useful when the order of fields matters, for instance to verify a
+/// signature computed over a form by a third party, before parsing the fields
+/// into a structure via [`RawForm::parse()`].
+///
+/// # Errors
+///
+/// If the request's content-type is not [`ContentType::Form`], the guard
+/// **forwards** with a status of `415 Unsupported Media Type`. The body is
+/// limited by the `form` data limit, as with [`Form`]. If reading the body
+/// fails or the limit is exceeded, the guard **fails** with the [`Errors`].
+///
+/// [`ContentType::Form`]: crate::http::ContentType::Form
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use rocket::form::RawForm;
+/// use rocket::http::Status;
+///
+/// #[derive(FromForm)]
+/// struct Payment<'r> {
+///     id: &'r str,
+///     amount: u64,
+/// }
+///
+/// # fn verify(signed: &str, signature: &str) -> bool { true }
+/// #[post("/webhook", data = "<form>")]
+/// fn webhook(form: RawForm<'_>) -> Result<String, Status> {
+///     // Sign every field but `signature`, in wire order.
+///     let signed = form.iter()
+///         .filter(|(name, _)| *name != "signature")
+///         .map(|(name, value)| format!("{}={}", name, value))
+///         .collect::<Vec<_>>()
+///         .join("&");
+///
+///     let signature = form.get("signature").ok_or(Status::BadRequest)?;
+///     if !verify(&signed, signature) {
+///         return Err(Status::Forbidden);
+///     }
+///
+///     let payment: Payment<'_> = form.parse().map_err(|_| Status::UnprocessableEntity)?;
+///     Ok(format!("paid {} for {}", payment.amount, payment.id))
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct RawForm<'r> {
+    raw: &'r RawStr,
+    fields: Vec<(&'r str, &'r str)>,
+}
+
+impl<'r> RawForm<'r> {
+    /// Returns the raw, undecoded form body.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use rocket::post;
+    /// use rocket::form::RawForm;
+    ///
+    /// #[post("/", data = "<form>")]
+    /// fn raw(form: RawForm<'_>) -> &str {
+    ///     form.raw().as_str()
+    /// }
+    /// ```
+    pub fn raw(&self) -> &'r RawStr {
+        self.raw
+    }
+
+    /// Returns the percent-decoded `(name, value)` pairs of the form in the
+    /// order they were received. Empty fields, as in `a=1&&b=2`, are skipped.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use rocket::post;
+    /// use rocket::form::RawForm;
+    ///
+    /// #[post("/", data = "<form>")]
+    /// fn count(form: RawForm<'_>) -> String {
+    ///     form.fields().len().to_string()
+    /// }
+    /// ```
+    pub fn fields(&self) -> &[(&'r str, &'r str)] {
+        &self.fields
+    }
+
+    /// Returns an iterator over the percent-decoded `(name, value)` pairs of
+    /// the form in the order they were received.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use rocket::post;
+    /// use rocket::form::RawForm;
+    ///
+    /// #[post("/", data = "<form>")]
+    /// fn names(form: RawForm<'_>) -> String {
+    ///     form.iter().map(|(name, _)| name).collect::<Vec<_>>().join(",")
+    /// }
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = (&'r str, &'r str)> + '_ {
+        self.fields.iter().copied()
+    }
+
+    /// Returns the value of the first field named exactly `name`, if there is
+    /// one.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use rocket::post;
+    /// use rocket::form::RawForm;
+    ///
+    /// #[post("/", data = "<form>")]
+    /// fn name(form: RawForm<'_>) -> Option<&str> {
+    ///     form.get("name")
+    /// }
+    /// ```
+    pub fn get(&self, name: &str) -> Option<&'r str> {
+        self.iter().find(|(n, _)| *n == name).map(|(_, v)| v)
+    }
+
+    /// Leniently parses a `T` from the fields of the form, exactly as
+    /// [`Form<T>`] would.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate rocket;
+    /// use rocket::form::RawForm;
+    ///
+    /// #[derive(FromForm)]
+    /// struct Login<'r> {
+    ///     user: &'r str,
+    /// }
+    ///
+    /// #[post("/", data = "<form>")]
+    /// fn login(form: RawForm<'_>) -> Option<String> {
+    ///     let login: Login<'_> = form.parse().ok()?;
+    ///     Some(login.user.to_string())
+    /// }
+    /// ```
+    pub fn parse<T: FromForm<'r>>(&self) -> Result<'r, T> {
+        Form::<T>::parse_iter(self.iter().map(ValueField::from))
+    }
+}
+
+#[crate::async_trait]
+impl<'r> FromData<'r> for RawForm<'r> {
+    type Error = Errors<'r>;
+
+    async fn from_data(req: &'r Request<'_>, data: Data<'r>) -> Outcome<'r, Self> {
+        if !req.content_type().map_or(false, |ct| ct.is_form()) {
+            return Outcome::Forward((data, Status::UnsupportedMediaType));
+        }
+
+        let limit = req.limits().get("form").unwrap_or(Limits::FORM);
+        let string = match data.open(limit).into_string().await {
+            Ok(string) if string.is_complete() => string.into_inner(),
+            Ok(_) => {
+                let error = Error::from((None, Some(limit.as_u64())));
+                return Outcome::Error((error.status(), error.into()));
+            }
+            Err(e) => {
+                let error = Error::from(e);
+                return Outcome::Error((error.status(), error.into()));
+            }
+        };
+
+        let raw = RawStr::new(local_cache_once!(req, string));
+        let buffer = local_cache_once!(req, SharedStack::new());
+        let fields = RawStrParser::new(buffer, raw)
+            .map(|field| (field.name.source().as_str(), field.value))
+            .collect();
+
+        Outcome::Success(RawForm { raw, fields })
+    }
+}
@@ -92,6 +92,50 @@ fn bool() {
     }
 }
 
+#[test]
+fn int_literals() {
+    assert_values_parse_eq! {
+        &["=1_000", "=0xFF", "=0o17", "=0b1010", "=0XfF_fF", "=+12"] => Vec<IntLiteral<u32>>
+            = vec![1000u32, 255u32, 15u32, 10u32, 65535u32, 12u32],
+        &["=-1_000", "=-0x10", "=0", "=-0b1"] => Vec<IntLiteral<i64>>
+            = vec![-1000i64, -16i64, 0i64, -1i64],
+    }
+
+    assert_parses_fail! {
+        &["=0x"] => IntLiteral<u8>,
+        &["=0x100"] => IntLiteral<u8>,
+        &["=-1"] => IntLiteral<u8>,
+        &["=0x-1"] => IntLiteral<i8>,
+        &["=--1"] => IntLiteral<i8>,
+        &["=0b102"] => IntLiteral<u8>,
+        &["=1.0"] => IntLiteral<u8>,
+        &["=_"] => IntLiteral<u8>,
+    }
+}
+
+#[test]
+fn comma_separated() {
+    assert_values_parse_eq! {
+        &["=1,2,3"] => CommaSeparated<usize> = std::vec![1, 2, 3].into(),
+        &["=7"] => CommaSeparated<usize> = std::vec![7].into(),
+        &["= 1 , 2 "] => CommaSeparated<u8> = std::vec![1, 2].into(),
+        &["="] => CommaSeparated<usize> = CommaSeparated::default(),
+        &["=a,b,c"] => CommaSeparated<&str> = std::vec!["a", "b", "c"].into(),
+        &["=1,2", "=3"] => Vec<CommaSeparated<usize>>
+            = std::vec![std::vec![1, 2].into(), std::vec![3].into()],
+    }
+
+    assert_parses_fail! {
+        &["=1,x,3"] => CommaSeparated<usize>,
+        &["=1,,3"] => CommaSeparated<usize>,
+        &["=1,256"] => CommaSeparated<u8>,
+        &[] => CommaSeparated<usize>,
+    }
+
+    let errors = parse::<CommaSeparated<u8>>(&["=x,2,256"]).unwrap_err();
+    assert_eq!(errors.len(), 2);
+}
+
 #[test]
 fn defaults() {
     assert_values_parse_eq! {
@@ -762,6 +762,10 @@ pub fn one_of<'v, V, I, R>(value: V, items: R) -> Result<'v, ()>
 /// File type validator: succeeds when a [`TempFile`] has the Content-Type
 /// `content_type`.
 ///
+/// Note that the Content-Type of a file is declared by the client and is not
+/// verified. To validate the actual contents of the file, use
+/// [`content_is()`].
+///
 /// On error, returns a validation error with one of the following messages:
 ///
 /// ```text
@@ -804,6 +808,48 @@ pub fn ext<'v>(file: &TempFile<'_>, r#type: ContentType) -> Result<'v, ()> {
     Err(Error::validation(msg))?
 }
 
+/// File contents validator: succeeds when the contents of a [`TempFile`] are
+/// of the Content-Type `content_type`, as determined by [`TempFile::sniff()`].
+///
+/// Unlike [`ext()`], which only checks the Content-Type _declared_ by the
+/// client, this validator inspects the magic number at the start of the file.
+/// It thus rejects mislabeled uploads, such as an executable declared to be a
+/// PDF. For best results, use both validators together.
+///
+/// On error, returns a validation error with one of the following messages:
+///
+/// ```text
+/// // the file's contents are of another recognized type
+/// invalid file contents: $sniffed, must be $type
+///
+/// // the file's contents are unrecognized
+/// file contents must be $type
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// use rocket::form::FromForm;
+/// use rocket::http::ContentType;
+/// use rocket::fs::TempFile;
+///
+/// #[derive(FromForm)]
+/// struct Foo<'r> {
+///     #[field(validate = ext(ContentType::PDF))]
+///     #[field(validate = content_is(ContentType::PDF))]
+///     document: TempFile<'r>,
+/// }
+/// ```
+pub fn content_is<'v>(file: &TempFile<'_>, r#type: ContentType) -> Result<'v, ()> {
+    let msg = match file.sniff() {
+        Some(sniffed) if sniffed == r#type => return Ok(()),
+        Some(sniffed) => format!("invalid file contents: {}, must be {}", sniffed, r#type),
+        None => format!("file contents must be {}", r#type),
+    };
+
+    Err(Error::validation(msg))?
+}
+
 /// With validator: succeeds when an arbitrary function or closure does.
 ///
 /// This is the most generic validator and, for readability, should only be used
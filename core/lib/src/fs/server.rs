@@ -3,7 +3,7 @@ use std::path::{PathBuf, Path};
 use crate::{Request, Data};
 use crate::http::{Method, Status, uri::Segments, ext::IntoOwned};
 use crate::route::{Route, Handler, Outcome};
-use crate::response::{Redirect, Responder};
+use crate::response::{self, Redirect, Responder};
 use crate::outcome::IntoOutcome;
 use crate::fs::NamedFile;
 
@@ -60,17 +60,31 @@ use crate::fs::NamedFile;
 ///     rocket::build().mount("/", FileServer::from(relative!("static")))
 /// }
 /// ```
+///
+/// ## Fingerprinted Assets
+///
+/// Files whose names contain a hash of their contents, as generated by most
+/// front-end build tools, never change and can be cached indefinitely. With
+/// the `fs-immutable` feature enabled, use
+/// [`FileServer::immutable_pattern()`] to mark such files as immutable.
 #[derive(Debug, Clone)]
 pub struct FileServer {
     root: PathBuf,
     options: Options,
     rank: isize,
+    #[cfg(feature = "fs-immutable")]
+    immutable: Option<regex::Regex>,
 }
 
 impl FileServer {
     /// The default rank use by `FileServer` routes.
     const DEFAULT_RANK: isize = 10;
 
+    /// The `Cache-Control` header value for files matching the
+    /// [immutable pattern](FileServer::immutable_pattern()).
+    #[cfg(feature = "fs-immutable")]
+    const IMMUTABLE_CACHE_CONTROL: &'static str = "public, max-age=31536000, immutable";
+
     /// Constructs a new `FileServer` that serves files from the file system
     /// `path`. By default, [`Options::Index`] is set, and the generated routes
     /// have a rank of `10`. To serve static files with other options, use
@@ -159,7 +173,13 @@ impl FileServer {
             }
         }
 
-        FileServer { root: path.into(), options, rank: Self::DEFAULT_RANK }
+        FileServer {
+            root: path.into(),
+            options,
+            rank: Self::DEFAULT_RANK,
+            #[cfg(feature = "fs-immutable")]
+            immutable: None,
+        }
     }
 
     /// Sets the rank for generated routes to `rank`.
@@ -179,6 +199,77 @@ impl FileServer {
         self.rank = rank;
         self
     }
+
+    /// Marks files whose path matches the regular expression `pattern` as
+    /// immutable, returning the modified `FileServer`.
+    ///
+    /// Responses for matching files include a `Cache-Control` header of
+    /// `public, max-age=31536000, immutable`, instructing clients and caches
+    /// to cache the file for a year without revalidation. The pattern is
+    /// matched against the path of the file relative to the served directory,
+    /// for instance, `js/app.3f2c8a1d.js`, and may match any part of it.
+    ///
+    /// Only use this for files whose names change whenever their contents do,
+    /// such as those fingerprinted with a content hash by a build tool.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `pattern` is not a valid regular expression.
+    ///
+    /// # Example
+    ///
+    /// Cache files with an 8-digit hexadecimal hash in their name, such as
+    /// `app.3f2c8a1d.js`, indefinitely:
+    ///
+    /// ```rust,no_run
+    /// # #[macro_use] extern crate rocket;
+    /// use rocket::fs::FileServer;
+    ///
+    /// #[launch]
+    /// fn rocket() -> _ {
+    ///     let server = FileServer::from("/www/dist").immutable_pattern(r"\.[0-9a-f]{8}\.");
+    ///     rocket::build().mount("/", server)
+    /// }
+    /// ```
+    #[track_caller]
+    #[cfg(feature = "fs-immutable")]
+    #[cfg_attr(nightly, doc(cfg(feature = "fs-immutable")))]
+    pub fn immutable_pattern(mut self, pattern: &str) -> Self {
+        use crate::yansi::Paint;
+
+        match regex::Regex::new(pattern) {
+            Ok(regex) => self.immutable = Some(regex),
+            Err(e) => {
+                error!("FileServer immutable pattern '{}' is invalid.", pattern.primary());
+                info_!("{}", e);
+                panic!("invalid immutable pattern: refusing to continue");
+            }
+        }
+
+        self
+    }
+
+    /// Returns `true` if the file at the relative path `path` is immutable.
+    #[cfg(feature = "fs-immutable")]
+    fn is_immutable(&self, path: &Path) -> bool {
+        self.immutable.as_ref().map_or(false, |regex| {
+            regex.is_match(&path.to_string_lossy().replace('\\', "/"))
+        })
+    }
+
+    /// Opens the file at the relative path `path` and responds with it,
+    /// marking the response as immutable if `path` matches the pattern.
+    async fn respond_with<'r>(&self, req: &'r Request<'_>, path: PathBuf) -> response::Result<'r> {
+        #[allow(unused_mut)]
+        let mut response = NamedFile::open(self.root.join(&path)).await.respond_to(req)?;
+
+        #[cfg(feature = "fs-immutable")]
+        if self.is_immutable(&path) {
+            response.set_raw_header("Cache-Control", Self::IMMUTABLE_CACHE_CONTROL);
+        }
+
+        Ok(response)
+    }
 }
 
 impl From<FileServer> for Vec<Route> {
@@ -214,11 +305,10 @@ impl Handler for FileServer {
         // Get the segments as a `PathBuf`, allowing dotfiles requested.
         let allow_dotfiles = options.contains(Options::DotFiles);
         let path = req.segments::<Segments<'_, Path>>(0..).ok()
-            .and_then(|segments| segments.to_path_buf(allow_dotfiles).ok())
-            .map(|path| self.root.join(path));
+            .and_then(|segments| segments.to_path_buf(allow_dotfiles).ok());
 
         match path {
-            Some(p) if p.is_dir() => {
+            Some(p) if self.root.join(&p).is_dir() => {
                 // Normalize '/a/b/foo' to '/a/b/foo/'.
                 if options.contains(Options::NormalizeDirs) && !req.uri().path().ends_with('/') {
                     let normal = req.uri().map_path(|p| format!("{}/", p))
@@ -234,12 +324,12 @@ impl Handler for FileServer {
                     return Outcome::forward(data, Status::NotFound);
                 }
 
-                let index = NamedFile::open(p.join("index.html")).await;
-                index.respond_to(req).or_forward((data, Status::NotFound))
+                let index = self.respond_with(req, p.join("index.html")).await;
+                index.or_forward((data, Status::NotFound))
             },
             Some(p) => {
-                let file = NamedFile::open(p).await;
-                file.respond_to(req).or_forward((data, Status::NotFound))
+                let file = self.respond_with(req, p).await;
+                file.or_forward((data, Status::NotFound))
             }
             None => Outcome::forward(data, Status::NotFound),
         }
@@ -3,14 +3,14 @@ use std::path::{PathBuf, Path};
 
 use crate::Request;
 use crate::http::{ContentType, Status};
-use crate::data::{self, FromData, Data, Capped, N, Limits};
+use crate::data::{self, FromData, Data, Capped, N, Limits, ByteUnit};
 use crate::form::{FromFormField, ValueField, DataField, error::Errors};
 use crate::outcome::IntoOutcome;
 use crate::fs::FileName;
 
 use tokio::task;
 use tokio::fs::{self, File};
-use tokio::io::{AsyncBufRead, BufReader};
+use tokio::io::{AsyncBufRead, AsyncWrite, BufReader};
 use tempfile::{NamedTempFile, TempPath};
 use either::Either;
 
@@ -107,6 +107,7 @@ pub enum TempFile<'v> {
         content_type: Option<ContentType>,
         path: Either<TempPath, PathBuf>,
         len: u64,
+        head: Vec<u8>,
     },
     #[doc(hidden)]
     Buffered {
@@ -194,7 +195,8 @@ impl<'v> TempFile<'v> {
                     file_name: None,
                     content_type: None,
                     path: Either::Right(new_path),
-                    len: content.len() as u64
+                    len: content.len() as u64,
+                    head: TempFile::head_of(content),
                 };
             }
         }
@@ -263,7 +265,8 @@ impl<'v> TempFile<'v> {
                     file_name: None,
                     content_type: None,
                     path: Either::Right(path.to_path_buf()),
-                    len: content.len() as u64
+                    len: content.len() as u64,
+                    head: TempFile::head_of(content),
                 };
             }
         }
@@ -356,6 +359,36 @@ impl<'v> TempFile<'v> {
         }
     }
 
+    /// Streams the contents of the file into `writer`, returning the number of
+    /// bytes written. The writer is flushed once streaming completes.
+    ///
+    /// This allows forwarding an upload to an arbitrary sink, such as a
+    /// network upload, without first persisting it. To avoid writing the data
+    /// to disk entirely, stream the request body directly with
+    /// [`Data::stream_to_writer()`](crate::Data::stream_to_writer()) instead.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate rocket;
+    /// use rocket::fs::TempFile;
+    ///
+    /// #[post("/", data = "<file>")]
+    /// async fn handle(file: TempFile<'_>) -> std::io::Result<String> {
+    ///     let sink = rocket::tokio::io::sink();
+    ///     let written = file.stream_to_writer(sink).await?;
+    ///     Ok(format!("forwarded {} bytes", written))
+    /// }
+    /// # let file = TempFile::Buffered { content: "hi".as_bytes() };
+    /// # rocket::async_test(handle(file)).unwrap();
+    /// ```
+    pub async fn stream_to_writer<W>(&self, mut writer: W) -> io::Result<u64>
+        where W: AsyncWrite + Unpin
+    {
+        let mut reader = self.open().await?;
+        tokio::io::copy_buf(&mut reader, &mut writer).await
+    }
+
     /// Returns whether the file is empty.
     ///
     /// This is equivalent to `file.len() == 0`.
@@ -503,6 +536,69 @@ impl<'v> TempFile<'v> {
         }
     }
 
+    /// Returns the Content-Type of the file as determined by the magic number
+    /// at the start of its contents, if it is recognized.
+    ///
+    /// Unlike [`TempFile::content_type()`], which returns the Content-Type
+    /// _declared_ by the client, the returned value is derived from the actual
+    /// contents of the file. Recognized types are PDF, PNG, JPEG, GIF, WEBP,
+    /// TIFF, icon, WAV, OGG, FLAC, MP4, WOFF, WOFF2, WASM, ZIP, GZIP, and TAR.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use rocket::post;
+    /// use rocket::fs::TempFile;
+    /// use rocket::http::ContentType;
+    ///
+    /// #[post("/", data = "<file>")]
+    /// fn handle(file: TempFile<'_>) -> &'static str {
+    ///     match file.sniff() {
+    ///         Some(ct) if ct == ContentType::PNG => "a PNG",
+    ///         Some(_) => "something else",
+    ///         None => "unrecognized",
+    ///     }
+    /// }
+    /// ```
+    pub fn sniff(&self) -> Option<ContentType> {
+        let bytes = match self {
+            TempFile::File { head, .. } => head.as_slice(),
+            TempFile::Buffered { content } => content,
+        };
+
+        let at = |i: usize, magic: &[u8]| bytes.get(i..i + magic.len()) == Some(magic);
+        let content_type = match () {
+            _ if at(0, b"%PDF-") => ContentType::PDF,
+            _ if at(0, b"\x89PNG\r\n\x1a\n") => ContentType::PNG,
+            _ if at(0, b"\xFF\xD8\xFF") => ContentType::JPEG,
+            _ if at(0, b"GIF87a") || at(0, b"GIF89a") => ContentType::GIF,
+            _ if at(0, b"RIFF") && at(8, b"WEBP") => ContentType::WEBP,
+            _ if at(0, b"RIFF") && at(8, b"WAVE") => ContentType::WAV,
+            _ if at(0, b"II*\0") || at(0, b"MM\0*") => ContentType::TIFF,
+            _ if at(0, b"\0\0\x01\0") => ContentType::Icon,
+            _ if at(0, b"OggS") => ContentType::OGG,
+            _ if at(0, b"fLaC") => ContentType::FLAC,
+            _ if at(4, b"ftyp") && ["isom", "iso2", "mp41", "mp42", "avc1"]
+                .iter().any(|brand| at(8, brand.as_bytes())) => ContentType::MP4,
+            _ if at(0, b"wOFF") => ContentType::WOFF,
+            _ if at(0, b"wOF2") => ContentType::WOFF2,
+            _ if at(0, b"\0asm") => ContentType::WASM,
+            _ if at(0, b"PK\x03\x04") => ContentType::ZIP,
+            _ if at(0, b"\x1f\x8b") => ContentType::GZIP,
+            _ if at(257, b"ustar") => ContentType::TAR,
+            _ => return None,
+        };
+
+        Some(content_type)
+    }
+
+    /// The number of bytes at the start of a file retained for sniffing.
+    const HEAD_LEN: usize = 512;
+
+    fn head_of(content: &[u8]) -> Vec<u8> {
+        content[..content.len().min(Self::HEAD_LEN)].to_vec()
+    }
+
     async fn from<'a>(
         req: &Request<'_>,
         data: Data<'_>,
@@ -516,19 +612,34 @@ impl<'v> TempFile<'v> {
             .unwrap_or(Limits::FILE);
 
         let temp_dir = req.rocket().config().temp_dir.relative();
+        TempFile::stream_from(data, limit, temp_dir, file_name, content_type).await
+    }
+
+    /// Streams at most `limit` bytes of `data` into a new temporary file in
+    /// `temp_dir`. The file is deleted if streaming fails.
+    pub(crate) async fn stream_from<'a>(
+        mut data: Data<'_>,
+        limit: ByteUnit,
+        temp_dir: PathBuf,
+        file_name: Option<&'a FileName>,
+        content_type: Option<ContentType>,
+    ) -> io::Result<Capped<TempFile<'a>>> {
         let file = task::spawn_blocking(move || NamedTempFile::new_in(temp_dir));
         let file = file.await;
         let file = file.map_err(|_| io::Error::new(io::ErrorKind::Other, "spawn_block panic"))??;
         let (file, temp_path) = file.into_parts();
 
+        let mut head = data.peek(Self::HEAD_LEN).await.to_vec();
         let mut file = File::from_std(file);
         let fut = data.open(limit).stream_to(tokio::io::BufWriter::new(&mut file));
         let n = fut.await;
         let n = n?;
+        head.truncate(n.written.try_into().unwrap_or(usize::MAX));
         let temp_file = TempFile::File {
             content_type, file_name,
             path: Either::Left(temp_path),
             len: n.written,
+            head,
         };
 
         Ok(Capped::new(temp_file, n))
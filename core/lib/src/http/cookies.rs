@@ -71,6 +71,9 @@ pub use cookie::{Cookie, SameSite, Iter};
 /// [`add_private()`]: #method.add_private
 /// [`remove()`]: #method.remove
 /// [`remove_private()`]: #method.remove_private
+/// [`get_signed()`]: #method.get_signed
+/// [`add_signed()`]: #method.add_signed
+/// [`remove_signed()`]: #method.remove_signed
 ///
 /// ## Examples
 ///
@@ -135,9 +138,22 @@ pub use cookie::{Cookie, SameSite, Iter};
 /// collection via the [`get_private()`], [`add_private()`], and
 /// [`remove_private()`] methods.
 ///
-/// ## Encryption Key
+/// # Signed Cookies
 ///
-/// To encrypt private cookies, Rocket uses the 256-bit key specified in the
+/// _Signed_ cookies are regular cookies whose values are accompanied by a
+/// signature computed using the secret key. Unlike private cookies, signed
+/// cookies are _not_ encrypted: clients can read their values but cannot
+/// tamper with or manufacture them. Signed cookies are useful for values a
+/// client may legitimately inspect, such as a non-secret user ID, but must not
+/// be able to forge.
+///
+/// Signed cookies can be retrieved, added, and removed from a `CookieJar`
+/// collection via the [`get_signed()`], [`add_signed()`], and
+/// [`remove_signed()`] methods.
+///
+/// ## Secret Key
+///
+/// To sign and encrypt cookies, Rocket uses the 256-bit key specified in the
 /// `secret_key` configuration parameter. If one is not specified, Rocket will
 /// automatically generate a fresh key. Note, however, that a private cookie can
 /// only be decrypted with the same key with which it was encrypted. As such, it
@@ -165,10 +181,20 @@ pub(crate) struct CookieState<'a> {
 
 #[derive(Clone)]
 enum Op {
-    Add(Cookie<'static>, bool),
+    Add(Cookie<'static>, Protection),
     Remove(Cookie<'static>),
 }
 
+/// How the value of an added cookie is protected.
+#[derive(Clone, Copy)]
+enum Protection {
+    None,
+    #[cfg(feature = "secrets")]
+    Signed,
+    #[cfg(feature = "secrets")]
+    Private,
+}
+
 impl<'a> CookieJar<'a> {
     pub(crate) fn new(base: Option<cookie::CookieJar>, rocket: &'a Rocket<Orbit>) -> Self {
         CookieJar {
@@ -230,6 +256,32 @@ impl<'a> CookieJar<'a> {
         self.jar.private(&self.state.config.secret_key.key).get(name)
     }
 
+    /// Retrieves the _original_ `Cookie` inside this collection with the name
+    /// `name` and verifies the cookie's signature. If the cookie cannot be
+    /// found or its signature fails to verify, `None` is returned. The
+    /// returned cookie's value is the original, unsigned value.
+    ///
+    /// **Note:** This method _does not_ observe changes made via additions and
+    /// removals to the cookie jar. To observe those changes, use
+    /// [`CookieJar::get_pending()`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate rocket;
+    /// use rocket::http::CookieJar;
+    ///
+    /// #[get("/")]
+    /// fn handler(jar: &CookieJar<'_>) {
+    ///     let cookie = jar.get_signed("name");
+    /// }
+    /// ```
+    #[cfg(feature = "secrets")]
+    #[cfg_attr(nightly, doc(cfg(feature = "secrets")))]
+    pub fn get_signed(&self, name: &str) -> Option<Cookie<'static>> {
+        self.jar.signed(&self.state.config.secret_key.key).get(name)
+    }
+
     /// Returns a reference to the _original or pending_ `Cookie` inside this
     /// container with the name `name`, irrespective of whether the cookie was
     /// private or not. If no such cookie exists, returns `None`.
@@ -262,7 +314,9 @@ impl<'a> CookieJar<'a> {
         drop(ops);
 
         #[cfg(feature = "secrets")] {
-            self.get_private(name).or_else(|| self.get(name).cloned())
+            self.get_private(name)
+                .or_else(|| self.get_signed(name))
+                .or_else(|| self.get(name).cloned())
         }
 
         #[cfg(not(feature = "secrets"))] {
@@ -305,7 +359,7 @@ impl<'a> CookieJar<'a> {
     pub fn add<C: Into<Cookie<'static>>>(&self, cookie: C) {
         let mut cookie = cookie.into();
         self.set_defaults(&mut cookie);
-        self.ops.lock().push(Op::Add(cookie, false));
+        self.ops.lock().push(Op::Add(cookie, Protection::None));
     }
 
     /// Adds `cookie` to the collection. The cookie's value is encrypted with
@@ -345,7 +399,36 @@ impl<'a> CookieJar<'a> {
     pub fn add_private<C: Into<Cookie<'static>>>(&self, cookie: C) {
         let mut cookie = cookie.into();
         self.set_private_defaults(&mut cookie);
-        self.ops.lock().push(Op::Add(cookie, true));
+        self.ops.lock().push(Op::Add(cookie, Protection::Private));
+    }
+
+    /// Adds `cookie` to the collection. The cookie's value is signed assuring
+    /// integrity and authenticity but _not_ confidentiality: clients can read
+    /// the value but cannot tamper with or manufacture it. The cookie can later
+    /// be retrieved using [`get_signed`](#method.get_signed) and removed using
+    /// [`remove_signed`](#method.remove_signed).
+    ///
+    /// The same defaults as those applied by
+    /// [`add_private()`](#method.add_private) are set on `cookie` before being
+    /// added to `self`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate rocket;
+    /// use rocket::http::CookieJar;
+    ///
+    /// #[get("/")]
+    /// fn handler(jar: &CookieJar<'_>) {
+    ///     jar.add_signed(("user_id", "1337"));
+    /// }
+    /// ```
+    #[cfg(feature = "secrets")]
+    #[cfg_attr(nightly, doc(cfg(feature = "secrets")))]
+    pub fn add_signed<C: Into<Cookie<'static>>>(&self, cookie: C) {
+        let mut cookie = cookie.into();
+        self.set_private_defaults(&mut cookie);
+        self.ops.lock().push(Op::Add(cookie, Protection::Signed));
     }
 
     /// Removes `cookie` from this collection and generates a "removal" cookie
@@ -434,6 +517,32 @@ impl<'a> CookieJar<'a> {
         self.ops.lock().push(Op::Remove(cookie));
     }
 
+    /// Removes the signed `cookie` from the collection.
+    ///
+    /// As with [`remove()`](#method.remove), `cookie` must contain the same
+    /// `path` and `domain` as the cookie that was originally set, and the same
+    /// defaults are applied when they are unspecified.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate rocket;
+    /// use rocket::http::{CookieJar, Cookie};
+    ///
+    /// #[get("/")]
+    /// fn handler(jar: &CookieJar<'_>) {
+    ///     jar.remove_signed("user_id");
+    ///     jar.remove_signed(Cookie::build("name").path("/login"));
+    /// }
+    /// ```
+    #[cfg(feature = "secrets")]
+    #[cfg_attr(nightly, doc(cfg(feature = "secrets")))]
+    pub fn remove_signed<C: Into<Cookie<'static>>>(&self, cookie: C) {
+        let mut cookie = cookie.into();
+        Self::set_removal_defaults(&mut cookie);
+        self.ops.lock().push(Op::Remove(cookie));
+    }
+
     /// Returns an iterator over all of the _original_ cookies present in this
     /// collection.
     ///
@@ -471,11 +580,15 @@ impl<'a> CookieJar<'a> {
 
         for op in ops {
             match op {
-                Op::Add(c, false) => jar.add(c),
+                Op::Add(c, Protection::None) => jar.add(c),
                 #[cfg(feature = "secrets")]
-                Op::Add(c, true) => {
+                Op::Add(c, Protection::Private) => {
                     jar.private_mut(&self.state.config.secret_key.key).add(c);
                 }
+                #[cfg(feature = "secrets")]
+                Op::Add(c, Protection::Signed) => {
+                    jar.signed_mut(&self.state.config.secret_key.key).add(c);
+                }
                 Op::Remove(mut c) => {
                     if self.jar.get(c.name()).is_some() {
                         c.make_removal();
@@ -506,6 +619,14 @@ impl<'a> CookieJar<'a> {
         self.jar.private_mut(&self.state.config.secret_key.key).add_original(cookie);
     }
 
+    /// Adds an original, signed `cookie` to the collection.
+    #[cfg(feature = "secrets")]
+    #[cfg_attr(nightly, doc(cfg(feature = "secrets")))]
+    #[inline(always)]
+    pub(crate) fn add_original_signed(&mut self, cookie: Cookie<'static>) {
+        self.jar.signed_mut(&self.state.config.secret_key.key).add_original(cookie);
+    }
+
     /// For each property mentioned below, this method checks if there is a
     /// provided value and if there is none, sets a default value. Default
     /// values are:
@@ -0,0 +1,185 @@
+use std::io::Cursor;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::{Rocket, Request, Response, Route, Build};
+use crate::fairing::{self, Fairing, Info, Kind};
+use crate::http::{Header, Method, Status, StatusClass};
+use crate::idempotency::store::{Store, Key, Cached};
+
+/// Fairing which replays cached responses for repeated idempotency keys.
+///
+/// See the [module level docs](crate::idempotency) for details.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use std::time::Duration;
+/// use rocket::idempotency::Idempotency;
+///
+/// #[launch]
+/// fn rocket() -> _ {
+///     rocket::build().attach(Idempotency::new().ttl(Duration::from_secs(600)))
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Idempotency {
+    ttl: Duration,
+    capacity: usize,
+}
+
+/// The result of looking up a request's idempotency key. Stored in
+/// request-local state by `on_intercept`.
+#[derive(Default)]
+struct Lookup {
+    hit: Option<Arc<Cached>>,
+}
+
+impl Idempotency {
+    /// The name of the header carrying the idempotency key.
+    pub const HEADER: &'static str = "Idempotency-Key";
+
+    /// The default time-to-live of cached responses: 24 hours.
+    pub const DEFAULT_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+
+    /// The default maximum number of cached responses: 10,000.
+    pub const DEFAULT_CAPACITY: usize = 10_000;
+
+    /// Returns a new `Idempotency` fairing which caches up to
+    /// [`Idempotency::DEFAULT_CAPACITY`] responses for
+    /// [`Idempotency::DEFAULT_TTL`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::idempotency::Idempotency;
+    ///
+    /// let fairing = Idempotency::new();
+    /// ```
+    pub fn new() -> Self {
+        Idempotency { ttl: Self::DEFAULT_TTL, capacity: Self::DEFAULT_CAPACITY }
+    }
+
+    /// Sets the duration for which successful responses are cached to `ttl`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use rocket::idempotency::Idempotency;
+    ///
+    /// let fairing = Idempotency::new().ttl(Duration::from_secs(60));
+    /// ```
+    pub fn ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    /// Sets the maximum number of responses cached at once to `capacity`.
+    /// When the cache is full, the response which expires soonest is evicted
+    /// to make room for a new one. A `capacity` of `0` disables caching.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::idempotency::Idempotency;
+    ///
+    /// let fairing = Idempotency::new().capacity(1024);
+    /// ```
+    pub fn capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity;
+        self
+    }
+
+    fn applies_to(method: Method) -> bool {
+        matches!(method, Method::Post | Method::Patch)
+    }
+
+    /// Returns the cache key for `req` handled by `route`, if `req` carries an
+    /// idempotency key and has an applicable method.
+    fn key(req: &Request<'_>, route: &Route) -> Option<Key> {
+        if !Self::applies_to(req.method()) {
+            return None;
+        }
+
+        let key = req.headers().get_one(Self::HEADER)?;
+        Some(Key::new(req, route, key))
+    }
+
+    /// Looks up the cached response for `req`'s idempotency key, if any.
+    fn lookup(req: &Request<'_>) -> Lookup {
+        let Some(store) = req.rocket().state::<Store>() else {
+            return Lookup::default();
+        };
+
+        // Responses are keyed on the route that handled the request, which
+        // isn't known until the request is routed. Any of the matching routes
+        // may have handled a previous request with the same key.
+        let mut routes = req.rocket().router.route(req);
+        let hit = routes.find_map(|route| store.get(&Self::key(req, route)?));
+        Lookup { hit }
+    }
+}
+
+impl Default for Idempotency {
+    fn default() -> Self {
+        Idempotency::new()
+    }
+}
+
+#[crate::async_trait]
+impl Fairing for Idempotency {
+    fn info(&self) -> Info {
+        Info {
+            name: "Idempotency",
+            kind: Kind::Ignite | Kind::Intercept | Kind::Response | Kind::Singleton,
+        }
+    }
+
+    async fn on_ignite(&self, rocket: Rocket<Build>) -> fairing::Result {
+        Ok(rocket.manage(Store::new(self.ttl, self.capacity)))
+    }
+
+    async fn on_intercept<'r>(&self, req: &'r Request<'_>) -> Option<Response<'r>> {
+        // Replay the cached response, if any, without routing the request.
+        let lookup = req.local_cache(|| Self::lookup(req));
+        lookup.hit.as_ref().map(|cached| cached.to_response())
+    }
+
+    async fn on_response<'r>(&self, req: &'r Request<'_>, res: &mut Response<'r>) {
+        if req.local_cache(Lookup::default).hit.is_some() {
+            return;
+        }
+
+        // Key on the route that actually handled the request.
+        let Some(key) = req.route().and_then(|route| Self::key(req, route)) else {
+            return;
+        };
+
+        if res.status().class() != StatusClass::Success || res.body().preset_size().is_none() {
+            return;
+        }
+
+        let Some(store) = req.rocket().state::<Store>() else {
+            return;
+        };
+
+        let body: Arc<[u8]> = match res.body_mut().to_bytes().await {
+            Ok(bytes) => bytes.into(),
+            Err(e) => {
+                error_!("Failed to read response for idempotency key '{}': {}", key.key, e);
+                res.set_status(Status::InternalServerError);
+                res.set_sized_body(0, Cursor::new(&[]));
+                return;
+            }
+        };
+
+        let headers = res.headers().iter()
+            .map(|h| Header::new(h.name().as_str().to_owned(), h.value().to_owned()))
+            .collect();
+
+        res.set_sized_body(body.len(), Cursor::new(body.clone()));
+        store.insert(key, Cached { status: res.status(), headers, body });
+    }
+}
@@ -0,0 +1,73 @@
+//! Deduplication of retried requests via the `Idempotency-Key` header.
+//!
+//! The [`Idempotency`] fairing allows clients to safely retry non-idempotent
+//! requests, such as a `POST` which initiates a payment. A client attaches a
+//! unique `Idempotency-Key` header to a request. The first request with a given
+//! key is handled as usual; if the response is successful, it is cached.
+//! Subsequent requests with the same key to the same route are answered with
+//! the cached response _without_ invoking the route's handler.
+//!
+//! # Usage
+//!
+//! Attach an instance of [`Idempotency`]:
+//!
+//! ```rust
+//! # #[macro_use] extern crate rocket;
+//! use std::time::Duration;
+//! use rocket::idempotency::Idempotency;
+//!
+//! #[post("/charge")]
+//! fn charge() -> &'static str {
+//!     /* charge the customer exactly once */
+//!     "charged"
+//! }
+//!
+//! #[launch]
+//! fn rocket() -> _ {
+//!     rocket::build()
+//!         .mount("/", routes![charge])
+//!         .attach(Idempotency::new().ttl(Duration::from_secs(60 * 60)))
+//! }
+//! ```
+//!
+//! # Semantics
+//!
+//!   * Only `POST` and `PATCH` requests are considered. Requests with other
+//!     methods are unaffected, even if they carry an `Idempotency-Key`.
+//!
+//!   * Responses are keyed on the request method and URI, the route which
+//!     handled the request, and the value of the `Idempotency-Key` header. A
+//!     request which is forwarded is keyed on the route it is forwarded to.
+//!     The same key may thus be reused across different routes. The request
+//!     body is _not_ considered.
+//!
+//!   * Only successful (`2xx`) responses with a body of known size are cached.
+//!     Error responses and streaming responses are never cached, so a failed
+//!     request can be retried with the same key.
+//!
+//!   * Cached responses expire after the configured [TTL](Idempotency::ttl()).
+//!     At most [`capacity`](Idempotency::capacity()) responses are cached at
+//!     once; when the cache is full, the response which expires soonest is
+//!     evicted to make room for a new one.
+//!
+//!   * Replayed responses include an `Idempotent-Replayed: true` header. They
+//!     are sent before the request is routed, from the fairing's
+//!     [intercept](crate::fairing::Fairing#intercept) callback, so no request
+//!     guards, data guards, or handlers run for them.
+//!
+//!   * Concurrent requests with the same key, where neither has completed, are
+//!     both handled; the response for the latter to complete is cached.
+//!
+//! # Store
+//!
+//! On ignition, the fairing places a [`Store`] in managed state. The store can
+//! be retrieved via [`State`](crate::State) or [`Rocket::state()`] to inspect
+//! or clear cached responses.
+//!
+//! [`Rocket::state()`]: crate::Rocket::state()
+
+mod fairing;
+mod store;
+
+pub use self::fairing::Idempotency;
+pub use self::store::Store;
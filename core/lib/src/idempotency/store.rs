@@ -0,0 +1,222 @@
+use std::collections::HashMap;
+use std::io::Cursor;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use parking_lot::Mutex;
+
+use crate::{Request, Response, Route};
+use crate::http::{Header, Method, Status};
+
+/// A store of cached responses, keyed by route and idempotency key.
+///
+/// A `Store` is placed in managed state by the [`Idempotency`] fairing.
+/// Cloning a `Store` is cheap; all clones refer to the same underlying cache.
+///
+/// A store holds at most [`capacity()`](Store::capacity()) responses. When a
+/// response is cached in a full store, expired responses are removed and, if
+/// the store is still full, so is the response which expires soonest.
+///
+/// [`Idempotency`]: crate::idempotency::Idempotency
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use rocket::State;
+/// use rocket::idempotency::Store;
+///
+/// #[delete("/idempotency")]
+/// fn clear(store: &State<Store>) -> String {
+///     let count = store.len();
+///     store.clear();
+///     format!("removed {} cached responses", count)
+/// }
+/// ```
+#[derive(Clone)]
+pub struct Store {
+    ttl: Duration,
+    capacity: usize,
+    entries: Arc<Mutex<HashMap<Key, Entry>>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct Key {
+    method: Method,
+    uri: String,
+    route: String,
+    pub(crate) key: String,
+}
+
+impl Key {
+    /// The key for a `req` with idempotency key `key` handled by `route`.
+    pub(crate) fn new(req: &Request<'_>, route: &Route, key: &str) -> Self {
+        // Routes may share a URI and differ only in rank or format.
+        let route = format!("{} {} {:?}", route.uri, route.rank, route.format);
+        let uri = req.uri().to_string();
+        Key { method: req.method(), uri, route, key: key.into() }
+    }
+}
+
+struct Entry {
+    expires: Instant,
+    response: Arc<Cached>,
+}
+
+/// A successful response, fully read into memory.
+pub(crate) struct Cached {
+    pub(crate) status: Status,
+    pub(crate) headers: Vec<Header<'static>>,
+    pub(crate) body: Arc<[u8]>,
+}
+
+impl Cached {
+    pub(crate) fn to_response(&self) -> Response<'static> {
+        let mut builder = Response::build();
+        builder.status(self.status);
+        for header in &self.headers {
+            builder.header_adjoin(header.clone());
+        }
+
+        builder.raw_header("Idempotent-Replayed", "true")
+            .sized_body(self.body.len(), Cursor::new(self.body.clone()))
+            .finalize()
+    }
+}
+
+impl Store {
+    pub(crate) fn new(ttl: Duration, capacity: usize) -> Self {
+        Store { ttl, capacity, entries: Arc::default() }
+    }
+
+    /// Returns the duration for which responses are cached.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate rocket;
+    /// use rocket::State;
+    /// use rocket::idempotency::Store;
+    ///
+    /// #[get("/idempotency/ttl")]
+    /// fn ttl(store: &State<Store>) -> String {
+    ///     format!("{}s", store.ttl().as_secs())
+    /// }
+    /// ```
+    pub fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    /// Returns the maximum number of responses cached at once.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate rocket;
+    /// use rocket::State;
+    /// use rocket::idempotency::Store;
+    ///
+    /// #[get("/idempotency/capacity")]
+    /// fn capacity(store: &State<Store>) -> String {
+    ///     store.capacity().to_string()
+    /// }
+    /// ```
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of cached responses, including any that have expired
+    /// but have not yet been removed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate rocket;
+    /// use rocket::State;
+    /// use rocket::idempotency::Store;
+    ///
+    /// #[get("/idempotency/count")]
+    /// fn count(store: &State<Store>) -> String {
+    ///     store.len().to_string()
+    /// }
+    /// ```
+    pub fn len(&self) -> usize {
+        self.entries.lock().len()
+    }
+
+    /// Returns `true` if there are no cached responses.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate rocket;
+    /// use rocket::State;
+    /// use rocket::idempotency::Store;
+    ///
+    /// #[get("/idempotency/empty")]
+    /// fn empty(store: &State<Store>) -> String {
+    ///     store.is_empty().to_string()
+    /// }
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.entries.lock().is_empty()
+    }
+
+    /// Removes all cached responses.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate rocket;
+    /// use rocket::State;
+    /// use rocket::idempotency::Store;
+    ///
+    /// #[delete("/idempotency")]
+    /// fn clear(store: &State<Store>) {
+    ///     store.clear();
+    /// }
+    /// ```
+    pub fn clear(&self) {
+        self.entries.lock().clear();
+    }
+
+    pub(crate) fn get(&self, key: &Key) -> Option<Arc<Cached>> {
+        let mut entries = self.entries.lock();
+        match entries.get(key) {
+            Some(entry) if entry.expires > Instant::now() => Some(entry.response.clone()),
+            Some(_) => {
+                entries.remove(key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    pub(crate) fn insert(&self, key: Key, response: Cached) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        let now = Instant::now();
+        let mut entries = self.entries.lock();
+        entries.retain(|_, entry| entry.expires > now);
+        if entries.len() >= self.capacity && !entries.contains_key(&key) {
+            let soonest = entries.iter().min_by_key(|(_, entry)| entry.expires);
+            if let Some(soonest) = soonest.map(|(key, _)| key.clone()) {
+                entries.remove(&soonest);
+            }
+        }
+
+        entries.insert(key, Entry { expires: now + self.ttl, response: Arc::new(response) });
+    }
+}
+
+impl std::fmt::Debug for Store {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Store")
+            .field("ttl", &self.ttl)
+            .field("capacity", &self.capacity)
+            .field("len", &self.len())
+            .finish()
+    }
+}
@@ -64,7 +64,11 @@
 //! | `http2`         | Support for HTTP/2 (enabled by default).                |
 //! | `json`          | Support for [JSON (de)serialization].                   |
 //! | `msgpack`       | Support for [MessagePack (de)serialization].            |
+//! | `openapi`       | Support for generating an [OpenAPI skeleton].           |
 //! | `uuid`          | Support for [UUID value parsing and (de)serialization]. |
+//! | `decompression` | Support for [decompressing request bodies].             |
+//! | `fs-immutable`  | Support for [immutable static assets].                  |
+//! | `route-lints`   | Warnings at launch about [unmounted routes].            |
 //! | `tokio-macros`  | Enables the `macros` feature in the exported `tokio`    |
 //! | `http3-preview` | Experimental preview support for [HTTP/3].              |
 //!
@@ -84,7 +88,11 @@
 //!
 //! [JSON (de)serialization]: crate::serde::json
 //! [MessagePack (de)serialization]: crate::serde::msgpack
+//! [OpenAPI skeleton]: crate::Rocket::openapi()
 //! [UUID value parsing and (de)serialization]: crate::serde::uuid
+//! [decompressing request bodies]: crate::decompression
+//! [immutable static assets]: crate::fs::FileServer::immutable_pattern()
+//! [unmounted routes]: crate::route::Declared
 //! [private cookies]: https://rocket.rs/master/guide/requests/#private-cookies
 //! [TLS]: https://rocket.rs/master/guide/configuration/#tls
 //! [mutual TLS]: crate::mtls
@@ -116,6 +124,9 @@ extern crate self as rocket;
 pub use yansi;
 #[doc(hidden)]
 pub use async_stream;
+#[doc(hidden)]
+#[cfg(feature = "route-lints")]
+pub use inventory;
 pub use futures;
 pub use tokio;
 pub use figment;
@@ -141,10 +152,16 @@ pub mod catcher;
 pub mod route;
 pub mod serde;
 pub mod shield;
+pub mod idempotency;
+pub mod request_id;
+#[cfg(feature = "decompression")]
+#[cfg_attr(nightly, doc(cfg(feature = "decompression")))]
+pub mod decompression;
 pub mod fs;
 pub mod http;
 pub mod listener;
 pub mod shutdown;
+pub mod state;
 #[cfg(feature = "tls")]
 #[cfg_attr(nightly, doc(cfg(feature = "tls")))]
 pub mod tls;
@@ -157,7 +174,6 @@ mod rkt;
 mod util;
 mod server;
 mod lifecycle;
-mod state;
 mod router;
 mod phase;
 mod erased;
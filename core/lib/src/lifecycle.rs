@@ -6,6 +6,7 @@ use crate::data::IoHandler;
 use crate::http::{Method, Status, Header};
 use crate::outcome::Outcome;
 use crate::form::Form;
+use crate::config::TrailingSlash;
 
 // A token returned to force the execution of one method before another.
 pub(crate) struct RequestToken;
@@ -89,20 +90,60 @@ impl Rocket<Orbit> {
     /// Finally, new cookies in the cookie jar are added to the response,
     /// Rocket-specific headers are written, and response fairings are run. Note
     /// that error responses have special cookie handling. See `handle_error`.
+    ///
+    /// If the request was assigned an ID by the `RequestId` fairing, all of the
+    /// above is run with log messages prefixed by the ID.
     pub(crate) async fn dispatch<'r, 's: 'r>(
         &'s self,
-        _token: RequestToken,
+        token: RequestToken,
         request: &'r Request<'s>,
         data: Data<'r>,
         // io_stream: impl Future<Output = io::Result<IoStream>> + Send,
+    ) -> Response<'r> {
+        match request.request_id() {
+            Some(id) => {
+                let dispatch = self._dispatch(token, request, data);
+                crate::log::with_request_id(id.into(), dispatch).await
+            }
+            None => self._dispatch(token, request, data).await,
+        }
+    }
+
+    async fn _dispatch<'r, 's: 'r>(
+        &'s self,
+        _token: RequestToken,
+        request: &'r Request<'s>,
+        data: Data<'r>,
     ) -> Response<'r> {
         info!("{}:", request);
 
         // Remember if the request is `HEAD` for later body stripping.
         let was_head_request = request.method() == Method::Head;
 
+        // Reject the request outright if we're at the concurrency limit. The
+        // permit, if any, is held until the response is finalized.
+        let permit = self.in_flight.as_ref().map(|in_flight| in_flight.try_acquire());
+        if let Some(Err(_)) = permit {
+            warn_!("Concurrent request limit reached. Rejecting request.");
+            let mut response = self.dispatch_error(Status::ServiceUnavailable, request).await;
+            response.set_raw_header("Retry-After", "1");
+            return self.finalize(request, response, was_head_request).await;
+        }
+
+        // Reject the request before its body is transferred if we know we
+        // can't meet its expectations.
+        if let Some(status) = self.expectation_failure(request) {
+            let response = self.dispatch_error(status, request).await;
+            return self.finalize(request, response, was_head_request).await;
+        }
+
+        // Let intercept fairings respond in place of the router.
+        if let Some(response) = self.fairings.handle_intercept(request).await {
+            return self.finalize(request, response, was_head_request).await;
+        }
+
         // Route the request and run the user's handlers.
-        let mut response = match self.route(request, data).await {
+        let response = match self.route(request, data).await {
             Outcome::Success(response) => response,
             Outcome::Forward((data, _)) if request.method() == Method::Head => {
                 info_!("Autohandling {} request.", "HEAD".primary().bold());
@@ -112,13 +153,90 @@ impl Rocket<Orbit> {
                 match self.route(request, data).await {
                     Outcome::Success(response) => response,
                     Outcome::Error(status) => self.dispatch_error(status, request).await,
-                    Outcome::Forward((_, status)) => self.dispatch_error(status, request).await,
+                    Outcome::Forward((_, status)) => match self.slash_redirect(status, request) {
+                        Some(response) => response,
+                        None => self.dispatch_error(status, request).await,
+                    }
                 }
             }
-            Outcome::Forward((_, status)) => self.dispatch_error(status, request).await,
+            Outcome::Forward((_, status)) => match self.slash_redirect(status, request) {
+                Some(response) => response,
+                None => self.dispatch_error(status, request).await,
+            }
             Outcome::Error(status) => self.dispatch_error(status, request).await,
         };
 
+        self.finalize(request, response, was_head_request).await
+    }
+
+    /// Returns `true` if a URI of length `len` exceeds the configured
+    /// `max_uri_length`.
+    pub(crate) fn is_uri_too_long(&self, len: usize) -> bool {
+        self.config.max_uri_length != 0 && len > self.config.max_uri_length
+    }
+
+    /// If `expect_continue` is enabled and the request has an `Expect` header
+    /// we can't meet, returns the status to reject the request with: `417` for
+    /// unknown expectations and `413` for `100-continue` requests with bodies
+    /// larger than any configured limit.
+    fn expectation_failure(&self, request: &Request<'_>) -> Option<Status> {
+        if !self.config.expect_continue {
+            return None;
+        }
+
+        let expect = request.headers().get_one("Expect")?;
+        if !expect.trim().eq_ignore_ascii_case("100-continue") {
+            warn_!("Unsupported expectation {:?}. Rejecting request.", expect);
+            return Some(Status::ExpectationFailed);
+        }
+
+        let length = request.headers().get_one("Content-Length")?.trim().parse::<u64>().ok()?;
+        if length > self.config.limits.max()?.as_u64() {
+            warn_!("Expected payload of {} bytes exceeds all limits. Rejecting request.", length);
+            return Some(Status::PayloadTooLarge);
+        }
+
+        None
+    }
+
+    /// If trailing slashes are configured to redirect, the request failed to
+    /// route with a `404`, and its path ends in a slash, returns a `301`
+    /// redirect to the same URI without trailing slashes.
+    ///
+    /// The path is fully normalized, collapsing leading and duplicate slashes,
+    /// so that a request for `//example.com/` can't be used to redirect to the
+    /// protocol-relative `//example.com`.
+    fn slash_redirect<'r>(
+        &self,
+        status: Status,
+        request: &'r Request<'_>,
+    ) -> Option<Response<'r>> {
+        if self.config.trailing_slash != TrailingSlash::Redirect || status != Status::NotFound {
+            return None;
+        }
+
+        let path = request.uri().path().as_str();
+        if path.len() <= 1 || !path.ends_with('/') {
+            return None;
+        }
+
+        let location = request.uri().clone().into_normalized_nontrailing().to_string();
+
+        info_!("Redirecting to {} without trailing slash.", location.primary());
+        Some(Response::build()
+            .status(Status::MovedPermanently)
+            .raw_header("Location", location)
+            .finalize())
+    }
+
+    /// Adds cookies and Rocket-specific headers to `response`, runs response
+    /// fairings, and strips the body if `was_head_request`.
+    async fn finalize<'r, 's: 'r>(
+        &'s self,
+        request: &'r Request<'s>,
+        mut response: Response<'r>,
+        was_head_request: bool,
+    ) -> Response<'r> {
         // Set the cookies. Note that error responses will only include cookies
         // set by the error handler. See `handle_error` for more.
         let delta_jar = request.cookies().take_delta_jar();
@@ -126,13 +244,9 @@ impl Rocket<Orbit> {
             response.adjoin_header(cookie);
         }
 
-        // Add a default 'Server' header if it isn't already there.
         // TODO: If removing Hyper, write out `Date` header too.
-        if let Some(ident) = request.rocket().config.ident.as_str() {
-            if !response.headers().contains("Server") {
-                response.set_header(Header::new("Server", ident));
-            }
-        }
+        self.set_server_header(&mut response);
+        Self::set_vary_header(request, &mut response);
 
         // Run the response fairings.
         self.fairings.handle_response(request, &mut response).await;
@@ -191,12 +305,25 @@ impl Rocket<Orbit> {
         let mut status = Status::NotFound;
         for route in self.router.route(request) {
             // Retrieve and set the requests parameters.
-            info_!("Matched: {}", route);
+            match route.metric {
+                Some(ref metric) => info_!("Matched: {} [{}]", route, metric.primary()),
+                None => info_!("Matched: {}", route),
+            }
+
             request.set_route(route);
 
             let name = route.name.as_deref();
-            let outcome = catch_handle(name, || route.handler.handle(request, data)).await
-                .unwrap_or(Outcome::Error(Status::InternalServerError));
+            let handle = catch_handle(name, || route.handler.handle(request, data));
+            let outcome = match route.timeout {
+                Some(duration) => match tokio::time::timeout(duration, handle).await {
+                    Ok(outcome) => outcome,
+                    Err(_) => {
+                        error_!("Handler timed out after {:?}.", duration);
+                        Some(Outcome::Error(Status::ServiceUnavailable))
+                    }
+                },
+                None => handle.await,
+            }.unwrap_or(Outcome::Error(Status::InternalServerError));
 
             // Check if the request processing completed (Some) or if the
             // request needs to be forwarded. If it does, continue the loop
@@ -212,6 +339,58 @@ impl Rocket<Orbit> {
         Outcome::Forward((data, status))
     }
 
+    /// Adds a `Server` header with the configured `ident`, if any, to
+    /// `response` if it doesn't already have one.
+    fn set_server_header(&self, response: &mut Response<'_>) {
+        if let Some(ident) = self.config.ident.as_str() {
+            if !response.headers().contains("Server") {
+                response.set_header(Header::new("Server", ident));
+            }
+        }
+    }
+
+    /// Adds the names of the request headers recorded via
+    /// [`Request::add_vary()`] to the `Vary` header of `response`, preserving
+    /// any existing values and leaving a `Vary: *` response unchanged.
+    fn set_vary_header(request: &Request<'_>, response: &mut Response<'_>) {
+        let recorded = request.vary();
+        if recorded.is_empty() {
+            return;
+        }
+
+        let mut names: Vec<String> = response.headers()
+            .get("Vary")
+            .flat_map(|value| value.split(','))
+            .map(|name| name.trim())
+            .filter(|name| !name.is_empty())
+            .map(|name| name.to_string())
+            .collect();
+
+        if names.iter().any(|name| name == "*") {
+            return;
+        }
+
+        for name in recorded {
+            if !names.iter().any(|n| n.eq_ignore_ascii_case(name.as_str())) {
+                names.push(name.as_str().to_string());
+            }
+        }
+
+        response.set_raw_header("Vary", names.join(", "));
+    }
+
+    // Invokes the catcher for `status` and adds the `Server` header to the
+    // resulting response. Used directly for requests rejected before routing.
+    pub(crate) async fn dispatch_error<'r, 's: 'r>(
+        &'s self,
+        status: Status,
+        req: &'r Request<'s>
+    ) -> Response<'r> {
+        let mut response = self.catch_error(status, req).await;
+        self.set_server_header(&mut response);
+        response
+    }
+
     // Invokes the catcher for `status`. Returns the response on success.
     //
     // Resets the cookie jar delta state to prevent any modifications from
@@ -219,7 +398,7 @@ impl Rocket<Orbit> {
     //
     // On catcher error, the 500 error catcher is attempted. If _that_ errors,
     // the (infallible) default 500 error cather is used.
-    pub(crate) async fn dispatch_error<'r, 's: 'r>(
+    async fn catch_error<'r, 's: 'r>(
         &'s self,
         mut status: Status,
         req: &'r Request<'s>
@@ -249,29 +428,33 @@ impl Rocket<Orbit> {
     /// Invokes the handler with `req` for catcher with status `status`.
     ///
     /// In order of preference, invoked handler is:
-    ///   * the user's registered handler for `status`
-    ///   * the user's registered `default` handler
+    ///   * the user's registered handlers for `status` and `default` handlers,
+    ///     in order of precedence, skipping those that decline
     ///   * Rocket's default handler for `status`
     ///
-    /// Return `Ok(result)` if the handler succeeded. Returns `Ok(Some(Status))`
-    /// if the handler ran to completion but failed. Returns `Ok(None)` if the
-    /// handler panicked while executing.
+    /// A handler declines by failing with `status` itself. Returns `Ok(result)`
+    /// if a handler succeeded. Returns `Err(Some(Status))` if the handler ran
+    /// to completion but failed. Returns `Err(None)` if the handler panicked
+    /// while executing.
     async fn invoke_catcher<'s, 'r: 's>(
         &'s self,
         status: Status,
         req: &'r Request<'s>
     ) -> Result<Response<'r>, Option<Status>> {
-        if let Some(catcher) = self.router.catch(status, req) {
+        for catcher in self.router.catch_all(status, req) {
             warn_!("Responding with registered {} catcher.", catcher);
             let name = catcher.name.as_deref();
-            catch_handle(name, || catcher.handler.handle(status, req)).await
-                .map(|result| result.map_err(Some))
-                .unwrap_or_else(|| Err(None))
-        } else {
-            let code = status.code.blue().bold();
-            warn_!("No {} catcher registered. Using Rocket default.", code);
-            Ok(crate::catcher::default_handler(status, req))
+            match catch_handle(name, || catcher.handler.handle(status, req)).await {
+                Some(Ok(response)) => return Ok(response),
+                Some(Err(e)) if e == status => info_!("Catcher declined. Trying next catcher."),
+                Some(Err(e)) => return Err(Some(e)),
+                None => return Err(None),
+            }
         }
+
+        let code = status.code.blue().bold();
+        warn_!("No {} catcher accepted the error. Using Rocket default.", code);
+        Ok(crate::catcher::default_handler(status, req))
     }
 
 }
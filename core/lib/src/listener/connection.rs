@@ -13,6 +13,20 @@ pub struct Certificates<'r>(Cow<'r, [der::CertificateDer<'r>]>);
 pub trait Connection: AsyncRead + AsyncWrite + Send + Unpin {
     fn endpoint(&self) -> io::Result<Endpoint>;
 
+    /// The local endpoint of the connection, that is, the endpoint the client
+    /// connected to.
+    ///
+    /// Defaults to an `Unsupported` error to indicate that the local endpoint
+    /// is unknown.
+    fn local_endpoint(&self) -> io::Result<Endpoint> {
+        Err(io::ErrorKind::Unsupported.into())
+    }
+
+    /// The server name requested by the client via TLS SNI, if any.
+    ///
+    /// Defaults to `None` to indicate that no server name was requested.
+    fn server_name(&self) -> Option<&str> { None }
+
     /// DER-encoded X.509 certificate chain presented by the client, if any.
     ///
     /// The certificate order must be as it appears in the TLS protocol: the
@@ -32,6 +46,20 @@ impl<A: Connection, B: Connection> Connection for Either<A, B> {
         }
     }
 
+    fn local_endpoint(&self) -> io::Result<Endpoint> {
+        match self {
+            Either::Left(c) => c.local_endpoint(),
+            Either::Right(c) => c.local_endpoint(),
+        }
+    }
+
+    fn server_name(&self) -> Option<&str> {
+        match self {
+            Either::Left(c) => c.server_name(),
+            Either::Right(c) => c.server_name(),
+        }
+    }
+
     fn certificates(&self) -> Option<Certificates<'_>> {
         match self {
             Either::Left(c) => c.certificates(),
@@ -4,6 +4,8 @@ use serde::Deserialize;
 use tokio_util::either::Either::{Left, Right};
 use either::Either;
 
+use figment::{Figment, value::Value};
+
 use crate::{Ignite, Rocket};
 use crate::listener::{Bind, Endpoint, tcp::TcpListener};
 
@@ -32,7 +34,7 @@ mod private {
     ///
     /// | parameter   | type              | default               |
     /// | ----------- | ----------------- | --------------------- |
-    /// | `address`   | [`Endpoint`]      | `tcp:127.0.0.1:8000`  |
+    /// | `address`   | [`Endpoint`]s     | `tcp:127.0.0.1:8000`  |
     /// | `tls`       | [`TlsConfig`]     | None                  |
     /// | `reuse`     | boolean           | `true`                |
     ///
@@ -55,6 +57,10 @@ mod private {
     ///  * **address type** is the variant the `address` parameter parses as.
     ///  * **`tls` enabled** is `yes` when the `tls` feature is enabled _and_ a
     ///    `tls` configuration is provided.
+    ///
+    /// When launched via [`Rocket::launch()`], `address` may also be an array
+    /// of endpoints, in which case one listener is bound to each endpoint as
+    /// described above. See [listeners](Rocket::launch()#listeners).
     #[cfg(doc)]
     pub struct DefaultListener(());
 }
@@ -70,6 +76,15 @@ struct Config {
 #[cfg(doc)]
 pub use private::DefaultListener;
 
+/// Returns the values in `address` if it is a non-empty array, in which case
+/// Rocket binds one default listener to each value.
+pub(crate) fn addresses(figment: &Figment) -> Option<Vec<Value>> {
+    match figment.find_value("address") {
+        Ok(Value::Array(_, values)) if !values.is_empty() => Some(values),
+        _ => None,
+    }
+}
+
 #[cfg(doc)]
 type Connection = crate::listener::tcp::TcpStream;
 
@@ -9,6 +9,9 @@ mod default;
 #[cfg(unix)]
 #[cfg_attr(nightly, doc(cfg(unix)))]
 pub mod unix;
+#[cfg(unix)]
+#[cfg_attr(nightly, doc(cfg(unix)))]
+pub mod systemd;
 pub mod tcp;
 #[cfg(feature = "http3-preview")]
 pub mod quic;
@@ -33,12 +33,13 @@ use s2n_quic_h3 as quic_h3;
 use quic_h3::h3 as h3;
 
 use bytes::Bytes;
-use futures::Stream;
 use tokio::sync::Mutex;
 use tokio_stream::StreamExt;
 
 use crate::tls::{TlsConfig, Error};
 use crate::listener::Endpoint;
+use crate::erased::ErasedResponse;
+use crate::util::ReaderStream;
 
 type H3Conn = h3::server::Connection<quic_h3::Connection, bytes::Bytes>;
 
@@ -134,9 +135,10 @@ impl H3Stream {
 }
 
 impl QuicTx {
-    pub async fn send_response<S>(&mut self, response: http::Response<S>) -> io::Result<()>
-        where S: Stream<Item = io::Result<Bytes>>
-    {
+    pub async fn send_response(
+        &mut self,
+        response: http::Response<ReaderStream<ErasedResponse>>
+    ) -> io::Result<()> {
         let (parts, body) = response.into_parts();
         let response = http::Response::from_parts(parts, ());
         self.0.send_response(response).await.map_err(io::Error::other)?;
@@ -147,6 +149,10 @@ impl QuicTx {
             self.0.send_data(bytes).await.map_err(io::Error::other)?;
         }
 
+        if let Some(trailers) = body.take_trailers() {
+            self.0.send_trailers(trailers).await.map_err(io::Error::other)?;
+        }
+
         self.0.finish().await.map_err(io::Error::other)
     }
 
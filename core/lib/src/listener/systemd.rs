@@ -0,0 +1,233 @@
+//! systemd socket activation listener.
+//!
+//! The [`SystemdListener`] accepts connections on a socket passed to Rocket by
+//! systemd via [socket activation]. When the process is socket-activated, that
+//! is, when `LISTEN_PID` is set to the process's ID and `LISTEN_FDS` to a
+//! non-zero count, the first passed socket, file descriptor `3`, is used. Both
+//! TCP and Unix domain stream sockets are supported. Otherwise, the listener
+//! falls back to binding as the [`DefaultListener`] would.
+//!
+//! Because the socket is bound by systemd, it is never unlinked or otherwise
+//! modified by Rocket. This allows systemd to hold the socket open across
+//! restarts of the application, enabling zero-downtime reloads.
+//!
+//! # Configuration
+//!
+//! When socket-activated, no configuration parameters are read; in particular,
+//! `address`, `port`, and `tls` are ignored. Otherwise, the configuration is
+//! that of the [`DefaultListener`].
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! # #[macro_use] extern crate rocket;
+//! use rocket::listener::systemd::SystemdListener;
+//!
+//! #[rocket::main]
+//! async fn main() -> Result<(), rocket::Error> {
+//!     rocket::build()
+//!         .launch_with::<SystemdListener>()
+//!         .await?;
+//!
+//!     Ok(())
+//! }
+//! ```
+//!
+//! [socket activation]: https://www.freedesktop.org/software/systemd/man/latest/sd_listen_fds.html
+
+use std::io;
+use std::mem::ManuallyDrop;
+use std::os::fd::{FromRawFd, RawFd};
+
+use futures::TryFutureExt;
+use tokio_util::either::Either::{self, Left, Right};
+
+use crate::listener::{Bind, DefaultListener, Endpoint, Listener};
+use crate::listener::{default, tcp::{TcpListener, TcpStream}, unix::UnixStream};
+use crate::util::unix;
+use crate::{Ignite, Rocket};
+
+/// The first file descriptor passed by systemd: `SD_LISTEN_FDS_START`.
+const LISTEN_FDS_START: RawFd = 3;
+
+/// A listener over a systemd-activated socket or, if the process wasn't socket
+/// activated, the [`DefaultListener`].
+///
+/// See the [module level docs](self) for details.
+pub struct SystemdListener(Either<Either<TcpListener, InheritedUnix>, DefaultListener>);
+
+/// A Unix listener over a socket we did not create and must not unlink.
+struct InheritedUnix(tokio::net::UnixListener);
+
+impl SystemdListener {
+    /// Returns the file descriptor of the activated socket if this process was
+    /// socket activated by systemd.
+    ///
+    /// The environment is read exactly once, on the first call, and the result
+    /// is reused thereafter: [`SystemdListener::inherit()`] clears the
+    /// activation variables, so reading them again, say in `bind_endpoint()`
+    /// after `bind()`, would misreport the process as not socket activated.
+    fn activated_fd() -> Option<RawFd> {
+        static ACTIVATED_FD: state::InitCell<Option<RawFd>> = state::InitCell::new();
+
+        *ACTIVATED_FD.get_or_init(|| {
+            let pid = std::env::var("LISTEN_PID").ok();
+            let fds = std::env::var("LISTEN_FDS").ok();
+            let fd = Self::parse_activation(pid.as_deref(), fds.as_deref(), std::process::id());
+            let count = fds.and_then(|n| n.parse::<u32>().ok()).unwrap_or(0);
+            if fd.is_some() && count > 1 {
+                warn!("systemd passed {} sockets; only the first is used", count);
+            }
+
+            fd
+        })
+    }
+
+    /// Returns the file descriptor to use given the values of the `LISTEN_PID`
+    /// and `LISTEN_FDS` environment variables, `pid` and `fds`, for the process
+    /// with ID `id`.
+    fn parse_activation(pid: Option<&str>, fds: Option<&str>, id: u32) -> Option<RawFd> {
+        let pid: u32 = pid?.parse().ok()?;
+        let fds: u32 = fds?.parse().ok()?;
+        if pid != id || fds == 0 {
+            return None;
+        }
+
+        Some(LISTEN_FDS_START)
+    }
+
+    /// Returns the endpoint of the socket `fd` without taking ownership of it.
+    fn endpoint_of(fd: RawFd) -> io::Result<Endpoint> {
+        match unix::socket_family(fd)? {
+            libc::AF_UNIX => {
+                let listener = unsafe { std::os::unix::net::UnixListener::from_raw_fd(fd) };
+                let addr = ManuallyDrop::new(listener).local_addr()?;
+                match addr.as_pathname() {
+                    Some(path) => Ok(Endpoint::Unix(path.to_path_buf())),
+                    None => Ok(Endpoint::new(format!("systemd:fd:{fd}"))),
+                }
+            }
+            _ => {
+                let listener = unsafe { std::net::TcpListener::from_raw_fd(fd) };
+                ManuallyDrop::new(listener).local_addr().map(Endpoint::Tcp)
+            }
+        }
+    }
+
+    /// Takes ownership of the socket `fd`.
+    fn inherit(fd: RawFd) -> io::Result<Either<TcpListener, InheritedUnix>> {
+        unix::set_cloexec(fd)?;
+        std::env::remove_var("LISTEN_PID");
+        std::env::remove_var("LISTEN_FDS");
+        std::env::remove_var("LISTEN_FDNAMES");
+
+        match unix::socket_family(fd)? {
+            libc::AF_UNIX => {
+                let listener = unsafe { std::os::unix::net::UnixListener::from_raw_fd(fd) };
+                listener.set_nonblocking(true)?;
+                let listener = tokio::net::UnixListener::from_std(listener)?;
+                Ok(Right(InheritedUnix(listener)))
+            }
+            libc::AF_INET | libc::AF_INET6 => {
+                let listener = unsafe { std::net::TcpListener::from_raw_fd(fd) };
+                listener.set_nonblocking(true)?;
+                Ok(Left(TcpListener::from_std(listener)?))
+            }
+            family => Err(io::Error::other(format!("unsupported socket family: {family}"))),
+        }
+    }
+}
+
+impl Bind for SystemdListener {
+    type Error = default::Error;
+
+    async fn bind(rocket: &Rocket<Ignite>) -> Result<Self, Self::Error> {
+        match Self::activated_fd() {
+            Some(fd) => {
+                let listener = Self::inherit(fd)?;
+                info!("Using systemd socket-activated listener on fd {}.", fd);
+                Ok(SystemdListener(Left(listener)))
+            }
+            None => {
+                let listener = <DefaultListener as Bind>::bind(rocket).await?;
+                Ok(SystemdListener(Right(listener)))
+            }
+        }
+    }
+
+    fn bind_endpoint(rocket: &Rocket<Ignite>) -> Result<Endpoint, Self::Error> {
+        match Self::activated_fd() {
+            Some(fd) => Ok(Self::endpoint_of(fd)?),
+            None => <DefaultListener as Bind>::bind_endpoint(rocket),
+        }
+    }
+}
+
+impl Listener for SystemdListener {
+    type Accept = Either<Either<TcpStream, UnixStream>, <DefaultListener as Listener>::Accept>;
+
+    type Connection = Either<
+        Either<TcpStream, UnixStream>,
+        <DefaultListener as Listener>::Connection
+    >;
+
+    async fn accept(&self) -> io::Result<Self::Accept> {
+        self.0.accept().await
+    }
+
+    async fn connect(&self, accept: Self::Accept) -> io::Result<Self::Connection> {
+        self.0.connect(accept).await
+    }
+
+    fn endpoint(&self) -> io::Result<Endpoint> {
+        self.0.endpoint()
+    }
+}
+
+impl Listener for InheritedUnix {
+    type Accept = UnixStream;
+
+    type Connection = UnixStream;
+
+    async fn accept(&self) -> io::Result<Self::Accept> {
+        self.0.accept().map_ok(|(stream, _)| stream).await
+    }
+
+    async fn connect(&self, accept: Self::Accept) -> io::Result<Self::Connection> {
+        Ok(accept)
+    }
+
+    fn endpoint(&self) -> io::Result<Endpoint> {
+        self.0.local_addr()?.try_into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SystemdListener, LISTEN_FDS_START};
+
+    #[test]
+    fn activation_requires_matching_pid() {
+        let parse = SystemdListener::parse_activation;
+        assert_eq!(parse(Some("100"), Some("1"), 100), Some(LISTEN_FDS_START));
+        assert_eq!(parse(Some("101"), Some("1"), 100), None);
+        assert_eq!(parse(Some("pid"), Some("1"), 100), None);
+        assert_eq!(parse(None, Some("1"), 100), None);
+    }
+
+    #[test]
+    fn activation_requires_fds() {
+        let parse = SystemdListener::parse_activation;
+        assert_eq!(parse(Some("100"), Some("0"), 100), None);
+        assert_eq!(parse(Some("100"), Some("-1"), 100), None);
+        assert_eq!(parse(Some("100"), Some("many"), 100), None);
+        assert_eq!(parse(Some("100"), None, 100), None);
+    }
+
+    #[test]
+    fn activation_uses_first_of_many_fds() {
+        let parse = SystemdListener::parse_activation;
+        assert_eq!(parse(Some("100"), Some("2"), 100), Some(LISTEN_FDS_START));
+        assert_eq!(parse(Some("100"), Some("16"), 100), Some(LISTEN_FDS_START));
+    }
+}
@@ -73,4 +73,8 @@ impl Connection for TcpStream {
     fn endpoint(&self) -> io::Result<Endpoint> {
         self.peer_addr().map(Endpoint::Tcp)
     }
+
+    fn local_endpoint(&self) -> io::Result<Endpoint> {
+        self.local_addr().map(Endpoint::Tcp)
+    }
 }
@@ -113,6 +113,10 @@ impl Connection for UnixStream {
     fn endpoint(&self) -> io::Result<Endpoint> {
         self.local_addr()?.try_into()
     }
+
+    fn local_endpoint(&self) -> io::Result<Endpoint> {
+        self.local_addr()?.try_into()
+    }
 }
 
 impl Drop for UnixListener {
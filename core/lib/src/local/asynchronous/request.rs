@@ -91,6 +91,15 @@ impl<'c> LocalRequest<'c> {
             }
         }
 
+        // Then, mirror the server's rejection of overly long URIs.
+        let uri_len = self.inner().uri().to_string().len();
+        if rocket.is_uri_too_long(uri_len) {
+            error!("request URI of {} bytes is too long", uri_len);
+            return LocalResponse::new(self.request, move |req| {
+                rocket.dispatch_error(Status::UriTooLong, req)
+            }).await
+        }
+
         // Actually dispatch the request.
         let mut data = Data::local(self.data);
         let token = rocket.preprocess(&mut self.request, &mut data).await;
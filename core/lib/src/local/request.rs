@@ -214,6 +214,32 @@ macro_rules! pub_request_impl {
         self
     }
 
+    /// Add a [signed cookie] to this request.
+    ///
+    /// [signed cookie]: crate::http::CookieJar::add_signed()
+    ///
+    /// # Examples
+    ///
+    /// Add `user_id` as a signed cookie:
+    ///
+    /// ```rust
+    #[doc = $import]
+    ///
+    /// # Client::_test(|_, request, _| {
+    /// let request: LocalRequest = request;
+    /// let req = request.signed_cookie(("user_id", "sb"));
+    /// # });
+    /// ```
+    #[cfg(feature = "secrets")]
+    #[cfg_attr(nightly, doc(cfg(feature = "secrets")))]
+    #[inline]
+    pub fn signed_cookie<C>(mut self, cookie: C) -> Self
+        where C: Into<crate::http::Cookie<'static>>
+    {
+        self._request_mut().cookies_mut().add_original_signed(cookie.into());
+        self
+    }
+
     /// Set mTLS client certificates to send along with the request.
     ///
     /// If the request already contained certificates, they are replaced with
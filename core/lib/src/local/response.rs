@@ -37,8 +37,59 @@ macro_rules! pub_response_impl {
     getter_method!($doc_prelude, "HTTP headers",
         headers -> &crate::http::HeaderMap<'_>);
 
+    getter_method!($doc_prelude, "handle to the trailers, if any are set,",
+        trailers -> Option<&crate::response::stream::Trailers>);
+
+    /// Returns the _first_ value of the header named `name`, if there is one.
+    /// Header names are matched case-insensitively.
+    ///
+    /// This is a shorthand for `response.headers().get_one(name)`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    #[doc = $doc_prelude]
+    ///
+    /// # Client::_test(|_, _, response| {
+    /// let response: LocalResponse = response;
+    /// let server = response.header("Server");
+    /// assert_eq!(server, response.header("server"));
+    /// # });
+    /// ```
+    #[inline(always)]
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self._response().headers().get_one(name)
+    }
+
+    /// Returns _all_ of the values of the headers named `name`, in the order
+    /// they were added, or an empty vector if there are none. Header names are
+    /// matched case-insensitively.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    #[doc = $doc_prelude]
+    ///
+    /// # Client::_test(|_, _, response| {
+    /// let response: LocalResponse = response;
+    /// let set_cookies = response.headers_all("Set-Cookie");
+    /// assert!(set_cookies.is_empty());
+    /// # });
+    /// ```
+    #[inline(always)]
+    pub fn headers_all(&self, name: &str) -> Vec<&str> {
+        self._response().headers().get(name).collect()
+    }
+
     /// Return a cookie jar containing the HTTP cookies in the response.
     ///
+    /// The jar contains one parsed [`Cookie`](crate::http::Cookie) for every
+    /// valid `Set-Cookie` header in the response, including those set by
+    /// handlers via a request's [`CookieJar`](crate::http::CookieJar). A
+    /// cookie that was removed is present with an empty value and a `Max-Age`
+    /// of zero. Use [`CookieJar::iter()`](crate::http::CookieJar::iter()) to
+    /// iterate over all of the cookies.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -46,7 +97,9 @@ macro_rules! pub_response_impl {
     ///
     /// # Client::_test(|_, _, response| {
     /// let response: LocalResponse = response;
-    /// let string = response.cookies();
+    /// let jar = response.cookies();
+    /// let session = jar.get("session").map(|c| c.value());
+    /// let names: Vec<_> = jar.iter().map(|c| c.name()).collect();
     /// # });
     /// ```
     #[inline(always)]
@@ -2,6 +2,8 @@
 
 use std::fmt;
 use std::str::FromStr;
+use std::sync::Arc;
+use std::future::Future;
 use std::sync::atomic::{AtomicBool, Ordering};
 
 use serde::{de, Serialize, Serializer, Deserialize, Deserializer};
@@ -67,6 +69,16 @@ macro_rules! write_out {
 #[derive(Debug)]
 struct RocketLogger;
 
+tokio::task_local! {
+    /// The correlation identifier of the request being processed, if any.
+    static REQUEST_ID: Arc<str>;
+}
+
+/// Runs `fut` with every message logged by it prefixed with `id`.
+pub(crate) async fn with_request_id<F: Future>(id: Arc<str>, fut: F) -> F::Output {
+    REQUEST_ID.scope(id, fut).await
+}
+
 /// Defines the maximum level of log messages to show.
 #[derive(PartialEq, Eq, Debug, Clone, Copy)]
 pub enum LogLevel {
@@ -112,6 +124,9 @@ impl log::Log for RocketLogger {
             return;
         }
 
+        // Prefix messages logged while processing a request with its ID.
+        let _ = REQUEST_ID.try_with(|id| write_out!("[{}] ", id.dim()));
+
         // In Rocket, we abuse targets with suffix "_" to indicate indentation.
         let indented = record.target().ends_with('_');
         if indented {
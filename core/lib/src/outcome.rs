@@ -627,6 +627,114 @@ impl<'a, S: Send + 'a, E: Send + 'a, F: Send + 'a> Outcome<S, E, F> {
     }
 }
 
+/// Combinators for chaining request guards.
+///
+/// These methods simplify implementing [`FromRequest`](crate::request::FromRequest)
+/// in terms of other guards, typically retrieved via
+/// [`Request::guard()`](crate::Request::guard()), before propagating their
+/// outcome with [`try_outcome!`](crate::outcome::try_outcome).
+impl<S, E> request::Outcome<S, E> {
+    /// Converts an `Error((status, _))` into a `Forward(status)`, discarding
+    /// the error value. `Success` and `Forward` are returned unchanged.
+    ///
+    /// This is useful when the failure of an inner guard should allow other
+    /// routes to handle the request instead of failing it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::request;
+    /// use rocket::outcome::Outcome::*;
+    /// use rocket::http::Status;
+    ///
+    /// let x: request::Outcome<usize, &str> = Error((Status::BadRequest, "bad"));
+    /// assert_eq!(x.or_else_forward(), Forward(Status::BadRequest));
+    ///
+    /// let x: request::Outcome<usize, &str> = Success(10);
+    /// assert_eq!(x.or_else_forward(), Success(10));
+    /// ```
+    ///
+    /// Within a guard:
+    ///
+    /// ```rust
+    /// use rocket::request::{self, Request, FromRequest};
+    /// use rocket::outcome::try_outcome;
+    /// # struct Token; struct Admin;
+    /// # #[rocket::async_trait]
+    /// # impl<'r> FromRequest<'r> for Token {
+    /// #     type Error = ();
+    /// #     async fn from_request(_: &'r Request<'_>) -> request::Outcome<Self, ()> {
+    /// #         request::Outcome::Success(Token)
+    /// #     }
+    /// # }
+    ///
+    /// #[rocket::async_trait]
+    /// impl<'r> FromRequest<'r> for Admin {
+    ///     type Error = ();
+    ///
+    ///     async fn from_request(req: &'r Request<'_>) -> request::Outcome<Self, ()> {
+    ///         // Forward, instead of fail, when the `Token` guard fails.
+    ///         let _token = try_outcome!(req.guard::<Token>().await.or_else_forward());
+    ///         request::Outcome::Success(Admin)
+    ///     }
+    /// }
+    /// ```
+    #[inline]
+    pub fn or_else_forward(self) -> request::Outcome<S, E> {
+        match self {
+            Success(val) => Success(val),
+            Error((status, _)) => Forward(status),
+            Forward(status) => Forward(status),
+        }
+    }
+
+    /// Replaces the status of an `Error` with `status`, preserving the error
+    /// value. `Success` and `Forward` are returned unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::request;
+    /// use rocket::outcome::Outcome::*;
+    /// use rocket::http::Status;
+    ///
+    /// let x: request::Outcome<usize, &str> = Error((Status::BadRequest, "bad"));
+    /// let mapped = x.map_error_status(Status::Unauthorized);
+    /// assert_eq!(mapped, Error((Status::Unauthorized, "bad")));
+    ///
+    /// let x: request::Outcome<usize, &str> = Forward(Status::NotFound);
+    /// let mapped = x.map_error_status(Status::Unauthorized);
+    /// assert_eq!(mapped, Forward(Status::NotFound));
+    /// ```
+    #[inline]
+    pub fn map_error_status(self, status: Status) -> request::Outcome<S, E> {
+        self.map_error(|(_, e)| (status, e))
+    }
+
+    /// Maps the value of an `Error` using `f`, preserving its status.
+    /// `Success` and `Forward` are returned unchanged.
+    ///
+    /// This is useful for propagating the error of an inner guard whose
+    /// [`FromRequest::Error`](crate::request::FromRequest::Error) differs from
+    /// that of the outer guard.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::request;
+    /// use rocket::outcome::Outcome::*;
+    /// use rocket::http::Status;
+    ///
+    /// let x: request::Outcome<usize, &str> = Error((Status::BadRequest, "bad"));
+    /// let mapped = x.map_error_value(|e| e.len());
+    /// assert_eq!(mapped, Error((Status::BadRequest, 3)));
+    /// ```
+    #[inline]
+    pub fn map_error_value<T, M: FnOnce(E) -> T>(self, f: M) -> request::Outcome<S, T> {
+        self.map_error(|(status, e)| (status, f(e)))
+    }
+}
+
 crate::export! {
     /// Unwraps a [`Success`](Outcome::Success) or propagates a `Forward` or
     /// `Error` by returning early.
@@ -116,5 +116,6 @@ phases! {
         pub(crate) state: TypeMap![Send + Sync],
         pub(crate) shutdown: Stages,
         pub(crate) endpoints: Vec<Endpoint>,
+        pub(crate) in_flight: Option<tokio::sync::Semaphore>,
     }
 }
@@ -0,0 +1,118 @@
+use std::convert::Infallible;
+
+use crate::Request;
+use crate::request::{FromRequest, Outcome};
+use crate::listener::Endpoint;
+
+/// Request guard for information about the connection a request arrived on.
+///
+/// `ConnectionInfo` consolidates the connection metadata Rocket captures when
+/// a connection is accepted: the remote and local endpoints, whether TLS was
+/// used, and the server name the client requested via TLS SNI, if any. The
+/// guard never fails; any piece of information that is unknown is reported as
+/// `None`.
+///
+/// Note that this information describes the _connection_ and ignores headers
+/// set by proxies. To retrieve the client's IP address as reported by a proxy,
+/// use [`Request::client_ip()`] instead.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use rocket::request::ConnectionInfo;
+///
+/// #[get("/")]
+/// fn index(conn: ConnectionInfo<'_>) -> String {
+///     let remote = conn.remote().map(|e| e.to_string());
+///     let sni = conn.server_name().unwrap_or("none");
+///     format!("{:?} (tls: {}, sni: {})", remote, conn.is_tls(), sni)
+/// }
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct ConnectionInfo<'r> {
+    remote: Option<&'r Endpoint>,
+    local: Option<&'r Endpoint>,
+    server_name: Option<&'r str>,
+}
+
+impl<'r> ConnectionInfo<'r> {
+    /// Returns the remote endpoint of the connection, if it is known.
+    ///
+    /// This is the same value as returned by [`Request::remote()`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::request::ConnectionInfo;
+    ///
+    /// fn log(conn: ConnectionInfo<'_>) {
+    ///     if let Some(remote) = conn.remote() {
+    ///         println!("connection from {}", remote);
+    ///     }
+    /// }
+    /// ```
+    pub fn remote(&self) -> Option<&'r Endpoint> {
+        self.remote
+    }
+
+    /// Returns the local endpoint of the connection, that is, the endpoint the
+    /// client connected to, if it is known.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::request::ConnectionInfo;
+    ///
+    /// fn port(conn: ConnectionInfo<'_>) -> Option<u16> {
+    ///     conn.local().and_then(|local| local.port())
+    /// }
+    /// ```
+    pub fn local(&self) -> Option<&'r Endpoint> {
+        self.local
+    }
+
+    /// Returns `true` if the connection was secured with TLS.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::request::ConnectionInfo;
+    ///
+    /// fn scheme(conn: ConnectionInfo<'_>) -> &'static str {
+    ///     if conn.is_tls() { "https" } else { "http" }
+    /// }
+    /// ```
+    pub fn is_tls(&self) -> bool {
+        self.remote.or(self.local).map_or(false, |e| e.is_tls())
+    }
+
+    /// Returns the server name the client requested during the TLS handshake
+    /// via SNI, if any. Always `None` for connections not secured with TLS.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::request::ConnectionInfo;
+    ///
+    /// fn is_api(conn: ConnectionInfo<'_>) -> bool {
+    ///     conn.server_name() == Some("api.rocket.rs")
+    /// }
+    /// ```
+    pub fn server_name(&self) -> Option<&'r str> {
+        self.server_name
+    }
+}
+
+#[crate::async_trait]
+impl<'r> FromRequest<'r> for ConnectionInfo<'r> {
+    type Error = Infallible;
+
+    async fn from_request(req: &'r Request<'_>) -> Outcome<Self, Infallible> {
+        Outcome::Success(ConnectionInfo {
+            remote: req.connection.peer_endpoint.as_ref(),
+            local: req.connection.local_endpoint.as_ref(),
+            server_name: req.connection.server_name.as_deref(),
+        })
+    }
+}
@@ -1,8 +1,11 @@
 use std::str::FromStr;
 use std::path::PathBuf;
 
+use time::format_description::well_known::Rfc3339;
+
 use crate::error::Empty;
 use crate::http::uri::{Segments, error::PathError, fmt::Path};
+use crate::form::{DATE_FMT, TIME_FMT1, TIME_FMT2, DATE_TIME_FMT1, DATE_TIME_FMT2};
 
 /// Trait to convert a dynamic path segment string to a concrete value.
 ///
@@ -81,6 +84,18 @@ use crate::http::uri::{Segments, error::PathError, fmt::Path};
 ///     type returns successfully. Otherwise, the raw path segment is returned
 ///     in the `Err` value.
 ///
+///   * **[`time::Date`], [`time::Time`], [`time::PrimitiveDateTime`],
+///     [`time::OffsetDateTime`]**
+///
+///     Parses the path segment in the same format as the type's
+///     [`FromFormField`](crate::form::FromFormField) implementation: `Date` as
+///     `YYYY-MM-DD`, `Time` as `HH:MM[:SS]`, `PrimitiveDateTime` as
+///     `YYYY-MM-DDTHH:MM[:SS]`, and `OffsetDateTime` as RFC 3339, for example
+///     `2012-10-12T13:30:00Z`. On failure, the [`time::error::Parse`] error,
+///     which describes the malformed component, is returned. Each type
+///     implements [`UriDisplay`](crate::http::uri::fmt::UriDisplay) in a
+///     compatible format, so values round-trip through [`uri!`](crate::uri!).
+///
 ///   * **&str, String**
 ///
 ///     _This implementation always returns successfully._
@@ -243,6 +258,43 @@ impl_with_fromstr! {
     bool, IpAddr, Ipv4Addr, Ipv6Addr, SocketAddrV4, SocketAddrV6, SocketAddr
 }
 
+impl<'a> FromParam<'a> for time::Date {
+    type Error = time::error::Parse;
+
+    #[inline]
+    fn from_param(param: &'a str) -> Result<Self, Self::Error> {
+        time::Date::parse(param, &DATE_FMT)
+    }
+}
+
+impl<'a> FromParam<'a> for time::Time {
+    type Error = time::error::Parse;
+
+    #[inline]
+    fn from_param(param: &'a str) -> Result<Self, Self::Error> {
+        time::Time::parse(param, &TIME_FMT1).or_else(|_| time::Time::parse(param, &TIME_FMT2))
+    }
+}
+
+impl<'a> FromParam<'a> for time::PrimitiveDateTime {
+    type Error = time::error::Parse;
+
+    #[inline]
+    fn from_param(param: &'a str) -> Result<Self, Self::Error> {
+        time::PrimitiveDateTime::parse(param, &DATE_TIME_FMT1)
+            .or_else(|_| time::PrimitiveDateTime::parse(param, &DATE_TIME_FMT2))
+    }
+}
+
+impl<'a> FromParam<'a> for time::OffsetDateTime {
+    type Error = time::error::Parse;
+
+    #[inline]
+    fn from_param(param: &'a str) -> Result<Self, Self::Error> {
+        time::OffsetDateTime::parse(param, &Rfc3339)
+    }
+}
+
 impl<'a> FromParam<'a> for PathBuf {
     type Error = PathError;
 
@@ -297,6 +349,23 @@ impl<'a, T: FromParam<'a>> FromParam<'a> for Option<T> {
 /// any other segments that begin with "*" or "." are ignored.  If a
 /// percent-decoded segment results in invalid UTF8, an `Err` is returned with
 /// the `Utf8Error`.
+///
+/// **`Vec<T>`** _where_ **`T: FromParam`**
+///
+/// The `Vec<T>` implementation parses each segment, in order, with `T`'s
+/// [`FromParam`] implementation. If any segment fails to parse, the `Err` from
+/// the first such segment is returned. If there are no segments, the vector is
+/// empty.
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// // A request to `/sum/1/2/3` responds with `6`. A request to `/sum/1/a`
+/// // fails to match this route.
+/// #[get("/sum/<nums..>")]
+/// fn sum(nums: Vec<u32>) -> String {
+///     nums.iter().sum::<u32>().to_string()
+/// }
+/// ```
 pub trait FromSegments<'r>: Sized {
     /// The associated error to be returned when parsing fails.
     type Error: std::fmt::Debug;
@@ -339,6 +408,15 @@ impl FromSegments<'_> for PathBuf {
     }
 }
 
+impl<'r, T: FromParam<'r>> FromSegments<'r> for Vec<T> {
+    type Error = T::Error;
+
+    #[inline]
+    fn from_segments(segments: Segments<'r, Path>) -> Result<Self, Self::Error> {
+        segments.map(T::from_param).collect()
+    }
+}
+
 impl<'r, T: FromSegments<'r>> FromSegments<'r> for Result<T, T::Error> {
     type Error = std::convert::Infallible;
 
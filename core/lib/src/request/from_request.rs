@@ -2,7 +2,7 @@ use std::fmt::Debug;
 use std::convert::Infallible;
 use std::net::{IpAddr, SocketAddr};
 
-use crate::{Request, Route};
+use crate::{Request, Route, Rocket, Orbit};
 use crate::outcome::{self, IntoOutcome, Outcome::*};
 
 use crate::http::uri::{Host, Origin};
@@ -142,6 +142,15 @@ pub type Outcome<S, E> = outcome::Outcome<S, (Status, E), Status>;
 ///
 ///     _This implementation always returns successfully._
 ///
+///   * **&[`Rocket<Orbit>`]**
+///
+///     Extracts the running [`Rocket`] instance via [`Request::rocket()`]. This
+///     allows a handler to introspect the application, for instance, to list
+///     all mounted [routes](Rocket::routes()) and registered
+///     [catchers](Rocket::catchers()).
+///
+///     _This implementation always returns successfully._
+///
 ///   * **&ContentType**
 ///
 ///     Extracts the [`ContentType`] header from the incoming request via
@@ -173,6 +182,28 @@ pub type Outcome<S, E> = outcome::Outcome<S, (Status, E), Status>;
 ///     via [`Request::remote()`]. If the remote address is not known, the
 ///     request is forwarded with a 500 Internal Server Error status.
 ///
+///   * **[`ConnectionInfo`]**
+///
+///     Extracts the remote and local endpoints of the connection the request
+///     arrived on, whether it was secured with TLS, and the requested TLS SNI
+///     server name.
+///
+///     _This implementation always returns successfully._
+///
+///   * **[`RangeHeader`]**
+///
+///     Parses the request's `Range` header. If the header is malformed, the
+///     request fails with a 400 Bad Request status. If it requests too many
+///     ranges, the request fails with a 416 Range Not Satisfiable status. If
+///     it is missing, the request is forwarded with a 404 Not Found status.
+///
+///   * **[`RouteConfig<T>`]** _where_ **T: DeserializeOwned**
+///
+///     Extracts a `T` from the `routes.{name}` configuration section, where
+///     `{name}` is the name of the matched route. If there is no named route or
+///     extraction fails, the request fails with a 500 Internal Server Error
+///     status.
+///
 ///   * **Option&lt;T>** _where_ **T: FromRequest**
 ///
 ///     The type `T` is derived from the incoming request using `T`'s
@@ -190,6 +221,9 @@ pub type Outcome<S, E> = outcome::Outcome<S, (Status, E), Status>;
 ///     forwarded with the same status code as the original forward.
 ///
 /// [`Config`]: crate::config::Config
+/// [`ConnectionInfo`]: crate::request::ConnectionInfo
+/// [`RangeHeader`]: crate::request::RangeHeader
+/// [`RouteConfig<T>`]: crate::request::RouteConfig
 ///
 /// # Example
 ///
@@ -432,6 +466,15 @@ impl<'r> FromRequest<'r> for &'r Route {
     }
 }
 
+#[crate::async_trait]
+impl<'r> FromRequest<'r> for &'r Rocket<Orbit> {
+    type Error = Infallible;
+
+    async fn from_request(request: &'r Request<'_>) -> Outcome<Self, Infallible> {
+        Success(request.rocket())
+    }
+}
+
 #[crate::async_trait]
 impl<'r> FromRequest<'r> for &'r CookieJar<'r> {
     type Error = Infallible;
@@ -446,6 +489,7 @@ impl<'r> FromRequest<'r> for &'r Accept {
     type Error = Infallible;
 
     async fn from_request(request: &'r Request<'_>) -> Outcome<Self, Infallible> {
+        request.add_vary("Accept");
         match request.accept() {
             Some(accept) => Success(accept),
             None => Forward(Status::InternalServerError)
@@ -0,0 +1,92 @@
+use std::hash::Hash;
+use std::sync::Arc;
+use std::collections::HashMap;
+
+use parking_lot::Mutex;
+
+/// A map from keys of type `K` to values of type `T` which hands out
+/// references to values that live as long as the map itself.
+///
+/// Values are inserted at-most once per key and are never removed or replaced
+/// thereafter: the first value inserted for a key wins.
+pub(crate) struct KeyedCache<K, T> {
+    map: Mutex<HashMap<K, Arc<T>>>,
+}
+
+impl<K: Eq + Hash, T> KeyedCache<K, T> {
+    pub fn new() -> Self {
+        KeyedCache { map: Mutex::new(HashMap::new()) }
+    }
+
+    /// Returns the value for `key`, if there is one.
+    pub fn get(&self, key: &K) -> Option<&T> {
+        let map = self.map.lock();
+        map.get(key).map(|value| self.extend(value))
+    }
+
+    /// Inserts `value` for `key` if there is no value for `key` and returns
+    /// the value for `key`, which may not be `value`.
+    pub fn insert(&self, key: K, value: T) -> &T {
+        let mut map = self.map.lock();
+        let value = map.entry(key).or_insert_with(|| Arc::new(value));
+        self.extend(value)
+    }
+
+    /// Extends the lifetime of a reference to a value in `map`, borrowed for
+    /// as long as the lock on `map` is held, to that of `self`.
+    fn extend(&self, value: &Arc<T>) -> &T {
+        // SAFETY: `Arc::as_ptr()` returns a valid pointer to a heap allocation
+        // which stays put, even as `map` reallocates or rehashes, for as long
+        // as an `Arc` to it exists. `map` holds such an `Arc` for as long as
+        // `self` lives since entries are never removed or replaced, and `map`
+        // never hands out its `Arc`s. The allocation thus outlives the returned
+        // reference, whose lifetime is that of `&self`. Finally, values are
+        // only ever accessed through shared references, so the returned `&T`
+        // can never alias a `&mut T`.
+        unsafe { &*Arc::as_ptr(value) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::KeyedCache;
+
+    #[test]
+    fn first_insert_wins() {
+        let cache = KeyedCache::new();
+        assert!(cache.get(&"a").is_none());
+        assert_eq!(cache.insert("a", String::from("first")), "first");
+        assert_eq!(cache.insert("a", String::from("second")), "first");
+        assert_eq!(cache.get(&"a").map(|s| s.as_str()), Some("first"));
+    }
+
+    #[test]
+    fn references_outlive_growth() {
+        let cache = KeyedCache::new();
+        let first = cache.insert(0, vec![0usize; 16]);
+
+        // Force `map` to reallocate many times while `first` is borrowed.
+        let refs: Vec<&Vec<usize>> = (1..1024).map(|i| cache.insert(i, vec![i; 16])).collect();
+
+        assert_eq!(first, &vec![0; 16]);
+        assert!(std::ptr::eq(first, cache.get(&0).unwrap()));
+        for (i, value) in refs.into_iter().enumerate() {
+            assert_eq!(value, &vec![i + 1; 16]);
+            assert!(std::ptr::eq(value, cache.get(&(i + 1)).unwrap()));
+        }
+    }
+
+    #[test]
+    fn shared_across_threads() {
+        let cache = KeyedCache::new();
+        std::thread::scope(|s| {
+            for i in 0..8 {
+                let cache = &cache;
+                s.spawn(move || assert_eq!(*cache.insert(i % 2, i % 2), i % 2));
+            }
+        });
+
+        assert_eq!(cache.get(&0), Some(&0));
+        assert_eq!(cache.get(&1), Some(&1));
+    }
+}
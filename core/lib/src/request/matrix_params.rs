@@ -0,0 +1,113 @@
+use std::convert::Infallible;
+
+use crate::Request;
+use crate::request::{FromRequest, Outcome};
+use crate::http::uri::MatrixParam;
+
+/// Request guard for the matrix parameters, `;name=value`, in a request's
+/// path.
+///
+/// Matrix parameters are not interpreted when routing: a request to
+/// `/cars;color=red` is routed exactly like a request to a path with a single
+/// segment `cars;color=red`. As such, routes which expect matrix parameters
+/// typically use a dynamic segment in their place. This guard never fails.
+///
+/// The [`segment`](MatrixParam::segment) of each parameter is the index of its
+/// segment in the request's full, unrouted path. See
+/// [`Path::matrix()`](crate::http::uri::Path::matrix()) for parsing details.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use rocket::request::MatrixParams;
+///
+/// #[get("/cars/<_>")]
+/// fn cars(params: MatrixParams<'_>) -> String {
+///     // For `/cars/models;color=red;year=2020`, returns `red 2020`.
+///     let color = params.get("color").unwrap_or("any");
+///     let year = params.get("year").unwrap_or("any");
+///     format!("{} {}", color, year)
+/// }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MatrixParams<'r> {
+    params: Vec<MatrixParam<'r>>,
+}
+
+impl<'r> MatrixParams<'r> {
+    /// Returns the value of the first parameter named `name`, if any.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # rocket::request::MatrixParams::_test("/a;x=1/b;x=2;y", |params| {
+    /// assert_eq!(params.get("x"), Some("1"));
+    /// assert_eq!(params.get("y"), Some(""));
+    /// assert_eq!(params.get("z"), None);
+    /// # });
+    /// ```
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.params.iter().find(|p| p.name == name).map(|p| &*p.value)
+    }
+
+    /// Returns the value of the first parameter named `name` in the path
+    /// segment with index `segment`, if any.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # rocket::request::MatrixParams::_test("/a;x=1/b;x=2;y", |params| {
+    /// assert_eq!(params.get_in(0, "x"), Some("1"));
+    /// assert_eq!(params.get_in(1, "x"), Some("2"));
+    /// assert_eq!(params.get_in(0, "y"), None);
+    /// # });
+    /// ```
+    pub fn get_in(&self, segment: usize, name: &str) -> Option<&str> {
+        self.params.iter()
+            .find(|p| p.segment == segment && p.name == name)
+            .map(|p| &*p.value)
+    }
+
+    /// Returns an iterator over all of the parameters, in path order.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # rocket::request::MatrixParams::_test("/a;x=1/b;x=2;y", |params| {
+    /// let names: Vec<_> = params.iter().map(|p| &*p.name).collect();
+    /// assert_eq!(names, ["x", "x", "y"]);
+    /// # });
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = &MatrixParam<'r>> {
+        self.params.iter()
+    }
+
+    /// Returns `true` if the path contains no matrix parameters.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # rocket::request::MatrixParams::_test("/a/b", |params| {
+    /// assert!(params.is_empty());
+    /// # });
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.params.is_empty()
+    }
+
+    #[doc(hidden)]
+    pub fn _test<F: FnOnce(MatrixParams<'_>)>(uri: &str, f: F) {
+        let uri = crate::http::uri::Origin::parse(uri).unwrap();
+        f(MatrixParams { params: uri.path().matrix().collect() })
+    }
+}
+
+#[crate::async_trait]
+impl<'r> FromRequest<'r> for MatrixParams<'r> {
+    type Error = Infallible;
+
+    async fn from_request(req: &'r Request<'_>) -> Outcome<Self, Infallible> {
+        Outcome::Success(MatrixParams { params: req.uri().path().matrix().collect() })
+    }
+}
@@ -4,6 +4,13 @@ mod request;
 mod from_param;
 mod from_request;
 mod atomic_method;
+mod connection_info;
+mod range_header;
+mod matrix_params;
+mod keyed_cache;
+mod same_origin;
+mod route_config;
+mod proxy_base;
 
 #[cfg(test)]
 mod tests;
@@ -11,12 +18,21 @@ mod tests;
 pub use self::request::Request;
 pub use self::from_request::{FromRequest, Outcome};
 pub use self::from_param::{FromParam, FromSegments};
+pub use self::connection_info::ConnectionInfo;
+pub use self::range_header::{RangeHeader, ByteRange, RangeError, Unsatisfiable};
+pub use self::matrix_params::MatrixParams;
+pub use self::same_origin::{SameOrigin, SameOriginHosts, SameOriginError};
+pub use self::route_config::{RouteConfig, RouteConfigError};
+pub(crate) use self::route_config::RouteSections;
+pub use self::proxy_base::ProxyBase;
 
 #[doc(inline)]
 pub use crate::response::flash::FlashMessage;
 
 pub(crate) use self::request::ConnectionMeta;
 pub(crate) use self::atomic_method::AtomicMethod;
+pub(crate) use self::keyed_cache::KeyedCache;
+pub(crate) use self::proxy_base::ConfiguredBase;
 
 crate::export! {
     /// Store and immediately retrieve a vector-like value `$v` (`String` or
@@ -0,0 +1,171 @@
+use std::borrow::Cow;
+
+use crate::Request;
+use crate::request::{FromRequest, Outcome};
+use crate::http::Status;
+use crate::http::uri::{Authority, Origin};
+
+/// Request guard for the external base of an application served behind a
+/// reverse proxy, as configured by [`AdHoc::proxy_base()`].
+///
+/// `ProxyBase` resolves origin URIs, such as those generated by [`uri!`], into
+/// URIs that are valid outside of the proxy: it prefixes the path with the
+/// external prefix and, if the proxy forwarded the public host, makes the URI
+/// absolute. The prefix and host are derived from the request exactly as they
+/// are when rewriting `Location` headers; see [`AdHoc::proxy_base()`].
+///
+/// # Errors
+///
+/// If [`AdHoc::proxy_base()`] is not attached, the guard forwards with a status
+/// of `500 Internal Server Error`.
+///
+/// [`AdHoc::proxy_base()`]: crate::fairing::AdHoc::proxy_base()
+/// [`uri!`]: crate::uri!
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use rocket::local::blocking::Client;
+/// use rocket::request::ProxyBase;
+/// use rocket::fairing::AdHoc;
+///
+/// #[get("/login")]
+/// fn login() { }
+///
+/// #[get("/")]
+/// fn index(base: ProxyBase<'_>) -> String {
+///     format!("log in at {}", base.resolve(&uri!(login)))
+/// }
+///
+/// #[launch]
+/// fn rocket() -> _ {
+///     rocket::build()
+///         .mount("/", routes![index, login])
+///         .attach(AdHoc::proxy_base("/app"))
+/// }
+///
+/// # let client = Client::debug(rocket()).unwrap();
+/// let response = client.get("/").dispatch();
+/// assert_eq!(response.into_string().unwrap(), "log in at /app/login");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProxyBase<'r> {
+    prefix: Cow<'r, str>,
+    origin: Option<String>,
+}
+
+/// The base configured by `AdHoc::proxy_base()`, kept in managed state.
+#[derive(Debug)]
+pub(crate) struct ConfiguredBase(pub(crate) String);
+
+impl<'r> ProxyBase<'r> {
+    /// Normalizes `prefix` into the form `/a/b` or the empty string. Returns
+    /// `None` if `prefix` could be used to redirect outside of the application,
+    /// that is, if it begins with `//` or contains a `\`, `?`, `#`, or a
+    /// control character.
+    pub(crate) fn normalize(prefix: &str) -> Option<String> {
+        let prefix = match prefix.trim().trim_end_matches('/') {
+            "" => String::new(),
+            p if p.starts_with('/') => p.to_string(),
+            p => format!("/{}", p),
+        };
+
+        let invalid = |c: char| matches!(c, '\\' | '?' | '#') || c.is_control();
+        if prefix.starts_with("//") || prefix.contains(invalid) {
+            return None;
+        }
+
+        Some(prefix)
+    }
+
+    /// Computes the external base for `req` given the configured `base`.
+    pub(crate) fn compute(req: &'r Request<'_>, base: &'r str) -> Self {
+        let prefix = match req.headers().get_one("X-Forwarded-Prefix") {
+            Some(header) => match Self::normalize(header) {
+                Some(prefix) => Cow::Owned(prefix),
+                None => {
+                    warn_!("Ignoring unsafe `X-Forwarded-Prefix` header {:?}.", header);
+                    Cow::Borrowed(base)
+                }
+            },
+            None => Cow::Borrowed(base),
+        };
+
+        let origin = req.headers()
+            .get_one("X-Forwarded-Host")
+            .and_then(|hosts| hosts.split(',').next())
+            .map(|host| host.trim())
+            .filter(|host| Authority::parse(host).is_ok())
+            .map(|host| {
+                let scheme = match req.context_is_likely_secure() {
+                    true => "https",
+                    false => "http",
+                };
+
+                format!("{}://{}", scheme, host)
+            });
+
+        ProxyBase { prefix, origin }
+    }
+
+    /// Returns the external path prefix, either empty or of the form `/a/b`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use rocket::get;
+    /// use rocket::request::ProxyBase;
+    ///
+    /// #[get("/")]
+    /// fn index(base: ProxyBase<'_>) -> String {
+    ///     base.prefix().to_string()
+    /// }
+    /// ```
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    /// Returns the external URI for the origin URI `uri`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate rocket;
+    /// use rocket::request::ProxyBase;
+    ///
+    /// #[get("/item/<id>")]
+    /// fn item(id: usize) { }
+    ///
+    /// #[get("/")]
+    /// fn index(base: ProxyBase<'_>) -> String {
+    ///     base.resolve(&uri!(item(10)))
+    /// }
+    /// ```
+    pub fn resolve(&self, uri: &Origin<'_>) -> String {
+        self.resolve_str(&uri.to_string())
+    }
+
+    /// Like [`ProxyBase::resolve()`] for a path beginning with a single `/`.
+    pub(crate) fn resolve_str(&self, path: &str) -> String {
+        match &self.origin {
+            Some(origin) => format!("{}{}{}", origin, self.prefix, path),
+            None => format!("{}{}", self.prefix, path),
+        }
+    }
+}
+
+#[crate::async_trait]
+impl<'r> FromRequest<'r> for ProxyBase<'r> {
+    type Error = std::convert::Infallible;
+
+    async fn from_request(req: &'r Request<'_>) -> Outcome<Self, Self::Error> {
+        match req.rocket().state::<ConfiguredBase>() {
+            Some(base) => Outcome::Success(ProxyBase::compute(req, &base.0)),
+            None => {
+                error_!("`ProxyBase` guard used without attaching `AdHoc::proxy_base()`.");
+                Outcome::Forward(Status::InternalServerError)
+            }
+        }
+    }
+}
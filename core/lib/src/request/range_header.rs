@@ -0,0 +1,292 @@
+use std::fmt;
+use std::ops::Range;
+
+use crate::{Request, Response};
+use crate::request::{FromRequest, Outcome};
+use crate::response::{self, Responder};
+use crate::http::Status;
+
+/// Request guard for a parsed and syntactically validated `Range` header.
+///
+/// A `RangeHeader` represents a `Range: bytes=...` request header as specified
+/// in [RFC 9110 § 14.2]: a non-empty list of [`ByteRange`]s. Because the
+/// satisfiability of a range depends on the length of the representation
+/// being served, which only the handler knows, validation happens in two
+/// steps:
+///
+///   1. The guard parses the header. If the header is present but malformed
+///      or uses a unit other than `bytes`, the guard fails with a status of
+///      `400 Bad Request` and a [`RangeError`]. If it requests more than
+///      [`RangeHeader::MAX_RANGES`] ranges, the guard fails with a status of
+///      `416 Range Not Satisfiable`. If the header is missing, the request is
+///      forwarded with a `404 Not Found` status. Use `Option<RangeHeader>` to
+///      serve full representations when the header is absent or invalid.
+///
+///   2. The handler calls [`RangeHeader::resolve()`] with the total length of
+///      the representation to retrieve the satisfiable byte ranges, with
+///      overlapping ranges coalesced. If none are satisfiable, an
+///      [`Unsatisfiable`] error is returned which responds with `416 Range Not
+///      Satisfiable` and the appropriate `Content-Range` header.
+///
+/// [RFC 9110 § 14.2]: https://www.rfc-editor.org/rfc/rfc9110#section-14.2
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use rocket::http::{Header, Status};
+/// use rocket::request::{RangeHeader, Unsatisfiable};
+///
+/// const DATA: &[u8] = b"Hello, world!";
+///
+/// #[derive(Responder)]
+/// enum Partial {
+///     #[response(status = 200)]
+///     Full(&'static [u8]),
+///     #[response(status = 206)]
+///     Partial(&'static [u8], Header<'static>),
+/// }
+///
+/// #[get("/data")]
+/// fn data(range: Option<RangeHeader>) -> Result<Partial, Unsatisfiable> {
+///     let Some(range) = range else {
+///         return Ok(Partial::Full(DATA));
+///     };
+///
+///     // Only serve the first requested range.
+///     let range = range.resolve(DATA.len() as u64)?.remove(0);
+///     let content_range = format!("bytes {}-{}/{}", range.start, range.end - 1, DATA.len());
+///     let slice = &DATA[range.start as usize..range.end as usize];
+///     Ok(Partial::Partial(slice, Header::new("Content-Range", content_range)))
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RangeHeader {
+    ranges: Vec<ByteRange>,
+}
+
+/// A single byte range in a `Range` header.
+///
+/// Positions are zero-based and inclusive, exactly as they appear in the
+/// header. Use [`ByteRange::resolve()`] to convert a `ByteRange` into a
+/// half-open [`Range`] for a representation of a known length.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ByteRange {
+    /// `first-last`: the bytes from `first` to `last`, inclusive.
+    Bounded(u64, u64),
+    /// `first-`: the bytes from `first` to the end.
+    From(u64),
+    /// `-length`: the last `length` bytes.
+    Suffix(u64),
+}
+
+/// Error returned by the [`RangeHeader`] guard when the header is invalid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RangeError {
+    /// The header uses a range unit other than `bytes`.
+    Unit(String),
+    /// The header is syntactically invalid.
+    Malformed,
+    /// The header requests more than [`RangeHeader::MAX_RANGES`] ranges.
+    TooMany,
+}
+
+/// Responder and error returned when no requested range is satisfiable.
+///
+/// Responds with a status of `416 Range Not Satisfiable` and a
+/// `Content-Range: bytes */{length}` header.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Unsatisfiable {
+    /// The total length of the representation.
+    pub length: u64,
+}
+
+impl RangeHeader {
+    /// The maximum number of ranges a `Range` header may request: `32`.
+    pub const MAX_RANGES: usize = 32;
+
+    /// Parses the value of a `Range` header. Fails with
+    /// [`RangeError::TooMany`] if the header requests more than
+    /// [`RangeHeader::MAX_RANGES`] ranges.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::request::{RangeHeader, ByteRange, RangeError};
+    ///
+    /// let range = RangeHeader::parse("bytes=0-99, 200-, -50").unwrap();
+    /// assert_eq!(range.ranges(), &[
+    ///     ByteRange::Bounded(0, 99),
+    ///     ByteRange::From(200),
+    ///     ByteRange::Suffix(50),
+    /// ]);
+    ///
+    /// assert_eq!(RangeHeader::parse("bytes=10-5"), Err(RangeError::Malformed));
+    /// assert_eq!(RangeHeader::parse("items=0-5"), Err(RangeError::Unit("items".into())));
+    ///
+    /// let many = format!("bytes=0-0{}", ",0-0".repeat(RangeHeader::MAX_RANGES));
+    /// assert_eq!(RangeHeader::parse(&many), Err(RangeError::TooMany));
+    /// ```
+    pub fn parse(value: &str) -> Result<RangeHeader, RangeError> {
+        let (unit, specs) = value.trim().split_once('=').ok_or(RangeError::Malformed)?;
+        let unit = unit.trim();
+        if !unit.eq_ignore_ascii_case("bytes") {
+            return Err(RangeError::Unit(unit.to_string()));
+        }
+
+        let parse_pos = |s: &str| match s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) {
+            true => Err(RangeError::Malformed),
+            false => s.parse::<u64>().map_err(|_| RangeError::Malformed),
+        };
+
+        let mut ranges = vec![];
+        for spec in specs.split(',').map(|s| s.trim()).filter(|s| !s.is_empty()) {
+            let (first, last) = spec.split_once('-').ok_or(RangeError::Malformed)?;
+            let range = match (first.trim(), last.trim()) {
+                ("", last) => ByteRange::Suffix(parse_pos(last)?),
+                (first, "") => ByteRange::From(parse_pos(first)?),
+                (first, last) => {
+                    let (first, last) = (parse_pos(first)?, parse_pos(last)?);
+                    if last < first {
+                        return Err(RangeError::Malformed);
+                    }
+
+                    ByteRange::Bounded(first, last)
+                }
+            };
+
+            if ranges.len() == Self::MAX_RANGES {
+                return Err(RangeError::TooMany);
+            }
+
+            ranges.push(range);
+        }
+
+        if ranges.is_empty() {
+            return Err(RangeError::Malformed);
+        }
+
+        Ok(RangeHeader { ranges })
+    }
+
+    /// Returns the requested byte ranges in the order they were specified.
+    pub fn ranges(&self) -> &[ByteRange] {
+        &self.ranges
+    }
+
+    /// Resolves the requested ranges against a representation of `length`
+    /// bytes, returning the satisfiable ranges as half-open ranges in the
+    /// order they were requested. Unsatisfiable ranges are skipped. Ranges
+    /// which overlap are coalesced into a single range in the position of the
+    /// earliest. If no range is satisfiable, returns an [`Unsatisfiable`]
+    /// error.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::request::{RangeHeader, Unsatisfiable};
+    ///
+    /// let range = RangeHeader::parse("bytes=90-200, 0-4, -5").unwrap();
+    /// assert_eq!(range.resolve(100).unwrap(), vec![90..100, 0..5]);
+    ///
+    /// let range = RangeHeader::parse("bytes=0-0, 0-0, 1-1, 0-1").unwrap();
+    /// assert_eq!(range.resolve(100).unwrap(), vec![0..2]);
+    ///
+    /// let range = RangeHeader::parse("bytes=100-").unwrap();
+    /// assert_eq!(range.resolve(100), Err(Unsatisfiable { length: 100 }));
+    /// ```
+    pub fn resolve(&self, length: u64) -> Result<Vec<Range<u64>>, Unsatisfiable> {
+        let mut ranges: Vec<Range<u64>> = vec![];
+        for mut range in self.ranges.iter().filter_map(|range| range.resolve(length)) {
+            // Absorb every range that overlaps with `range`, keeping the
+            // position of the earliest. `range` may overlap several of them.
+            let mut position = None;
+            let mut i = 0;
+            while i < ranges.len() {
+                let other = &ranges[i];
+                if range.start < other.end && other.start < range.end {
+                    range = range.start.min(other.start)..range.end.max(other.end);
+                    position.get_or_insert(i);
+                    ranges.remove(i);
+                } else {
+                    i += 1;
+                }
+            }
+
+            match position {
+                Some(i) => ranges.insert(i, range),
+                None => ranges.push(range),
+            }
+        }
+
+        match ranges.is_empty() {
+            true => Err(Unsatisfiable { length }),
+            false => Ok(ranges),
+        }
+    }
+}
+
+impl ByteRange {
+    /// Resolves `self` against a representation of `length` bytes, returning
+    /// the half-open range of bytes it refers to, or `None` if `self` is not
+    /// satisfiable.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::request::ByteRange;
+    ///
+    /// assert_eq!(ByteRange::Bounded(5, 1000).resolve(10), Some(5..10));
+    /// assert_eq!(ByteRange::From(3).resolve(10), Some(3..10));
+    /// assert_eq!(ByteRange::Suffix(20).resolve(10), Some(0..10));
+    /// assert_eq!(ByteRange::From(10).resolve(10), None);
+    /// assert_eq!(ByteRange::Suffix(0).resolve(10), None);
+    /// ```
+    pub fn resolve(&self, length: u64) -> Option<Range<u64>> {
+        match *self {
+            ByteRange::Bounded(first, last) if first < length => {
+                Some(first..last.saturating_add(1).min(length))
+            }
+            ByteRange::From(first) if first < length => Some(first..length),
+            ByteRange::Suffix(n) if n > 0 && length > 0 => Some(length - n.min(length)..length),
+            _ => None,
+        }
+    }
+}
+
+#[crate::async_trait]
+impl<'r> FromRequest<'r> for RangeHeader {
+    type Error = RangeError;
+
+    async fn from_request(req: &'r Request<'_>) -> Outcome<Self, RangeError> {
+        match req.headers().get_one("Range") {
+            Some(value) => match RangeHeader::parse(value) {
+                Ok(range) => Outcome::Success(range),
+                Err(e@RangeError::TooMany) => Outcome::Error((Status::RangeNotSatisfiable, e)),
+                Err(e) => Outcome::Error((Status::BadRequest, e)),
+            },
+            None => Outcome::Forward(Status::NotFound),
+        }
+    }
+}
+
+impl<'r> Responder<'r, 'static> for Unsatisfiable {
+    fn respond_to(self, _: &'r Request<'_>) -> response::Result<'static> {
+        Response::build()
+            .status(Status::RangeNotSatisfiable)
+            .raw_header("Content-Range", format!("bytes */{}", self.length))
+            .ok()
+    }
+}
+
+impl fmt::Display for RangeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RangeError::Unit(unit) => write!(f, "unsupported range unit: {}", unit),
+            RangeError::Malformed => write!(f, "malformed range header"),
+            RangeError::TooMany => write!(f, "too many ranges"),
+        }
+    }
+}
+
+impl std::error::Error for RangeError { }
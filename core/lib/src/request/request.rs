@@ -1,8 +1,9 @@
-use std::{io, fmt};
+use std::fmt;
 use std::ops::RangeFrom;
 use std::sync::{Arc, atomic::Ordering};
 use std::borrow::Cow;
 use std::future::Future;
+use std::hash::Hash;
 use std::net::IpAddr;
 
 use yansi::Paint;
@@ -11,14 +12,15 @@ use futures::future::BoxFuture;
 use ref_swap::OptionRefSwap;
 
 use crate::{Rocket, Route, Orbit};
-use crate::request::{FromParam, FromSegments, FromRequest, Outcome, AtomicMethod};
+use crate::request::{FromParam, FromSegments, FromRequest, Outcome, AtomicMethod, KeyedCache};
 use crate::form::{self, ValueField, FromForm};
 use crate::data::Limits;
 
 use crate::http::ProxyProto;
-use crate::http::{Method, Header, HeaderMap, ContentType, Accept, MediaType, CookieJar, Cookie};
+use crate::http::uncased::Uncased;
+use crate::http::{Method, Status, Header, HeaderMap, ContentType, Accept, MediaType, CookieJar, Cookie};
 use crate::http::uri::{fmt::Path, Origin, Segments, Host, Authority};
-use crate::listener::{Certificates, Endpoint};
+use crate::listener::{Certificates, Connection, Endpoint};
 
 /// The type of an incoming web request.
 ///
@@ -40,15 +42,19 @@ pub struct Request<'r> {
 #[derive(Clone, Default)]
 pub(crate) struct ConnectionMeta {
     pub peer_endpoint: Option<Endpoint>,
+    pub local_endpoint: Option<Endpoint>,
+    pub server_name: Option<Arc<str>>,
     #[cfg_attr(not(feature = "mtls"), allow(dead_code))]
     pub peer_certs: Option<Arc<Certificates<'static>>>,
 }
 
-impl ConnectionMeta {
-    pub fn new(endpoint: io::Result<Endpoint>, certs: Option<Certificates<'_>>) -> Self {
+impl<C: Connection> From<&C> for ConnectionMeta {
+    fn from(conn: &C) -> Self {
         ConnectionMeta {
-            peer_endpoint: endpoint.ok(),
-            peer_certs: certs.map(|c| c.into_owned()).map(Arc::new),
+            peer_endpoint: conn.endpoint().ok(),
+            local_endpoint: conn.local_endpoint().ok(),
+            server_name: conn.server_name().map(Arc::from),
+            peer_certs: conn.certificates().map(|c| c.into_owned()).map(Arc::new),
         }
     }
 }
@@ -64,6 +70,10 @@ pub(crate) struct RequestState<'r> {
     pub host: Option<Host<'r>>,
 }
 
+/// Names of request headers the response depends on, in request-local state.
+#[derive(Default)]
+struct Vary(parking_lot::Mutex<Vec<Uncased<'static>>>);
+
 impl Clone for RequestState<'_> {
     fn clone(&self) -> Self {
         RequestState {
@@ -612,6 +622,13 @@ impl<'r> Request<'r> {
         self.headers.replace(header);
     }
 
+    /// Removes all headers named `name` from `self`. Must not be used to remove
+    /// headers whose values `self` caches, such as `Content-Type`.
+    #[cfg(feature = "decompression")]
+    pub(crate) fn remove_header(&mut self, name: &str) {
+        self.headers.remove(name);
+    }
+
     /// Returns the Content-Type header of `self`. If the header is not present,
     /// returns `None`.
     ///
@@ -713,6 +730,62 @@ impl<'r> Request<'r> {
         }
     }
 
+    /// Records that the response to `self` depends on the value of the
+    /// request header `name`, for instance, because `name` was used to
+    /// negotiate the response's format, encoding, or language.
+    ///
+    /// Before a response is sent, Rocket adds every recorded header name to
+    /// the response's `Vary` header, if it isn't already present, so that
+    /// caches key the response on the value of the header. Rocket records
+    /// `Accept` automatically when a route's `format` is matched against it
+    /// and when the `&Accept` request guard is used. Negotiation helpers
+    /// should record every header they consult.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use rocket::get;
+    /// use rocket::Request;
+    ///
+    /// #[get("/")]
+    /// fn index(req: &Request<'_>) -> &'static str {
+    ///     req.add_vary("Accept-Language");
+    ///     match req.headers().get_one("Accept-Language") {
+    ///         Some(lang) if lang.starts_with("fr") => "Bonjour!",
+    ///         _ => "Hello!",
+    ///     }
+    /// }
+    /// ```
+    pub fn add_vary<N: Into<Uncased<'static>>>(&self, name: N) {
+        let name = name.into();
+        let mut names = self.local_cache(Vary::default).0.lock();
+        if !names.contains(&name) {
+            names.push(name);
+        }
+    }
+
+    /// Returns the names of the request headers recorded via
+    /// [`Request::add_vary()`], in the order they were first recorded.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # let c = rocket::local::blocking::Client::debug_with(vec![]).unwrap();
+    /// # let request = c.get("/");
+    /// assert!(request.vary().is_empty());
+    ///
+    /// request.add_vary("Accept-Encoding");
+    /// request.add_vary("accept-encoding");
+    /// assert_eq!(request.vary().len(), 1);
+    /// assert_eq!(request.vary()[0].as_str(), "Accept-Encoding");
+    /// ```
+    pub fn vary(&self) -> Vec<Uncased<'static>> {
+        match self.state.cache.try_get::<Vary>() {
+            Some(vary) => vary.0.lock().clone(),
+            None => vec![],
+        }
+    }
+
     /// Returns the [`Rocket`] instance that is handling this request.
     ///
     /// # Example
@@ -736,9 +809,10 @@ impl<'r> Request<'r> {
         self.state.rocket
     }
 
-    /// Returns the configured application data limits.
+    /// Returns the application data limits in effect for this request.
     ///
-    /// This is convenience function equivalent to:
+    /// When the matched route, if any, has no [`limit`](Route::limit), this is
+    /// convenience function equivalent to:
     ///
     /// ```rust
     /// # let c = rocket::local::blocking::Client::debug_with(vec![]).unwrap();
@@ -747,6 +821,9 @@ impl<'r> Request<'r> {
     /// # ;
     /// ```
     ///
+    /// Otherwise, every configured and default limit is replaced with the
+    /// route's limit.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -762,7 +839,10 @@ impl<'r> Request<'r> {
     /// ```
     #[inline(always)]
     pub fn limits(&self) -> &'r Limits {
-        &self.rocket().config().limits
+        match self.route().and_then(|route| route.limits.as_ref()) {
+            Some(limits) => limits,
+            None => &self.rocket().config().limits,
+        }
     }
 
     /// Get the presently matched route, if any.
@@ -783,6 +863,24 @@ impl<'r> Request<'r> {
         self.state.route.load(Ordering::Acquire)
     }
 
+    /// Returns the correlation identifier assigned to this request by the
+    /// [`RequestId`](crate::request_id::RequestId) fairing, if it is attached.
+    /// This method returns `None` when the fairing isn't attached and before
+    /// the fairing's request callback has run.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # let c = rocket::local::blocking::Client::debug_with(vec![]).unwrap();
+    /// # let request = c.get("/");
+    /// // The `RequestId` fairing isn't attached.
+    /// assert_eq!(request.request_id(), None);
+    /// ```
+    #[inline]
+    pub fn request_id(&self) -> Option<&str> {
+        self.state.cache.try_get::<crate::request_id::Id>().map(|id| &*id.0)
+    }
+
     /// Invokes the request guard implementation for `T`, returning its outcome.
     ///
     /// # Example
@@ -810,8 +908,9 @@ impl<'r> Request<'r> {
     /// returned.
     ///
     /// Different values of the same type _cannot_ be cached without using a
-    /// proxy, wrapper type. To avoid the need to write these manually, or for
-    /// libraries wishing to store values of public types, use the
+    /// proxy, wrapper type or a key via [`Request::local_cache_keyed()`]. To
+    /// avoid the need to write these manually, or for libraries wishing to
+    /// store values of public types, use the
     /// [`local_cache!`](crate::request::local_cache) or
     /// [`local_cache_once!`](crate::request::local_cache_once) macros to
     /// generate a locally anonymous wrapper type, store, and retrieve the
@@ -877,6 +976,88 @@ impl<'r> Request<'r> {
         }
     }
 
+    /// Retrieves the cached value of type `T` for `key` from the request-local
+    /// cached state of `self`. If no such value has previously been cached for
+    /// `key` in this request, `f` is called to produce the value which is
+    /// subsequently returned.
+    ///
+    /// Unlike [`Request::local_cache()`], any number of values of the same
+    /// type can be cached, one per distinct key. Values cached with different
+    /// key _types_ never conflict, nor do they conflict with values cached via
+    /// [`Request::local_cache()`]. As such, a library can use a private key
+    /// type to avoid conflicts with values cached by other code.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # let c = rocket::local::blocking::Client::debug_with(vec![]).unwrap();
+    /// # let request = c.get("/");
+    /// // The first store into local cache for a given key wins.
+    /// assert_eq!(*request.local_cache_keyed("greeting", || "hello"), "hello");
+    /// assert_eq!(*request.local_cache_keyed("greeting", || "goodbye"), "hello");
+    ///
+    /// // Values of the same type are cached independently for each key.
+    /// assert_eq!(*request.local_cache_keyed("farewell", || "goodbye"), "goodbye");
+    /// assert_eq!(*request.local_cache_keyed("greeting", || "hi"), "hello");
+    /// ```
+    #[inline]
+    pub fn local_cache_keyed<K, T, F>(&self, key: K, f: F) -> &T
+        where F: FnOnce() -> T,
+              K: Eq + Hash + Send + 'static,
+              T: Send + Sync + 'static
+    {
+        let cache = self.local_cache(KeyedCache::<K, T>::new);
+        match cache.get(&key) {
+            Some(value) => value,
+            None => cache.insert(key, f()),
+        }
+    }
+
+    /// Retrieves the cached value of type `T` for `key` from the request-local
+    /// cached state of `self`. If no such value has previously been cached for
+    /// `key` in this request, `fut` is `await`ed to produce the value which is
+    /// subsequently returned.
+    ///
+    /// See [`Request::local_cache_keyed()`] for details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use rocket::Request;
+    /// # type User = String;
+    /// async fn load_user<'r>(request: &Request<'r>, id: &str) -> User {
+    ///     // load from database, etc
+    /// #   id.into()
+    /// }
+    ///
+    /// # rocket::async_test(async move {
+    /// # let c = rocket::local::asynchronous::Client::debug_with(vec![]).await.unwrap();
+    /// # let request = c.get("/");
+    /// let author = request.local_cache_keyed_async("author", async {
+    ///     load_user(&request, "alice").await
+    /// }).await;
+    ///
+    /// let editor = request.local_cache_keyed_async("editor", async {
+    ///     load_user(&request, "bob").await
+    /// }).await;
+    ///
+    /// assert_eq!(author, "alice");
+    /// assert_eq!(editor, "bob");
+    /// # })
+    /// ```
+    #[inline]
+    pub async fn local_cache_keyed_async<'a, K, T, F>(&'a self, key: K, fut: F) -> &'a T
+        where F: Future<Output = T>,
+              K: Eq + Hash + Send + 'static,
+              T: Send + Sync + 'static
+    {
+        let cache = self.local_cache(KeyedCache::<K, T>::new);
+        match cache.get(&key) {
+            Some(value) => value,
+            None => cache.insert(key, fut.await),
+        }
+    }
+
     /// Retrieves and parses into `T` the 0-indexed `n`th non-empty segment from
     /// the _routed_ request, that is, the `n`th segment _after_ the mount
     /// point. If the request has not been routed, then this is simply the `n`th
@@ -1125,6 +1306,12 @@ impl<'r> Request<'r> {
                 Origin::root().clone()
             });
 
+        // Reject overly long URIs before doing any more work.
+        let uri_len = hyper.uri.path_and_query().map_or(0, |uri| uri.as_str().len());
+        if rocket.is_uri_too_long(uri_len) {
+            errors.push(RequestError::UriTooLong(uri_len));
+        }
+
         // Construct the request object; fill in metadata and headers next.
         let mut request = Request::new(rocket, method, uri);
         request.errors = errors;
@@ -1177,6 +1364,17 @@ impl<'r> Request<'r> {
 pub(crate) enum RequestError {
     InvalidUri(hyper::Uri),
     BadMethod(hyper::Method),
+    UriTooLong(usize),
+}
+
+impl RequestError {
+    /// The status of the error response to a request with this error.
+    pub fn status(&self) -> Status {
+        match self {
+            RequestError::UriTooLong(_) => Status::UriTooLong,
+            _ => Status::BadRequest,
+        }
+    }
 }
 
 impl fmt::Display for RequestError {
@@ -1184,6 +1382,7 @@ impl fmt::Display for RequestError {
         match self {
             RequestError::InvalidUri(u) => write!(f, "invalid origin URI: {}", u),
             RequestError::BadMethod(m) => write!(f, "invalid or unrecognized method: {}", m),
+            RequestError::UriTooLong(n) => write!(f, "URI of {} bytes is too long", n),
         }
     }
 }
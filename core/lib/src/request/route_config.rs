@@ -0,0 +1,179 @@
+use std::fmt;
+use std::ops::Deref;
+use std::collections::HashMap;
+
+use serde::Deserialize;
+use serde::de::DeserializeOwned;
+use figment::{Figment, value::{Dict, Value}};
+
+use crate::{Request, Route};
+use crate::request::{FromRequest, Outcome};
+use crate::http::Status;
+
+/// Request guard which extracts a typed configuration section for the matched
+/// route.
+///
+/// `RouteConfig<T>` deserializes `T` from the `routes.{name}` section of the
+/// application's [configuration figment](crate::Rocket::figment()), where
+/// `{name}` is the [name](crate::Route::name) of the route that matched the
+/// request. Routes generated by the route attributes are named after their
+/// function, so a handler `fn checkout()` reads the `routes.checkout` section.
+/// This allows declaring per-route settings alongside the rest of the
+/// configuration.
+///
+/// As with all configuration, sections are nested in a profile in
+/// `Rocket.toml`, for instance, `[default.routes.checkout]`, and can be set via
+/// environment variables such as `ROCKET_ROUTES={checkout={max_items=5}}`.
+///
+/// A missing section is treated as an empty one: if every field of `T` has a
+/// default, for instance, via `#[serde(default)]`, extraction succeeds even
+/// when the section is absent.
+///
+/// Sections are read from the figment once, when the application is ignited;
+/// each request only deserializes `T` from the cached section. Because
+/// sections are keyed by route name, routes that share a name, such as
+/// same-named handlers in different modules or a route mounted at several
+/// bases, share a section. Set a distinct name on a [`Route`] to give it its
+/// own section.
+///
+/// # Errors
+///
+/// The guard fails with a status of `500 Internal Server Error` and a
+/// [`RouteConfigError`] when:
+///
+///   * there is no matched route, as in a catcher, or the route is unnamed, or
+///   * the section cannot be deserialized into `T`.
+///
+/// # Example
+///
+/// With the following in `Rocket.toml`:
+///
+/// ```toml
+/// [default.routes.checkout]
+/// max_items = 5
+/// ```
+///
+/// the `checkout` route below can read its limit as follows:
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use rocket::request::RouteConfig;
+/// use rocket::serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// #[serde(crate = "rocket::serde")]
+/// struct Checkout {
+///     #[serde(default)]
+///     max_items: usize,
+/// }
+///
+/// #[post("/checkout")]
+/// fn checkout(config: RouteConfig<Checkout>) -> String {
+///     format!("at most {} items", config.max_items)
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RouteConfig<T>(T);
+
+/// Error returned by the [`RouteConfig`] guard.
+#[derive(Debug)]
+pub enum RouteConfigError {
+    /// There is no matched route or the matched route has no name.
+    Unnamed,
+    /// The route's configuration section failed to deserialize.
+    Extract(crate::figment::Error),
+}
+
+impl<T> RouteConfig<T> {
+    /// Consumes `self` and returns the extracted configuration.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use rocket::get;
+    /// use std::collections::HashMap;
+    /// use rocket::request::RouteConfig;
+    ///
+    /// #[get("/")]
+    /// fn index(config: RouteConfig<HashMap<String, String>>) -> String {
+    ///     let map = config.into_inner();
+    ///     format!("{} settings", map.len())
+    /// }
+    /// ```
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T: DeserializeOwned> RouteConfig<T> {
+    fn extract(req: &Request<'_>) -> Result<Self, RouteConfigError> {
+        let name = req.route()
+            .and_then(|route| route.name.as_deref())
+            .ok_or(RouteConfigError::Unnamed)?;
+
+        let section = req.rocket().state::<RouteSections>()
+            .and_then(|sections| sections.0.get(name))
+            .ok_or(RouteConfigError::Unnamed)?
+            .as_ref()
+            .map_err(|e| RouteConfigError::Extract(e.clone()))?;
+
+        T::deserialize(section)
+            .map(RouteConfig)
+            .map_err(RouteConfigError::Extract)
+    }
+}
+
+/// The `routes.{name}` section of every named route, extracted at ignition.
+pub(crate) struct RouteSections(HashMap<String, Result<Value, crate::figment::Error>>);
+
+impl RouteSections {
+    pub(crate) fn extract<'a, I>(figment: &Figment, routes: I) -> Self
+        where I: Iterator<Item = &'a Route>
+    {
+        let sections = routes.filter_map(|route| route.name.as_deref())
+            .map(|name| {
+                let section = figment.focus(&format!("routes.{}", name))
+                    .extract::<Dict>()
+                    .map(Value::from);
+
+                (name.to_string(), section)
+            })
+            .collect();
+
+        RouteSections(sections)
+    }
+}
+
+impl<T> Deref for RouteConfig<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+#[crate::async_trait]
+impl<'r, T: DeserializeOwned + Send> FromRequest<'r> for RouteConfig<T> {
+    type Error = RouteConfigError;
+
+    async fn from_request(req: &'r Request<'_>) -> Outcome<Self, RouteConfigError> {
+        match RouteConfig::extract(req) {
+            Ok(config) => Outcome::Success(config),
+            Err(e) => {
+                error_!("Failed to read route configuration: {}.", e);
+                Outcome::Error((Status::InternalServerError, e))
+            }
+        }
+    }
+}
+
+impl fmt::Display for RouteConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RouteConfigError::Unnamed => write!(f, "no matched route or route is unnamed"),
+            RouteConfigError::Extract(e) => write!(f, "invalid route configuration: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for RouteConfigError { }
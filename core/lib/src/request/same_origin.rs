@@ -0,0 +1,181 @@
+use std::fmt;
+
+use crate::Request;
+use crate::request::{FromRequest, Outcome};
+use crate::http::Status;
+use crate::http::uri::{Absolute, Host};
+
+/// Request guard which succeeds only if a request originates from an allowed
+/// host, as indicated by its `Origin` or `Referer` header.
+///
+/// Browsers attach an `Origin` header, and usually a `Referer` header, to
+/// cross-site requests. Requiring that either names an allowed host is an
+/// effective defense against cross-site request forgery (CSRF) for
+/// state-changing routes, complementing cookies' `SameSite` attribute.
+///
+/// The guard checks the `Origin` header if it is present and the `Referer`
+/// header otherwise. The host of the header's URI must match, case-insensitively
+/// and including any port, either the request's own [`Host`] or one of the
+/// hosts in the managed [`SameOriginHosts`], if any.
+///
+/// # Errors
+///
+/// The guard fails with a status of `403 Forbidden` and a [`SameOriginError`]
+/// when:
+///
+///   * neither header is present,
+///   * the relevant header is `null` or is not an absolute URI with a host, or
+///   * the host in the header is not allowed.
+///
+/// Because requests from non-browser clients often carry neither header, the
+/// guard is best suited for routes called from web pages.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use rocket::request::{SameOrigin, SameOriginHosts};
+/// use rocket::http::uri::Host;
+///
+/// #[post("/transfer")]
+/// fn transfer(_origin: SameOrigin<'_>) -> &'static str {
+///     "transferred"
+/// }
+///
+/// #[launch]
+/// fn rocket() -> _ {
+///     // Also allow requests originating from `app.example.com`.
+///     let hosts = SameOriginHosts::new([Host::new(uri!("app.example.com"))]);
+///     rocket::build()
+///         .manage(hosts)
+///         .mount("/", routes![transfer])
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct SameOrigin<'r> {
+    host: Host<'r>,
+}
+
+/// Additional hosts allowed by the [`SameOrigin`] request guard.
+///
+/// To allow hosts other than the request's own, place a `SameOriginHosts` in
+/// managed state. See [`SameOrigin`] for an example.
+#[derive(Debug, Clone, Default)]
+pub struct SameOriginHosts(Vec<Host<'static>>);
+
+/// Error returned by the [`SameOrigin`] guard.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SameOriginError {
+    /// Neither an `Origin` nor a `Referer` header is present.
+    Missing,
+    /// The `Origin` or `Referer` header is `null` or does not contain an
+    /// absolute URI with a host.
+    Malformed,
+    /// The host in the header, which is included, is not allowed.
+    Disallowed(String),
+}
+
+impl<'r> SameOrigin<'r> {
+    /// Returns the allowed host the request originates from.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use rocket::post;
+    /// use rocket::request::SameOrigin;
+    ///
+    /// #[post("/")]
+    /// fn index(origin: SameOrigin<'_>) -> String {
+    ///     origin.host().to_string()
+    /// }
+    /// ```
+    pub fn host(&self) -> &Host<'r> {
+        &self.host
+    }
+}
+
+impl SameOriginHosts {
+    /// Creates a new `SameOriginHosts` allowing each host in `hosts`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use rocket::uri;
+    /// use rocket::request::SameOriginHosts;
+    /// use rocket::http::uri::Host;
+    ///
+    /// let hosts = SameOriginHosts::new([
+    ///     Host::new(uri!("example.com")),
+    ///     Host::new(uri!("example.com:8443")),
+    /// ]);
+    /// ```
+    pub fn new<I: IntoIterator<Item = Host<'static>>>(hosts: I) -> Self {
+        SameOriginHosts(hosts.into_iter().collect())
+    }
+
+    /// Returns `true` if `host` is in `self`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use rocket::uri;
+    /// use rocket::request::SameOriginHosts;
+    /// use rocket::http::uri::Host;
+    ///
+    /// let hosts = SameOriginHosts::new([Host::new(uri!("example.com"))]);
+    /// assert!(hosts.contains(&Host::new(uri!("EXAMPLE.com"))));
+    /// assert!(!hosts.contains(&Host::new(uri!("example.com:8000"))));
+    /// ```
+    pub fn contains(&self, host: &Host<'_>) -> bool {
+        self.0.iter().any(|allowed| allowed == host)
+    }
+}
+
+impl<'r> SameOrigin<'r> {
+    fn check(req: &'r Request<'_>) -> Result<Self, SameOriginError> {
+        let headers = req.headers();
+        let value = headers.get_one("Origin")
+            .or_else(|| headers.get_one("Referer"))
+            .ok_or(SameOriginError::Missing)?;
+
+        let host = Absolute::parse(value.trim()).ok()
+            .and_then(|uri| uri.authority().cloned())
+            .map(Host::new)
+            .ok_or(SameOriginError::Malformed)?;
+
+        let allowed = req.host().map_or(false, |own| own == &host)
+            || req.rocket().state::<SameOriginHosts>().map_or(false, |h| h.contains(&host));
+
+        match allowed {
+            true => Ok(SameOrigin { host }),
+            false => Err(SameOriginError::Disallowed(host.to_string())),
+        }
+    }
+}
+
+#[crate::async_trait]
+impl<'r> FromRequest<'r> for SameOrigin<'r> {
+    type Error = SameOriginError;
+
+    async fn from_request(req: &'r Request<'_>) -> Outcome<Self, SameOriginError> {
+        match SameOrigin::check(req) {
+            Ok(origin) => Outcome::Success(origin),
+            Err(e) => {
+                warn_!("Rejecting cross-origin request: {}.", e);
+                Outcome::Error((Status::Forbidden, e))
+            }
+        }
+    }
+}
+
+impl fmt::Display for SameOriginError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SameOriginError::Missing => write!(f, "missing `Origin` and `Referer` headers"),
+            SameOriginError::Malformed => write!(f, "malformed `Origin` or `Referer` header"),
+            SameOriginError::Disallowed(host) => write!(f, "disallowed origin host `{}`", host),
+        }
+    }
+}
+
+impl std::error::Error for SameOriginError { }
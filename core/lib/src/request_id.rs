@@ -0,0 +1,152 @@
+//! Request-scoped correlation identifiers via the `X-Request-Id` header.
+//!
+//! The [`RequestId`] fairing assigns every incoming request an identifier,
+//! taken from the request's `X-Request-Id` header if it carries a valid one and
+//! generated otherwise. The identifier is:
+//!
+//!   * Stored in request-local state and retrievable via
+//!     [`Request::request_id()`].
+//!
+//!   * Prefixed to every log message Rocket's logger emits while the request is
+//!     being routed and handled, including those emitted by handlers, guards,
+//!     catchers, and response fairings.
+//!
+//!   * Echoed back to the client in the `X-Request-Id` header of the response.
+//!
+//! # Usage
+//!
+//! Attach an instance of [`RequestId`]:
+//!
+//! ```rust
+//! # #[macro_use] extern crate rocket;
+//! use rocket::Request;
+//! use rocket::request_id::RequestId;
+//!
+//! #[get("/")]
+//! fn index(req: &Request<'_>) -> String {
+//!     info!("handling index");
+//!     format!("your request id is {}", req.request_id().unwrap())
+//! }
+//!
+//! #[launch]
+//! fn rocket() -> _ {
+//!     rocket::build()
+//!         .mount("/", routes![index])
+//!         .attach(RequestId::new())
+//! }
+//! ```
+//!
+//! # Validation
+//!
+//! An incoming identifier is accepted only if it is between 1 and
+//! [`RequestId::MAX_LEN`] bytes long and consists entirely of visible ASCII
+//! characters. This prevents clients from injecting arbitrary content into log
+//! output. Invalid identifiers are replaced with a generated one.
+//!
+//! Generated identifiers are 32 lowercase hexadecimal characters derived from
+//! 128 random bits.
+
+use std::sync::Arc;
+
+use crate::{Request, Response, Data};
+use crate::fairing::{Fairing, Info, Kind};
+use crate::http::uncased::Uncased;
+
+/// Fairing which assigns every request a correlation identifier.
+///
+/// See the [module level docs](crate::request_id) for details.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use rocket::request_id::RequestId;
+///
+/// #[launch]
+/// fn rocket() -> _ {
+///     rocket::build().attach(RequestId::new().header("X-Correlation-Id"))
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct RequestId {
+    header: Uncased<'static>,
+}
+
+/// The identifier of a request, stored in request-local state.
+pub(crate) struct Id(pub(crate) Arc<str>);
+
+impl RequestId {
+    /// The default name of the header carrying the identifier: `X-Request-Id`.
+    pub const HEADER: &'static str = "X-Request-Id";
+
+    /// The maximum length, in bytes, of an accepted incoming identifier.
+    pub const MAX_LEN: usize = 128;
+
+    /// Returns a new `RequestId` fairing which reads and writes the
+    /// [`RequestId::HEADER`] header.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::request_id::RequestId;
+    ///
+    /// let fairing = RequestId::new();
+    /// ```
+    pub fn new() -> Self {
+        RequestId { header: Uncased::from_borrowed(Self::HEADER) }
+    }
+
+    /// Sets the name of the header the identifier is read from and echoed in
+    /// to `name`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::request_id::RequestId;
+    ///
+    /// let fairing = RequestId::new().header("X-Correlation-Id");
+    /// ```
+    pub fn header<N: Into<String>>(mut self, name: N) -> Self {
+        self.header = Uncased::from_owned(name.into());
+        self
+    }
+
+    fn is_valid(id: &str) -> bool {
+        (1..=Self::MAX_LEN).contains(&id.len()) && id.bytes().all(|b| b.is_ascii_graphic())
+    }
+
+    fn generate() -> Arc<str> {
+        format!("{:032x}", rand::random::<u128>()).into()
+    }
+}
+
+impl Default for RequestId {
+    fn default() -> Self {
+        RequestId::new()
+    }
+}
+
+#[crate::async_trait]
+impl Fairing for RequestId {
+    fn info(&self) -> Info {
+        Info {
+            name: "Request ID",
+            kind: Kind::Request | Kind::Response | Kind::Singleton,
+        }
+    }
+
+    async fn on_request(&self, req: &mut Request<'_>, _: &mut Data<'_>) {
+        let id = match req.headers().get_one(self.header.as_str()) {
+            Some(id) if RequestId::is_valid(id) => id.into(),
+            _ => RequestId::generate(),
+        };
+
+        req.local_cache(|| Id(id));
+    }
+
+    async fn on_response<'r>(&self, req: &'r Request<'_>, res: &mut Response<'r>) {
+        if let Some(id) = req.request_id() {
+            res.set_raw_header(self.header.to_string(), id.to_string());
+        }
+    }
+}
@@ -0,0 +1,200 @@
+use time::{OffsetDateTime, PrimitiveDateTime, UtcOffset};
+use time::format_description::FormatItem;
+use time::macros::format_description;
+
+use crate::request::Request;
+use crate::response::{self, Responder, Response};
+use crate::http::{EntityTag, Method, Status};
+
+/// The IMF-fixdate format of an HTTP-date, for example
+/// `Sun, 06 Nov 1994 08:49:37 GMT`.
+const HTTP_DATE: &[FormatItem<'static>] = format_description!(
+    "[weekday repr:short], [day] [month repr:short] [year] [hour]:[minute]:[second] GMT"
+);
+
+/// Responds conditionally based on validators of the response: an `ETag`
+/// and/or a `Last-Modified` date.
+///
+/// `Conditional` evaluates the conditional request headers `If-Match`,
+/// `If-None-Match`, `If-Modified-Since`, and `If-Unmodified-Since` against
+/// its validators _before_ generating the wrapped response, as specified in
+/// [RFC 9110 §13.2.2]:
+///
+///   * If a precondition for a state-changing request fails, responds with a
+///     `412 Precondition Failed` by forwarding to the `412` catcher.
+///   * If the client's cached representation is current for a `GET` or `HEAD`
+///     request, responds with an empty `304 Not Modified`.
+///   * Otherwise, responds with the wrapped responder.
+///
+/// In all but the `412` case, and unless the wrapped responder fails or
+/// responds with a non-successful status, the `ETag` and `Last-Modified`
+/// headers are set to the validators. When the wrapped responder is not
+/// invoked, it is not evaluated at all, so it can be cheaply constructed, as
+/// with a lazy streaming responder.
+///
+/// [RFC 9110 §13.2.2]: https://www.rfc-editor.org/rfc/rfc9110#section-13.2.2
+///
+/// # Example
+///
+/// ```rust
+/// # use rocket::get;
+/// use rocket::http::EntityTag;
+/// use rocket::response::Conditional;
+/// use rocket::time::OffsetDateTime;
+///
+/// struct Article { version: u64, updated: OffsetDateTime, body: String }
+///
+/// # fn load(id: usize) -> Article {
+/// #     Article { version: 1, updated: OffsetDateTime::now_utc(), body: "".into() }
+/// # }
+/// #[get("/article/<id>")]
+/// fn article(id: usize) -> Conditional<String> {
+///     let article = load(id);
+///     Conditional::new(article.body)
+///         .etag(EntityTag::strong(article.version.to_string()))
+///         .last_modified(article.updated)
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Conditional<R> {
+    inner: R,
+    etag: Option<EntityTag<'static>>,
+    last_modified: Option<OffsetDateTime>,
+}
+
+impl<R> Conditional<R> {
+    /// Creates a new `Conditional` wrapping `inner` without any validators.
+    /// Without validators, only the `*` wildcard in an `If-Match` or
+    /// `If-None-Match` header has an effect.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::response::Conditional;
+    ///
+    /// let response = Conditional::new("Hello, world!");
+    /// ```
+    pub fn new(inner: R) -> Self {
+        Conditional { inner, etag: None, last_modified: None }
+    }
+
+    /// Sets the entity tag validator of the response to `etag`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::http::EntityTag;
+    /// use rocket::response::Conditional;
+    ///
+    /// let response = Conditional::new("Hello, world!")
+    ///     .etag(EntityTag::strong("hello-v1"));
+    /// ```
+    pub fn etag(mut self, etag: EntityTag<'_>) -> Self {
+        self.etag = Some(etag.into_owned());
+        self
+    }
+
+    /// Sets the last modification date validator of the response to `date`.
+    /// HTTP dates have a resolution of one second; sub-second precision is
+    /// discarded.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::response::Conditional;
+    /// use rocket::time::macros::datetime;
+    ///
+    /// let response = Conditional::new("Hello, world!")
+    ///     .last_modified(datetime!(2024-01-01 12:00 UTC));
+    /// ```
+    pub fn last_modified(mut self, date: OffsetDateTime) -> Self {
+        let date = date.to_offset(UtcOffset::UTC);
+        self.last_modified = Some(date.replace_nanosecond(0).unwrap_or(date));
+        self
+    }
+
+    /// Returns the status to respond with instead of the wrapped responder,
+    /// if any, after evaluating the conditional headers in `req`.
+    fn precondition(&self, req: &Request<'_>) -> Option<Status> {
+        let safe = matches!(req.method(), Method::Get | Method::Head);
+
+        if let Some(matches) = self.etag_matches(req, "If-Match", EntityTag::strong_eq) {
+            if !matches {
+                return Some(Status::PreconditionFailed);
+            }
+        } else if let Some(date) = self.date_header(req, "If-Unmodified-Since") {
+            if self.last_modified.map_or(false, |modified| modified > date) {
+                return Some(Status::PreconditionFailed);
+            }
+        }
+
+        if let Some(matches) = self.etag_matches(req, "If-None-Match", EntityTag::weak_eq) {
+            if matches {
+                return Some(if safe { Status::NotModified } else { Status::PreconditionFailed });
+            }
+        } else if safe {
+            if let Some(date) = self.date_header(req, "If-Modified-Since") {
+                if self.last_modified.map_or(false, |modified| modified <= date) {
+                    return Some(Status::NotModified);
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Returns `None` if there is no header `name` in `req`. Otherwise returns
+    /// whether the header is `*` or contains a tag matching `self.etag`
+    /// according to `eq`.
+    fn etag_matches<F>(&self, req: &Request<'_>, name: &str, eq: F) -> Option<bool>
+        where F: Fn(&EntityTag<'static>, &EntityTag<'_>) -> bool
+    {
+        let mut values = req.headers().get(name).peekable();
+        values.peek()?;
+
+        let matches = values.any(|value| {
+            if value.trim() == "*" {
+                return true;
+            }
+
+            let (Some(etag), Some(tags)) = (&self.etag, EntityTag::parse_list(value)) else {
+                return false;
+            };
+
+            tags.iter().any(|tag| eq(etag, tag))
+        });
+
+        Some(matches)
+    }
+
+    /// Returns the date in the header `name` in `req`, if there is a valid one.
+    fn date_header(&self, req: &Request<'_>, name: &str) -> Option<OffsetDateTime> {
+        let value = req.headers().get_one(name)?;
+        PrimitiveDateTime::parse(value.trim(), HTTP_DATE).ok().map(|date| date.assume_utc())
+    }
+}
+
+impl<'r, 'o: 'r, R: Responder<'r, 'o>> Responder<'r, 'o> for Conditional<R> {
+    fn respond_to(self, req: &'r Request<'_>) -> response::Result<'o> {
+        let mut response = match self.precondition(req) {
+            Some(Status::NotModified) => Response::build().status(Status::NotModified).finalize(),
+            Some(status) => return Err(status),
+            None => self.inner.respond_to(req)?,
+        };
+
+        let status = response.status();
+        if status.class().is_success() || status == Status::NotModified {
+            if let Some(etag) = self.etag {
+                response.set_header(etag);
+            }
+
+            if let Some(date) = self.last_modified {
+                if let Ok(date) = date.format(HTTP_DATE) {
+                    response.set_raw_header("Last-Modified", date);
+                }
+            }
+        }
+
+        Ok(response)
+    }
+}
@@ -0,0 +1,93 @@
+use crate::request::Request;
+use crate::response::{self, Responder};
+
+macro_rules! either {
+    ($(#[$attr:meta])* $name:ident: $($T:ident $t:lifetime),+) => {
+        $(#[$attr])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum $name<$($T),+> {
+            $(
+                #[doc = concat!("The responder of type `", stringify!($T), "`.")]
+                $T($T),
+            )+
+        }
+
+        /// Responds with the wrapped `Responder` in `self`, whichever variant
+        /// it is.
+        impl<'r, 'o: 'r, $($t: 'o,)+ $($T),+> Responder<'r, 'o> for $name<$($T),+>
+            where $($T: Responder<'r, $t>),+
+        {
+            fn respond_to(self, req: &'r Request<'_>) -> response::Result<'o> {
+                match self {
+                    $($name::$T(r) => r.respond_to(req),)+
+                }
+            }
+        }
+
+        impl<$($T: crate::Sentinel),+> crate::Sentinel for $name<$($T),+> {
+            fn abort(rocket: &crate::Rocket<crate::Ignite>) -> bool {
+                // Run every sentinel without short-circuiting, for the logs.
+                let aborts = [$($T::abort(rocket)),+];
+                aborts.iter().any(|abort| *abort)
+            }
+        }
+    };
+}
+
+either! {
+    /// A responder that is one of three responders.
+    ///
+    /// Like [`Either`](https://docs.rs/either) for two responders, `Either3`
+    /// allows a handler to return one of three different responder types
+    /// without nesting, as in `Either<A, Either<B, C>>`. It responds with
+    /// whichever responder it wraps. It is a [`Sentinel`](crate::Sentinel) if
+    /// all of its type parameters are.
+    ///
+    /// For four responders, use [`Either4`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use rocket::get;
+    /// use rocket::response::{Either3, Redirect};
+    /// use rocket::response::status::NotFound;
+    ///
+    /// #[get("/<id>")]
+    /// fn item(id: usize) -> Either3<String, Redirect, NotFound<&'static str>> {
+    ///     match id {
+    ///         0 => Either3::A("the first item".to_string()),
+    ///         1 => Either3::B(Redirect::to("/0")),
+    ///         _ => Either3::C(NotFound("no such item")),
+    ///     }
+    /// }
+    /// ```
+    Either3: A 'a, B 'b, C 'c
+}
+
+either! {
+    /// A responder that is one of four responders.
+    ///
+    /// Like [`Either3`], but for four responders.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use rocket::get;
+    /// use rocket::http::Status;
+    /// use rocket::response::{content, Either4, Redirect};
+    /// use rocket::response::status::NotFound;
+    ///
+    /// type Json = content::RawJson<&'static str>;
+    ///
+    /// #[get("/<id>")]
+    /// fn item(id: usize) -> Either4<Json, Redirect, NotFound<&'static str>, Status> {
+    ///     match id {
+    ///         0 => Either4::A(content::RawJson("{ \"id\": 0 }")),
+    ///         1 => Either4::B(Redirect::to("/0")),
+    ///         2..=9 => Either4::C(NotFound("no such item")),
+    ///         _ => Either4::D(Status::BadRequest),
+    ///     }
+    /// }
+    /// ```
+    Either4: A 'a, B 'b, C 'c, D 'd
+}
@@ -12,12 +12,21 @@
 //! result, you'll often have types of the form `A<B<C>>` consisting of three
 //! `Responder`s `A`, `B`, and `C`. This is normal and encouraged as the type
 //! names typically illustrate the intended response.
+//!
+//! # Alternatives
+//!
+//! A handler that returns one of several responder types can return an
+//! `Either<A, B>` for two types or an [`Either3`] or [`Either4`] for three or
+//! four, respectively, responding with whichever responder is returned.
 
 mod responder;
 mod redirect;
 mod response;
 mod debug;
 mod body;
+mod with_status;
+mod either;
+mod conditional;
 
 pub(crate) mod flash;
 
@@ -34,6 +43,69 @@ pub use self::responder::Responder;
 pub use self::redirect::Redirect;
 pub use self::flash::Flash;
 pub use self::debug::Debug;
+pub use self::with_status::WithStatus;
+pub use self::either::{Either3, Either4};
+pub use self::conditional::Conditional;
 
 /// Type alias for the `Result` of a [`Responder::respond_to()`] call.
 pub type Result<'r> = std::result::Result<Response<'r>, crate::http::Status>;
+
+crate::export! {
+    /// Creates a [`Redirect`] to a route-checked URI built by [`uri!`].
+    ///
+    /// `redirect!(...)` expands to `Redirect::to(uri!(...))`. Because [`uri!`]
+    /// verifies at compile-time that the target route exists and that the
+    /// supplied parameters are of the correct types, so does `redirect!`. This
+    /// eliminates redirects to nonexistent routes, a common result of
+    /// hardcoding URI strings.
+    ///
+    /// The redirect kind defaults to [`Redirect::to()`] (`303 See Other`). To
+    /// select a different kind, prefix the arguments with the name of the
+    /// corresponding constructor followed by a colon: one of `to`, `temporary`,
+    /// `permanent`, `found`, or `moved`.
+    ///
+    /// [`uri!`]: ../macro.uri.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate rocket;
+    /// use rocket::response::{redirect, Redirect};
+    ///
+    /// #[get("/hello/<name>")]
+    /// fn hello(name: &str) -> String {
+    ///     format!("Hello, {}!", name)
+    /// }
+    ///
+    /// #[get("/")]
+    /// fn index() -> Redirect {
+    ///     // Equivalent to `Redirect::to(uri!(hello("world")))`.
+    ///     redirect!(hello("world"))
+    /// }
+    ///
+    /// #[get("/old/<name>")]
+    /// fn old(name: &str) -> Redirect {
+    ///     redirect!(permanent: "/v2", hello(name))
+    /// }
+    /// ```
+    ///
+    /// Referring to a route that doesn't exist fails to compile:
+    ///
+    /// ```rust,compile_fail
+    /// # #[macro_use] extern crate rocket;
+    /// use rocket::response::{redirect, Redirect};
+    ///
+    /// #[get("/")]
+    /// fn index() -> Redirect {
+    ///     redirect!(nonexistent())
+    /// }
+    /// ```
+    macro_rules! redirect {
+        (to: $($uri:tt)+) => ($crate::response::Redirect::to($crate::uri!($($uri)+)));
+        (temporary: $($uri:tt)+) => ($crate::response::Redirect::temporary($crate::uri!($($uri)+)));
+        (permanent: $($uri:tt)+) => ($crate::response::Redirect::permanent($crate::uri!($($uri)+)));
+        (found: $($uri:tt)+) => ($crate::response::Redirect::found($crate::uri!($($uri)+)));
+        (moved: $($uri:tt)+) => ($crate::response::Redirect::moved($crate::uri!($($uri)+)));
+        ($($uri:tt)+) => ($crate::response::Redirect::to($crate::uri!($($uri)+)));
+    }
+}
@@ -4,10 +4,11 @@ use std::collections::HashMap;
 
 use tokio::io::{AsyncRead, AsyncSeek};
 
-use crate::http::{Header, HeaderMap, Status, ContentType, Cookie};
+use crate::http::{Header, HeaderMap, Status, CustomStatus, ContentType, Cookie};
 use crate::http::uncased::{Uncased, AsUncased};
 use crate::data::IoHandler;
 use crate::response::Body;
+use crate::response::stream::Trailers;
 
 /// Builder for the [`Response`] type.
 ///
@@ -109,6 +110,25 @@ impl<'r> Builder<'r> {
         self
     }
 
+    /// Sets the status of the `Response` being built to `status`, including
+    /// its custom reason phrase.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::Response;
+    /// use rocket::http::Status;
+    ///
+    /// let response = Response::build()
+    ///     .custom_status(Status::custom(299, "Stale Data"))
+    ///     .finalize();
+    /// ```
+    #[inline(always)]
+    pub fn custom_status(&mut self, status: CustomStatus) -> &mut Builder<'r> {
+        self.response.set_custom_status(status);
+        self
+    }
+
     /// Adds `header` to the `Response`, replacing any header with the same name
     /// that already exists in the response. If multiple headers with
     /// the same name exist, they are all removed, and only the new header and
@@ -324,6 +344,32 @@ impl<'r> Builder<'r> {
         self
     }
 
+    /// Sets the trailers of the response to `trailers`.
+    ///
+    /// See [`Response::set_trailers()`] for notes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use rocket::Response;
+    /// use rocket::http::Header;
+    /// use rocket::response::stream::Trailers;
+    ///
+    /// let trailers = Trailers::new();
+    /// let response = Response::build()
+    ///     .streamed_body(Cursor::new("Hello, world!"))
+    ///     .trailers(trailers.clone())
+    ///     .finalize();
+    ///
+    /// trailers.set(Header::new("X-Checksum", "8843d7f9"));
+    /// ```
+    #[inline(always)]
+    pub fn trailers(&mut self, trailers: Trailers) -> &mut Builder<'r> {
+        self.response.set_trailers(trailers);
+        self
+    }
+
     /// Merges the `other` `Response` into `self` by setting any fields in
     /// `self` to the corresponding value in `other` if they are set in `other`.
     /// Fields in `self` are unchanged if they are not set in `other`. If a
@@ -482,9 +528,11 @@ impl<'r> Builder<'r> {
 #[derive(Default)]
 pub struct Response<'r> {
     status: Option<Status>,
+    reason: Option<&'static str>,
     headers: HeaderMap<'r>,
     body: Body<'r>,
     upgrade: HashMap<Uncased<'r>, Box<dyn IoHandler + 'r>>,
+    trailers: Option<Trailers>,
 }
 
 impl<'r> Response<'r> {
@@ -560,7 +608,8 @@ impl<'r> Response<'r> {
         self.status.unwrap_or(Status::Ok)
     }
 
-    /// Sets the status of `self` to `status`.
+    /// Sets the status of `self` to `status`, clearing any custom reason
+    /// phrase.
     ///
     /// # Example
     ///
@@ -575,6 +624,51 @@ impl<'r> Response<'r> {
     #[inline(always)]
     pub fn set_status(&mut self, status: Status) {
         self.status = Some(status);
+        self.reason = None;
+    }
+
+    /// Returns the custom reason phrase of `self`, if one was set via
+    /// [`Response::set_custom_status()`]. The phrase is emitted in the status
+    /// line of HTTP/1 responses in place of the canonical reason phrase.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::Response;
+    /// use rocket::http::Status;
+    ///
+    /// let mut response = Response::new();
+    /// assert_eq!(response.reason(), None);
+    ///
+    /// response.set_custom_status(Status::custom(299, "Stale Data"));
+    /// assert_eq!(response.reason(), Some("Stale Data"));
+    ///
+    /// response.set_status(Status::Ok);
+    /// assert_eq!(response.reason(), None);
+    /// ```
+    #[inline(always)]
+    pub fn reason(&self) -> Option<&'static str> {
+        self.reason
+    }
+
+    /// Sets the status of `self` to `status`, including its custom reason
+    /// phrase.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::Response;
+    /// use rocket::http::Status;
+    ///
+    /// let mut response = Response::new();
+    /// response.set_custom_status(Status::custom(299, "Stale Data"));
+    /// assert_eq!(response.status(), Status::new(299));
+    /// assert_eq!(response.reason(), Some("Stale Data"));
+    /// ```
+    #[inline(always)]
+    pub fn set_custom_status(&mut self, status: CustomStatus) {
+        self.status = Some(status.status);
+        self.reason = Some(status.reason());
     }
 
     /// Returns the Content-Type header of `self`. If the header is not present
@@ -1014,9 +1108,53 @@ impl<'r> Response<'r> {
         self.body_mut().set_max_chunk_size(size);
     }
 
-    /// Replaces this response's status and body with that of `other`, if they
-    /// exist in `other`. Any headers that exist in `other` replace the ones in
-    /// `self`. Any in `self` that aren't in `other` remain in `self`.
+    /// Returns the handle to the trailers of this response, if any are set.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::Response;
+    /// use rocket::response::stream::Trailers;
+    ///
+    /// let mut response = Response::new();
+    /// assert!(response.trailers().is_none());
+    ///
+    /// response.set_trailers(Trailers::new());
+    /// assert!(response.trailers().is_some());
+    /// ```
+    #[inline(always)]
+    pub fn trailers(&self) -> Option<&Trailers> {
+        self.trailers.as_ref()
+    }
+
+    /// Sets the trailers of this response to `trailers`, replacing any
+    /// existing trailers. Trailers are sent after the body has been written;
+    /// see [`Trailers`] for details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use rocket::Response;
+    /// use rocket::http::Header;
+    /// use rocket::response::stream::Trailers;
+    ///
+    /// let trailers = Trailers::new();
+    /// trailers.set(Header::new("X-Checksum", "8843d7f9"));
+    ///
+    /// let mut response = Response::new();
+    /// response.set_streamed_body(Cursor::new("Hello, world!"));
+    /// response.set_trailers(trailers);
+    /// ```
+    #[inline(always)]
+    pub fn set_trailers(&mut self, trailers: Trailers) {
+        self.trailers = Some(trailers);
+    }
+
+    /// Replaces this response's status, body, and trailers with those of
+    /// `other`, if they exist in `other`. Any headers that exist in `other`
+    /// replace the ones in `self`. Any in `self` that aren't in `other` remain
+    /// in `self`.
     ///
     /// # Example
     ///
@@ -1048,20 +1186,25 @@ impl<'r> Response<'r> {
     pub fn merge(&mut self, other: Response<'r>) {
         if let Some(status) = other.status {
             self.status = Some(status);
+            self.reason = other.reason;
         }
 
         if other.body().is_some() {
             self.body = other.body;
         }
 
+        if other.trailers.is_some() {
+            self.trailers = other.trailers;
+        }
+
         for (name, values) in other.headers.into_iter_raw() {
             self.headers.replace_all(name.into_cow(), values);
         }
     }
 
-    /// Sets `self`'s status and body to that of `other` if they are not already
-    /// set in `self`. Any headers present in both `other` and `self` are
-    /// adjoined.
+    /// Sets `self`'s status, body, and trailers to those of `other` if they are
+    /// not already set in `self`. Any headers present in both `other` and
+    /// `self` are adjoined.
     ///
     /// # Example
     ///
@@ -1093,12 +1236,17 @@ impl<'r> Response<'r> {
     pub fn join(&mut self, other: Response<'r>) {
         if self.status.is_none() {
             self.status = other.status;
+            self.reason = other.reason;
         }
 
         if self.body.is_none() {
             self.body = other.body;
         }
 
+        if self.trailers.is_none() {
+            self.trailers = other.trailers;
+        }
+
         for (name, mut values) in other.headers.into_iter_raw() {
             self.headers.add_all(name.into_cow(), &mut values);
         }
@@ -1107,7 +1255,10 @@ impl<'r> Response<'r> {
 
 impl fmt::Debug for Response<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        writeln!(f, "{}", self.status())?;
+        match self.reason {
+            Some(reason) => writeln!(f, "{} {}", self.status().code, reason)?,
+            None => writeln!(f, "{}", self.status())?,
+        }
 
         for header in self.headers().iter() {
             writeln!(f, "{}", header)?;
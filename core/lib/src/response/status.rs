@@ -12,7 +12,10 @@
 //! The [`Custom`] type allows responding with _any_ `Status` but _does not_
 //! ensure that all of the required headers are present. As a convenience,
 //! `(Status, R)` where `R: Responder` is _also_ a `Responder`, identical to
-//! `Custom`.
+//! `Custom`. To construct a status dynamically from a code or to additionally
+//! set headers, use [`WithStatus`](crate::response::WithStatus). Similarly,
+//! `(CustomStatus, R)` responds with a status with a custom reason phrase,
+//! created via [`Status::custom()`].
 //!
 //! ```rust
 //! # extern crate rocket;
@@ -31,7 +34,7 @@ use std::borrow::Cow;
 
 use crate::request::Request;
 use crate::response::{self, Responder, Response};
-use crate::http::Status;
+use crate::http::{Status, CustomStatus};
 
 /// Sets the status of the response to 201 Created.
 ///
@@ -249,6 +252,28 @@ impl<'r, 'o: 'r, R: Responder<'r, 'o>> Responder<'r, 'o> for (Status, R) {
     }
 }
 
+/// Sets the status and custom reason phrase of the response and then delegates
+/// the remainder of the response to the wrapped responder.
+///
+/// # Example
+///
+/// ```rust
+/// # use rocket::get;
+/// use rocket::http::{Status, CustomStatus};
+///
+/// #[get("/")]
+/// fn handler() -> (CustomStatus, &'static str) {
+///     (Status::custom(299, "Stale Data"), "old news")
+/// }
+/// ```
+impl<'r, 'o: 'r, R: Responder<'r, 'o>> Responder<'r, 'o> for (CustomStatus, R) {
+    fn respond_to(self, request: &'r Request<'_>) -> response::Result<'o> {
+        Response::build_from(self.1.respond_to(request)?)
+            .custom_status(self.0)
+            .ok()
+    }
+}
+
 macro_rules! status_response {
     ($T:ident $kind:expr) => {
         /// Sets the status of the response to
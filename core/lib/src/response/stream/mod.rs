@@ -32,10 +32,11 @@
 //!
 //! # Typed Streams
 //!
-//! A raw stream is not a `Responder`, so it cannot be directly returned from a
-//! route handler. Instead, one of three _typed_ streams may be used. Each typed
-//! stream places type bounds on the `Item` of the stream, allowing for
-//! `Responder` implementation on the stream itself.
+//! A raw stream is not a `Responder`. While a route handler declared to return
+//! an `impl Stream` is [treated specially](#returning-raw-streams), one of the
+//! _typed_ streams below must be used in all other cases. Each typed stream
+//! places type bounds on the `Item` of the stream, allowing for `Responder`
+//! implementation on the stream itself.
 //!
 //! Each typed stream exists both as a type and as a macro. They are:
 //!
@@ -44,6 +45,12 @@
 //!   * [`struct@TextStream`] ([`TextStream!`]) - streams of `T: AsRef<str>`
 //!   * [`struct@EventStream`] ([`EventStream!`]) - Server-Sent [`Event`] stream
 //!
+//! With the `json` feature enabled, [`JsonStream`] ([`JsonStream!`]) streams
+//! values of type `T: Serialize` as the elements of a single JSON array.
+//!
+//! [`JsonStream`]: crate::serde::json::JsonStream
+//! [`JsonStream!`]: crate::serde::json::JsonStream!
+//!
 //! Each type implements `Responder`; each macro can be invoked to generate a
 //! typed stream, exactly like [`stream!`] above. Additionally, each macro is
 //! also a _type_ macro, expanding to a wrapped `impl Stream<Item = $T>`, where
@@ -92,6 +99,34 @@
 //! The expansions are identical for `ReaderStream` and `ByteStream`, with
 //! `TextStream` replaced with `ReaderStream` and `ByteStream`, respectively.
 //!
+//! ## Returning Raw Streams
+//!
+//! As a convenience, a route handler whose return type is declared as `impl
+//! Stream<Item = T>`, where `T: AsRef<[u8]>`, responds exactly as if it had
+//! returned a [`struct@ByteStream`]: the body is streamed in chunks as items
+//! are yielded, and the `Content-Type` is set to
+//! [`Binary`](crate::http::ContentType::Binary).
+//!
+//! ```rust
+//! # use rocket::get;
+//! use rocket::futures::stream::Stream;
+//! use rocket::response::stream::stream;
+//!
+//! #[get("/numbers")]
+//! fn numbers() -> impl Stream<Item = String> {
+//!     stream! {
+//!         for i in 0..10 {
+//!             yield format!("{}\n", i);
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! Only the declared return type is considered: a raw stream nested in
+//! another type, such as an `Option<impl Stream>`, must still be wrapped. To
+//! control the `Content-Type`, use a typed stream such as
+//! [`struct@TextStream`] instead.
+//!
 //! ## Borrowing
 //!
 //! A stream can _yield_ borrowed values with no extra effort:
@@ -187,6 +222,31 @@
 //!     }
 //! }
 //! ```
+//!
+//! # Trailers
+//!
+//! A streaming response can send [`Trailers`], headers sent after the body,
+//! for metadata that is only known once the stream has completed, such as a
+//! checksum. [`WithTrailers`] provides the stream with a handle to set them:
+//!
+//! ```rust
+//! # use rocket::get;
+//! use rocket::http::Header;
+//! use rocket::response::stream::{TextStream, WithTrailers};
+//!
+//! #[get("/words")]
+//! fn words() -> WithTrailers<TextStream![&'static str]> {
+//!     WithTrailers::new(|trailers| TextStream! {
+//!         let mut count = 0;
+//!         for word in ["hello", " ", "world"] {
+//!             count += 1;
+//!             yield word;
+//!         }
+//!
+//!         trailers.set(Header::new("X-Word-Count", count.to_string()));
+//!     })
+//! }
+//! ```
 
 mod reader;
 mod bytes;
@@ -194,6 +254,7 @@ mod text;
 mod one;
 mod sse;
 mod raw_sse;
+mod trailers;
 
 pub(crate) use self::raw_sse::*;
 
@@ -202,6 +263,7 @@ pub use self::text::TextStream;
 pub use self::bytes::ByteStream;
 pub use self::reader::ReaderStream;
 pub use self::sse::{Event, EventStream};
+pub use self::trailers::{Trailers, WithTrailers};
 
 crate::export! {
     /// Retrofitted support for [`Stream`]s with `yield`, `for await` syntax.
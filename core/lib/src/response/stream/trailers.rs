@@ -0,0 +1,196 @@
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+
+use crate::request::Request;
+use crate::response::{self, Responder};
+use crate::http::{Header, HeaderMap};
+
+/// A handle to the trailing headers of a streaming response.
+///
+/// Trailers are headers sent _after_ the response body, allowing a streaming
+/// response to send metadata only known once the body has been generated,
+/// such as a checksum or a final status. A `Trailers` handle is cheap to clone
+/// and all clones refer to the same set of trailers. Trailers set at any point
+/// before the body has been completely written are sent to the client.
+///
+/// Trailers are attached to a response via [`WithTrailers`] or
+/// [`Response::set_trailers()`](crate::Response::set_trailers()).
+///
+/// # Protocol Support
+///
+/// Trailers are always sent over HTTP/2 and HTTP/3. Over HTTP/1.1, trailers
+/// are only sent for chunked responses, which streaming responses typically
+/// are, to clients that indicate support for them with a `TE: trailers`
+/// request header. It is customary, though not required, to list the names of
+/// the trailers in a `Trailer` response header. Trailers that are not valid
+/// HTTP headers are logged and ignored.
+///
+/// # Example
+///
+/// ```rust
+/// use rocket::http::Header;
+/// use rocket::response::stream::Trailers;
+///
+/// let trailers = Trailers::new();
+/// trailers.set(Header::new("X-Checksum", "8843d7f9"));
+/// assert_eq!(trailers.get_one("X-Checksum").as_deref(), Some("8843d7f9"));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Trailers(Arc<Mutex<HeaderMap<'static>>>);
+
+impl Trailers {
+    /// Returns a new handle to an empty set of trailers.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::response::stream::Trailers;
+    ///
+    /// let trailers = Trailers::new();
+    /// assert!(trailers.headers().is_empty());
+    /// ```
+    pub fn new() -> Self {
+        Trailers::default()
+    }
+
+    /// Sets the trailer `header`, replacing any existing trailers with the
+    /// same name.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::http::Header;
+    /// use rocket::response::stream::Trailers;
+    ///
+    /// let trailers = Trailers::new();
+    /// trailers.set(Header::new("X-Status", "pending"));
+    /// trailers.set(Header::new("X-Status", "done"));
+    /// assert_eq!(trailers.get_one("X-Status").as_deref(), Some("done"));
+    /// ```
+    pub fn set<H: Into<Header<'static>>>(&self, header: H) {
+        self.0.lock().replace(header);
+    }
+
+    /// Adds the trailer `header`, preserving any existing trailers with the
+    /// same name.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::http::Header;
+    /// use rocket::response::stream::Trailers;
+    ///
+    /// let trailers = Trailers::new();
+    /// trailers.add(Header::new("X-Part", "1"));
+    /// trailers.add(Header::new("X-Part", "2"));
+    /// assert_eq!(trailers.headers().get("X-Part").count(), 2);
+    /// ```
+    pub fn add<H: Into<Header<'static>>>(&self, header: H) {
+        self.0.lock().add(header);
+    }
+
+    /// Returns the first value of the trailer named `name`, if there is one.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::http::Header;
+    /// use rocket::response::stream::Trailers;
+    ///
+    /// let trailers = Trailers::new();
+    /// assert!(trailers.get_one("X-Checksum").is_none());
+    ///
+    /// trailers.set(Header::new("X-Checksum", "8843d7f9"));
+    /// assert_eq!(trailers.get_one("X-Checksum").as_deref(), Some("8843d7f9"));
+    /// ```
+    pub fn get_one(&self, name: &str) -> Option<String> {
+        self.0.lock().get_one(name).map(|value| value.to_string())
+    }
+
+    /// Returns a copy of the trailers currently set.
+    ///
+    /// Once the trailers have been sent to the client, the set is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::http::Header;
+    /// use rocket::response::stream::Trailers;
+    ///
+    /// let trailers = Trailers::new();
+    /// trailers.set(Header::new("X-Checksum", "8843d7f9"));
+    /// assert_eq!(trailers.headers().len(), 1);
+    /// ```
+    pub fn headers(&self) -> HeaderMap<'static> {
+        self.0.lock().clone()
+    }
+
+    /// Takes the currently set trailers, leaving the set empty.
+    pub(crate) fn take(&self) -> HeaderMap<'static> {
+        std::mem::take(&mut *self.0.lock())
+    }
+}
+
+/// A responder that attaches [`Trailers`] to the response of another.
+///
+/// The responder is constructed from a function which receives a handle to the
+/// trailers and returns the wrapped responder, typically a stream that sets
+/// trailers as it completes.
+///
+/// # Example
+///
+/// Send a checksum of the streamed body as a trailer:
+///
+/// ```rust
+/// # use rocket::get;
+/// use rocket::http::Header;
+/// use rocket::response::stream::{ByteStream, WithTrailers};
+///
+/// #[get("/data")]
+/// fn data() -> WithTrailers<ByteStream![Vec<u8>]> {
+///     WithTrailers::new(|trailers| ByteStream! {
+///         let mut checksum = 0u8;
+///         for chunk in [vec![1, 2, 3], vec![4, 5, 6]] {
+///             checksum = chunk.iter().fold(checksum, |a, b| a.wrapping_add(*b));
+///             yield chunk;
+///         }
+///
+///         trailers.set(Header::new("X-Checksum", checksum.to_string()));
+///     })
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct WithTrailers<R> {
+    inner: R,
+    trailers: Trailers,
+}
+
+impl<R> WithTrailers<R> {
+    /// Creates a new `WithTrailers` from the responder returned by `f`, which
+    /// is passed a handle to the trailers of the response.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::http::Header;
+    /// use rocket::response::stream::{TextStream, WithTrailers};
+    ///
+    /// let response = WithTrailers::new(|trailers| TextStream! {
+    ///     yield "Hello, world!";
+    ///     trailers.set(Header::new("X-Done", "true"));
+    /// });
+    /// ```
+    pub fn new<F: FnOnce(Trailers) -> R>(f: F) -> Self {
+        let trailers = Trailers::new();
+        WithTrailers { inner: f(trailers.clone()), trailers }
+    }
+}
+
+impl<'r, 'o: 'r, R: Responder<'r, 'o>> Responder<'r, 'o> for WithTrailers<R> {
+    fn respond_to(self, req: &'r Request<'_>) -> response::Result<'o> {
+        let mut response = self.inner.respond_to(req)?;
+        response.set_trailers(self.trailers);
+        Ok(response)
+    }
+}
@@ -0,0 +1,175 @@
+use std::borrow::Cow;
+
+use crate::request::Request;
+use crate::response::{self, Responder, Response};
+use crate::http::{Header, Status};
+
+/// Sets a dynamic status and additional headers before delegating the
+/// remainder of the response to a wrapped responder.
+///
+/// Unlike [`status::Custom`](crate::response::status::Custom), `WithStatus`
+/// can be constructed from a raw status code, rejecting invalid codes, and
+/// allows setting any number of headers via builder methods. Because it wraps
+/// any [`Responder`], it composes with the wrappers in
+/// [`content`](crate::response::content), either as the outer or inner type.
+///
+/// # Invalid Status Codes
+///
+/// HTTP status codes are three digits long, and thus must be in the range
+/// `[100, 599]`. [`WithStatus::from_code()`] returns `None` when given a code
+/// outside of this range. [`WithStatus::new()`] accepts any [`Status`]; if its
+/// code is invalid, responding fails with a `500 Internal Server Error`.
+///
+/// # Example
+///
+/// ```rust
+/// # use rocket::get;
+/// use rocket::http::{ContentType, Status};
+/// use rocket::response::{content, WithStatus};
+///
+/// #[get("/<code>")]
+/// fn forced(code: u16) -> Option<WithStatus<content::RawJson<String>>> {
+///     let body = content::RawJson(format!("{{ \"code\": {} }}", code));
+///     let response = WithStatus::from_code(code, body)?
+///         .raw_header("X-Forced", "true")
+///         .header(ContentType::new("application", "problem+json"));
+///
+///     Some(response)
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct WithStatus<R> {
+    status: Status,
+    headers: Vec<Header<'static>>,
+    inner: R,
+}
+
+impl<R> WithStatus<R> {
+    /// Creates a new `WithStatus` which responds with `status` and delegates
+    /// the remainder of the response to `inner`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::http::Status;
+    /// use rocket::response::WithStatus;
+    ///
+    /// let response = WithStatus::new(Status::ImATeapot, "short and stout");
+    /// assert_eq!(response.status(), Status::ImATeapot);
+    /// ```
+    pub fn new(status: Status, inner: R) -> Self {
+        WithStatus { status, headers: vec![], inner }
+    }
+
+    /// Creates a new `WithStatus` which responds with a status of `code` and
+    /// delegates the remainder of the response to `inner`. Returns `None` if
+    /// `code` is not a valid HTTP status code, i.e, not in `[100, 599]`.
+    ///
+    /// Codes without a known reason phrase, such as `299`, are valid.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::http::Status;
+    /// use rocket::response::WithStatus;
+    ///
+    /// let response = WithStatus::from_code(418, "short and stout").unwrap();
+    /// assert_eq!(response.status(), Status::ImATeapot);
+    ///
+    /// let response = WithStatus::from_code(299, "custom").unwrap();
+    /// assert_eq!(response.status().code, 299);
+    ///
+    /// assert!(WithStatus::from_code(99, "invalid").is_none());
+    /// assert!(WithStatus::from_code(600, "invalid").is_none());
+    /// ```
+    pub fn from_code(code: u16, inner: R) -> Option<Self> {
+        Self::is_valid(code).then(|| WithStatus::new(Status::new(code), inner))
+    }
+
+    /// Adds `header` to the response, replacing any existing headers with the
+    /// same name, including those set by the wrapped responder.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::http::{ContentType, Status};
+    /// use rocket::response::WithStatus;
+    ///
+    /// let response = WithStatus::new(Status::Accepted, "{}")
+    ///     .header(ContentType::JSON);
+    /// ```
+    pub fn header<H: Into<Header<'static>>>(mut self, header: H) -> Self {
+        self.headers.push(header.into());
+        self
+    }
+
+    /// Adds a header with `name` and `value` to the response, replacing any
+    /// existing headers with the same name, including those set by the wrapped
+    /// responder.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::http::Status;
+    /// use rocket::response::WithStatus;
+    ///
+    /// let response = WithStatus::new(Status::Accepted, "queued")
+    ///     .raw_header("Retry-After", "120");
+    /// ```
+    pub fn raw_header<N, V>(self, name: N, value: V) -> Self
+        where N: Into<Cow<'static, str>>, V: Into<Cow<'static, str>>
+    {
+        self.header(Header::new(name, value))
+    }
+
+    /// Returns the status the response will be sent with.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::http::Status;
+    /// use rocket::response::WithStatus;
+    ///
+    /// let response = WithStatus::new(Status::Gone, ());
+    /// assert_eq!(response.status(), Status::Gone);
+    /// ```
+    pub fn status(&self) -> Status {
+        self.status
+    }
+
+    /// Returns the wrapped responder.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::http::Status;
+    /// use rocket::response::WithStatus;
+    ///
+    /// let response = WithStatus::new(Status::Gone, "gone");
+    /// assert_eq!(response.into_inner(), "gone");
+    /// ```
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    fn is_valid(code: u16) -> bool {
+        (100..=599).contains(&code)
+    }
+}
+
+impl<'r, 'o: 'r, R: Responder<'r, 'o>> Responder<'r, 'o> for WithStatus<R> {
+    fn respond_to(self, req: &'r Request<'_>) -> response::Result<'o> {
+        if !Self::is_valid(self.status.code) {
+            error_!("Invalid status code {} in `WithStatus` responder.", self.status.code);
+            return Err(Status::InternalServerError);
+        }
+
+        let mut builder = Response::build_from(self.inner.respond_to(req)?);
+        builder.status(self.status);
+        for header in self.headers {
+            builder.header(header);
+        }
+
+        builder.ok()
+    }
+}
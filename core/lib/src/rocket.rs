@@ -11,12 +11,13 @@ use figment::{Figment, Provider};
 use futures::TryFutureExt;
 
 use crate::shutdown::{Stages, Shutdown};
-use crate::{sentinel, shield::Shield, Catcher, Config, Route};
+use crate::{sentinel, shield::Shield, Catcher, Config, Request, Route};
 use crate::listener::{Bind, DefaultListener, Endpoint, Listener};
 use crate::router::Router;
 use crate::fairing::{Fairing, Fairings};
 use crate::phase::{Phase, Build, Building, Ignite, Igniting, Orbit, Orbiting};
 use crate::phase::{Stateful, StateRef, State};
+use crate::http::Status;
 use crate::http::uri::Origin;
 use crate::http::ext::IntoOwned;
 use crate::error::{Error, ErrorKind};
@@ -241,6 +242,41 @@ impl Rocket<Build> {
         self
     }
 
+    /// Merges `provider` into the current configuration provider, overriding
+    /// only the values `provider` sets.
+    ///
+    /// This is a shorthand for [`Rocket::reconfigure()`] with the result of
+    /// merging `provider` into [`Rocket::figment()`]. As such, `provider` can
+    /// be any [`Provider`], including a key-value tuple, making it convenient
+    /// for small configuration overrides, particularly in tests.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::Config;
+    /// use rocket::data::{Limits, ToByteUnit};
+    /// # type Result = std::result::Result<(), rocket::Error>;
+    ///
+    /// # let _: Result = rocket::async_test(async move {
+    /// let rocket = rocket::build()
+    ///     .merge_config(("port", 0))
+    ///     .merge_config((Config::KEEP_ALIVE, 10))
+    ///     .merge_config((Config::LIMITS, Limits::new().limit("json", 2.mebibytes())))
+    ///     .ignite().await?;
+    ///
+    /// assert_eq!(rocket.figment().extract_inner::<u16>("port").unwrap(), 0);
+    /// assert_eq!(rocket.config().keep_alive, 10);
+    /// assert_eq!(rocket.config().limits.get("json"), Some(2.mebibytes()));
+    /// # Ok(())
+    /// # });
+    /// ```
+    #[must_use]
+    pub fn merge_config<T: Provider>(mut self, provider: T) -> Self {
+        let figment = std::mem::replace(&mut self.figment, Figment::new());
+        self.figment = figment.merge(provider);
+        self
+    }
+
     #[track_caller]
     fn load<'a, B, T, F, M>(mut self, kind: &str, base: B, items: Vec<T>, m: M, f: F) -> Self
         where B: TryInto<Origin<'a>> + Clone + fmt::Display,
@@ -372,6 +408,56 @@ impl Rocket<Build> {
             |r, route| r.0.routes.push(route))
     }
 
+    /// Retains only the mounted routes for which `f` returns `true`, removing
+    /// all others.
+    ///
+    /// Together with [`Rocket::mount()`], this allows an
+    /// [ignite fairing](crate::fairing::Fairing#ignite) to compose the set of
+    /// routes based on configuration. Routes are collected into the router
+    /// only after all ignite fairings have run, so collisions are checked for
+    /// the final set of routes.
+    ///
+    /// # Example
+    ///
+    /// Mount `admin` routes only when `admin` is enabled in the configuration,
+    /// and remove the `debug` route in release mode:
+    ///
+    /// ```rust,no_run
+    /// # #[macro_use] extern crate rocket;
+    /// use rocket::fairing::AdHoc;
+    ///
+    /// #[get("/debug")]
+    /// fn debug() -> &'static str { "debug" }
+    ///
+    /// #[get("/admin")]
+    /// fn admin() -> &'static str { "admin" }
+    ///
+    /// #[launch]
+    /// fn rocket() -> _ {
+    ///     rocket::build()
+    ///         .mount("/", routes![debug])
+    ///         .attach(AdHoc::on_ignite("Route Flags", |rocket| async {
+    ///             let admin = rocket.figment().extract_inner("admin").unwrap_or(false);
+    ///             let rocket = match admin {
+    ///                 true => rocket.mount("/", routes![admin]),
+    ///                 false => rocket,
+    ///             };
+    ///
+    ///             match cfg!(debug_assertions) {
+    ///                 true => rocket,
+    ///                 false => rocket.retain_routes(|r| r.name.as_deref() != Some("debug")),
+    ///             }
+    ///         }))
+    /// }
+    /// ```
+    #[must_use]
+    pub fn retain_routes<F>(mut self, f: F) -> Self
+        where F: FnMut(&Route) -> bool
+    {
+        self.0.routes.retain(f);
+        self
+    }
+
     /// Registers all of the catchers in the supplied vector, scoped to `base`.
     ///
     /// # Panics
@@ -465,6 +551,49 @@ impl Rocket<Build> {
         self
     }
 
+    /// Manages a lazily initialized value of type `T`, wrapped in a
+    /// [`Lazy<T>`](crate::state::Lazy).
+    ///
+    /// `init` is not called until the value is first requested via
+    /// [`Lazy::get()`](crate::state::Lazy::get()), at which point the returned
+    /// future is awaited and its output cached. Concurrent first accesses
+    /// initialize the value exactly once. The value is retrieved with a
+    /// `&State<Lazy<T>>` request guard.
+    ///
+    /// # Panics
+    ///
+    /// Panics if state of type `Lazy<T>` is already being managed.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # #[macro_use] extern crate rocket;
+    /// use rocket::State;
+    /// use rocket::state::Lazy;
+    ///
+    /// struct Pool(usize);
+    ///
+    /// #[get("/")]
+    /// async fn index(pool: &State<Lazy<Pool>>) -> String {
+    ///     pool.get().await.0.to_string()
+    /// }
+    ///
+    /// #[launch]
+    /// fn rocket() -> _ {
+    ///     rocket::build()
+    ///         .manage_lazy(|| async { Pool(16) })
+    ///         .mount("/", routes![index])
+    /// }
+    /// ```
+    #[must_use]
+    pub fn manage_lazy<T, F, Fut>(self, init: F) -> Self
+        where T: Send + Sync + 'static,
+              F: Fn() -> Fut + Send + Sync + 'static,
+              Fut: Future<Output = T> + Send + 'static,
+    {
+        self.manage(crate::state::Lazy::new(init))
+    }
+
     /// Attaches a fairing to this instance of Rocket. No fairings are eagerly
     /// executed; fairings are executed at their appropriate time.
     ///
@@ -492,6 +621,57 @@ impl Rocket<Build> {
         self
     }
 
+    /// Attaches `fairing` to this instance of Rocket if `condition` is `true`.
+    /// Otherwise, `fairing` is dropped and `self` is returned unchanged.
+    ///
+    /// This is a convenience for conditionally attaching fairings, such as
+    /// those that should only run in some environments, without breaking a
+    /// chain of builder calls. See [`Rocket::attach()`] for details on
+    /// attaching.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # #[macro_use] extern crate rocket;
+    /// use rocket::fairing::AdHoc;
+    ///
+    /// #[launch]
+    /// fn rocket() -> _ {
+    ///     rocket::build()
+    ///         .attach_if(cfg!(debug_assertions), AdHoc::on_liftoff("Debug", |_| {
+    ///             Box::pin(async { println!("Running a debug build."); })
+    ///         }))
+    /// }
+    /// ```
+    #[must_use]
+    pub fn attach_if<F: Fairing>(self, condition: bool, fairing: F) -> Self {
+        if condition { self.attach(fairing) } else { self }
+    }
+
+    /// Attaches `fairing` to this instance of Rocket unless `condition` is
+    /// `true`. Otherwise, `fairing` is dropped and `self` is returned
+    /// unchanged. This is the inverse of [`Rocket::attach_if()`].
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # #[macro_use] extern crate rocket;
+    /// use rocket::fairing::AdHoc;
+    ///
+    /// #[launch]
+    /// fn rocket() -> _ {
+    ///     let testing = std::env::var_os("TESTING").is_some();
+    ///     rocket::build()
+    ///         .attach_unless(testing, AdHoc::on_liftoff("Banner", |_| {
+    ///             Box::pin(async { println!("Welcome!"); })
+    ///         }))
+    /// }
+    /// ```
+    #[must_use]
+    pub fn attach_unless<F: Fairing>(self, condition: bool, fairing: F) -> Self {
+        self.attach_if(!condition, fairing)
+    }
+
     /// Returns a `Future` that transitions this instance of `Rocket` into the
     /// _ignite_ phase.
     ///
@@ -562,9 +742,22 @@ impl Rocket<Build> {
 
         // Initialize the router; check for collisions.
         let mut router = Router::new();
-        self.routes.clone().into_iter().for_each(|r| router.add_route(r));
+        self.routes.clone().into_iter().for_each(|mut r| {
+            r.resolve_limits(&config.limits);
+            router.add_route(r);
+        });
         self.catchers.clone().into_iter().for_each(|c| router.add_catcher(c));
         router.finalize().map_err(ErrorKind::Collisions)?;
+        for (a, b) in router.rank_ambiguities() {
+            warn!("Catch-all routes {} and {} are ordered only by default rank.", a, b);
+            warn_!("Both match every {} request under {}.", a.method, a.uri.base());
+            warn_!("The route with the lower rank, {}, is always tried first.", a.rank.min(b.rank));
+            warn_!("Set an explicit `rank` on either route to silence this warning.");
+        }
+
+        // Extract per-route configuration sections once, for `RouteConfig`.
+        let sections = crate::request::RouteSections::extract(&self.figment, self.routes());
+        self.state.set(sections);
 
         // Finally, freeze managed state.
         self.state.freeze();
@@ -573,6 +766,16 @@ impl Rocket<Build> {
         // TODO: Store/print managed state type names?
         config.pretty_print(self.figment());
         log_items("📬 ", "Routes", self.routes(), |r| &r.uri.base, |r| &r.uri);
+        #[cfg(feature = "route-lints")]
+        for declared in crate::route::Declared::iter() {
+            if !self.routes().any(|r| declared.is(r)) {
+                let (file, line, col) = declared.location;
+                warn!("Route {} {} {} is declared but never mounted.",
+                    declared.name.primary(), declared.method.green(), declared.uri.blue());
+                warn_!("Declared at {}:{}:{}.", file, line, col);
+            }
+        }
+
         log_items("🥅 ", "Catchers", self.catchers(), |c| &c.base, |c| &c.base);
         self.fairings.pretty_print();
 
@@ -664,8 +867,14 @@ impl Rocket<Ignite> {
     }
 
     pub(crate) fn into_orbit(self, endpoints: Vec<Endpoint>) -> Rocket<Orbit> {
+        let in_flight = match self.0.config.max_concurrent_requests {
+            0 => None,
+            n => Some(tokio::sync::Semaphore::new(n)),
+        };
+
         Rocket(Orbiting {
             endpoints,
+            in_flight,
             router: self.0.router,
             fairings: self.0.fairings,
             figment: self.0.figment,
@@ -681,8 +890,8 @@ impl Rocket<Ignite> {
         rocket
     }
 
-    async fn _launch<L: Listener + 'static>(self, listener: L) -> Result<Rocket<Ignite>, Error> {
-        let rocket = self.listen_and_serve(listener, |rocket| async move {
+    async fn _launch<L: Listener + 'static>(self, listeners: Vec<L>) -> Result<Rocket<Ignite>, Error> {
+        let rocket = self.listen_and_serve(listeners, |rocket| async move {
             let rocket = Arc::new(rocket);
 
             rocket.shutdown.spawn_listener(&rocket.config.shutdown);
@@ -769,6 +978,10 @@ impl Rocket<Orbit> {
         launch_info!("{}{} {}", "🚀 ".emoji(),
             "Rocket has launched on".bold().primary().linger(),
             rocket.endpoints[0].underline());
+
+        for endpoint in &rocket.endpoints[1..] {
+            launch_msg_!("{} {}", "and on".primary(), endpoint.underline());
+        }
     }
 
     /// Returns the finalized, active configuration. This is guaranteed to
@@ -793,10 +1006,82 @@ impl Rocket<Orbit> {
         &self.config
     }
 
+    /// Returns an iterator over the endpoints Rocket is listening on.
+    ///
+    /// The endpoints are those of the bound listeners: they are _concrete_. In
+    /// particular, when configured to listen on port `0`, the endpoint
+    /// contains the port assigned by the operating system. This makes this
+    /// method useful to discover where an application is reachable, for
+    /// instance from a liftoff fairing when embedding Rocket in a larger
+    /// application.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # #[macro_use] extern crate rocket;
+    /// use rocket::fairing::AdHoc;
+    ///
+    /// #[launch]
+    /// fn rocket() -> _ {
+    ///     rocket::build()
+    ///         .merge_config(("port", 0))
+    ///         .attach(AdHoc::on_liftoff("Port", |rocket| Box::pin(async move {
+    ///             let tcp = rocket.endpoints().find_map(|e| e.tcp());
+    ///             println!("Rocket is listening on port {:?}.", tcp.map(|a| a.port()));
+    ///         })))
+    /// }
+    /// ```
     pub fn endpoints(&self) -> impl Iterator<Item = &Endpoint> {
         self.endpoints.iter()
     }
 
+    /// Returns an iterator over the catchers that handle an error with
+    /// `status` arising from `req`, in the order Rocket tries them.
+    ///
+    /// The first catcher yielded is the one Rocket invokes. If it _declines_,
+    /// by failing with `status` itself, Rocket invokes the next one, and so on.
+    /// If every catcher declines or none is yielded, Rocket's built-in default
+    /// catcher handles the error. See [catcher routing](Catcher#routing)
+    /// for details.
+    ///
+    /// This method is primarily useful in tests to check that errors are
+    /// handled by the expected catchers.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use rocket::*;
+    /// use rocket::http::Status;
+    /// use rocket::local::blocking::Client;
+    ///
+    /// #[catch(404)] fn not_found() { }
+    /// #[catch(default)] fn fallback() { }
+    ///
+    /// let rocket = rocket::build()
+    ///     .register("/", catchers![not_found, fallback])
+    ///     .register("/api", catchers![fallback]);
+    ///
+    /// let client = Client::debug(rocket).unwrap();
+    /// let req = client.get("/api/users");
+    /// let bases: Vec<_> = client.rocket()
+    ///     .catchers_for(Status::NotFound, &req)
+    ///     .map(|c| (c.code, c.base().to_string()))
+    ///     .collect();
+    ///
+    /// assert_eq!(bases, vec![
+    ///     (None, "/api".to_string()),
+    ///     (Some(404), "/".to_string()),
+    ///     (None, "/".to_string()),
+    /// ]);
+    /// ```
+    pub fn catchers_for<'r, 'a: 'r>(
+        &'a self,
+        status: Status,
+        req: &'r Request<'r>
+    ) -> impl Iterator<Item = &'a Catcher> + 'r {
+        self.router.catch_all(status, req)
+    }
+
     /// Returns a handle which can be used to trigger a shutdown and detect a
     /// triggered shutdown.
     ///
@@ -853,6 +1138,24 @@ impl<P: Phase> Rocket<P> {
     /// assert!(rocket.routes().any(|r| r.uri == "/hello"));
     /// assert!(rocket.routes().any(|r| r.uri == "/hi/hello"));
     /// ```
+    ///
+    /// Routes can also be listed at runtime via the `&Rocket<Orbit>` request
+    /// guard, for instance, to serve a self-documenting debug page:
+    ///
+    /// ```rust
+    /// # use rocket::*;
+    /// use rocket::{Rocket, Orbit};
+    ///
+    /// #[get("/__routes")]
+    /// fn list_routes(rocket: &Rocket<Orbit>) -> String {
+    ///     rocket.routes()
+    ///         .map(|r| {
+    ///             let name = r.name.as_deref().unwrap_or("-");
+    ///             format!("{} {} (rank {}) {}\n", r.method, r.uri, r.rank, name)
+    ///         })
+    ///         .collect()
+    /// }
+    /// ```
     pub fn routes(&self) -> impl Iterator<Item = &Route> {
         match self.0.as_state_ref() {
             StateRef::Build(p) => Either::Left(p.routes.iter()),
@@ -884,6 +1187,24 @@ impl<P: Phase> Rocket<P> {
     /// assert!(rocket.catchers().any(|c| c.code == Some(500) && c.base() == "/"));
     /// assert!(rocket.catchers().any(|c| c.code == None && c.base() == "/"));
     /// ```
+    ///
+    /// As with [`Rocket::routes()`], catchers can be listed at runtime via the
+    /// `&Rocket<Orbit>` request guard:
+    ///
+    /// ```rust
+    /// # use rocket::*;
+    /// use rocket::{Rocket, Orbit};
+    ///
+    /// #[get("/__catchers")]
+    /// fn list_catchers(rocket: &Rocket<Orbit>) -> String {
+    ///     rocket.catchers()
+    ///         .map(|c| match c.code {
+    ///             Some(code) => format!("{} {}\n", code, c.base()),
+    ///             None => format!("default {}\n", c.base()),
+    ///         })
+    ///         .collect()
+    /// }
+    /// ```
     pub fn catchers(&self) -> impl Iterator<Item = &Catcher> {
         match self.0.as_state_ref() {
             StateRef::Build(p) => Either::Left(p.catchers.iter()),
@@ -892,6 +1213,42 @@ impl<P: Phase> Rocket<P> {
         }
     }
 
+    /// Returns a minimal [OpenAPI 3] document describing all of the routes
+    /// mounted on this instance of Rocket.
+    ///
+    /// The document contains an entry for every route path and method. Path
+    /// and query parameters are described with a schema inferred from the
+    /// parameter's Rust type when the route was generated by a route attribute
+    /// and as a `string` otherwise. Ignored `<_>` path segments are described
+    /// as `string` parameters named `_{index}`, where `index` is the index of
+    /// the segment in the path. Request and response bodies, as well as
+    /// trailing `<param..>` query parameters, are not described.
+    ///
+    /// [OpenAPI 3]: https://spec.openapis.org/oas/v3.0.3
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use rocket::*;
+    /// #[get("/item/<id>?<verbose>")]
+    /// fn item(id: u64, verbose: Option<bool>) { }
+    ///
+    /// let rocket = rocket::build().mount("/api", routes![item]);
+    /// let doc = rocket.openapi();
+    ///
+    /// let op = &doc["paths"]["/api/item/{id}"]["get"];
+    /// assert_eq!(op["operationId"], "item");
+    /// assert_eq!(op["parameters"][0]["name"], "id");
+    /// assert_eq!(op["parameters"][0]["schema"]["type"], "integer");
+    /// assert_eq!(op["parameters"][1]["required"], false);
+    /// assert_eq!(op["parameters"][1]["schema"]["type"], "boolean");
+    /// ```
+    #[cfg(feature = "openapi")]
+    #[cfg_attr(nightly, doc(cfg(feature = "openapi")))]
+    pub fn openapi(&self) -> serde_json::Value {
+        crate::route::openapi_document("Rocket", self.routes())
+    }
+
     /// Returns `Some` of the managed state value for the type `T` if it is
     /// being managed by `self`. Otherwise, returns `None`.
     ///
@@ -974,6 +1331,23 @@ impl<P: Phase> Rocket<P> {
     ///
     /// The `Future` does not resolve otherwise.
     ///
+    /// # Listeners
+    ///
+    /// Rocket listens via the [`DefaultListener`], configured by the
+    /// `address` configuration parameter. When `address` is an array of
+    /// endpoints, Rocket binds a listener to every endpoint and serves the
+    /// application on all of them at once, for example both on a TCP port and
+    /// on a Unix domain socket:
+    ///
+    /// ```toml
+    /// [default]
+    /// address = ["tcp:0.0.0.0:8000", "unix:/run/app.sock"]
+    /// ```
+    ///
+    /// All other listener parameters, such as `port` and `tls`, apply to every
+    /// listener. The endpoints of all listeners are available via
+    /// [`Rocket::endpoints()`].
+    ///
     /// # Error
     ///
     /// If there is a problem starting the application or the application fails
@@ -993,7 +1367,26 @@ impl<P: Phase> Rocket<P> {
     /// }
     /// ```
     pub async fn launch(self) -> Result<Rocket<Ignite>, Error> {
-        self.launch_with::<DefaultListener>().await
+        let mut rocket = self.into_ignite().await?;
+        let Some(addresses) = crate::listener::addresses(rocket.figment()) else {
+            return rocket.launch_with::<DefaultListener>().await;
+        };
+
+        // Bind one listener to each address by binding with a figment in which
+        // `address` is that one address.
+        let figment = rocket.figment().clone();
+        let mut listeners = Vec::with_capacity(addresses.len());
+        for address in addresses {
+            rocket.0.figment = figment.clone().merge(("address", address));
+            let bind_endpoint = <DefaultListener as Bind>::bind_endpoint(&rocket).ok();
+            let listener = <DefaultListener as Bind>::bind(&rocket).await
+                .map_err(|e| ErrorKind::Bind(bind_endpoint, Box::new(e)))?;
+
+            listeners.push(listener);
+        }
+
+        rocket.0.figment = figment;
+        rocket._launch(listeners).await
     }
 
     pub async fn launch_with<B: Bind>(self) -> Result<Rocket<Ignite>, Error> {
@@ -1008,13 +1401,13 @@ impl<P: Phase> Rocket<P> {
                 let listener = *listener;
                 crate::util::for_both!(listener, listener => {
                     crate::util::for_both!(listener, listener => {
-                        rocket._launch(listener).await
+                        rocket._launch(vec![listener]).await
                     })
                 })
             }
             Err(any) => {
                 let listener = *any.downcast::<B>().unwrap();
-                rocket._launch(listener).await
+                rocket._launch(vec![listener]).await
             }
         }
     }
@@ -1025,13 +1418,13 @@ impl<P: Phase> Rocket<P> {
               E: std::error::Error + Send + 'static
     {
         let listener = listener.map_err(|e| ErrorKind::Bind(None, Box::new(e))).await?;
-        self.into_ignite().await?._launch(listener).await
+        self.into_ignite().await?._launch(vec![listener]).await
     }
 
     pub async fn launch_on<L>(self, listener: L) -> Result<Rocket<Ignite>, Error>
         where L: Listener + 'static,
     {
-        self.into_ignite().await?._launch(listener).await
+        self.into_ignite().await?._launch(vec![listener]).await
     }
 }
 
@@ -0,0 +1,101 @@
+use crate::http::Method;
+use crate::route::{Route, RouteUri};
+
+/// A route declared via a route attribute such as [`#[get]`](crate::get).
+///
+/// When the `route-lints` feature is enabled, every route attribute in the
+/// application registers a `Declared` route. On ignition, Rocket warns about
+/// every declared route which is not mounted, a common mistake:
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// #[get("/")]
+/// fn index() -> &'static str { "index" }
+///
+/// // Never mounted: with `route-lints`, Rocket warns about `forgotten`.
+/// #[get("/forgotten")]
+/// fn forgotten() -> &'static str { "forgotten" }
+///
+/// # rocket::async_test(async {
+/// let rocket = rocket::build()
+///     .mount("/", routes![index])
+///     .ignite().await;
+/// # });
+/// ```
+///
+/// Declared routes are collected across the entire binary. As such, routes
+/// which are intentionally mounted only by some instances of `Rocket`, like
+/// those used only in tests, are also reported as unmounted by the others. To
+/// exempt such a route, apply `#[suppress(unmounted_route)]` or, equivalently,
+/// `#[allow(unmounted_route)]` to its handler:
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// // Mounted only in tests: not reported as unmounted.
+/// #[get("/debug")]
+/// #[allow(unmounted_route)]
+/// fn debug() -> &'static str { "debug" }
+/// ```
+///
+/// Routes are matched with their declarations by the full path to their
+/// handler, so same-named handlers in different modules are told apart.
+#[derive(Debug)]
+pub struct Declared {
+    /// The route's name, i.e, the name of the function.
+    pub name: &'static str,
+    /// The full path to the route's handler, i.e, `module::path::name`.
+    pub path: &'static str,
+    /// The route's method.
+    pub method: Method,
+    /// The route's URI, without the base mount point.
+    pub uri: &'static str,
+    /// The `(file, line, column)` of the route's declaration.
+    pub location: (&'static str, u32, u32),
+}
+
+inventory::collect!(Declared);
+
+impl Declared {
+    /// Returns an iterator over all of the routes declared in the binary.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate rocket;
+    /// use rocket::route::Declared;
+    ///
+    /// #[get("/hello")]
+    /// fn hello() -> &'static str { "hello" }
+    ///
+    /// assert!(Declared::iter().any(|r| r.name == "hello" && r.uri == "/hello"));
+    /// ```
+    pub fn iter() -> impl Iterator<Item = &'static Declared> {
+        inventory::iter::<Declared>.into_iter()
+    }
+
+    /// Returns `true` if `route` was generated from this declaration.
+    pub(crate) fn is(&self, route: &Route) -> bool {
+        route.path == Some(self.path)
+            && route.method == self.method
+            && route.uri.unmounted() == RouteUri::new("/", self.uri).unmounted()
+    }
+}
+
+/// Registers a [`Declared`] route when the `route-lints` feature is enabled.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! declare_route {
+    ($name:ident, $method:expr, $uri:expr) => (
+        $crate::inventory::submit! {
+            $crate::route::Declared {
+                name: std::stringify!($name),
+                path: std::concat!(std::module_path!(), "::", std::stringify!($name)),
+                method: $method,
+                uri: $uri,
+                location: (std::file!(), std::line!(), std::column!()),
+            }
+        }
+    )
+}
+
+pub use declare_route;
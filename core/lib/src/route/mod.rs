@@ -4,9 +4,30 @@ mod route;
 mod handler;
 mod uri;
 mod segment;
+#[cfg(feature = "openapi")]
+mod openapi;
+#[cfg(feature = "route-lints")]
+mod declared;
 
 pub use route::*;
 pub use handler::*;
 pub use uri::*;
 
+#[cfg(feature = "route-lints")]
+#[cfg_attr(nightly, doc(cfg(feature = "route-lints")))]
+pub use declared::*;
+
+/// Registers nothing: the `route-lints` feature is disabled.
+#[cfg(not(feature = "route-lints"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! declare_route {
+    ($($t:tt)*) => ()
+}
+
+#[cfg(not(feature = "route-lints"))]
+pub use declare_route;
+
 pub(crate) use segment::Segment;
+#[cfg(feature = "openapi")]
+pub(crate) use openapi::document as openapi_document;
@@ -0,0 +1,145 @@
+use std::borrow::Cow;
+
+use serde_json::{json, Map, Value};
+
+use crate::Route;
+
+/// The OpenAPI schema for a parameter of Rust type `ty`, as stringified by
+/// codegen, and whether the parameter is required.
+fn schema(ty: &str) -> (Value, bool) {
+    let ty: String = ty.chars().filter(|c| !c.is_whitespace()).collect();
+    let inner = |prefix: &str| ty.strip_prefix(prefix)
+        .and_then(|rest| rest.strip_suffix('>'))
+        .map(|inner| inner.split(',').next().unwrap_or(inner).to_string());
+
+    if let Some(inner) = inner("Option<") {
+        return (schema(&inner).0, false);
+    }
+
+    if let Some(inner) = inner("Result<") {
+        return schema(&inner);
+    }
+
+    let name = ty.rsplit("::").next().unwrap_or(&ty);
+    let schema = match name {
+        "i8" | "i16" | "i32" => json!({ "type": "integer", "format": "int32" }),
+        "i64" | "isize" | "i128" => json!({ "type": "integer", "format": "int64" }),
+        "u8" | "u16" | "u32" | "u64" | "usize" | "u128" => {
+            json!({ "type": "integer", "minimum": 0 })
+        }
+        "NonZeroU8" | "NonZeroU16" | "NonZeroU32" | "NonZeroU64" | "NonZeroUsize" => {
+            json!({ "type": "integer", "minimum": 1 })
+        }
+        "f32" => json!({ "type": "number", "format": "float" }),
+        "f64" => json!({ "type": "number", "format": "double" }),
+        "bool" => json!({ "type": "boolean" }),
+        "Uuid" => json!({ "type": "string", "format": "uuid" }),
+        "Ipv4Addr" => json!({ "type": "string", "format": "ipv4" }),
+        "Ipv6Addr" => json!({ "type": "string", "format": "ipv6" }),
+        "IpAddr" => json!({
+            "oneOf": [
+                { "type": "string", "format": "ipv4" },
+                { "type": "string", "format": "ipv6" },
+            ]
+        }),
+        "Date" => json!({ "type": "string", "format": "date" }),
+        "PrimitiveDateTime" | "OffsetDateTime" => {
+            json!({ "type": "string", "format": "date-time" })
+        }
+        _ => json!({ "type": "string" }),
+    };
+
+    (schema, true)
+}
+
+fn parameter(route: &Route, name: &str, location: &str) -> Value {
+    let ty = route.params.iter().find(|(n, _)| *n == name).map(|(_, ty)| *ty);
+    let (schema, required) = ty.map(schema).unwrap_or_else(|| schema("String"));
+    json!({
+        "name": name,
+        "in": location,
+        "required": location == "path" || required,
+        "schema": schema,
+    })
+}
+
+/// The names of the templated path parameters of `route`, in order. Ignored
+/// `<_>` segments are given a unique name of the form `_{index}`, where
+/// `index` is the segment's index, so that every template variable in the path
+/// has a distinct name.
+fn path_parameters(route: &Route) -> impl Iterator<Item = Cow<'_, str>> {
+    let segments = route.uri.metadata.uri_segments.iter().enumerate();
+    segments.filter(|(_, s)| s.dynamic).map(|(i, s)| match s.value.as_str() {
+        "_" => Cow::Owned(format!("_{}", i)),
+        name => Cow::Borrowed(name),
+    })
+}
+
+fn operation(route: &Route) -> Value {
+    let mut parameters = vec![];
+    for name in path_parameters(route) {
+        parameters.push(parameter(route, &name, "path"));
+    }
+
+    let query_segments = route.uri.query().into_iter().flat_map(|q| q.raw_segments());
+    for segment in query_segments {
+        let segment = segment.as_str();
+        if let Some(name) = segment.strip_prefix('<').and_then(|s| s.strip_suffix('>')) {
+            if !name.ends_with("..") && name != "_" {
+                parameters.push(parameter(route, name, "query"));
+            }
+        }
+    }
+
+    let mut operation = Map::new();
+    if let Some(name) = &route.name {
+        operation.insert("operationId".into(), name.as_ref().into());
+    }
+
+    if !parameters.is_empty() {
+        operation.insert("parameters".into(), parameters.into());
+    }
+
+    operation.insert("responses".into(), json!({ "default": { "description": "" } }));
+    operation.into()
+}
+
+/// Returns the OpenAPI path template for `route`'s path.
+fn path(route: &Route) -> String {
+    let mut path = String::new();
+    let mut parameters = path_parameters(route);
+    for segment in &route.uri.metadata.uri_segments {
+        path.push('/');
+        if segment.dynamic {
+            path.push('{');
+            path.push_str(&parameters.next().unwrap_or_default());
+            path.push('}');
+        } else {
+            path.push_str(&segment.value);
+        }
+    }
+
+    match path.is_empty() || route.uri.has_trailing_slash() {
+        true => path + "/",
+        false => path,
+    }
+}
+
+pub(crate) fn document<'a, I>(title: &str, routes: I) -> Value
+    where I: Iterator<Item = &'a Route>
+{
+    let mut paths = Map::new();
+    for route in routes {
+        let item = paths.entry(path(route)).or_insert_with(|| Map::new().into());
+        let method = route.method.as_str().to_ascii_lowercase();
+        if let Value::Object(item) = item {
+            item.entry(method).or_insert_with(|| operation(route));
+        }
+    }
+
+    json!({
+        "openapi": "3.0.3",
+        "info": { "title": title, "version": "0.0.0" },
+        "paths": paths,
+    })
+}
@@ -1,9 +1,11 @@
 use std::fmt;
 use std::borrow::Cow;
+use std::time::Duration;
 
 use yansi::Paint;
 
 use crate::http::{uri, Method, MediaType};
+use crate::data::{ByteUnit, Limits};
 use crate::route::{Handler, RouteUri, BoxFuture};
 use crate::sentinel::Sentry;
 
@@ -176,8 +178,21 @@ pub struct Route {
     pub rank: isize,
     /// The media type this route matches against, if any.
     pub format: Option<MediaType>,
+    /// The maximum duration the route's handler may run for, if any.
+    pub timeout: Option<Duration>,
+    /// The data limit which replaces all configured limits for this route, if
+    /// any.
+    pub limit: Option<ByteUnit>,
+    /// The configured limits with `limit` applied. Resolved on ignition.
+    pub(crate) limits: Option<Limits>,
+    /// The stable label identifying this route in metrics and logs, if any.
+    pub metric: Option<Cow<'static, str>>,
     /// The discovered sentinels.
     pub(crate) sentinels: Vec<Sentry>,
+    /// The `(name, type)` of codegen-discovered path and query parameters.
+    pub(crate) params: &'static [(&'static str, &'static str)],
+    /// The full path to the handler function, for codegen-generated routes.
+    pub(crate) path: Option<&'static str>,
 }
 
 impl Route {
@@ -250,7 +265,13 @@ impl Route {
         Route {
             name: None,
             format: None,
+            timeout: None,
+            limit: None,
+            limits: None,
+            metric: None,
             sentinels: Vec::new(),
+            params: &[],
+            path: None,
             handler: Box::new(handler),
             rank, uri, method,
         }
@@ -341,6 +362,93 @@ impl Route {
         self.uri = RouteUri::try_new(&base, &self.uri.unmounted_origin.to_string())?;
         Ok(self)
     }
+
+    /// Sets the maximum duration this route's handler may run for to
+    /// `timeout`, returning the modified route.
+    ///
+    /// If the handler does not complete within `timeout`, it is cancelled and
+    /// the request fails with a `503 Service Unavailable` status. This is the
+    /// programmatic equivalent of the `timeout` route attribute parameter.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    ///
+    /// use rocket::Route;
+    /// use rocket::http::Method;
+    /// # use rocket::route::dummy_handler as handler;
+    ///
+    /// let route = Route::new(Method::Get, "/slow", handler)
+    ///     .with_timeout(Duration::from_secs(5));
+    ///
+    /// assert_eq!(route.timeout, Some(Duration::from_secs(5)));
+    /// ```
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the data limit for this route to `limit`, returning the modified
+    /// route.
+    ///
+    /// While the route's guards and handler run, `limit` replaces every data
+    /// limit configured in [`Config::limits`](crate::Config::limits) as well as
+    /// every [default limit](Limits#built-in-limits), as observed via
+    /// [`Request::limits()`](crate::Request::limits()). Limits for other routes
+    /// are unaffected. This is the programmatic equivalent of the `limit` route
+    /// attribute parameter.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::Route;
+    /// use rocket::http::Method;
+    /// use rocket::data::ToByteUnit;
+    /// # use rocket::route::dummy_handler as handler;
+    ///
+    /// let route = Route::new(Method::Post, "/upload", handler)
+    ///     .with_limit(500.mebibytes());
+    ///
+    /// assert_eq!(route.limit, Some(500.mebibytes()));
+    /// ```
+    pub fn with_limit(mut self, limit: ByteUnit) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the metric label of this route to `metric`, returning the modified
+    /// route.
+    ///
+    /// The label is a stable name for the route, independent of its handler's
+    /// name, for use by metrics and logging fairings, which can retrieve it
+    /// via [`Request::route()`](crate::Request::route()). Rocket includes the
+    /// label in the log message emitted when a request matches the route. This
+    /// is the programmatic equivalent of the `metric` route attribute
+    /// parameter.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::Route;
+    /// use rocket::http::Method;
+    /// # use rocket::route::dummy_handler as handler;
+    ///
+    /// let route = Route::new(Method::Post, "/cart/checkout", handler)
+    ///     .with_metric("checkout");
+    ///
+    /// assert_eq!(route.metric.as_deref(), Some("checkout"));
+    /// ```
+    pub fn with_metric<M: Into<Cow<'static, str>>>(mut self, metric: M) -> Self {
+        self.metric = Some(metric.into());
+        self
+    }
+
+    /// Resolves the limits in effect for this route given the configured
+    /// `limits`.
+    pub(crate) fn resolve_limits(&mut self, limits: &Limits) {
+        self.limits = self.limit.map(|limit| limits.replaced_with(limit));
+    }
 }
 
 impl fmt::Display for Route {
@@ -372,6 +480,9 @@ impl fmt::Debug for Route {
             .field("uri", &self.uri)
             .field("rank", &self.rank)
             .field("format", &self.format)
+            .field("timeout", &self.timeout)
+            .field("limit", &self.limit)
+            .field("metric", &self.metric)
             .finish()
     }
 }
@@ -391,9 +502,19 @@ pub struct StaticInfo {
     pub handler: for<'r> fn(&'r crate::Request<'_>, crate::Data<'r>) -> BoxFuture<'r>,
     /// The route's rank, if any.
     pub rank: Option<isize>,
+    /// The route's handler timeout, if any.
+    pub timeout: Option<Duration>,
+    /// The route's data limit, if any.
+    pub limit: Option<ByteUnit>,
+    /// The route's metric label, if any.
+    pub metric: Option<&'static str>,
     /// Route-derived sentinels, if any.
     /// This isn't `&'static [SentryInfo]` because `type_name()` isn't `const`.
     pub sentinels: Vec<Sentry>,
+    /// The `(name, type)` of the route's path and query parameters.
+    pub params: &'static [(&'static str, &'static str)],
+    /// The full path to the handler function, i.e, `module::path::name`.
+    pub path: &'static str,
 }
 
 #[doc(hidden)]
@@ -408,7 +529,13 @@ impl From<StaticInfo> for Route {
             handler: Box::new(info.handler),
             rank: info.rank.unwrap_or_else(|| uri.default_rank()),
             format: info.format,
+            timeout: info.timeout,
+            limit: info.limit,
+            limits: None,
+            metric: info.metric.map(Cow::Borrowed),
             sentinels: info.sentinels.into_iter().collect(),
+            params: info.params,
+            path: Some(info.path),
             uri,
         }
     }
@@ -239,6 +239,20 @@ impl<'a> RouteUri<'a> {
         -((raw_weight as isize) - 3)
     }
 
+    /// The default rank of a route with this URI before it was mounted. This
+    /// is the rank routes are given when they aren't explicitly ranked.
+    pub(crate) fn unmounted_default_rank(&self) -> isize {
+        RouteUri::new("/", &self.unmounted_origin.to_string()).default_rank()
+    }
+
+    /// Whether the route URI, after the base, is a single trailing dynamic
+    /// parameter, `<param..>`, and thus matches every path under the base.
+    pub(crate) fn is_catch_all(&self) -> bool {
+        let Metadata { uri_segments, base_len, .. } = &self.metadata;
+        uri_segments.len() == base_len + 1
+            && uri_segments.last().map_or(false, |s| s.dynamic_trail)
+    }
+
     pub(crate) fn color_fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         use yansi::Paint;
 
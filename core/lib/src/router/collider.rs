@@ -92,6 +92,30 @@ impl Route {
             && self.uri.collides_with(&other.uri)
             && formats_collide(self, other)
     }
+
+    /// Returns `true` if `self` and `other` are [catch-all
+    /// routes](RouteUri::is_catch_all()) at the same base which only avoid
+    /// colliding because their _default_ ranks differ. Which of the two
+    /// handles a request is thus decided implicitly, typically by the
+    /// presence of a query, instead of by an explicitly chosen rank.
+    ///
+    /// The ranks are required to _differ_ on purpose: routes with equal ranks
+    /// and colliding URIs and formats [collide](Route::collides_with()), which
+    /// is an error reported separately. This method instead identifies pairs
+    /// that satisfy every other condition for a collision, and thus match the
+    /// same requests, but are silently ordered by default ranks the user never
+    /// chose. Explicitly ranked routes are never considered ambiguous.
+    pub(crate) fn is_rank_ambiguous_with(&self, other: &Route) -> bool {
+        self.method == other.method
+            && self.rank != other.rank
+            && self.rank == self.uri.unmounted_default_rank()
+            && other.rank == other.uri.unmounted_default_rank()
+            && self.uri.is_catch_all()
+            && other.uri.is_catch_all()
+            && base_segments(self).eq(base_segments(other))
+            && self.uri.collides_with(&other.uri)
+            && formats_collide(self, other)
+    }
 }
 
 impl Catcher {
@@ -190,6 +214,10 @@ impl Collide for MediaType {
     }
 }
 
+fn base_segments<'a>(route: &'a Route) -> impl Iterator<Item = &'a str> {
+    route.uri.base.path().segments().filter(|s| !s.is_empty())
+}
+
 fn formats_collide(route: &Route, other: &Route) -> bool {
     match (route.method.allows_request_body(), other.method.allows_request_body()) {
         // Payload supporting methods match against `Content-Type` which must be
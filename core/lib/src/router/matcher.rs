@@ -1,7 +1,9 @@
 use crate::{Route, Request, Catcher};
 use crate::router::Collide;
 use crate::http::Status;
+use crate::http::uncased::UncasedStr;
 use crate::route::Color;
+use crate::config::TrailingSlash;
 
 impl Route {
     /// Returns `true` if `self` matches `request`.
@@ -142,26 +144,48 @@ fn paths_match(route: &Route, req: &Request<'_>) -> bool {
     trace!("checking path match: route {} vs. request {}", route, req);
     let route_segments = &route.uri.metadata.uri_segments;
     let req_segments = req.uri().path().segments();
+    let (mut route_len, mut req_len) = (route_segments.len(), req_segments.num());
+
+    // When trailing slashes are ignored, drop the empty segment a trailing
+    // slash induces, if any, from both paths. The root path `/` is unaffected.
+    let ignore_slash = req.rocket().config.trailing_slash == TrailingSlash::Ignore;
+    if ignore_slash && !route.uri.metadata.dynamic_trail {
+        if route_len > 1 && route_segments[route_len - 1].value.is_empty() {
+            route_len -= 1;
+        }
+
+        if req_len > 1 && req_segments.get(req_len - 1) == Some("") {
+            req_len -= 1;
+        }
+    }
 
     // A route can never have more segments than a request. Recall that a
     // trailing slash is considering a segment, albeit empty.
-    if route_segments.len() > req_segments.num() {
+    if route_len > req_len {
         return false;
     }
 
     // requests with longer paths only match if we have dynamic trail (<a..>).
-    if req_segments.num() > route_segments.len() && !route.uri.metadata.dynamic_trail {
+    if req_len > route_len && !route.uri.metadata.dynamic_trail {
         return false;
     }
 
     // We've checked everything beyond the zip of their lengths already.
+    let uncased = req.rocket().config.case_insensitive_paths;
     for (route_seg, req_seg) in route_segments.iter().zip(req_segments.clone()) {
         if route_seg.dynamic_trail {
             return true;
         }
 
-        if !route_seg.dynamic && route_seg.value != req_seg {
-            return false;
+        if !route_seg.dynamic {
+            let matches = match uncased {
+                true => UncasedStr::new(&route_seg.value) == UncasedStr::new(req_seg),
+                false => route_seg.value == req_seg,
+            };
+
+            if !matches {
+                return false;
+            }
         }
     }
 
@@ -204,9 +228,13 @@ fn formats_match(route: &Route, req: &Request<'_>) -> bool {
             Some(f) if f.specificity() == 2 => route_format.collides_with(f),
             _ => false
         },
-        _ => match req.format() {
-            Some(f) => route_format.collides_with(f),
-            None => true
+        _ => {
+            // The `Accept` header determines whether this route is chosen.
+            req.add_vary("Accept");
+            match req.format() {
+                Some(f) => route_format.collides_with(f),
+                None => true
+            }
         }
     }
 }
@@ -280,6 +308,58 @@ mod tests {
         assert!(!req_matches_route("/a/b", "/a/b?<a>&b&<rest..>"));
     }
 
+    fn req_matches_route_ignoring_slash(a: &'static str, b: &'static str) -> bool {
+        use crate::config::{Config, TrailingSlash};
+
+        let config = Config { trailing_slash: TrailingSlash::Ignore, ..Config::debug_default() };
+        let client = Client::debug(crate::custom(config)).expect("client");
+        let route = Route::ranked(0, Get, b, dummy_handler);
+        route.matches(&client.get(a))
+    }
+
+    #[test]
+    fn request_route_matching_ignoring_trailing_slash() {
+        assert!(req_matches_route_ignoring_slash("/a", "/a"));
+        assert!(req_matches_route_ignoring_slash("/a/", "/a/"));
+        assert!(req_matches_route_ignoring_slash("/a", "/a/"));
+        assert!(req_matches_route_ignoring_slash("/a/", "/a"));
+        assert!(req_matches_route_ignoring_slash("/a///", "/a"));
+        assert!(req_matches_route_ignoring_slash("/a/b/", "/a/b"));
+        assert!(req_matches_route_ignoring_slash("/a/b/", "/<a>/<b>"));
+        assert!(req_matches_route_ignoring_slash("/a/b", "/<a>/<b>/"));
+        assert!(req_matches_route_ignoring_slash("/a/?b=c", "/a?<b>"));
+        assert!(req_matches_route_ignoring_slash("/", "/"));
+        assert!(req_matches_route_ignoring_slash("/a/", "/a/<b..>"));
+
+        assert!(!req_matches_route_ignoring_slash("/", "/a"));
+        assert!(!req_matches_route_ignoring_slash("/a/", "/<a>/<b>"));
+        assert!(!req_matches_route_ignoring_slash("/a/b/", "/a"));
+        assert!(!req_matches_route_ignoring_slash("/a/", "/a/b"));
+    }
+
+    #[test]
+    fn request_route_matching_case_insensitively() {
+        use crate::config::Config;
+
+        fn matches(a: &'static str, b: &'static str) -> bool {
+            let config = Config { case_insensitive_paths: true, ..Config::debug_default() };
+            let client = Client::debug(crate::custom(config)).expect("client");
+            let route = Route::ranked(0, Get, b, dummy_handler);
+            route.matches(&client.get(a))
+        }
+
+        assert!(matches("/api/users", "/api/users"));
+        assert!(matches("/API/Users", "/api/users"));
+        assert!(matches("/api/users", "/API/USERS"));
+        assert!(matches("/API/Bob", "/api/<name>"));
+        assert!(matches("/Static/A/B", "/static/<path..>"));
+        assert!(matches("/%41pi", "/api"));
+
+        assert!(!matches("/apis", "/api"));
+        assert!(!matches("/API/users/x", "/api/users"));
+        assert!(!req_matches_route("/API/Users", "/api/users"));
+    }
+
     fn req_matches_format<S1, S2>(m: Method, mt1: S1, mt2: S2) -> bool
         where S1: Into<Option<&'static str>>, S2: Into<Option<&'static str>>
     {
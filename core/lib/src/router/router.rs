@@ -57,19 +57,29 @@ impl Router {
 
     // For many catchers, using aho-corasick or similar should be much faster.
     pub fn catch<'r>(&self, status: Status, req: &'r Request<'r>) -> Option<&Catcher> {
-        // Note that catchers are presorted by descending base length.
-        let explicit = self.catchers.get(&Some(status.code))
-            .and_then(|c| c.iter().find(|c| c.matches(status, req)));
-
-        let default = self.catchers.get(&None)
-            .and_then(|c| c.iter().find(|c| c.matches(status, req)));
+        self.catch_all(status, req).next()
+    }
 
-        match (explicit, default) {
-            (None, None) => None,
-            (None, c@Some(_)) | (c@Some(_), None) => c,
-            (Some(a), Some(b)) if a.rank <= b.rank => Some(a),
-            (Some(_), Some(b)) => Some(b),
-        }
+    /// Returns all of the catchers matching `status` and `req` in order of
+    /// precedence: the catcher Rocket invokes first is yielded first.
+    pub fn catch_all<'r, 'a: 'r>(
+        &'a self,
+        status: Status,
+        req: &'r Request<'r>
+    ) -> impl Iterator<Item = &'a Catcher> + 'r {
+        // Note that catchers are presorted by descending base length.
+        let matching = move |code| self.catchers.get(&code)
+            .into_iter()
+            .flat_map(|catchers| catchers.iter())
+            .filter(move |c| c.matches(status, req));
+
+        let mut explicit = matching(Some(status.code)).peekable();
+        let mut default = matching(None).peekable();
+        std::iter::from_fn(move || match (explicit.peek(), default.peek()) {
+            (Some(a), Some(b)) if a.rank <= b.rank => explicit.next(),
+            (Some(_), None) => explicit.next(),
+            (_, _) => default.next(),
+        })
     }
 
     fn collisions<'a, I, T>(&self, items: I) -> impl Iterator<Item = (T, T)> + 'a
@@ -84,6 +94,22 @@ impl Router {
             })
     }
 
+    /// Returns pairs of catch-all routes which are ordered only by their
+    /// default rank. See [`Route::is_rank_ambiguous_with()`].
+    pub fn rank_ambiguities(&self) -> impl Iterator<Item = (&Route, &Route)> {
+        let routes: Vec<_> = self.routes().collect();
+        let mut ambiguities = vec![];
+        for (i, a) in routes.iter().enumerate() {
+            for b in routes.iter().skip(i + 1) {
+                if a.is_rank_ambiguous_with(b) {
+                    ambiguities.push((*a, *b));
+                }
+            }
+        }
+
+        ambiguities.into_iter()
+    }
+
     pub fn finalize(&self) -> Result<(), Collisions> {
         let routes: Vec<_> = self.collisions(self.routes()).collect();
         let catchers: Vec<_> = self.collisions(self.catchers()).collect();
@@ -284,6 +310,50 @@ mod test {
         assert!(!default_rank_route_collisions(&["/<foo>?a=b", "/<foo>?c=d&<d>"]));
     }
 
+    fn rank_ambiguities(routes: &[(Option<isize>, &'static str, &'static str)]) -> usize {
+        let mut router = Router::new();
+        for &(rank, base, uri) in routes {
+            let route = Route::ranked(rank, Get, uri, dummy_handler);
+            router.add_route(route.map_base(|_| base.to_string()).unwrap());
+        }
+
+        assert!(!router.has_collisions());
+        router.rank_ambiguities().count()
+    }
+
+    #[test]
+    fn test_catch_all_rank_ambiguities() {
+        assert_eq!(rank_ambiguities(&[(None, "/", "/<a..>"), (None, "/", "/<b..>?<c>")]), 1);
+        assert_eq!(rank_ambiguities(&[(None, "/", "/<a..>"), (None, "/", "/<b..>?c")]), 1);
+        assert_eq!(rank_ambiguities(&[(None, "/a", "/<a..>"), (None, "/a/", "/<b..>?c")]), 1);
+        assert_eq!(rank_ambiguities(&[
+            (None, "/", "/<a..>"),
+            (None, "/", "/<b..>?c"),
+            (None, "/", "/<c..>?<d>"),
+        ]), 3);
+
+        assert_eq!(rank_ambiguities(&[(None, "/", "/<a..>")]), 0);
+        assert_eq!(rank_ambiguities(&[(Some(1), "/", "/<a..>"), (None, "/", "/<b..>?c")]), 0);
+        assert_eq!(rank_ambiguities(&[(Some(1), "/", "/<a..>"), (Some(2), "/", "/<b..>")]), 0);
+        assert_eq!(rank_ambiguities(&[(None, "/a", "/<a..>"), (None, "/b", "/<b..>?c")]), 0);
+        assert_eq!(rank_ambiguities(&[(None, "/", "/<a..>"), (None, "/", "/a/<b..>?c")]), 0);
+        assert_eq!(rank_ambiguities(&[(None, "/", "/<a..>"), (None, "/", "/<a>/<b..>?c")]), 0);
+    }
+
+    #[test]
+    fn test_collisions_are_not_rank_ambiguities() {
+        let mut router = Router::new();
+        router.add_route(Route::new(Get, "/<a..>", dummy_handler));
+        router.add_route(Route::new(Get, "/<b..>", dummy_handler));
+        assert!(router.has_collisions());
+        assert_eq!(router.rank_ambiguities().count(), 0);
+
+        let mut router = Router::new();
+        router.add_route(Route::new(Get, "/<a..>", dummy_handler));
+        router.add_route(Route::new(Post, "/<b..>?c", dummy_handler));
+        assert_eq!(router.rank_ambiguities().count(), 0);
+    }
+
     fn matches<'a>(router: &'a Router, method: Method, uri: &'a str) -> Vec<&'a Route> {
         let client = Client::debug_with(vec![]).expect("client");
         let request = client.req(method, Origin::parse(uri).unwrap());
@@ -664,4 +734,37 @@ mod test {
             with: [(None, "/a/b"), (500, "/a/b"), (None, "/a/b"), (500, "/a/b/c")]
         }
     }
+
+    fn catchers_for(router: &Router, status: u16, uri: &str) -> Vec<(Option<u16>, String)> {
+        let client = Client::debug_with(vec![]).expect("client");
+        let request = client.get(Origin::parse(uri).unwrap());
+        let status = Status::from_code(status).expect("valid status");
+        router.catch_all(status, &request)
+            .map(|c| (c.code, c.base.path().to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_catcher_resolution_order() {
+        let router = router_with_catchers(&[
+            (None, "/"), (404, "/"), (None, "/a/b"), (404, "/a"), (500, "/a/b/c"),
+        ]);
+
+        let order = |status, uri| catchers_for(&router, status, uri);
+        let expected = |list: &[(Option<u16>, &str)]| list.iter()
+            .map(|(code, base)| (*code, base.to_string()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(order(404, "/"), expected(&[(Some(404), "/"), (None, "/")]));
+        assert_eq!(order(500, "/"), expected(&[(None, "/")]));
+        assert_eq!(order(404, "/a/b/c"), expected(&[
+            (None, "/a/b"), (Some(404), "/a"), (Some(404), "/"), (None, "/")
+        ]));
+
+        assert_eq!(order(500, "/a/b/c/d"), expected(&[
+            (Some(500), "/a/b/c"), (None, "/a/b"), (None, "/")
+        ]));
+
+        assert_eq!(order(422, "/x"), expected(&[(None, "/")]));
+    }
 }
@@ -29,12 +29,15 @@ use std::ops::{Deref, DerefMut};
 
 use crate::request::{Request, local_cache};
 use crate::data::{Limits, Data, FromData, Outcome};
-use crate::response::{self, Responder, content};
+use crate::response::{self, Responder, Response, content};
+use crate::response::stream::ReaderStream;
 use crate::form::prelude as form;
 use crate::http::uri::fmt::{UriDisplay, FromUriParam, Query, Formatter as UriFormatter};
-use crate::http::Status;
+use crate::http::{Status, ContentType};
+use crate::config::SerdeLimitError;
 
 use serde::{Serialize, Deserialize};
+use futures::stream::Stream;
 
 #[doc(hidden)]
 pub use serde_json;
@@ -122,6 +125,10 @@ pub use serde_json;
 /// [global.limits]
 /// json = 5242880
 /// ```
+///
+/// The nesting depth and number of elements in arrays and objects of incoming
+/// JSON data are additionally bounded by the `serde_limits` configuration
+/// parameter. See [`SerdeLimits`](crate::config::SerdeLimits) for details.
 #[repr(transparent)]
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Json<T>(pub T);
@@ -189,7 +196,11 @@ impl<'r, T: Deserialize<'r>> Json<T> {
             Err(e) => return Err(Error::Io(e)),
         };
 
-        Self::from_str(local_cache!(req, string))
+        let string = local_cache!(req, string);
+        req.rocket().config().serde_limits.check_json(string.as_bytes())
+            .map_err(|e| Error::Io(e.into()))?;
+
+        Self::from_str(string)
     }
 }
 
@@ -203,6 +214,11 @@ impl<'r, T: Deserialize<'r>> FromData<'r> for Json<T> {
             Err(Error::Io(e)) if e.kind() == io::ErrorKind::UnexpectedEof => {
                 Outcome::Error((Status::PayloadTooLarge, Error::Io(e)))
             },
+            Err(Error::Io(e)) if SerdeLimitError::from_io(&e).is_some() => {
+                let limit = SerdeLimitError::from_io(&e);
+                let status = limit.map_or(Status::BadRequest, |e| e.status());
+                Outcome::Error((status, Error::Io(e)))
+            },
             Err(Error::Parse(s, e)) if e.classify() == serde_json::error::Category::Data => {
                 Outcome::Error((Status::UnprocessableEntity, Error::Parse(s, e)))
             },
@@ -227,6 +243,132 @@ impl<'r, T: Serialize> Responder<'r, 'static> for Json<T> {
     }
 }
 
+/// A potentially infinite stream of values serialized as a single JSON array.
+///
+/// A `JsonStream` can be constructed from any [`Stream`] of items of type `T`
+/// where `T: Serialize`. The stream can be constructed directly, via
+/// `JsonStream(..)` or [`JsonStream::from()`], or through generator syntax via
+/// [`JsonStream!`](crate::serde::json::JsonStream!).
+///
+/// [`Stream`]: https://docs.rs/futures/0.3/futures/stream/trait.Stream.html
+///
+/// # Responder
+///
+/// `JsonStream` is a (potentially infinite) responder. The response
+/// `Content-Type` is set to [`JSON`](crate::http::ContentType::JSON). The body
+/// is [unsized](crate::response::Body#unsized): an opening `[` is sent
+/// immediately, each value is serialized and sent, preceded by a `,` if
+/// necessary, as soon as it is yielded by the stream, and a closing `]` is sent
+/// when the stream ends. Values are never buffered, making `JsonStream` ideal
+/// for large result sets such as those read from a database cursor.
+///
+/// Because the response has begun by the time a value is serialized, a value
+/// that fails to serialize is logged and skipped so that the response remains
+/// valid JSON.
+///
+/// MessagePack arrays are prefixed by their length and thus cannot be streamed
+/// incrementally in a similar manner.
+///
+/// # Example
+///
+/// ```rust
+/// # use rocket::*;
+/// use rocket::serde::Serialize;
+/// use rocket::serde::json::JsonStream;
+/// use rocket::futures::stream::{self, StreamExt};
+///
+/// #[derive(Serialize)]
+/// # #[serde(crate = "rocket::serde")]
+/// struct User {
+///     id: usize,
+/// }
+///
+/// #[get("/users")]
+/// fn users() -> JsonStream![User] {
+///     JsonStream(stream::iter(0..3).map(|id| User { id }))
+/// }
+///
+/// #[get("/numbers")]
+/// fn numbers() -> JsonStream![usize] {
+///     JsonStream! {
+///         for i in 0..3 {
+///             yield i;
+///         }
+///     }
+/// }
+///
+/// # let client = local::blocking::Client::debug_with(routes![users, numbers]).unwrap();
+/// # let response = client.get("/users").dispatch();
+/// # assert_eq!(response.into_string().unwrap(), r#"[{"id":0},{"id":1},{"id":2}]"#);
+/// # let response = client.get("/numbers").dispatch();
+/// # assert_eq!(response.into_string().unwrap(), "[0,1,2]");
+/// ```
+///
+/// The syntax of [`JsonStream!`](crate::serde::json::JsonStream!) as an
+/// expression is identical to that of
+/// [`stream!`](crate::response::stream::stream).
+#[derive(Debug, Clone)]
+pub struct JsonStream<S>(pub S);
+
+impl<S> From<S> for JsonStream<S> {
+    /// Creates a `JsonStream` from any `S: Stream`.
+    fn from(stream: S) -> Self {
+        JsonStream(stream)
+    }
+}
+
+impl<'r, S: Stream> Responder<'r, 'r> for JsonStream<S>
+    where S: Send + 'r, S::Item: Serialize + Send + 'r
+{
+    fn respond_to(self, _: &'r Request<'_>) -> response::Result<'r> {
+        let values = self.0;
+        let stream = crate::response::stream::stream! {
+            yield io::Cursor::new(vec![b'[']);
+
+            let mut first = true;
+            for await value in values {
+                let mut buf = if first { vec![] } else { vec![b','] };
+                match serde_json::to_writer(&mut buf, &value) {
+                    Ok(()) => yield io::Cursor::new(buf),
+                    Err(e) => {
+                        error_!("JSON stream value failed to serialize: {:?}", e);
+                        continue;
+                    }
+                }
+
+                first = false;
+            }
+
+            yield io::Cursor::new(vec![b']']);
+        };
+
+        Response::build()
+            .header(ContentType::JSON)
+            .streamed_body(ReaderStream::from(stream))
+            .ok()
+    }
+}
+
+crate::export! {
+    /// Type and stream expression macro for [`struct@JsonStream`].
+    ///
+    /// See [`stream!`](crate::response::stream::stream) for the syntax
+    /// supported by this macro.
+    ///
+    /// See [`struct@JsonStream`] and the [module level
+    /// docs](crate::response::stream#typed-streams) for usage details.
+    macro_rules! JsonStream {
+        ($($s:tt)*) => (
+            $crate::__typed_stream! {
+                $crate::serde::json::JsonStream,
+                $crate::response::stream::stream,
+                $crate::futures::stream::Stream,
+                $($s)*
+            }
+        );
+    }
+}
+
 impl<T: Serialize> UriDisplay<Query> for Json<T> {
     fn fmt(&self, f: &mut UriFormatter<'_, Query>) -> fmt::Result {
         let string = to_string(&self.0).map_err(|_| fmt::Error)?;
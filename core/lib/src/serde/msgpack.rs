@@ -31,6 +31,7 @@ use crate::request::{Request, local_cache};
 use crate::data::{Limits, Data, FromData, Outcome};
 use crate::response::{self, Responder, content};
 use crate::http::Status;
+use crate::config::SerdeLimitError;
 use crate::form::prelude as form;
 // use crate::http::uri::fmt;
 
@@ -122,6 +123,11 @@ pub use rmp_serde::decode::Error;
 /// [global.limits]
 /// msgpack = 5242880
 /// ```
+///
+/// The nesting depth and number of elements in arrays and maps of incoming
+/// MessagePack data are additionally bounded by the `serde_limits`
+/// configuration parameter. See [`SerdeLimits`](crate::config::SerdeLimits)
+/// for details.
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct MsgPack<T>(pub T);
 
@@ -158,7 +164,11 @@ impl<'r, T: Deserialize<'r>> MsgPack<T> {
             Err(e) => return Err(Error::InvalidDataRead(e)),
         };
 
-        Self::from_bytes(local_cache!(req, bytes))
+        let bytes = local_cache!(req, bytes);
+        req.rocket().config().serde_limits.check_msgpack(bytes)
+            .map_err(|e| Error::InvalidDataRead(e.into()))?;
+
+        Self::from_bytes(bytes)
     }
 }
 
@@ -172,6 +182,11 @@ impl<'r, T: Deserialize<'r>> FromData<'r> for MsgPack<T> {
             Err(Error::InvalidDataRead(e)) if e.kind() == io::ErrorKind::UnexpectedEof => {
                 Outcome::Error((Status::PayloadTooLarge, Error::InvalidDataRead(e)))
             },
+            Err(Error::InvalidDataRead(e)) if SerdeLimitError::from_io(&e).is_some() => {
+                let limit = SerdeLimitError::from_io(&e);
+                let status = limit.map_or(Status::BadRequest, |e| e.status());
+                Outcome::Error((status, Error::InvalidDataRead(e)))
+            },
             | Err(e@Error::TypeMismatch(_))
             | Err(e@Error::OutOfRange)
             | Err(e@Error::LengthMismatch(_))
@@ -16,7 +16,6 @@ use crate::listener::{Listener, Connection, BouncedExt, CancellableExt};
 use crate::error::log_server_error;
 use crate::data::{IoStream, RawStream};
 use crate::util::{spawn_inspect, FutureExt, ReaderStream};
-use crate::http::Status;
 
 type Result<T, E = crate::Error> = std::result::Result<T, E>;
 
@@ -36,8 +35,9 @@ impl Rocket<Orbit> {
             stream,
             |rocket, request, data| Box::pin(rocket.preprocess(request, data)),
             |token, rocket, request, data| Box::pin(async move {
-                if !request.errors.is_empty() {
-                    return rocket.dispatch_error(Status::BadRequest, request).await;
+                if let Some(error) = request.errors.first() {
+                    warn_!("Rejecting malformed request: {}.", error);
+                    return rocket.dispatch_error(error.status(), request).await;
                 }
 
                 rocket.dispatch(token, request, data).await
@@ -53,6 +53,13 @@ impl Rocket<Orbit> {
 
         let mut builder = hyper::Response::builder();
         builder = builder.status(response.inner().status().code);
+        if let Some(reason) = response.inner().reason() {
+            match hyper::ext::ReasonPhrase::try_from(reason.as_bytes()) {
+                Ok(reason) => builder = builder.extension(reason),
+                Err(e) => warn_!("Invalid custom reason phrase {reason:?}: {e}"),
+            }
+        }
+
         for header in response.inner().headers().iter() {
             builder = builder.header(header.name().as_str(), header.value());
         }
@@ -93,31 +100,37 @@ async fn io_handler_task<S>(stream: S, mut handler: ErasedIoHandler)
 impl Rocket<Ignite> {
     pub(crate) async fn listen_and_serve<L, R>(
         self,
-        listener: L,
+        listeners: Vec<L>,
         orbit_callback: impl FnOnce(Rocket<Orbit>) -> R,
     ) -> Result<Arc<Rocket<Orbit>>>
         where L: Listener + 'static,
               R: Future<Output = Result<Arc<Rocket<Orbit>>>>
     {
-        let endpoint = listener.endpoint()?;
+        let endpoints = listeners.iter()
+            .map(|listener| listener.endpoint())
+            .collect::<io::Result<Vec<_>>>()?;
 
         #[cfg(feature = "http3-preview")]
-        if let (Some(addr), Some(tls)) = (endpoint.tcp(), endpoint.tls_config()) {
-            use crate::error::ErrorKind;
-
-            let h3listener = crate::listener::quic::QuicListener::bind(addr, tls.clone())
-                .map_err(|e| ErrorKind::Bind(Some(endpoint.clone()), Box::new(e)))
-                .await?;
-
-            let rocket = self.into_orbit(vec![h3listener.endpoint()?, endpoint]);
-            let rocket = orbit_callback(rocket).await?;
-
-            let http12 = tokio::task::spawn(rocket.clone().serve12(listener));
-            let http3 = tokio::task::spawn(rocket.clone().serve3(h3listener));
-            let (r1, r2) = tokio::join!(http12, http3);
-            r1.map_err(|e| ErrorKind::Liftoff(Err(rocket.clone()), Box::new(e)))??;
-            r2.map_err(|e| ErrorKind::Liftoff(Err(rocket.clone()), Box::new(e)))??;
-            return Ok(rocket);
+        if let [endpoint] = &endpoints[..] {
+            if let (Some(addr), Some(tls)) = (endpoint.tcp(), endpoint.tls_config()) {
+                use crate::error::ErrorKind;
+
+                let h3listener = crate::listener::quic::QuicListener::bind(addr, tls.clone())
+                    .map_err(|e| ErrorKind::Bind(Some(endpoint.clone()), Box::new(e)))
+                    .await?;
+
+                let (mut endpoints, mut listeners) = (endpoints, listeners);
+                endpoints.insert(0, h3listener.endpoint()?);
+                let rocket = self.into_orbit(endpoints);
+                let rocket = orbit_callback(rocket).await?;
+
+                let http12 = tokio::task::spawn(rocket.clone().serve12(listeners.remove(0)));
+                let http3 = tokio::task::spawn(rocket.clone().serve3(h3listener));
+                let (r1, r2) = tokio::join!(http12, http3);
+                r1.map_err(|e| ErrorKind::Liftoff(Err(rocket.clone()), Box::new(e)))??;
+                r2.map_err(|e| ErrorKind::Liftoff(Err(rocket.clone()), Box::new(e)))??;
+                return Ok(rocket);
+            }
         }
 
         if cfg!(feature = "http3-preview") {
@@ -125,9 +138,10 @@ impl Rocket<Ignite> {
             info_!("Falling back to HTTP/1 + HTTP/2 server.");
         }
 
-        let rocket = self.into_orbit(vec![endpoint]);
+        let rocket = self.into_orbit(endpoints);
         let rocket = orbit_callback(rocket).await?;
-        rocket.clone().serve12(listener).await?;
+        let servers = listeners.into_iter().map(|listener| rocket.clone().serve12(listener));
+        futures::future::try_join_all(servers).await?;
         Ok(rocket)
     }
 }
@@ -137,31 +151,24 @@ impl Rocket<Orbit> {
         where L: Listener + 'static,
               L::Connection: AsyncRead + AsyncWrite
     {
-        let mut builder = Builder::new(TokioExecutor::new());
-        let keep_alive = Duration::from_secs(self.config.keep_alive.into());
-        builder.http1()
-            .half_close(true)
-            .timer(TokioTimer::new())
-            .keep_alive(keep_alive > Duration::ZERO)
-            .preserve_header_case(true)
-            .header_read_timeout(Duration::from_secs(15));
-
-        #[cfg(feature = "http2")] {
-            builder.http2().timer(TokioTimer::new());
-            if keep_alive > Duration::ZERO {
-                builder.http2()
-                    .timer(TokioTimer::new())
-                    .keep_alive_interval(keep_alive / 4)
-                    .keep_alive_timeout(keep_alive);
-            }
-        }
+        // Plaintext connections are served with HTTP/1 only unless h2c is on.
+        let builder = self.http_builder();
+        let http1_only = self.http_builder().http1_only();
+        let (builder, http1_only) = (Arc::new(builder), Arc::new(http1_only));
 
-        let (listener, server) = (Arc::new(listener.bounced()), Arc::new(builder));
+        let listener = Arc::new(listener.bounced());
         while let Some(accept) = listener.accept().race(self.shutdown()).await.left().transpose()? {
-            let (listener, rocket, server) = (listener.clone(), self.clone(), server.clone());
+            let (listener, rocket) = (listener.clone(), self.clone());
+            let (builder, http1_only) = (builder.clone(), http1_only.clone());
             spawn_inspect(|e| log_server_error(&**e), async move {
                 let conn = listener.connect(accept).race_io(rocket.shutdown()).await?;
-                let meta = ConnectionMeta::new(conn.endpoint(), conn.certificates());
+                let meta = ConnectionMeta::from(&conn);
+                let is_tls = meta.peer_endpoint.as_ref().map_or(false, |e| e.is_tls());
+                let server = match is_tls || rocket.config.h2c {
+                    true => builder,
+                    false => http1_only,
+                };
+
                 let service = service_fn(|mut req| {
                     let upgrade = hyper::upgrade::on(&mut req);
                     let (parts, incoming) = req.into_parts();
@@ -183,6 +190,34 @@ impl Rocket<Orbit> {
         Ok(())
     }
 
+    /// Returns a connection builder configured as per `self.config`.
+    fn http_builder(&self) -> Builder<TokioExecutor> {
+        let mut builder = Builder::new(TokioExecutor::new());
+        let keep_alive = Duration::from_secs(self.config.keep_alive.into());
+        let read_timeout = Duration::from_secs(self.config.read_timeout.into());
+        builder.http1()
+            .half_close(true)
+            .timer(TokioTimer::new())
+            .keep_alive(keep_alive > Duration::ZERO)
+            .preserve_header_case(true);
+
+        if read_timeout > Duration::ZERO {
+            builder.http1().header_read_timeout(read_timeout);
+        }
+
+        #[cfg(feature = "http2")] {
+            builder.http2().timer(TokioTimer::new());
+            if keep_alive > Duration::ZERO {
+                builder.http2()
+                    .timer(TokioTimer::new())
+                    .keep_alive_interval(keep_alive / 4)
+                    .keep_alive_timeout(keep_alive);
+            }
+        }
+
+        builder
+    }
+
     #[cfg(feature = "http3-preview")]
     async fn serve3(self: Arc<Self>, listener: crate::listener::quic::QuicListener) -> Result<()> {
         let rocket = self.clone();
@@ -194,7 +229,10 @@ impl Rocket<Orbit> {
                 while let Some(mut conn) = stream.accept().race_io(rocket.shutdown()).await? {
                     let rocket = rocket.clone();
                     spawn_inspect(|e: &io::Error| log_server_error(e), async move {
-                        let meta = ConnectionMeta::new(conn.endpoint(), None);
+                        let meta = ConnectionMeta {
+                            peer_endpoint: conn.endpoint().ok(),
+                            ..Default::default()
+                        };
                         let rx = conn.rx.cancellable(rocket.shutdown.clone());
                         let response = rocket.clone()
                             .service(conn.parts, rx, None, meta)
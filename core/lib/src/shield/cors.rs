@@ -0,0 +1,462 @@
+//! Per-route Cross-Origin Resource Sharing (CORS) handling.
+//!
+//! The [`Cors`] request guard parses the CORS-related headers of a request: the
+//! `Origin` and, for preflight requests, the `Access-Control-Request-Method`
+//! and `Access-Control-Request-Headers`. It makes no policy decision of its
+//! own. Instead, a handler inspects the request, decides whether and how to
+//! allow it, for instance, by looking up the origin in a database, and
+//! responds with [`CorsHeaders`], either on their own for a preflight request
+//! or wrapping another responder via [`CorsHeaders::respond()`].
+//!
+//! # Example
+//!
+//! ```rust
+//! # #[macro_use] extern crate rocket;
+//! use rocket::shield::cors::{Cors, CorsHeaders, WithCors};
+//! use rocket::http::Method;
+//!
+//! fn is_allowed(origin: &str) -> bool {
+//!     /* look up `origin`, perhaps in a database */
+//!     # origin == "https://example.com"
+//! }
+//!
+//! #[options("/api/data")]
+//! fn preflight(cors: Cors<'_>) -> Option<CorsHeaders> {
+//!     let origin = cors.origin().filter(|o| is_allowed(o))?;
+//!     let allowed = CorsHeaders::new()
+//!         .allow_origin(origin.to_string())
+//!         .allow_methods([Method::Get, Method::Put])
+//!         .allow_headers(["Content-Type"]);
+//!
+//!     Some(allowed)
+//! }
+//!
+//! #[get("/api/data")]
+//! fn data(cors: Cors<'_>) -> WithCors<&'static str> {
+//!     let headers = match cors.origin() {
+//!         Some(origin) if is_allowed(origin) => cors.allow(),
+//!         _ => CorsHeaders::new(),
+//!     };
+//!
+//!     headers.respond("data")
+//! }
+//! ```
+//!
+//! Responses without an `Access-Control-Allow-Origin` header are treated as
+//! denials by browsers, so responding with an empty [`CorsHeaders::new()`]
+//! denies a cross-origin request.
+
+use std::borrow::Cow;
+use std::convert::Infallible;
+use std::time::Duration;
+
+use crate::{Request, Response};
+use crate::request::{FromRequest, Outcome};
+use crate::response::{self, Responder};
+use crate::http::{Method, Status};
+
+/// Request guard for the CORS-related headers of a request.
+///
+/// The guard never fails. See the [module level docs](crate::shield::cors) for
+/// details.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cors<'r> {
+    origin: Option<&'r str>,
+    method: Method,
+    request_method: Option<Method>,
+    request_headers: Vec<&'r str>,
+}
+
+impl<'r> Cors<'r> {
+    /// The `Origin` request header.
+    pub const ORIGIN: &'static str = "Origin";
+
+    /// The `Access-Control-Request-Method` preflight request header.
+    pub const REQUEST_METHOD: &'static str = "Access-Control-Request-Method";
+
+    /// The `Access-Control-Request-Headers` preflight request header.
+    pub const REQUEST_HEADERS: &'static str = "Access-Control-Request-Headers";
+
+    /// Returns the value of the request's `Origin` header, if any.
+    ///
+    /// Note that browsers send `Origin` with same-origin requests that aren't
+    /// `GET` or `HEAD` as well, so its presence alone doesn't imply that a
+    /// request is cross-origin.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::shield::cors::Cors;
+    ///
+    /// fn origin<'r>(cors: &Cors<'r>) -> &'r str {
+    ///     cors.origin().unwrap_or("none")
+    /// }
+    /// ```
+    pub fn origin(&self) -> Option<&'r str> {
+        self.origin
+    }
+
+    /// Returns `true` if the request is a CORS preflight request: an `OPTIONS`
+    /// request with both an `Origin` and an `Access-Control-Request-Method`
+    /// header.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::shield::cors::Cors;
+    ///
+    /// fn kind(cors: &Cors<'_>) -> &'static str {
+    ///     if cors.is_preflight() { "preflight" } else { "simple" }
+    /// }
+    /// ```
+    pub fn is_preflight(&self) -> bool {
+        self.method == Method::Options
+            && self.origin.is_some()
+            && self.request_method.is_some()
+    }
+
+    /// Returns the method named in the `Access-Control-Request-Method` header,
+    /// if any and if it is valid.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::shield::cors::Cors;
+    /// use rocket::http::Method;
+    ///
+    /// fn is_write(cors: &Cors<'_>) -> bool {
+    ///     matches!(cors.request_method(), Some(Method::Put | Method::Post))
+    /// }
+    /// ```
+    pub fn request_method(&self) -> Option<Method> {
+        self.request_method
+    }
+
+    /// Returns the header names listed in the `Access-Control-Request-Headers`
+    /// header, if any. Names are trimmed; empty names are skipped.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::shield::cors::Cors;
+    ///
+    /// fn wants_auth(cors: &Cors<'_>) -> bool {
+    ///     cors.request_headers().iter().any(|h| h.eq_ignore_ascii_case("authorization"))
+    /// }
+    /// ```
+    pub fn request_headers(&self) -> &[&'r str] {
+        &self.request_headers
+    }
+
+    /// Returns `CorsHeaders` which allow exactly what this request asks for:
+    /// the request's origin and, for preflight requests, the requested method
+    /// and headers. If the request has no `Origin`, the headers are empty.
+    ///
+    /// Call this method only once the request has been determined to be
+    /// allowed. The returned headers can be further customized.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate rocket;
+    /// use std::time::Duration;
+    /// use rocket::shield::cors::{Cors, CorsHeaders};
+    ///
+    /// #[options("/<_..>")]
+    /// fn preflight(cors: Cors<'_>) -> CorsHeaders {
+    ///     cors.allow().max_age(Duration::from_secs(3600))
+    /// }
+    /// ```
+    pub fn allow(&self) -> CorsHeaders {
+        let mut headers = CorsHeaders::new();
+        if let Some(origin) = self.origin {
+            headers = headers.allow_origin(origin.to_string());
+            if self.is_preflight() {
+                headers = headers
+                    .allow_methods(self.request_method)
+                    .allow_headers(self.request_headers.iter().map(|h| h.to_string()));
+            }
+        }
+
+        headers
+    }
+}
+
+#[crate::async_trait]
+impl<'r> FromRequest<'r> for Cors<'r> {
+    type Error = Infallible;
+
+    async fn from_request(req: &'r Request<'_>) -> Outcome<Self, Infallible> {
+        let headers = req.headers();
+        let request_headers = headers.get(Cors::REQUEST_HEADERS)
+            .flat_map(|v| v.split(','))
+            .map(|h| h.trim())
+            .filter(|h| !h.is_empty())
+            .collect();
+
+        Outcome::Success(Cors {
+            origin: headers.get_one(Cors::ORIGIN),
+            method: req.method(),
+            request_method: headers.get_one(Cors::REQUEST_METHOD).and_then(|m| m.parse().ok()),
+            request_headers,
+        })
+    }
+}
+
+/// The CORS response headers to send.
+///
+/// A `CorsHeaders` is a [`Responder`] which responds with `204 No Content`
+/// and the configured headers, suitable as the response to a preflight
+/// request. To add the headers to another response, use
+/// [`CorsHeaders::respond()`].
+///
+/// An empty `CorsHeaders`, as returned by [`CorsHeaders::new()`], sets no
+/// headers and thus allows no cross-origin access.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+/// use rocket::shield::cors::CorsHeaders;
+/// use rocket::http::Method;
+///
+/// let headers = CorsHeaders::new()
+///     .allow_origin("https://example.com")
+///     .allow_methods([Method::Get, Method::Post])
+///     .allow_headers(["Content-Type", "Authorization"])
+///     .expose_headers(["X-Total-Count"])
+///     .allow_credentials(true)
+///     .max_age(Duration::from_secs(600));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CorsHeaders {
+    origin: Option<Cow<'static, str>>,
+    methods: Vec<Method>,
+    headers: Vec<Cow<'static, str>>,
+    expose: Vec<Cow<'static, str>>,
+    credentials: bool,
+    max_age: Option<Duration>,
+}
+
+impl CorsHeaders {
+    /// Returns an empty set of CORS headers.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::shield::cors::CorsHeaders;
+    ///
+    /// let headers = CorsHeaders::new();
+    /// ```
+    pub fn new() -> Self {
+        CorsHeaders::default()
+    }
+
+    /// Sets `Access-Control-Allow-Origin` to `origin`. When `origin` isn't
+    /// `*`, `Vary: Origin` is also set so that caches key the response on the
+    /// origin.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::shield::cors::CorsHeaders;
+    ///
+    /// let headers = CorsHeaders::new().allow_origin("https://example.com");
+    /// ```
+    pub fn allow_origin<O: Into<Cow<'static, str>>>(mut self, origin: O) -> Self {
+        self.origin = Some(origin.into());
+        self
+    }
+
+    /// Sets `Access-Control-Allow-Origin` to `*`, allowing any origin.
+    ///
+    /// Browsers reject credentialed requests with a wildcard origin. To allow
+    /// credentials, echo the request's origin via
+    /// [`CorsHeaders::allow_origin()`] instead.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::shield::cors::CorsHeaders;
+    ///
+    /// let headers = CorsHeaders::new().allow_any_origin();
+    /// ```
+    pub fn allow_any_origin(self) -> Self {
+        self.allow_origin("*")
+    }
+
+    /// Adds `methods` to `Access-Control-Allow-Methods`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::shield::cors::CorsHeaders;
+    /// use rocket::http::Method;
+    ///
+    /// let headers = CorsHeaders::new().allow_methods([Method::Get, Method::Delete]);
+    /// ```
+    pub fn allow_methods<I: IntoIterator<Item = Method>>(mut self, methods: I) -> Self {
+        for method in methods {
+            if !self.methods.contains(&method) {
+                self.methods.push(method);
+            }
+        }
+
+        self
+    }
+
+    /// Adds `headers` to `Access-Control-Allow-Headers`. Header names are
+    /// case-insensitive: names already present, in any case, are skipped.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::shield::cors::CorsHeaders;
+    ///
+    /// let headers = CorsHeaders::new().allow_headers(["Content-Type"]);
+    /// ```
+    pub fn allow_headers<I, H>(mut self, headers: I) -> Self
+        where I: IntoIterator<Item = H>, H: Into<Cow<'static, str>>
+    {
+        extend_uncased(&mut self.headers, headers);
+        self
+    }
+
+    /// Adds `headers` to `Access-Control-Expose-Headers`. As with
+    /// [`CorsHeaders::allow_headers()`], duplicate names are skipped.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::shield::cors::CorsHeaders;
+    ///
+    /// let headers = CorsHeaders::new().expose_headers(["ETag", "X-Total-Count"]);
+    /// ```
+    pub fn expose_headers<I, H>(mut self, headers: I) -> Self
+        where I: IntoIterator<Item = H>, H: Into<Cow<'static, str>>
+    {
+        extend_uncased(&mut self.expose, headers);
+        self
+    }
+
+    /// Sets `Access-Control-Allow-Credentials: true` if `allow` is `true`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::shield::cors::CorsHeaders;
+    ///
+    /// let headers = CorsHeaders::new()
+    ///     .allow_origin("https://example.com")
+    ///     .allow_credentials(true);
+    /// ```
+    pub fn allow_credentials(mut self, allow: bool) -> Self {
+        self.credentials = allow;
+        self
+    }
+
+    /// Sets `Access-Control-Max-Age`, the duration, in whole seconds, for
+    /// which the results of a preflight request may be cached.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use rocket::shield::cors::CorsHeaders;
+    ///
+    /// let headers = CorsHeaders::new().max_age(Duration::from_secs(3600));
+    /// ```
+    pub fn max_age(mut self, max_age: Duration) -> Self {
+        self.max_age = Some(max_age);
+        self
+    }
+
+    /// Returns a responder which responds with `responder` and additionally
+    /// sets these headers.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate rocket;
+    /// use rocket::shield::cors::{CorsHeaders, WithCors};
+    ///
+    /// #[get("/")]
+    /// fn index() -> WithCors<&'static str> {
+    ///     CorsHeaders::new().allow_any_origin().respond("Hello, world!")
+    /// }
+    /// ```
+    pub fn respond<R>(self, responder: R) -> WithCors<R> {
+        WithCors { headers: self, responder }
+    }
+
+    fn apply_to(self, response: &mut Response<'_>) {
+        let Some(origin) = self.origin else {
+            return;
+        };
+
+        if origin != "*" {
+            response.adjoin_raw_header("Vary", "Origin");
+        }
+
+        response.set_raw_header("Access-Control-Allow-Origin", origin);
+        if self.credentials {
+            response.set_raw_header("Access-Control-Allow-Credentials", "true");
+        }
+
+        if !self.methods.is_empty() {
+            let methods: Vec<_> = self.methods.iter().map(|m| m.as_str()).collect();
+            response.set_raw_header("Access-Control-Allow-Methods", methods.join(", "));
+        }
+
+        if !self.headers.is_empty() {
+            response.set_raw_header("Access-Control-Allow-Headers", self.headers.join(", "));
+        }
+
+        if !self.expose.is_empty() {
+            response.set_raw_header("Access-Control-Expose-Headers", self.expose.join(", "));
+        }
+
+        if let Some(max_age) = self.max_age {
+            response.set_raw_header("Access-Control-Max-Age", max_age.as_secs().to_string());
+        }
+    }
+}
+
+/// Appends the header names in `new` to `names`, skipping any already present
+/// in either, compared case-insensitively.
+fn extend_uncased<I, H>(names: &mut Vec<Cow<'static, str>>, new: I)
+    where I: IntoIterator<Item = H>, H: Into<Cow<'static, str>>
+{
+    for name in new.into_iter().map(Into::into) {
+        if !names.iter().any(|n| n.eq_ignore_ascii_case(&name)) {
+            names.push(name);
+        }
+    }
+}
+
+/// Responds with `204 No Content` and the CORS headers.
+impl<'r> Responder<'r, 'static> for CorsHeaders {
+    fn respond_to(self, _: &'r Request<'_>) -> response::Result<'static> {
+        let mut response = Response::build().status(Status::NoContent).finalize();
+        self.apply_to(&mut response);
+        Ok(response)
+    }
+}
+
+/// A responder which adds [`CorsHeaders`] to the response of another
+/// responder.
+///
+/// Created via [`CorsHeaders::respond()`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct WithCors<R> {
+    headers: CorsHeaders,
+    responder: R,
+}
+
+impl<'r, 'o: 'r, R: Responder<'r, 'o>> Responder<'r, 'o> for WithCors<R> {
+    fn respond_to(self, req: &'r Request<'_>) -> response::Result<'o> {
+        let mut response = self.responder.respond_to(req)?;
+        self.headers.apply_to(&mut response);
+        Ok(response)
+    }
+}
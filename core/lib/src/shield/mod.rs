@@ -3,6 +3,8 @@
 //! The [`Shield`] fairing provides a typed interface for injecting HTTP
 //! security and privacy headers into all outgoing responses. It takes some
 //! inspiration from [helmetjs], a similar piece of middleware for [express].
+//! Per-route Cross-Origin Resource Sharing is handled separately, by the
+//! request guard and responders in [`cors`].
 //!
 //! [fairing]: https://rocket.rs/master/guide/fairings/
 //! [helmetjs]: https://helmetjs.github.io/
@@ -100,5 +102,7 @@
 mod shield;
 mod policy;
 
+pub mod cors;
+
 pub use self::shield::Shield;
 pub use self::policy::*;
@@ -1,11 +1,23 @@
+//! Managed state: the [`State`] request guard, [`Lazy`] initializer, and
+//! [`ReloadableState`] configuration.
+
 use std::fmt;
 use std::ops::Deref;
 use std::any::type_name;
+use std::future::Future;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use futures::future::BoxFuture;
+use figment::Figment;
+use parking_lot::RwLock;
+use serde::de::DeserializeOwned;
 
 use ref_cast::RefCast;
 use yansi::Paint;
 
-use crate::{Phase, Rocket, Ignite, Sentinel};
+use crate::{Phase, Rocket, Build, Ignite, Sentinel, Config};
+use crate::fairing::{self, Fairing, Info, Kind};
 use crate::request::{self, FromRequest, Request};
 use crate::outcome::Outcome;
 use crate::http::Status;
@@ -235,3 +247,338 @@ impl<T: Send + Sync + 'static> Deref for State<T> {
         &self.0
     }
 }
+
+/// Lazily initialized managed state.
+///
+/// A `Lazy<T>` is managed via [`Rocket::manage_lazy()`] and retrieved like any
+/// other managed value, via a `&State<Lazy<T>>` request guard. The inner value
+/// is constructed by an asynchronous initializer the first time
+/// [`Lazy::get()`] is called and is cached for all subsequent calls. This makes
+/// `Lazy` a good fit for values that are expensive to construct, such as HTTP
+/// client pools, but that may never be needed.
+///
+/// The initializer runs _exactly once_, even when `get()` is called
+/// concurrently before the value is available: the first caller runs the
+/// initializer while the others wait for it to complete.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use rocket::State;
+/// use rocket::state::Lazy;
+///
+/// struct Client { name: String }
+///
+/// impl Client {
+///     async fn connect() -> Client {
+///         // Imagine this was expensive.
+///         Client { name: "upstream".into() }
+///     }
+/// }
+///
+/// #[get("/")]
+/// async fn index(client: &State<Lazy<Client>>) -> String {
+///     client.get().await.name.clone()
+/// }
+///
+/// #[launch]
+/// fn rocket() -> _ {
+///     rocket::build()
+///         .manage_lazy(Client::connect)
+///         .mount("/", routes![index])
+/// }
+/// ```
+pub struct Lazy<T> {
+    cell: tokio::sync::OnceCell<T>,
+    init: Box<dyn Fn() -> BoxFuture<'static, T> + Send + Sync>,
+}
+
+impl<T: Send + Sync + 'static> Lazy<T> {
+    /// Creates a new `Lazy` which initializes its value with the future
+    /// returned by `init`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::state::Lazy;
+    ///
+    /// let lazy = Lazy::new(|| async { 42 });
+    /// assert!(lazy.try_get().is_none());
+    /// ```
+    pub fn new<F, Fut>(init: F) -> Self
+        where F: Fn() -> Fut + Send + Sync + 'static,
+              Fut: Future<Output = T> + Send + 'static,
+    {
+        Lazy {
+            cell: tokio::sync::OnceCell::new(),
+            init: Box::new(move || Box::pin(init())),
+        }
+    }
+
+    /// Returns the value, initializing it first if it hasn't been already.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::state::Lazy;
+    ///
+    /// # rocket::async_test(async {
+    /// let lazy = Lazy::new(|| async { 42 });
+    /// assert_eq!(*lazy.get().await, 42);
+    /// assert_eq!(lazy.try_get(), Some(&42));
+    /// # });
+    /// ```
+    pub async fn get(&self) -> &T {
+        self.cell.get_or_init(|| (self.init)()).await
+    }
+
+    /// Returns the value if it has already been initialized. Otherwise returns
+    /// `None`. Never runs the initializer.
+    #[inline]
+    pub fn try_get(&self) -> Option<&T> {
+        self.cell.get()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Lazy<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Lazy").field("value", &self.cell.get()).finish()
+    }
+}
+
+/// Managed state extracted from configuration which can be reloaded at
+/// runtime.
+///
+/// A `ReloadableState<T>` is managed by the fairing returned from
+/// [`ReloadableState::fairing()`] and retrieved like any other managed value,
+/// via a `&State<ReloadableState<T>>` request guard. On ignition, the fairing
+/// extracts a `T` from [`Rocket::figment()`], exactly like
+/// [`AdHoc::config()`](crate::fairing::AdHoc::config()). Afterwards, the value
+/// can be re-extracted from a freshly created figment and atomically swapped
+/// in, either explicitly via [`ReloadableState::reload()`], say from an admin
+/// route, or, on Unix platforms, by sending the process a `SIGHUP` signal.
+///
+/// [`ReloadableState::get()`] returns the current value as an `Arc<T>`. A
+/// handler that retrieves the value once observes a consistent value even if
+/// a reload occurs concurrently. If extracting a reloaded value fails, the
+/// current value is retained.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use rocket::State;
+/// use rocket::state::ReloadableState;
+/// use rocket::serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// #[serde(crate = "rocket::serde")]
+/// struct Settings {
+///     #[serde(default)]
+///     motd: String,
+/// }
+///
+/// #[get("/")]
+/// fn index(settings: &State<ReloadableState<Settings>>) -> String {
+///     settings.get().motd.clone()
+/// }
+///
+/// #[post("/admin/reload")]
+/// fn reload(settings: &State<ReloadableState<Settings>>) -> Result<(), String> {
+///     settings.reload().map_err(|e| e.to_string())
+/// }
+///
+/// #[launch]
+/// fn rocket() -> _ {
+///     rocket::build()
+///         .attach(ReloadableState::<Settings>::fairing())
+///         .mount("/", routes![index, reload])
+/// }
+/// ```
+pub struct ReloadableState<T> {
+    value: Arc<RwLock<Arc<T>>>,
+    figment: Arc<dyn Fn() -> Figment + Send + Sync>,
+}
+
+/// The fairing that manages and reloads a [`ReloadableState`].
+struct Reloader<T> {
+    figment: Arc<dyn Fn() -> Figment + Send + Sync>,
+    _value: PhantomData<fn() -> T>,
+}
+
+impl<T: DeserializeOwned + Send + Sync + 'static> ReloadableState<T> {
+    /// Returns a fairing that manages a `ReloadableState<T>`, reloading from
+    /// the figment returned by [`Config::figment()`]: that is, from
+    /// `Rocket.toml` and `ROCKET_` environment variables.
+    ///
+    /// On ignition, the fairing extracts the initial value from
+    /// [`Rocket::figment()`] and fails if extraction fails.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use rocket::launch;
+    /// use rocket::state::ReloadableState;
+    ///
+    /// #[derive(rocket::serde::Deserialize)]
+    /// #[serde(crate = "rocket::serde")]
+    /// struct Settings { /* .. */ }
+    ///
+    /// #[launch]
+    /// fn rocket() -> _ {
+    ///     rocket::build().attach(ReloadableState::<Settings>::fairing())
+    /// }
+    /// ```
+    pub fn fairing() -> impl Fairing {
+        Self::fairing_with(Config::figment)
+    }
+
+    /// Like [`ReloadableState::fairing()`], but reloads from the figment
+    /// returned by `figment`, which is called on every reload. Use this method
+    /// when Rocket is configured via a custom figment.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use rocket::launch;
+    /// use rocket::state::ReloadableState;
+    /// use rocket::figment::{Figment, providers::{Format, Toml}};
+    ///
+    /// #[derive(rocket::serde::Deserialize)]
+    /// #[serde(crate = "rocket::serde")]
+    /// struct Settings { /* .. */ }
+    ///
+    /// fn figment() -> Figment {
+    ///     rocket::Config::figment().merge(Toml::file("App.toml").nested())
+    /// }
+    ///
+    /// #[launch]
+    /// fn rocket() -> _ {
+    ///     rocket::custom(figment())
+    ///         .attach(ReloadableState::<Settings>::fairing_with(figment))
+    /// }
+    /// ```
+    pub fn fairing_with<F>(figment: F) -> impl Fairing
+        where F: Fn() -> Figment + Send + Sync + 'static
+    {
+        Reloader::<T> { figment: Arc::new(figment), _value: PhantomData }
+    }
+
+    /// Returns the current value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate rocket;
+    /// use rocket::State;
+    /// use rocket::state::ReloadableState;
+    ///
+    /// #[derive(rocket::serde::Deserialize)]
+    /// #[serde(crate = "rocket::serde")]
+    /// struct Settings { motd: String }
+    ///
+    /// #[get("/")]
+    /// fn index(settings: &State<ReloadableState<Settings>>) -> String {
+    ///     settings.get().motd.clone()
+    /// }
+    /// ```
+    pub fn get(&self) -> Arc<T> {
+        self.value.read().clone()
+    }
+
+    /// Extracts a new value from a freshly created figment and, if extraction
+    /// succeeds, replaces the current value with it. If extraction fails, the
+    /// current value is retained and the error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate rocket;
+    /// use rocket::State;
+    /// use rocket::state::ReloadableState;
+    ///
+    /// #[derive(rocket::serde::Deserialize)]
+    /// #[serde(crate = "rocket::serde")]
+    /// struct Settings { motd: String }
+    ///
+    /// #[post("/admin/reload")]
+    /// fn reload(settings: &State<ReloadableState<Settings>>) -> Option<()> {
+    ///     settings.reload().ok()
+    /// }
+    /// ```
+    pub fn reload(&self) -> Result<(), figment::Error> {
+        let value = (self.figment)().extract::<T>()?;
+        *self.value.write() = Arc::new(value);
+        Ok(())
+    }
+}
+
+impl<T> Clone for ReloadableState<T> {
+    fn clone(&self) -> Self {
+        ReloadableState { value: self.value.clone(), figment: self.figment.clone() }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for ReloadableState<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ReloadableState").field("value", &*self.value.read()).finish()
+    }
+}
+
+#[crate::async_trait]
+impl<T: DeserializeOwned + Send + Sync + 'static> Fairing for Reloader<T> {
+    fn info(&self) -> Info {
+        Info {
+            name: type_name::<ReloadableState<T>>(),
+            kind: Kind::Ignite | Kind::Liftoff,
+        }
+    }
+
+    async fn on_ignite(&self, rocket: Rocket<Build>) -> fairing::Result {
+        let value = match rocket.figment().extract::<T>() {
+            Ok(value) => value,
+            Err(e) => {
+                crate::config::pretty_print_error(e);
+                return Err(rocket);
+            }
+        };
+
+        Ok(rocket.manage(ReloadableState::<T> {
+            value: Arc::new(RwLock::new(Arc::new(value))),
+            figment: self.figment.clone(),
+        }))
+    }
+
+    #[cfg(unix)]
+    async fn on_liftoff(&self, rocket: &Rocket<crate::Orbit>) {
+        use futures::StreamExt;
+        use tokio::signal::unix::{signal, SignalKind};
+        use tokio_stream::wrappers::SignalStream;
+
+        let Some(state) = rocket.state::<ReloadableState<T>>().cloned() else {
+            return;
+        };
+
+        let hangups = match signal(SignalKind::hangup()) {
+            Ok(signal) => SignalStream::new(signal).take_until(rocket.shutdown()),
+            Err(e) => {
+                warn!("Failed to enable `hup` reload signal: {}", e);
+                return;
+            }
+        };
+
+        tokio::spawn(async move {
+            let mut hangups = std::pin::pin!(hangups);
+            while hangups.next().await.is_some() {
+                match state.reload() {
+                    Ok(()) => info!("Reloaded `{}`.", type_name::<T>().primary().bold()),
+                    Err(e) => {
+                        error!("Failed to reload `{}`.", type_name::<T>().primary().bold());
+                        crate::config::pretty_print_error(e);
+                    }
+                }
+            }
+        });
+    }
+}
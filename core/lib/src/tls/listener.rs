@@ -87,6 +87,14 @@ impl<C: Connection> Connection for TlsStream<C> {
         Ok(self.get_ref().0.endpoint()?.assume_tls())
     }
 
+    fn local_endpoint(&self) -> io::Result<Endpoint> {
+        Ok(self.get_ref().0.local_endpoint()?.assume_tls())
+    }
+
+    fn server_name(&self) -> Option<&str> {
+        self.get_ref().1.server_name()
+    }
+
     fn certificates(&self) -> Option<Certificates<'_>> {
         #[cfg(feature = "mtls")] {
             let cert_chain = self.get_ref().1.peer_certificates()?;
@@ -1,11 +1,13 @@
 use std::pin::Pin;
-use std::task::{Context, Poll};
+use std::task::{ready, Context, Poll};
 
 use bytes::{Bytes, BytesMut};
 use futures::stream::Stream;
 use pin_project_lite::pin_project;
 use tokio::io::AsyncRead;
 
+use crate::erased::ErasedResponse;
+
 pin_project! {
     /// Convert an [`AsyncRead`] into a [`Stream`] of byte chunks.
     ///
@@ -110,15 +112,26 @@ impl<R: AsyncRead> Stream for ReaderStream<R> {
     // }
 }
 
-impl<R: AsyncRead> hyper::body::Body for ReaderStream<R> {
+impl ReaderStream<ErasedResponse> {
+    /// Takes the trailers of the response, if there are any. Trailers should
+    /// only be taken once the stream has terminated.
+    pub fn take_trailers(self: Pin<&mut Self>) -> Option<http::HeaderMap> {
+        self.project().reader.get_mut().take_trailers()
+    }
+}
+
+impl hyper::body::Body for ReaderStream<ErasedResponse> {
     type Data = bytes::Bytes;
 
     type Error = std::io::Error;
 
     fn poll_frame(
-        self: Pin<&mut Self>,
+        mut self: Pin<&mut Self>,
         cx: &mut Context<'_>,
     ) -> Poll<Option<Result<hyper::body::Frame<Self::Data>, Self::Error>>> {
-        self.poll_next(cx).map_ok(hyper::body::Frame::data)
+        match ready!(self.as_mut().poll_next(cx)) {
+            Some(result) => Poll::Ready(Some(result.map(hyper::body::Frame::data))),
+            None => Poll::Ready(self.take_trailers().map(|t| Ok(hyper::body::Frame::trailers(t)))),
+        }
     }
 }
@@ -23,3 +23,25 @@ pub fn unlock_nonblocking<T: AsRawFd>(file: &T) -> io::Result<()> {
         _ => Err(io::Error::last_os_error()),
     }
 }
+
+/// Returns the address family (`AF_INET`, `AF_UNIX`, ...) of the socket `fd`.
+pub fn socket_family(fd: std::os::fd::RawFd) -> io::Result<libc::c_int> {
+    let mut addr: libc::sockaddr_storage = unsafe { std::mem::zeroed() };
+    let mut len = std::mem::size_of_val(&addr) as libc::socklen_t;
+    let res = unsafe {
+        libc::getsockname(fd, &mut addr as *mut _ as *mut libc::sockaddr, &mut len)
+    };
+
+    match res {
+        0 => Ok(addr.ss_family as libc::c_int),
+        _ => Err(io::Error::last_os_error()),
+    }
+}
+
+pub fn set_cloexec(fd: std::os::fd::RawFd) -> io::Result<()> {
+    let res = unsafe { libc::fcntl(fd, libc::F_SETFD, libc::FD_CLOEXEC) };
+    match res {
+        -1 => Err(io::Error::last_os_error()),
+        _ => Ok(()),
+    }
+}
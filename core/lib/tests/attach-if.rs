@@ -0,0 +1,42 @@
+#[macro_use] extern crate rocket;
+
+use rocket::{Build, Rocket};
+use rocket::fairing::AdHoc;
+use rocket::local::blocking::Client;
+
+#[get("/")]
+fn index() -> &'static str {
+    "index"
+}
+
+fn header(name: &'static str) -> AdHoc {
+    AdHoc::on_response(name, move |_, res| Box::pin(async move {
+        res.set_raw_header(name, "yes");
+    }))
+}
+
+fn rocket(condition: bool) -> Rocket<Build> {
+    rocket::build()
+        .mount("/", routes![index])
+        .attach_if(condition, header("X-If"))
+        .attach_unless(condition, header("X-Unless"))
+        .attach(header("X-Always"))
+}
+
+#[test]
+fn attach_if_true() {
+    let client = Client::debug(rocket(true)).unwrap();
+    let response = client.get("/").dispatch();
+    assert_eq!(response.headers().get_one("X-If"), Some("yes"));
+    assert!(response.headers().get_one("X-Unless").is_none());
+    assert_eq!(response.headers().get_one("X-Always"), Some("yes"));
+}
+
+#[test]
+fn attach_if_false() {
+    let client = Client::debug(rocket(false)).unwrap();
+    let response = client.get("/").dispatch();
+    assert!(response.headers().get_one("X-If").is_none());
+    assert_eq!(response.headers().get_one("X-Unless"), Some("yes"));
+    assert_eq!(response.headers().get_one("X-Always"), Some("yes"));
+}
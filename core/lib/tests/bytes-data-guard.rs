@@ -0,0 +1,44 @@
+#[macro_use] extern crate rocket;
+
+use rocket::data::{Bytes, Capped, Limits, ToByteUnit};
+use rocket::http::Status;
+use rocket::local::blocking::Client;
+
+#[post("/", data = "<body>")]
+fn upload(body: Bytes) -> String {
+    format!("{} bytes: {:?}", body.len(), &body[..])
+}
+
+#[post("/capped", data = "<body>")]
+fn capped(body: Capped<Bytes>) -> String {
+    format!("{} complete: {}", body.len(), body.is_complete())
+}
+
+fn client(limit: u64) -> Client {
+    let config = rocket::Config {
+        limits: Limits::default().limit("bytes", limit.bytes()),
+        ..rocket::Config::debug_default()
+    };
+
+    Client::debug(rocket::custom(config).mount("/", routes![upload, capped])).unwrap()
+}
+
+#[test]
+fn bytes_within_limit() {
+    let client = client(8);
+    let response = client.post("/").body([1u8, 2, 3]).dispatch();
+    assert_eq!(response.into_string().unwrap(), "3 bytes: [1, 2, 3]");
+
+    let response = client.post("/").body([0u8; 8]).dispatch();
+    assert_eq!(response.status(), Status::Ok);
+}
+
+#[test]
+fn bytes_over_limit() {
+    let client = client(8);
+    let response = client.post("/").body([0u8; 9]).dispatch();
+    assert_eq!(response.status(), Status::PayloadTooLarge);
+
+    let response = client.post("/capped").body([0u8; 9]).dispatch();
+    assert_eq!(response.into_string().unwrap(), "8 complete: false");
+}
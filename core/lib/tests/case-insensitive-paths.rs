@@ -0,0 +1,40 @@
+#[macro_use] extern crate rocket;
+
+use rocket::{Rocket, Build, Config};
+use rocket::http::Status;
+use rocket::local::blocking::Client;
+
+#[get("/api/users/<name>")]
+fn user(name: &str) -> String {
+    format!("user: {}", name)
+}
+
+#[get("/api/users")]
+fn users() -> &'static str {
+    "users"
+}
+
+fn rocket(case_insensitive_paths: bool) -> Rocket<Build> {
+    let config = Config { case_insensitive_paths, ..Config::debug_default() };
+    rocket::custom(config).mount("/", routes![user, users])
+}
+
+#[test]
+fn paths_are_case_sensitive_by_default() {
+    let client = Client::debug(rocket(false)).unwrap();
+    assert_eq!(client.get("/api/users").dispatch().status(), Status::Ok);
+    assert_eq!(client.get("/API/Users").dispatch().status(), Status::NotFound);
+}
+
+#[test]
+fn static_segments_match_case_insensitively() {
+    let client = Client::debug(rocket(true)).unwrap();
+    assert_eq!(client.get("/API/Users").dispatch().into_string().unwrap(), "users");
+    assert_eq!(client.get("/api/USERS").dispatch().into_string().unwrap(), "users");
+
+    // Dynamic parameters are passed through as-is.
+    let response = client.get("/Api/Users/BoB").dispatch();
+    assert_eq!(response.into_string().unwrap(), "user: BoB");
+
+    assert_eq!(client.get("/API/Userz").dispatch().status(), Status::NotFound);
+}
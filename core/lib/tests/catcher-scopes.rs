@@ -0,0 +1,140 @@
+#[macro_use] extern crate rocket;
+
+use rocket::{Rocket, Build, Request};
+use rocket::http::Status;
+use rocket::local::blocking::Client;
+
+#[get("/<code>")]
+fn forced_error(code: u16) -> Status {
+    Status::new(code)
+}
+
+#[catch(404)]
+fn root_404() -> &'static str {
+    "root 404"
+}
+
+#[catch(default)]
+fn root_default(status: Status) -> String {
+    format!("root {}", status.code)
+}
+
+#[catch(default)]
+fn api_default(status: Status, req: &Request<'_>) -> Result<String, Status> {
+    match req.uri().query().map(|q| q.as_str()) {
+        Some("decline") => Err(status),
+        _ => Ok(format!("api {}", status.code)),
+    }
+}
+
+#[catch(404)]
+fn api_v1_404() -> Option<&'static str> {
+    None
+}
+
+#[catch(418)]
+fn api_v1_418() -> Result<&'static str, Status> {
+    Err(Status::BadRequest)
+}
+
+fn rocket() -> Rocket<Build> {
+    rocket::build()
+        .mount("/", routes![forced_error])
+        .mount("/api/v1", routes![forced_error])
+        .register("/", catchers![root_404, root_default])
+        .register("/api", catchers![api_default])
+        .register("/api/v1", catchers![api_v1_404, api_v1_418])
+}
+
+fn resolved(client: &Client, status: Status, uri: &str) -> Vec<(Option<u16>, String)> {
+    let req = client.get(uri);
+    client.rocket()
+        .catchers_for(status, &req)
+        .map(|c| (c.code, c.base().to_string()))
+        .collect()
+}
+
+#[test]
+fn catchers_for_lists_catchers_in_order() {
+    let client = Client::debug(rocket()).unwrap();
+
+    let expected = |list: &[(Option<u16>, &str)]| list.iter()
+        .map(|(code, base)| (*code, base.to_string()))
+        .collect::<Vec<_>>();
+
+    assert_eq!(resolved(&client, Status::NotFound, "/foo"),
+        expected(&[(Some(404), "/"), (None, "/")]));
+
+    assert_eq!(resolved(&client, Status::NotFound, "/api/foo"),
+        expected(&[(None, "/api"), (Some(404), "/"), (None, "/")]));
+
+    assert_eq!(resolved(&client, Status::NotFound, "/api/v1/foo"),
+        expected(&[(Some(404), "/api/v1"), (None, "/api"), (Some(404), "/"), (None, "/")]));
+
+    assert_eq!(resolved(&client, Status::InternalServerError, "/api/v1/foo"),
+        expected(&[(None, "/api"), (None, "/")]));
+
+    let empty = Client::debug_with(vec![]).unwrap();
+    assert!(resolved(&empty, Status::NotFound, "/").is_empty());
+}
+
+#[test]
+fn catchers_handle_errors_by_scope() {
+    let client = Client::debug(rocket()).unwrap();
+
+    let response = client.get("/nothing/here").dispatch();
+    assert_eq!(response.status(), Status::NotFound);
+    assert_eq!(response.into_string().unwrap(), "root 404");
+
+    let response = client.get("/503").dispatch();
+    assert_eq!(response.status(), Status::ServiceUnavailable);
+    assert_eq!(response.into_string().unwrap(), "root 503");
+
+    let response = client.get("/api/nothing/here").dispatch();
+    assert_eq!(response.status(), Status::NotFound);
+    assert_eq!(response.into_string().unwrap(), "api 404");
+}
+
+#[test]
+fn declining_catchers_forward_to_broader_scopes() {
+    let client = Client::debug(rocket()).unwrap();
+
+    // `api_v1_404` declines by returning `None`; `api_default` handles it.
+    let response = client.get("/api/v1/no/thing").dispatch();
+    assert_eq!(response.status(), Status::NotFound);
+    assert_eq!(response.into_string().unwrap(), "api 404");
+
+    // Both `api_v1_404` and `api_default` decline; `root_404` handles it.
+    let response = client.get("/api/v1/no/thing?decline").dispatch();
+    assert_eq!(response.status(), Status::NotFound);
+    assert_eq!(response.into_string().unwrap(), "root 404");
+
+    // `api_default` declines; `root_404` handles it.
+    let response = client.get("/api/nothing?decline").dispatch();
+    assert_eq!(response.status(), Status::NotFound);
+    assert_eq!(response.into_string().unwrap(), "root 404");
+}
+
+#[test]
+fn declining_every_catcher_uses_builtin_default() {
+    #[catch(404)]
+    fn decline(status: Status) -> Result<&'static str, Status> {
+        Err(status)
+    }
+
+    let client = Client::debug(rocket::build().register("/", catchers![decline])).unwrap();
+
+    let response = client.get("/").dispatch();
+    assert_eq!(response.status(), Status::NotFound);
+    assert!(response.into_string().unwrap().contains("404"));
+}
+
+#[test]
+fn failing_catchers_are_not_declines() {
+    let client = Client::debug(rocket()).unwrap();
+
+    // `api_v1_418` fails with `400`: the `500` catchers are invoked instead.
+    let response = client.get("/api/v1/418").dispatch();
+    assert_eq!(response.status(), Status::InternalServerError);
+    assert_eq!(response.into_string().unwrap(), "api 500");
+}
@@ -0,0 +1,111 @@
+#[macro_use] extern crate rocket;
+
+use rocket::http::{EntityTag, Header, Status};
+use rocket::local::blocking::Client;
+use rocket::response::Conditional;
+use rocket::time::macros::datetime;
+
+#[get("/")]
+fn index() -> Conditional<&'static str> {
+    Conditional::new("Hello, world!")
+        .etag(EntityTag::strong("v1"))
+        .last_modified(datetime!(2024-01-01 12:00:00.5 UTC))
+}
+
+#[put("/")]
+fn update() -> Conditional<&'static str> {
+    Conditional::new("Updated!").etag(EntityTag::strong("v1"))
+}
+
+#[get("/missing")]
+fn missing() -> Conditional<Option<&'static str>> {
+    Conditional::new(None).etag(EntityTag::strong("v1"))
+}
+
+fn client() -> Client {
+    Client::debug_with(routes![index, update, missing]).unwrap()
+}
+
+#[test]
+fn sets_validators() {
+    let client = client();
+    let response = client.get("/").dispatch();
+    assert_eq!(response.status(), Status::Ok);
+    assert_eq!(response.headers().get_one("ETag"), Some("\"v1\""));
+    assert_eq!(response.headers().get_one("Last-Modified"), Some("Mon, 01 Jan 2024 12:00:00 GMT"));
+    assert_eq!(response.into_string().unwrap(), "Hello, world!");
+
+    let response = client.get("/missing").dispatch();
+    assert_eq!(response.status(), Status::NotFound);
+    assert!(response.headers().get_one("ETag").is_none());
+}
+
+#[test]
+fn if_none_match() {
+    let client = client();
+    for value in ["\"v1\"", "W/\"v1\"", "\"v0\", \"v1\"", "*"] {
+        let response = client.get("/").header(Header::new("If-None-Match", value)).dispatch();
+        assert_eq!(response.status(), Status::NotModified, "{}", value);
+        assert_eq!(response.headers().get_one("ETag"), Some("\"v1\""));
+        assert!(response.into_string().unwrap_or_default().is_empty());
+    }
+
+    let response = client.get("/").header(Header::new("If-None-Match", "\"v0\"")).dispatch();
+    assert_eq!(response.status(), Status::Ok);
+
+    let response = client.put("/").header(Header::new("If-None-Match", "*")).dispatch();
+    assert_eq!(response.status(), Status::PreconditionFailed);
+}
+
+#[test]
+fn if_match() {
+    let client = client();
+    let response = client.put("/").header(Header::new("If-Match", "\"v1\"")).dispatch();
+    assert_eq!(response.status(), Status::Ok);
+    assert_eq!(response.into_string().unwrap(), "Updated!");
+
+    for value in ["\"v0\"", "W/\"v1\""] {
+        let response = client.put("/").header(Header::new("If-Match", value)).dispatch();
+        assert_eq!(response.status(), Status::PreconditionFailed, "{}", value);
+    }
+}
+
+#[test]
+fn if_modified_since() {
+    let client = client();
+    let response = client.get("/")
+        .header(Header::new("If-Modified-Since", "Mon, 01 Jan 2024 12:00:00 GMT"))
+        .dispatch();
+
+    assert_eq!(response.status(), Status::NotModified);
+
+    let response = client.get("/")
+        .header(Header::new("If-Modified-Since", "Mon, 01 Jan 2024 11:59:59 GMT"))
+        .dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+
+    // `If-None-Match` takes precedence over `If-Modified-Since`.
+    let response = client.get("/")
+        .header(Header::new("If-None-Match", "\"v0\""))
+        .header(Header::new("If-Modified-Since", "Mon, 01 Jan 2024 12:00:00 GMT"))
+        .dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+}
+
+#[test]
+fn if_unmodified_since() {
+    let client = client();
+    let response = client.get("/")
+        .header(Header::new("If-Unmodified-Since", "Sun, 31 Dec 2023 00:00:00 GMT"))
+        .dispatch();
+
+    assert_eq!(response.status(), Status::PreconditionFailed);
+
+    let response = client.get("/")
+        .header(Header::new("If-Unmodified-Since", "Mon, 01 Jan 2024 12:00:00 GMT"))
+        .dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+}
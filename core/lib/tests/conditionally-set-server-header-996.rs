@@ -49,4 +49,41 @@ mod conditionally_set_server_header {
         let server = response.headers().get_one("Server");
         assert_eq!(server, Some("My Special Server"));
     }
+
+    #[test]
+    fn none_ident_suppresses_server_header() {
+        let config = rocket::Config {
+            ident: rocket::config::Ident::none(),
+            ..rocket::Config::debug_default()
+        };
+
+        let rocket = rocket::custom(config).mount("/", routes![do_not_overwrite, use_default]);
+        let client = Client::debug(rocket).unwrap();
+
+        let response = client.get("/use_default").dispatch();
+        assert!(response.headers().get_one("Server").is_none());
+
+        let response = client.get("/missing").dispatch();
+        assert!(response.headers().get_one("Server").is_none());
+
+        let response = client.get("/do_not_overwrite").dispatch();
+        assert_eq!(response.headers().get_one("Server"), Some("Test"));
+    }
+
+    #[test]
+    fn server_header_set_on_rejected_requests() {
+        let config = rocket::Config {
+            max_uri_length: 8,
+            ..rocket::Config::debug_default()
+        };
+
+        let client = Client::debug(rocket::custom(config)).unwrap();
+        let response = client.get("/0123456789").dispatch();
+        assert_eq!(response.status(), rocket::http::Status::UriTooLong);
+        assert_eq!(response.headers().get_one("Server"), Some("Rocket"));
+
+        let response = client.get("/404").dispatch();
+        assert_eq!(response.status(), rocket::http::Status::NotFound);
+        assert_eq!(response.headers().get_one("Server"), Some("Rocket"));
+    }
 }
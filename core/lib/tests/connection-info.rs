@@ -0,0 +1,25 @@
+#[macro_use] extern crate rocket;
+
+use rocket::request::ConnectionInfo;
+use rocket::local::blocking::Client;
+
+#[get("/")]
+fn index(conn: ConnectionInfo<'_>) -> String {
+    format!("{:?} {:?} {} {:?}",
+        conn.remote().map(|e| e.to_string()),
+        conn.local().map(|e| e.to_string()),
+        conn.is_tls(),
+        conn.server_name())
+}
+
+#[test]
+fn connection_info_guard() {
+    let client = Client::debug_with(routes![index]).unwrap();
+
+    let response = client.get("/").dispatch();
+    assert_eq!(response.into_string().unwrap(), "None None false None");
+
+    let response = client.get("/").remote("tcp:8.8.8.8:80").dispatch();
+    assert_eq!(response.into_string().unwrap(),
+        "Some(\"http://8.8.8.8:80\") None false None");
+}
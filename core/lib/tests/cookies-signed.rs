@@ -0,0 +1,67 @@
+#![cfg(feature = "secrets")]
+#![deny(warnings)]
+
+use rocket::http::{Cookie, CookieJar};
+use rocket::{get, post, routes};
+
+#[post("/")]
+fn cookie_add_signed(jar: &CookieJar<'_>) {
+    jar.add(("a", "v1"));
+    jar.add_signed(("b", "v2"));
+    assert_eq!(jar.get_pending("b").unwrap().value(), "v2");
+}
+
+#[get("/")]
+fn cookie_get_signed(jar: &CookieJar<'_>) -> Option<String> {
+    assert!(jar.get_private("b").is_none());
+    jar.get_signed("b").map(|c| c.value().to_string())
+}
+
+#[cfg(test)]
+mod cookies_signed_tests {
+    use super::*;
+    use rocket::local::blocking::Client;
+    use rocket::http::Status;
+    use rocket::{Build, Rocket};
+
+    fn rocket() -> Rocket<Build> {
+        rocket::build().mount("/", routes![cookie_add_signed, cookie_get_signed])
+    }
+
+    #[test]
+    fn test_signed_cookie_value_is_readable() {
+        let client = Client::debug(rocket()).unwrap();
+        let response = client.post("/").dispatch();
+        let cookies = response.cookies();
+        assert_eq!(cookies.iter().count(), 2);
+        assert_eq!(cookies.get("a").unwrap().value(), "v1");
+        assert_eq!(cookies.get_signed("b").unwrap().value(), "v2");
+        assert_eq!(cookies.get_pending("b").unwrap().value(), "v2");
+
+        // The raw value is the signature followed by the plaintext value.
+        let raw = cookies.get("b").unwrap().value();
+        assert_ne!(raw, "v2");
+        assert!(raw.ends_with("v2"));
+    }
+
+    #[test]
+    fn test_signed_cookie_roundtrip() {
+        let client = Client::debug(rocket()).unwrap();
+        let response = client.get("/").signed_cookie(("b", "hello")).dispatch();
+        assert_eq!(response.into_string().unwrap(), "hello");
+    }
+
+    #[test]
+    fn test_tampered_signed_cookie_is_rejected() {
+        let client = Client::untracked(rocket()).unwrap();
+        let response = client.post("/").dispatch();
+        let raw = response.cookies().get("b").unwrap().value().to_string();
+        let tampered = format!("{}v3", raw.strip_suffix("v2").unwrap());
+
+        let response = client.get("/").cookie(Cookie::new("b", tampered)).dispatch();
+        assert_eq!(response.status(), Status::NotFound);
+
+        let response = client.get("/").cookie(("b", "v2")).dispatch();
+        assert_eq!(response.status(), Status::NotFound);
+    }
+}
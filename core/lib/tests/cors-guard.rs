@@ -0,0 +1,119 @@
+#[macro_use] extern crate rocket;
+
+use std::time::Duration;
+
+use rocket::shield::cors::{Cors, CorsHeaders, WithCors};
+use rocket::http::{Header, Method, Status};
+use rocket::local::blocking::Client;
+
+const ALLOWED: &str = "https://allowed.example";
+
+#[options("/data")]
+fn preflight(cors: Cors<'_>) -> Option<CorsHeaders> {
+    assert!(cors.is_preflight());
+    cors.origin().filter(|o| *o == ALLOWED)?;
+    Some(cors.allow().max_age(Duration::from_secs(60)))
+}
+
+#[get("/data")]
+fn data(cors: Cors<'_>) -> WithCors<&'static str> {
+    assert!(!cors.is_preflight());
+    let headers = match cors.origin() {
+        Some(ALLOWED) => cors.allow().allow_credentials(true).expose_headers(["X-Count"]),
+        _ => CorsHeaders::new(),
+    };
+
+    headers.respond("data")
+}
+
+#[get("/public")]
+fn public() -> WithCors<&'static str> {
+    CorsHeaders::new().allow_any_origin().respond("public")
+}
+
+#[get("/dedup")]
+fn dedup() -> WithCors<&'static str> {
+    CorsHeaders::new()
+        .allow_origin(ALLOWED)
+        .allow_headers(["X-Custom", "Accept"])
+        .allow_headers(["x-custom", "ACCEPT", "Authorization"])
+        .respond("dedup")
+}
+
+fn client() -> Client {
+    Client::debug_with(routes![preflight, data, public, dedup]).unwrap()
+}
+
+#[test]
+fn preflight_allowed() {
+    let client = client();
+    let response = client.req(Method::Options, "/data")
+        .header(Header::new("Origin", ALLOWED))
+        .header(Header::new("Access-Control-Request-Method", "PUT"))
+        .header(Header::new("Access-Control-Request-Headers", "content-type, x-custom,"))
+        .dispatch();
+
+    let headers = response.headers();
+    assert_eq!(response.status(), Status::NoContent);
+    assert_eq!(headers.get_one("Access-Control-Allow-Origin"), Some(ALLOWED));
+    assert_eq!(headers.get_one("Access-Control-Allow-Methods"), Some("PUT"));
+    assert_eq!(headers.get_one("Access-Control-Allow-Headers"), Some("content-type, x-custom"));
+    assert_eq!(headers.get_one("Access-Control-Max-Age"), Some("60"));
+    assert_eq!(headers.get_one("Vary"), Some("Origin"));
+}
+
+#[test]
+fn allowed_headers_are_deduplicated() {
+    let client = client();
+    let response = client.req(Method::Options, "/data")
+        .header(Header::new("Origin", ALLOWED))
+        .header(Header::new("Access-Control-Request-Method", "PUT"))
+        .header(Header::new("Access-Control-Request-Headers", "Content-Type, content-type"))
+        .dispatch();
+
+    let headers = response.headers();
+    assert_eq!(headers.get_one("Access-Control-Allow-Headers"), Some("Content-Type"));
+
+    let response = client.get("/dedup").dispatch();
+    let allowed = response.headers().get_one("Access-Control-Allow-Headers");
+    assert_eq!(allowed, Some("X-Custom, Accept, Authorization"));
+}
+
+#[test]
+fn preflight_denied() {
+    let client = client();
+    let response = client.req(Method::Options, "/data")
+        .header(Header::new("Origin", "https://evil.example"))
+        .header(Header::new("Access-Control-Request-Method", "PUT"))
+        .dispatch();
+
+    assert_eq!(response.status(), Status::NotFound);
+    assert!(response.headers().get_one("Access-Control-Allow-Origin").is_none());
+}
+
+#[test]
+fn actual_request() {
+    let client = client();
+    let response = client.get("/data").header(Header::new("Origin", ALLOWED)).dispatch();
+    let headers = response.headers();
+    assert_eq!(headers.get_one("Access-Control-Allow-Origin"), Some(ALLOWED));
+    assert_eq!(headers.get_one("Access-Control-Allow-Credentials"), Some("true"));
+    assert_eq!(headers.get_one("Access-Control-Expose-Headers"), Some("X-Count"));
+    assert!(headers.get_one("Access-Control-Allow-Methods").is_none());
+    assert_eq!(response.into_string().unwrap(), "data");
+
+    let evil = Header::new("Origin", "https://evil.example");
+    let response = client.get("/data").header(evil).dispatch();
+    assert!(response.headers().get_one("Access-Control-Allow-Origin").is_none());
+    assert_eq!(response.into_string().unwrap(), "data");
+
+    let response = client.get("/data").dispatch();
+    assert!(response.headers().get_one("Access-Control-Allow-Origin").is_none());
+}
+
+#[test]
+fn wildcard_origin() {
+    let response = client().get("/public").header(Header::new("Origin", ALLOWED)).dispatch();
+    assert_eq!(response.headers().get_one("Access-Control-Allow-Origin"), Some("*"));
+    assert!(response.headers().get_one("Vary").is_none());
+}
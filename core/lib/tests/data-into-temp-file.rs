@@ -0,0 +1,30 @@
+#[macro_use] extern crate rocket;
+
+use rocket::data::{Data, ToByteUnit};
+use rocket::local::blocking::Client;
+
+#[post("/", data = "<data>")]
+async fn upload(data: Data<'_>) -> std::io::Result<String> {
+    let file = data.into_temp_file(8.bytes()).await?;
+    let path = file.path().expect("file is on disk").to_path_buf();
+    assert!(path.starts_with(std::env::temp_dir()));
+
+    let contents = rocket::tokio::fs::read_to_string(&path).await?;
+    let summary = format!("{}:{}:{}", file.len(), file.is_complete(), contents);
+
+    // The file is deleted when dropped.
+    drop(file);
+    assert!(!path.exists());
+    Ok(summary)
+}
+
+#[test]
+fn into_temp_file_respects_limit() {
+    let client = Client::debug_with(routes![upload]).unwrap();
+
+    let response = client.post("/").body("hello").dispatch();
+    assert_eq!(response.into_string().unwrap(), "5:true:hello");
+
+    let response = client.post("/").body("hello, world!").dispatch();
+    assert_eq!(response.into_string().unwrap(), "8:false:hello, w");
+}
@@ -0,0 +1,98 @@
+#![cfg(feature = "decompression")]
+
+#[macro_use] extern crate rocket;
+
+use rocket::{Rocket, Build, Config, Request};
+use rocket::data::{Limits, ToByteUnit};
+use rocket::decompression::Decompression;
+use rocket::http::{Header, Status};
+use rocket::local::blocking::Client;
+
+const BODY: &str = "Hello, world! Hello, world! Hello, world!";
+
+const GZIP: &[u8] = &[
+    31, 139, 8, 0, 0, 0, 0, 0, 2, 3, 243, 72, 205, 201, 201, 215, 81, 40, 207, 47, 202,
+    73, 81, 84, 240, 192, 205, 3, 0, 183, 4, 191, 32, 41, 0, 0, 0
+];
+
+const DEFLATE: &[u8] = &[
+    120, 156, 243, 72, 205, 201, 201, 215, 81, 40, 207, 47, 202, 73, 81, 84, 240, 192,
+    205, 3, 0, 36, 229, 13, 220
+];
+
+// `BODY`, deflated and then gzipped.
+const DEFLATE_GZIP: &[u8] = &[
+    31, 139, 8, 0, 0, 0, 0, 0, 2, 3, 171, 152, 243, 217, 227, 236, 201, 147, 215, 3, 53,
+    206, 235, 159, 242, 12, 12, 249, 112, 224, 44, 51, 131, 202, 83, 222, 59, 0, 52, 14,
+    183, 17, 25, 0, 0, 0
+];
+
+#[post("/", data = "<body>")]
+fn echo(body: String, req: &Request<'_>) -> String {
+    assert!(!req.headers().contains("Content-Encoding"));
+    body
+}
+
+fn rocket(limit: usize) -> Rocket<Build> {
+    let config = Config {
+        limits: Limits::default().limit("string", limit.bytes()),
+        ..Config::debug_default()
+    };
+
+    rocket::custom(config)
+        .mount("/", routes![echo])
+        .attach(Decompression::new())
+}
+
+fn post(client: &Client, encoding: &'static str, body: &[u8]) -> (Status, Option<String>) {
+    let response = client.post("/")
+        .header(Header::new("Content-Encoding", encoding))
+        .body(body)
+        .dispatch();
+
+    (response.status(), response.into_string())
+}
+
+#[test]
+fn decompresses_supported_encodings() {
+    let client = Client::debug(rocket(1024)).unwrap();
+
+    assert_eq!(post(&client, "gzip", GZIP), (Status::Ok, Some(BODY.into())));
+    assert_eq!(post(&client, "X-GZIP", GZIP), (Status::Ok, Some(BODY.into())));
+    assert_eq!(post(&client, "deflate", DEFLATE), (Status::Ok, Some(BODY.into())));
+    assert_eq!(post(&client, "deflate, gzip", DEFLATE_GZIP), (Status::Ok, Some(BODY.into())));
+    assert_eq!(post(&client, "identity", BODY.as_bytes()), (Status::Ok, Some(BODY.into())));
+
+    let response = client.post("/").body(BODY).dispatch();
+    assert_eq!(response.into_string().unwrap(), BODY);
+}
+
+#[test]
+fn rejects_unsupported_encodings() {
+    let client = Client::debug(rocket(1024)).unwrap();
+    for encoding in ["br", "br, gzip", "gzip, zstd"] {
+        let response = client.post("/")
+            .header(Header::new("Content-Encoding", encoding))
+            .body(GZIP)
+            .dispatch();
+
+        assert_eq!(response.status(), Status::UnsupportedMediaType);
+        let accept = response.headers().get_one("Accept-Encoding");
+        assert_eq!(accept, Some("gzip, deflate, identity"));
+    }
+}
+
+#[test]
+fn limits_apply_to_decompressed_data() {
+    // The compressed body fits within the limit; the decompressed body doesn't.
+    assert!(GZIP.len() < 39 && BODY.len() > 39);
+    let client = Client::debug(rocket(39)).unwrap();
+    assert_eq!(post(&client, "gzip", GZIP).0, Status::PayloadTooLarge);
+}
+
+#[test]
+fn invalid_compressed_data_fails() {
+    let client = Client::debug(rocket(1024)).unwrap();
+    let (status, _) = post(&client, "gzip", BODY.as_bytes());
+    assert_ne!(status, Status::Ok);
+}
@@ -0,0 +1,65 @@
+#[macro_use] extern crate rocket;
+
+use rocket::{Rocket, Build, Config};
+use rocket::config::ErrorFormat;
+use rocket::http::{Accept, ContentType, Status};
+use rocket::local::blocking::Client;
+
+#[get("/")]
+fn index() -> &'static str {
+    "index"
+}
+
+fn rocket(error_format: ErrorFormat) -> Rocket<Build> {
+    let config = Config { error_format, ..Config::debug_default() };
+    rocket::custom(config).mount("/", routes![index])
+}
+
+#[test]
+fn auto_format_follows_accept_header() {
+    let client = Client::debug(rocket(ErrorFormat::Auto)).unwrap();
+    let response = client.get("/missing").dispatch();
+    assert_eq!(response.status(), Status::NotFound);
+    assert_eq!(response.content_type(), Some(ContentType::HTML));
+
+    let response = client.get("/missing").header(Accept::JSON).dispatch();
+    assert_eq!(response.status(), Status::NotFound);
+    assert_eq!(response.content_type(), Some(ContentType::JSON));
+}
+
+#[test]
+fn json_format_ignores_accept_header() {
+    let client = Client::debug(rocket(ErrorFormat::Json)).unwrap();
+    for accept in [None, Some(Accept::HTML), Some(Accept::JSON)] {
+        let mut request = client.get("/missing");
+        if let Some(accept) = accept {
+            request.add_header(accept);
+        }
+
+        let response = request.dispatch();
+        assert_eq!(response.status(), Status::NotFound);
+        assert_eq!(response.content_type(), Some(ContentType::JSON));
+        assert!(response.into_string().unwrap().contains("\"code\": 404"));
+    }
+
+    let response = client.get("/").dispatch();
+    assert_eq!(response.into_string().unwrap(), "index");
+}
+
+#[test]
+fn html_format_ignores_accept_header() {
+    let client = Client::debug(rocket(ErrorFormat::Html)).unwrap();
+    let response = client.get("/missing").header(Accept::JSON).dispatch();
+    assert_eq!(response.status(), Status::NotFound);
+    assert_eq!(response.content_type(), Some(ContentType::HTML));
+}
+
+#[test]
+fn error_format_is_configurable() {
+    let figment = Config::figment().merge(("error_format", "JSON"));
+    let config = Config::from(figment);
+    assert_eq!(config.error_format, ErrorFormat::Json);
+
+    let figment = Config::figment().merge(("error_format", "xml"));
+    assert!(Config::try_from(figment).is_err());
+}
@@ -0,0 +1,57 @@
+#[macro_use] extern crate rocket;
+
+use rocket::http::{ContentType, Status};
+use rocket::local::blocking::Client;
+use rocket::response::{Either3, Either4, Redirect};
+use rocket::response::content::RawHtml;
+use rocket::response::status::NotFound;
+
+#[get("/three/<n>")]
+fn three(n: usize) -> Either3<&'static str, RawHtml<&'static str>, Redirect> {
+    match n {
+        0 => Either3::A("text"),
+        1 => Either3::B(RawHtml("<b>html</b>")),
+        _ => Either3::C(Redirect::to("/three/0")),
+    }
+}
+
+#[get("/four/<n>")]
+fn four(n: usize) -> Either4<&'static str, Vec<u8>, NotFound<String>, Status> {
+    match n {
+        0 => Either4::A("text"),
+        1 => Either4::B(vec![1, 2, 3]),
+        2 => Either4::C(NotFound("missing".into())),
+        _ => Either4::D(Status::ImATeapot),
+    }
+}
+
+#[test]
+fn either_responders_respond_with_inner() {
+    let client = Client::debug_with(routes![three, four]).unwrap();
+
+    let response = client.get("/three/0").dispatch();
+    assert_eq!(response.content_type(), Some(ContentType::Plain));
+    assert_eq!(response.into_string().unwrap(), "text");
+
+    let response = client.get("/three/1").dispatch();
+    assert_eq!(response.content_type(), Some(ContentType::HTML));
+    assert_eq!(response.into_string().unwrap(), "<b>html</b>");
+
+    let response = client.get("/three/2").dispatch();
+    assert_eq!(response.status(), Status::SeeOther);
+    assert_eq!(response.headers().get_one("Location"), Some("/three/0"));
+
+    let response = client.get("/four/0").dispatch();
+    assert_eq!(response.into_string().unwrap(), "text");
+
+    let response = client.get("/four/1").dispatch();
+    assert_eq!(response.content_type(), Some(ContentType::Binary));
+    assert_eq!(response.into_bytes().unwrap(), vec![1, 2, 3]);
+
+    let response = client.get("/four/2").dispatch();
+    assert_eq!(response.status(), Status::NotFound);
+    assert_eq!(response.into_string().unwrap(), "missing");
+
+    let response = client.get("/four/3").dispatch();
+    assert_eq!(response.status(), Status::ImATeapot);
+}
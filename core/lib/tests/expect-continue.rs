@@ -0,0 +1,100 @@
+#[macro_use] extern crate rocket;
+
+use rocket::{Rocket, Build, Config};
+use rocket::data::{Limits, ToByteUnit};
+use rocket::http::{Header, Status};
+use rocket::local::blocking::Client;
+
+#[post("/", data = "<body>")]
+fn upload(body: String) -> String {
+    body
+}
+
+fn rocket(expect_continue: bool) -> Rocket<Build> {
+    let config = Config {
+        expect_continue,
+        limits: Limits::new().limit("string", 16.bytes()),
+        ..Config::debug_default()
+    };
+
+    rocket::custom(config).mount("/", routes![upload])
+}
+
+#[test]
+fn expect_continue_is_honored() {
+    let client = Client::debug(rocket(true)).unwrap();
+
+    let response = client.post("/")
+        .header(Header::new("Expect", "100-continue"))
+        .body("hello")
+        .dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+    assert_eq!(response.into_string().unwrap(), "hello");
+
+    let response = client.post("/")
+        .header(Header::new("Expect", "100-Continue"))
+        .header(Header::new("Content-Length", "5"))
+        .body("hello")
+        .dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+}
+
+#[test]
+fn unmet_expectations_are_rejected() {
+    let client = Client::debug(rocket(true)).unwrap();
+
+    let response = client.post("/")
+        .header(Header::new("Expect", "something-else"))
+        .body("hello")
+        .dispatch();
+
+    assert_eq!(response.status(), Status::ExpectationFailed);
+
+    let response = client.post("/")
+        .header(Header::new("Expect", "100-continue"))
+        .header(Header::new("Content-Length", "1024"))
+        .dispatch();
+
+    assert_eq!(response.status(), Status::PayloadTooLarge);
+}
+
+#[test]
+fn expect_continue_is_disabled_by_default() {
+    assert!(!Config::default().expect_continue);
+
+    let config = Config {
+        limits: Limits::new().limit("string", 16.bytes()),
+        ..Config::debug_default()
+    };
+
+    let client = Client::debug(rocket::custom(config).mount("/", routes![upload])).unwrap();
+    let response = client.post("/")
+        .header(Header::new("Expect", "100-continue"))
+        .header(Header::new("Content-Length", "1024"))
+        .body("hello")
+        .dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+}
+
+#[test]
+fn expectations_are_ignored_when_disabled() {
+    let client = Client::debug(rocket(false)).unwrap();
+
+    let response = client.post("/")
+        .header(Header::new("Expect", "something-else"))
+        .body("hello")
+        .dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+
+    let response = client.post("/")
+        .header(Header::new("Expect", "100-continue"))
+        .header(Header::new("Content-Length", "1024"))
+        .body("hello")
+        .dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+}
@@ -190,3 +190,40 @@ fn test_redirection() {
     assert_eq!(response.status(), Status::PermanentRedirect);
     assert_eq!(response.headers().get("Location").next(), Some("/redir_index/other/"));
 }
+
+#[test]
+#[cfg(feature = "fs-immutable")]
+fn test_immutable_pattern() {
+    let root = static_root();
+    let rocket = rocket::build()
+        .mount("/", FileServer::from(&root).immutable_pattern(r"^other/.*\.txt$"))
+        .mount("/index", FileServer::from(&root).immutable_pattern(r"^inner/index"));
+
+    let client = Client::debug(rocket).expect("valid rocket");
+    let immutable = Some("public, max-age=31536000, immutable");
+
+    let response = client.get("/other/hello.txt").dispatch();
+    assert_eq!(response.status(), Status::Ok);
+    assert_eq!(response.headers().get_one("Cache-Control"), immutable);
+
+    let response = client.get("/inner/goodbye").dispatch();
+    assert_eq!(response.status(), Status::Ok);
+    assert!(response.headers().get_one("Cache-Control").is_none());
+
+    let response = client.get("/index/inner/").dispatch();
+    assert_eq!(response.status(), Status::Ok);
+    assert_eq!(response.headers().get_one("Cache-Control"), immutable);
+
+    let response = client.get("/index/").dispatch();
+    assert!(response.headers().get_one("Cache-Control").is_none());
+
+    let response = client.get("/other/missing.txt").dispatch();
+    assert_eq!(response.status(), Status::NotFound);
+}
+
+#[test]
+#[should_panic]
+#[cfg(feature = "fs-immutable")]
+fn test_invalid_immutable_pattern() {
+    let _ = FileServer::from(static_root()).immutable_pattern("[0-9");
+}
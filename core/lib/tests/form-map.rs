@@ -0,0 +1,60 @@
+#[macro_use] extern crate rocket;
+
+use std::collections::HashMap;
+
+use rocket::form::FormMap;
+use rocket::http::{ContentType, Status};
+use rocket::local::blocking::Client;
+
+#[post("/get", data = "<form>")]
+fn get(form: FormMap<'_>) -> String {
+    format!("{:?} {:?} {:?} {}", form.get("a"), form.get_all("a"), form.get("z"), form.len())
+}
+
+#[post("/map", data = "<form>")]
+fn map(form: FormMap<'_>) -> String {
+    let map = form.into_map();
+    let mut fields: Vec<_> = map.iter().collect();
+    fields.sort();
+    format!("{:?}", fields)
+}
+
+#[post("/multimap", data = "<form>")]
+fn multimap(form: FormMap<'_>) -> String {
+    let map: HashMap<String, Vec<String>> = form.into_multimap();
+    format!("{:?}", map.get("a"))
+}
+
+fn client() -> Client {
+    Client::debug_with(routes![get, map, multimap]).unwrap()
+}
+
+#[test]
+fn last_value_wins() {
+    let client = client();
+    let body = "a=1&b=hello+there&a=2%21";
+
+    let response = client.post("/get").header(ContentType::Form).body(body).dispatch();
+    let expected = r#"Some("2!") ["1", "2!"] None 2"#;
+    assert_eq!(response.into_string().unwrap(), expected);
+
+    let response = client.post("/map").header(ContentType::Form).body(body).dispatch();
+    let expected = r#"[("a", "2!"), ("b", "hello there")]"#;
+    assert_eq!(response.into_string().unwrap(), expected);
+}
+
+#[test]
+fn multimap_preserves_all_values() {
+    let response = client().post("/multimap")
+        .header(ContentType::Form)
+        .body("a=1&b=2&a=3&a=")
+        .dispatch();
+
+    assert_eq!(response.into_string().unwrap(), r#"Some(["1", "3", ""])"#);
+}
+
+#[test]
+fn forwards_on_non_form() {
+    let response = client().post("/get").header(ContentType::JSON).body("{}").dispatch();
+    assert_eq!(response.status(), Status::UnsupportedMediaType);
+}
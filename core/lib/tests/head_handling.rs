@@ -1,6 +1,6 @@
 #[macro_use] extern crate rocket;
 
-use rocket::http::Status;
+use rocket::http::{Status, Header, ContentType};
 use rocket::response::content::RawJson;
 
 #[get("/empty")]
@@ -13,6 +13,18 @@ fn index() -> &'static str {
     "Hello, world!"
 }
 
+#[derive(Responder)]
+#[response(content_type = "html")]
+struct Custom {
+    body: &'static str,
+    header: Header<'static>,
+}
+
+#[get("/custom")]
+fn custom() -> Custom {
+    Custom { body: "<h1>Hi!</h1>", header: Header::new("X-Custom", "value") }
+}
+
 #[head("/other")]
 fn other() -> RawJson<&'static str> {
     RawJson("{ 'hi': 'hello' }")
@@ -23,10 +35,9 @@ mod head_handling_tests {
 
     use rocket::Route;
     use rocket::local::blocking::Client;
-    use rocket::http::ContentType;
 
     fn routes() -> Vec<Route> {
-        routes![index, empty, other]
+        routes![index, empty, other, custom]
     }
 
     #[test]
@@ -56,4 +67,28 @@ mod head_handling_tests {
         assert_eq!(response.body().preset_size(), Some(17));
         assert!(response.into_bytes().unwrap().is_empty());
     }
+
+    #[test]
+    fn auto_head_preserves_get_headers() {
+        let client = Client::debug_with(routes()).unwrap();
+        let get = client.get("/custom").dispatch();
+        let head = client.head("/custom").dispatch();
+
+        assert_eq!(head.status(), get.status());
+        assert_eq!(head.content_type(), Some(ContentType::HTML));
+        assert_eq!(head.headers().get_one("X-Custom"), Some("value"));
+        assert_eq!(head.headers().get_one("Content-Length"), Some("12"));
+        assert_eq!(get.headers().get_one("Content-Length"), Some("12"));
+        assert!(head.into_bytes().unwrap().is_empty());
+    }
+
+    #[test]
+    fn auto_head_content_length() {
+        let client = Client::debug_with(routes()).unwrap();
+        let response = client.head("/").dispatch();
+        assert_eq!(response.headers().get_one("Content-Length"), Some("13"));
+
+        let response = client.head("/other").dispatch();
+        assert_eq!(response.headers().get_one("Content-Length"), Some("17"));
+    }
 }
@@ -0,0 +1,172 @@
+#[macro_use] extern crate rocket;
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use rocket::State;
+use rocket::http::{Header, Status};
+use rocket::idempotency::{Idempotency, Store};
+use rocket::local::blocking::Client;
+
+#[derive(Default)]
+struct Counter(AtomicUsize);
+
+#[post("/charge")]
+fn charge(counter: &State<Counter>) -> String {
+    format!("charge #{}", counter.0.fetch_add(1, Ordering::SeqCst))
+}
+
+#[post("/refund")]
+fn refund(counter: &State<Counter>) -> String {
+    format!("refund #{}", counter.0.fetch_add(1, Ordering::SeqCst))
+}
+
+#[post("/fail")]
+fn fail(counter: &State<Counter>) -> Status {
+    counter.0.fetch_add(1, Ordering::SeqCst);
+    Status::BadRequest
+}
+
+#[post("/item/<id>")]
+fn item_by_id(id: usize, counter: &State<Counter>) -> String {
+    format!("id {} #{}", id, counter.0.fetch_add(1, Ordering::SeqCst))
+}
+
+#[post("/item/<name>", rank = 2)]
+fn item_by_name(name: &str, counter: &State<Counter>) -> String {
+    format!("name {} #{}", name, counter.0.fetch_add(1, Ordering::SeqCst))
+}
+
+#[put("/charge")]
+fn put_charge(counter: &State<Counter>) -> String {
+    format!("put #{}", counter.0.fetch_add(1, Ordering::SeqCst))
+}
+
+fn client_with(fairing: Idempotency) -> Client {
+    let rocket = rocket::build()
+        .manage(Counter::default())
+        .mount("/", routes![charge, refund, fail, put_charge, item_by_id, item_by_name])
+        .attach(fairing);
+
+    Client::debug(rocket).unwrap()
+}
+
+fn client() -> Client {
+    client_with(Idempotency::new())
+}
+
+fn key(value: &'static str) -> Header<'static> {
+    Header::new(Idempotency::HEADER, value)
+}
+
+#[test]
+fn replays_cached_response() {
+    let client = client();
+    let response = client.post("/charge").header(key("abc")).dispatch();
+    assert_eq!(response.status(), Status::Ok);
+    assert!(response.headers().get_one("Idempotent-Replayed").is_none());
+    assert_eq!(response.into_string().unwrap(), "charge #0");
+
+    let response = client.post("/charge").header(key("abc")).dispatch();
+    assert_eq!(response.status(), Status::Ok);
+    assert_eq!(response.headers().get_one("Idempotent-Replayed"), Some("true"));
+    assert_eq!(response.content_type(), Some(rocket::http::ContentType::Plain));
+    assert_eq!(response.into_string().unwrap(), "charge #0");
+
+    let response = client.post("/charge").header(key("def")).dispatch();
+    assert_eq!(response.into_string().unwrap(), "charge #1");
+
+    let response = client.post("/charge").dispatch();
+    assert_eq!(response.into_string().unwrap(), "charge #2");
+    assert_eq!(client.rocket().state::<Store>().unwrap().len(), 2);
+}
+
+#[test]
+fn keyed_per_route() {
+    let client = client();
+    let response = client.post("/charge").header(key("abc")).dispatch();
+    assert_eq!(response.into_string().unwrap(), "charge #0");
+
+    let response = client.post("/refund").header(key("abc")).dispatch();
+    assert_eq!(response.into_string().unwrap(), "refund #1");
+
+    let response = client.post("/refund").header(key("abc")).dispatch();
+    assert_eq!(response.into_string().unwrap(), "refund #1");
+}
+
+#[test]
+fn does_not_cache_errors_or_idempotent_methods() {
+    let client = client();
+    for _ in 0..2 {
+        let response = client.post("/fail").header(key("abc")).dispatch();
+        assert_eq!(response.status(), Status::BadRequest);
+    }
+
+    for i in 2..4 {
+        let response = client.put("/charge").header(key("abc")).dispatch();
+        assert_eq!(response.into_string().unwrap(), format!("put #{}", i));
+    }
+
+    let counter = client.rocket().state::<Counter>().unwrap();
+    assert_eq!(counter.0.load(Ordering::SeqCst), 4);
+
+    let response = client.post("/missing").header(key("abc")).dispatch();
+    assert_eq!(response.status(), Status::NotFound);
+    assert!(client.rocket().state::<Store>().unwrap().is_empty());
+}
+
+#[test]
+fn mounts_no_routes() {
+    let client = client();
+    assert_eq!(client.rocket().routes().count(), 6);
+
+    // Replays bypass routing, so guards of the original route don't run again.
+    client.post("/charge").header(key("abc")).dispatch();
+    let response = client.post("/charge").header(key("abc")).dispatch();
+    assert_eq!(response.headers().get_one("Idempotent-Replayed"), Some("true"));
+    assert_eq!(client.rocket().state::<Counter>().unwrap().0.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn keyed_on_handling_route() {
+    let client = client();
+
+    // `item_by_id` forwards to `item_by_name`, which handles the request.
+    let response = client.post("/item/abc").header(key("k")).dispatch();
+    assert_eq!(response.into_string().unwrap(), "name abc #0");
+
+    let response = client.post("/item/abc").header(key("k")).dispatch();
+    assert_eq!(response.headers().get_one("Idempotent-Replayed"), Some("true"));
+    assert_eq!(response.into_string().unwrap(), "name abc #0");
+
+    // `item_by_id` handles this request: nothing is replayed for it.
+    let response = client.post("/item/7").header(key("k")).dispatch();
+    assert!(response.headers().get_one("Idempotent-Replayed").is_none());
+    assert_eq!(response.into_string().unwrap(), "id 7 #1");
+
+    let response = client.post("/item/7").header(key("k")).dispatch();
+    assert_eq!(response.into_string().unwrap(), "id 7 #1");
+}
+
+#[test]
+fn capacity_is_bounded() {
+    let client = client_with(Idempotency::new().capacity(2));
+    let store = client.rocket().state::<Store>().unwrap();
+    assert_eq!(store.capacity(), 2);
+
+    for k in ["a", "b", "c"] {
+        client.post("/charge").header(key(k)).dispatch();
+    }
+
+    // The response for "a" expires soonest and was evicted for "c".
+    assert_eq!(store.len(), 2);
+    let response = client.post("/charge").header(key("c")).dispatch();
+    assert_eq!(response.into_string().unwrap(), "charge #2");
+    let response = client.post("/charge").header(key("a")).dispatch();
+    assert!(response.headers().get_one("Idempotent-Replayed").is_none());
+    assert_eq!(response.into_string().unwrap(), "charge #3");
+    assert_eq!(store.len(), 2);
+
+    let client = client_with(Idempotency::new().capacity(0));
+    client.post("/charge").header(key("a")).dispatch();
+    assert!(client.rocket().state::<Store>().unwrap().is_empty());
+}
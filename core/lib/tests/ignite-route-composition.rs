@@ -0,0 +1,79 @@
+#[macro_use] extern crate rocket;
+
+use rocket::{Rocket, Build, Config};
+use rocket::fairing::AdHoc;
+use rocket::figment::Figment;
+use rocket::http::Status;
+use rocket::local::blocking::Client;
+
+#[get("/")]
+fn index() -> &'static str {
+    "index"
+}
+
+#[get("/debug")]
+fn debug() -> &'static str {
+    "debug"
+}
+
+#[get("/admin")]
+fn admin() -> &'static str {
+    "admin"
+}
+
+#[get("/<_..>", rank = 20)]
+fn other() -> &'static str {
+    "other"
+}
+
+fn rocket(admin: bool, debug: bool) -> Rocket<Build> {
+    let figment = Figment::from(Config::debug_default())
+        .merge(("admin", admin))
+        .merge(("debug", debug));
+
+    rocket::custom(figment)
+        .mount("/", routes![index, debug])
+        .attach(AdHoc::on_ignite("Route Flags", |rocket| async {
+            let admin = rocket.figment().extract_inner("admin").unwrap_or(false);
+            let debug = rocket.figment().extract_inner("debug").unwrap_or(false);
+
+            let rocket = match admin {
+                true => rocket.mount("/", routes![self::admin]),
+                false => rocket,
+            };
+
+            match debug {
+                true => rocket,
+                false => rocket.retain_routes(|r| r.name.as_deref() != Some("debug")),
+            }
+        }))
+}
+
+#[test]
+fn ignite_fairings_compose_routes() {
+    let client = Client::debug(rocket(true, true)).unwrap();
+    assert_eq!(client.rocket().routes().count(), 3);
+    assert_eq!(client.get("/admin").dispatch().into_string().unwrap(), "admin");
+    assert_eq!(client.get("/debug").dispatch().into_string().unwrap(), "debug");
+
+    let client = Client::debug(rocket(false, false)).unwrap();
+    assert_eq!(client.rocket().routes().count(), 1);
+    assert_eq!(client.get("/").dispatch().into_string().unwrap(), "index");
+    assert_eq!(client.get("/admin").dispatch().status(), Status::NotFound);
+    assert_eq!(client.get("/debug").dispatch().status(), Status::NotFound);
+}
+
+#[test]
+fn retained_routes_are_rebuilt_into_router() {
+    // `other` would never be reached for `/debug` if `debug` was retained.
+    let client = Client::debug(rocket(false, false).mount("/", routes![other])).unwrap();
+    assert_eq!(client.get("/debug").dispatch().into_string().unwrap(), "other");
+
+    let client = Client::debug(rocket(false, true).mount("/", routes![other])).unwrap();
+    assert_eq!(client.get("/debug").dispatch().into_string().unwrap(), "debug");
+}
+
+#[test]
+fn routes_added_in_ignite_are_checked_for_collisions() {
+    assert!(Client::debug(rocket(true, true).mount("/", routes![admin])).is_err());
+}
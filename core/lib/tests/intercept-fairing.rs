@@ -0,0 +1,74 @@
+#[macro_use] extern crate rocket;
+
+use rocket::{Request, Response, Rocket, Build};
+use rocket::fairing::{AdHoc, Fairing, Info, Kind};
+use rocket::http::{Header, Method, Status};
+use rocket::local::blocking::Client;
+
+#[get("/")]
+fn index() -> &'static str {
+    "index"
+}
+
+struct Maintenance;
+
+#[rocket::async_trait]
+impl Fairing for Maintenance {
+    fn info(&self) -> Info {
+        Info { name: "Maintenance", kind: Kind::Intercept }
+    }
+
+    async fn on_intercept<'r>(&self, req: &'r Request<'_>) -> Option<Response<'r>> {
+        if !req.headers().contains("X-Maintenance") {
+            return None;
+        }
+
+        let body = "down for maintenance";
+        Some(Response::build()
+            .status(Status::ServiceUnavailable)
+            .sized_body(body.len(), std::io::Cursor::new(body))
+            .finalize())
+    }
+}
+
+fn rocket() -> Rocket<Build> {
+    rocket::build()
+        .mount("/", routes![index])
+        .attach(AdHoc::on_request("Rewrite", |req, _| Box::pin(async move {
+            if req.headers().contains("X-Down") {
+                req.add_header(Header::new("X-Maintenance", "true"));
+            }
+        })))
+        .attach(Maintenance)
+        .attach(AdHoc::on_intercept("Never", |req| Box::pin(async move {
+            assert!(!req.headers().contains("X-Maintenance"), "not short-circuited");
+            None
+        })))
+        .attach(AdHoc::on_response("Marker", |_, res| Box::pin(async move {
+            res.set_raw_header("X-Marker", "seen");
+        })))
+}
+
+#[test]
+fn intercept_short_circuits_routing() {
+    let client = Client::debug(rocket()).unwrap();
+
+    let response = client.get("/").header(Header::new("X-Down", "1")).dispatch();
+    assert_eq!(response.status(), Status::ServiceUnavailable);
+    assert_eq!(response.headers().get_one("X-Marker"), Some("seen"));
+    assert_eq!(response.headers().get_one("Server"), Some("Rocket"));
+    assert_eq!(response.into_string().unwrap(), "down for maintenance");
+
+    let response = client.req(Method::Head, "/").header(Header::new("X-Down", "1")).dispatch();
+    assert_eq!(response.status(), Status::ServiceUnavailable);
+    assert!(response.into_string().unwrap_or_default().is_empty());
+}
+
+#[test]
+fn no_intercept_routes_normally() {
+    let client = Client::debug(rocket()).unwrap();
+    let response = client.get("/").dispatch();
+    assert_eq!(response.status(), Status::Ok);
+    assert_eq!(response.headers().get_one("X-Marker"), Some("seen"));
+    assert_eq!(response.into_string().unwrap(), "index");
+}
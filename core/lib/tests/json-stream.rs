@@ -0,0 +1,64 @@
+#![cfg(feature = "json")]
+
+#[macro_use] extern crate rocket;
+
+use rocket::http::ContentType;
+use rocket::local::blocking::Client;
+use rocket::serde::{Serialize, Serializer, ser::Error};
+use rocket::serde::json::JsonStream;
+use rocket::futures::stream;
+
+#[get("/empty")]
+fn empty() -> JsonStream![u8] {
+    JsonStream(stream::empty())
+}
+
+#[get("/strings")]
+fn strings() -> JsonStream![&'static str] {
+    JsonStream(stream::iter(vec!["a", "b,c", "[d]"]))
+}
+
+#[get("/many/<n>")]
+fn many(n: usize) -> JsonStream![Vec<usize>] {
+    JsonStream! {
+        for i in 0..n {
+            yield vec![i; i % 4];
+        }
+    }
+}
+
+struct Fails(bool);
+
+impl Serialize for Fails {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.0 {
+            true => Err(S::Error::custom("failed to serialize")),
+            false => serializer.serialize_bool(true),
+        }
+    }
+}
+
+#[get("/unserializable")]
+fn unserializable() -> JsonStream![Fails] {
+    JsonStream(stream::iter(vec![Fails(true), Fails(false), Fails(true), Fails(false)]))
+}
+
+#[test]
+fn json_stream_is_valid_json() {
+    let client = Client::debug_with(routes![empty, strings, many, unserializable]).unwrap();
+
+    let response = client.get("/empty").dispatch();
+    assert_eq!(response.content_type(), Some(ContentType::JSON));
+    assert_eq!(response.into_string().unwrap(), "[]");
+
+    let response = client.get("/strings").dispatch();
+    assert_eq!(response.into_json::<Vec<String>>().unwrap(), ["a", "b,c", "[d]"]);
+
+    let response = client.get("/many/1000").dispatch();
+    let values = response.into_json::<Vec<Vec<usize>>>().unwrap();
+    assert_eq!(values.len(), 1000);
+    assert!(values.iter().enumerate().all(|(i, v)| v.len() == i % 4));
+
+    let response = client.get("/unserializable").dispatch();
+    assert_eq!(response.into_string().unwrap(), "[true,true]");
+}
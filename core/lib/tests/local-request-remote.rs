@@ -0,0 +1,62 @@
+#[macro_use] extern crate rocket;
+
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+
+use rocket::local::blocking::Client;
+
+#[get("/")]
+fn client_ip(ip: Option<IpAddr>) -> String {
+    ip.map(|ip| ip.to_string()).unwrap_or_else(|| "<none>".into())
+}
+
+#[get("/remote")]
+fn remote(req: &rocket::Request<'_>) -> String {
+    req.remote().map(|e| e.to_string()).unwrap_or_else(|| "<none>".into())
+}
+
+#[test]
+fn local_requests_have_no_remote_by_default() {
+    let client = Client::debug_with(routes![client_ip, remote]).unwrap();
+    assert_eq!(client.get("/").dispatch().into_string().unwrap(), "<none>");
+    assert_eq!(client.get("/remote").dispatch().into_string().unwrap(), "<none>");
+}
+
+#[test]
+fn local_requests_can_spoof_remote() {
+    let client = Client::debug_with(routes![client_ip, remote]).unwrap();
+
+    let response = client.get("/").remote("1.2.3.4:5678").dispatch();
+    assert_eq!(response.into_string().unwrap(), "1.2.3.4");
+
+    let response = client.get("/remote").remote("1.2.3.4:5678").dispatch();
+    assert_eq!(response.into_string().unwrap(), "http://1.2.3.4:5678");
+
+    let response = client.get("/").remote("[::1]:80").dispatch();
+    assert_eq!(response.into_string().unwrap(), "::1");
+
+    let addr = SocketAddr::new(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1).into(), 443);
+    let response = client.get("/").remote(addr).dispatch();
+    assert_eq!(response.into_string().unwrap(), "2001:db8::1");
+
+    let addr = SocketAddr::new(Ipv4Addr::new(10, 0, 0, 1).into(), 80);
+    let response = client.get("/").remote(addr).dispatch();
+    assert_eq!(response.into_string().unwrap(), "10.0.0.1");
+}
+
+#[test]
+fn invalid_remotes_are_ignored() {
+    let client = Client::debug_with(routes![client_ip]).unwrap();
+    let response = client.get("/").remote("not an address").dispatch();
+    assert_eq!(response.into_string().unwrap(), "<none>");
+}
+
+#[test]
+fn ip_header_overrides_spoofed_remote() {
+    let client = Client::debug_with(routes![client_ip]).unwrap();
+    let response = client.get("/")
+        .remote("1.2.3.4:5678")
+        .header(rocket::http::Header::new("X-Real-IP", "8.8.8.8"))
+        .dispatch();
+
+    assert_eq!(response.into_string().unwrap(), "8.8.8.8");
+}
@@ -0,0 +1,45 @@
+#[macro_use] extern crate rocket;
+
+use rocket::http::{Cookie, CookieJar};
+use rocket::local::blocking::Client;
+
+#[get("/set")]
+fn set(jar: &CookieJar<'_>) {
+    jar.add(("session", "abc123"));
+    jar.add(Cookie::build(("theme", "dark")).path("/settings").http_only(true));
+}
+
+#[get("/remove")]
+fn remove(jar: &CookieJar<'_>) {
+    jar.remove("session");
+}
+
+#[test]
+fn local_response_exposes_set_cookies() {
+    let client = Client::debug_with(routes![set, remove]).unwrap();
+    let response = client.get("/set").dispatch();
+
+    let jar = response.cookies();
+    assert_eq!(jar.iter().count(), 2);
+    assert_eq!(jar.get("session").map(|c| c.value()), Some("abc123"));
+
+    let theme = jar.get("theme").unwrap();
+    assert_eq!(theme.value(), "dark");
+    assert_eq!(theme.path(), Some("/settings"));
+    assert_eq!(theme.http_only(), Some(true));
+
+    let set_cookies = response.headers().get("Set-Cookie").count();
+    assert_eq!(set_cookies, jar.iter().count());
+}
+
+#[test]
+fn local_response_exposes_removed_cookies() {
+    let client = Client::debug_with(routes![set, remove]).unwrap();
+    client.get("/set").dispatch();
+
+    let response = client.get("/remove").dispatch();
+    let removed = response.cookies().get("session").unwrap();
+    assert_eq!(removed.value(), "");
+    assert_eq!(removed.max_age(), Some(rocket::time::Duration::ZERO));
+    assert!(response.cookies().get("theme").is_none());
+}
@@ -0,0 +1,111 @@
+#[macro_use] extern crate rocket;
+
+use std::path::PathBuf;
+use std::time::Duration;
+
+use rocket::{Build, Config, Rocket};
+use rocket::http::Status;
+use rocket::local::blocking::Client;
+use rocket::fairing::Maintenance;
+
+#[get("/")]
+fn index() -> &'static str {
+    "index"
+}
+
+#[get("/healthz/<_..>")]
+fn health() -> &'static str {
+    "ok"
+}
+
+#[catch(503)]
+fn unavailable() -> &'static str {
+    "be right back"
+}
+
+fn rocket(maintenance: bool, fairing: Maintenance) -> Rocket<Build> {
+    let figment = Config::figment().merge(("maintenance", maintenance));
+    rocket::custom(figment)
+        .mount("/", routes![index, health])
+        .register("/", catchers![unavailable])
+        .attach(fairing)
+}
+
+#[test]
+fn disabled_by_default() {
+    let client = Client::debug(rocket::build()
+        .mount("/", routes![index])
+        .attach(Maintenance::new())).unwrap();
+
+    let response = client.get("/").dispatch();
+    assert_eq!(response.status(), Status::Ok);
+    assert!(response.headers().get_one("Retry-After").is_none());
+}
+
+#[test]
+fn config_flag_enables_maintenance() {
+    let client = Client::debug(rocket(true, Maintenance::new().allow("/healthz"))).unwrap();
+
+    let response = client.get("/").dispatch();
+    assert_eq!(response.status(), Status::ServiceUnavailable);
+    assert_eq!(response.headers().get_one("Retry-After"), Some("60"));
+    assert_eq!(response.into_string().unwrap(), "be right back");
+
+    let response = client.get("/healthz").dispatch();
+    assert_eq!(response.status(), Status::Ok);
+
+    let response = client.get("/healthz/db").dispatch();
+    assert_eq!(response.status(), Status::Ok);
+
+    let response = client.get("/healthzz").dispatch();
+    assert_eq!(response.status(), Status::ServiceUnavailable);
+}
+
+#[test]
+fn sentinel_file_toggles_maintenance() {
+    let sentinel: PathBuf = std::env::temp_dir()
+        .join(format!("rocket-maintenance-{}", std::process::id()));
+
+    let _ = std::fs::remove_file(&sentinel);
+    let fairing = Maintenance::new()
+        .sentinel(&sentinel)
+        .sentinel_ttl(Duration::ZERO)
+        .retry_after(Duration::from_secs(300));
+
+    let client = Client::debug(rocket(false, fairing)).unwrap();
+    assert_eq!(client.get("/").dispatch().status(), Status::Ok);
+
+    std::fs::write(&sentinel, b"").unwrap();
+    let response = client.get("/").dispatch();
+    assert_eq!(response.status(), Status::ServiceUnavailable);
+    assert_eq!(response.headers().get_one("Retry-After"), Some("300"));
+
+    std::fs::remove_file(&sentinel).unwrap();
+    assert_eq!(client.get("/").dispatch().status(), Status::Ok);
+}
+
+#[test]
+fn sentinel_check_is_cached() {
+    let sentinel: PathBuf = std::env::temp_dir()
+        .join(format!("rocket-maintenance-cached-{}", std::process::id()));
+
+    let _ = std::fs::remove_file(&sentinel);
+    let fairing = Maintenance::new()
+        .sentinel(&sentinel)
+        .sentinel_ttl(Duration::from_secs(3600));
+
+    let client = Client::debug(rocket(false, fairing)).unwrap();
+    assert_eq!(client.get("/").dispatch().status(), Status::Ok);
+
+    // The cached result is reused until the TTL elapses.
+    std::fs::write(&sentinel, b"").unwrap();
+    assert_eq!(client.get("/").dispatch().status(), Status::Ok);
+    std::fs::remove_file(&sentinel).unwrap();
+}
+
+#[test]
+fn invalid_config_fails_ignition() {
+    let figment = Config::figment().merge(("maintenance", "sometimes"));
+    let rocket = rocket::custom(figment).attach(Maintenance::new());
+    assert!(Client::debug(rocket).is_err());
+}
@@ -0,0 +1,58 @@
+#[macro_use] extern crate rocket;
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+use rocket::State;
+use rocket::state::Lazy;
+use rocket::local::asynchronous::Client;
+
+struct Expensive(usize);
+
+#[get("/")]
+async fn index(lazy: &State<Lazy<Expensive>>) -> String {
+    lazy.get().await.0.to_string()
+}
+
+#[get("/peek")]
+fn peek(lazy: &State<Lazy<Expensive>>) -> &'static str {
+    match lazy.try_get() {
+        Some(_) => "initialized",
+        None => "uninitialized",
+    }
+}
+
+#[rocket::async_test]
+async fn lazy_state_initializes_exactly_once() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let counter = calls.clone();
+    let rocket = rocket::build()
+        .mount("/", routes![index, peek])
+        .manage_lazy(move || {
+            let counter = counter.clone();
+            async move {
+                rocket::tokio::time::sleep(Duration::from_millis(50)).await;
+                Expensive(counter.fetch_add(1, Ordering::SeqCst) + 100)
+            }
+        });
+
+    let client = Client::debug(rocket).await.unwrap();
+    assert_eq!(client.get("/peek").dispatch().await.into_string().await.unwrap(),
+        "uninitialized");
+    assert_eq!(calls.load(Ordering::SeqCst), 0);
+
+    let (a, b, c) = rocket::futures::join!(
+        client.get("/").dispatch(),
+        client.get("/").dispatch(),
+        client.get("/").dispatch(),
+    );
+
+    for response in [a, b, c] {
+        assert_eq!(response.into_string().await.unwrap(), "100");
+    }
+
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+    assert_eq!(client.get("/peek").dispatch().await.into_string().await.unwrap(),
+        "initialized");
+}
@@ -0,0 +1,40 @@
+#[macro_use] extern crate rocket;
+
+use rocket::request::MatrixParams;
+use rocket::local::blocking::Client;
+
+#[get("/cars/<model>")]
+fn cars(model: &str, params: MatrixParams<'_>) -> String {
+    let color = params.get("color").unwrap_or("any");
+    let year = params.get_in(1, "year").unwrap_or("any");
+    format!("{} {} {}", model, color, year)
+}
+
+#[get("/plain")]
+fn plain(params: MatrixParams<'_>) -> String {
+    params.is_empty().to_string()
+}
+
+#[test]
+fn matrix_params_are_parsed() {
+    let client = Client::debug_with(routes![cars, plain]).unwrap();
+
+    let response = client.get("/cars/models;color=red;year=2020").dispatch();
+    assert_eq!(response.into_string().unwrap(), "models;color=red;year=2020 red 2020");
+
+    let response = client.get("/cars/models;color=dark%20blue").dispatch();
+    assert_eq!(response.into_string().unwrap(), "models;color=dark blue dark blue any");
+
+    let response = client.get("/cars/models").dispatch();
+    assert_eq!(response.into_string().unwrap(), "models any any");
+
+    let response = client.get("/plain").dispatch();
+    assert_eq!(response.into_string().unwrap(), "true");
+}
+
+#[test]
+fn matrix_params_do_not_affect_routing() {
+    let client = Client::debug_with(routes![cars, plain]).unwrap();
+    let response = client.get("/plain;x=1").dispatch();
+    assert_eq!(response.status(), rocket::http::Status::NotFound);
+}
@@ -0,0 +1,52 @@
+#[macro_use] extern crate rocket;
+
+use std::time::Duration;
+
+use rocket::{Config, Build, Rocket};
+use rocket::http::Status;
+use rocket::local::asynchronous::Client;
+
+#[get("/")]
+async fn slow() -> &'static str {
+    rocket::tokio::time::sleep(Duration::from_millis(250)).await;
+    "done"
+}
+
+fn rocket(max_concurrent_requests: usize) -> Rocket<Build> {
+    let config = Config { max_concurrent_requests, ..Config::debug_default() };
+    rocket::custom(config).mount("/", routes![slow])
+}
+
+#[rocket::async_test]
+async fn excess_concurrent_requests_are_rejected() {
+    let client = Client::debug(rocket(1)).await.unwrap();
+    let (a, b) = rocket::futures::join!(
+        client.get("/").dispatch(),
+        client.get("/").dispatch(),
+    );
+
+    let mut statuses = vec![a.status(), b.status()];
+    statuses.sort_by_key(|s| s.code);
+    assert_eq!(statuses, vec![Status::Ok, Status::ServiceUnavailable]);
+
+    let rejected = if a.status() == Status::Ok { b } else { a };
+    assert_eq!(rejected.headers().get_one("Retry-After"), Some("1"));
+
+    // Once the in-flight request completes, new requests are accepted.
+    let response = client.get("/").dispatch().await;
+    assert_eq!(response.status(), Status::Ok);
+}
+
+#[rocket::async_test]
+async fn unlimited_by_default() {
+    let client = Client::debug(rocket(0)).await.unwrap();
+    let (a, b, c) = rocket::futures::join!(
+        client.get("/").dispatch(),
+        client.get("/").dispatch(),
+        client.get("/").dispatch(),
+    );
+
+    assert_eq!(a.status(), Status::Ok);
+    assert_eq!(b.status(), Status::Ok);
+    assert_eq!(c.status(), Status::Ok);
+}
@@ -0,0 +1,49 @@
+#[macro_use] extern crate rocket;
+
+use rocket::{Config, Build, Rocket};
+use rocket::http::Status;
+use rocket::local::blocking::Client;
+
+#[get("/<path..>")]
+fn echo(path: std::path::PathBuf) -> String {
+    path.display().to_string()
+}
+
+fn rocket(max_uri_length: usize) -> Rocket<Build> {
+    let config = Config { max_uri_length, ..Config::debug_default() };
+    rocket::custom(config).mount("/", routes![echo])
+}
+
+#[test]
+fn long_uris_are_rejected() {
+    let client = Client::debug(rocket(16)).unwrap();
+
+    let response = client.get("/0123456789abcde").dispatch();
+    assert_eq!(response.status(), Status::Ok);
+    assert_eq!(response.into_string().unwrap(), "0123456789abcde");
+
+    let response = client.get("/0123456789abcdef").dispatch();
+    assert_eq!(response.status(), Status::UriTooLong);
+
+    let response = client.get("/0123456789?abcdef").dispatch();
+    assert_eq!(response.status(), Status::UriTooLong);
+}
+
+#[test]
+fn zero_disables_limit() {
+    let client = Client::debug(rocket(0)).unwrap();
+    let path = format!("/{}", "a".repeat(16 * 1024));
+    assert_eq!(client.get(path).dispatch().status(), Status::Ok);
+}
+
+#[test]
+fn default_limit_is_8kib() {
+    assert_eq!(Config::default().max_uri_length, 8192);
+
+    let client = Client::debug(rocket::build().mount("/", routes![echo])).unwrap();
+    let path = format!("/{}", "a".repeat(8191));
+    assert_eq!(client.get(path).dispatch().status(), Status::Ok);
+
+    let path = format!("/{}", "a".repeat(8192));
+    assert_eq!(client.get(path).dispatch().status(), Status::UriTooLong);
+}
@@ -0,0 +1,29 @@
+use rocket::Config;
+use rocket::data::{Limits, ToByteUnit};
+use rocket::figment::providers::{Format, Toml};
+
+#[rocket::async_test]
+async fn merge_config_overrides_only_merged_values() {
+    let rocket = rocket::custom(Config::debug_default())
+        .merge_config((Config::KEEP_ALIVE, 1234))
+        .merge_config(Toml::string("max_blocking = 77"))
+        .ignite().await
+        .unwrap();
+
+    assert_eq!(rocket.config().keep_alive, 1234);
+    assert_eq!(rocket.config().max_blocking, 77);
+    assert_eq!(rocket.config().ident, Config::debug_default().ident);
+}
+
+#[rocket::async_test]
+async fn merge_config_later_values_win() {
+    let rocket = rocket::custom(Config::debug_default())
+        .merge_config((Config::LIMITS, Limits::new().limit("json", 1.mebibytes())))
+        .merge_config((Config::KEEP_ALIVE, 1))
+        .merge_config((Config::KEEP_ALIVE, 2))
+        .ignite().await
+        .unwrap();
+
+    assert_eq!(rocket.config().keep_alive, 2);
+    assert_eq!(rocket.config().limits.get("json"), Some(1.mebibytes()));
+}
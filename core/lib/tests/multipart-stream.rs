@@ -0,0 +1,142 @@
+#[macro_use] extern crate rocket;
+
+use rocket::form::MultipartStream;
+use rocket::futures::TryStreamExt;
+use rocket::data::{Limits, ToByteUnit};
+use rocket::http::{ContentType, Status};
+use rocket::local::blocking::Client;
+use rocket::tokio::io::AsyncReadExt;
+
+#[post("/", data = "<parts>")]
+async fn parts(mut parts: MultipartStream<'_>) -> Result<String, String> {
+    let mut summary = vec![];
+    while let Some(mut part) = parts.try_next().await.map_err(|e| e.to_string())? {
+        let mut body = String::new();
+        part.read_to_string(&mut body).await.map_err(|e| e.to_string())?;
+        summary.push(format!("{}|{}|{}|{}|{}",
+            part.name().unwrap_or("-"),
+            part.file_name().unwrap_or("-"),
+            part.content_type().map(|ct| ct.to_string()).unwrap_or_default(),
+            part.headers().get_one("X-Extra").unwrap_or("-"),
+            body));
+    }
+
+    Ok(summary.join("\n"))
+}
+
+#[post("/prefix", data = "<parts>")]
+async fn prefix(mut parts: MultipartStream<'_>) -> Result<String, (Status, String)> {
+    let mut prefixes = vec![];
+    while let Some(part) = parts.try_next().await.map_err(|e| (e.status(), e.to_string()))? {
+        let mut prefix = String::new();
+        let mut reader = part.take(3);
+        reader.read_to_string(&mut prefix).await
+            .map_err(|e| (Status::BadRequest, e.to_string()))?;
+
+        // Dropping the partially read part discards the rest of its data.
+        prefixes.push(prefix);
+    }
+
+    Ok(prefixes.join(","))
+}
+
+fn form_data() -> ContentType {
+    "multipart/form-data; boundary=X-BOUNDARY".parse().unwrap()
+}
+
+#[test]
+fn streams_parts_in_order() {
+    let body = &[
+        "--X-BOUNDARY",
+        r#"Content-Disposition: form-data; name="title""#,
+        "X-Extra: yes",
+        "",
+        "hello",
+        "--X-BOUNDARY",
+        r#"Content-Disposition: form-data; name="file"; filename="foo.txt""#,
+        "Content-Type: text/plain",
+        "",
+        "line one\r\nline two",
+        "--X-BOUNDARY--",
+        "",
+    ].join("\r\n");
+
+    let client = Client::debug_with(routes![parts]).unwrap();
+    let response = client.post("/").header(form_data()).body(body).dispatch();
+    assert_eq!(response.status(), Status::Ok);
+    assert_eq!(response.into_string().unwrap(), [
+        "title|-||yes|hello",
+        "file|foo.txt|text/plain|-|line one\r\nline two",
+    ].join("\n"));
+}
+
+#[test]
+fn skips_partially_read_parts() {
+    let body = &[
+        "--X-BOUNDARY",
+        r#"Content-Disposition: form-data; name="title""#,
+        "",
+        "hello",
+        "--X-BOUNDARY",
+        r#"Content-Disposition: form-data; name="file"; filename="foo.txt""#,
+        "",
+        &"line one\r\n".repeat(1024),
+        "--X-BOUNDARY",
+        r#"Content-Disposition: form-data; name="empty""#,
+        "",
+        "",
+        "--X-BOUNDARY--",
+        "",
+    ].join("\r\n");
+
+    let client = Client::debug_with(routes![prefix]).unwrap();
+    let response = client.post("/prefix").header(form_data()).body(body).dispatch();
+    assert_eq!(response.status(), Status::Ok);
+    assert_eq!(response.into_string().unwrap(), "hel,lin,");
+}
+
+#[test]
+fn data_form_limit_is_enforced() {
+    let body = &[
+        "--X-BOUNDARY",
+        r#"Content-Disposition: form-data; name="title""#,
+        "",
+        "hello",
+        "--X-BOUNDARY",
+        r#"Content-Disposition: form-data; name="file"; filename="foo.txt""#,
+        "",
+        &"x".repeat(1024),
+        "--X-BOUNDARY--",
+        "",
+    ].join("\r\n");
+
+    let config = rocket::Config {
+        limits: Limits::default().limit("data-form", 256.bytes()),
+        ..rocket::Config::debug_default()
+    };
+
+    let rocket = rocket::custom(config).mount("/", routes![parts]);
+    let client = Client::debug(rocket).unwrap();
+    let response = client.post("/").header(form_data()).body(body).dispatch();
+    let error = response.into_string().unwrap();
+    assert!(!error.contains("xxxx"), "{error:?}");
+    assert!(error.contains("exceeded"), "{error:?}");
+
+    let client = Client::debug_with(routes![parts]).unwrap();
+    let response = client.post("/").header(form_data()).body(body).dispatch();
+    assert_eq!(response.status(), Status::Ok);
+}
+
+#[test]
+fn forwards_on_non_multipart() {
+    let client = Client::debug_with(routes![parts]).unwrap();
+    let response = client.post("/").header(ContentType::Form).body("a=b").dispatch();
+    assert_eq!(response.status(), Status::UnsupportedMediaType);
+}
+
+#[test]
+fn fails_without_boundary() {
+    let client = Client::debug_with(routes![parts]).unwrap();
+    let response = client.post("/").header(ContentType::FormData).body("").dispatch();
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+}
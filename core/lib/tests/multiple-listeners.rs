@@ -0,0 +1,48 @@
+use rocket::config::Config;
+use rocket::fairing::AdHoc;
+use rocket::futures::channel::oneshot;
+use rocket::listener::Endpoint;
+
+async fn launch_endpoints(addresses: Vec<String>) -> Vec<Endpoint> {
+    let (tx, rx) = oneshot::channel();
+    let rocket = rocket::custom(Config::debug_default())
+        .merge_config(("address", addresses))
+        .attach(AdHoc::on_liftoff("Send Endpoints -> Channel", move |rocket| {
+            Box::pin(async move {
+                let endpoints: Vec<_> = rocket.endpoints().cloned().collect();
+                rocket.shutdown().notify();
+                tx.send(endpoints).expect("send okay");
+            })
+        }));
+
+    rocket.launch().await.expect("launch okay");
+    rx.await.unwrap()
+}
+
+#[rocket::async_test]
+async fn binds_all_tcp_addresses() {
+    let addresses = vec!["tcp:127.0.0.1:0".to_string(), "tcp:127.0.0.1:0".to_string()];
+    let endpoints = launch_endpoints(addresses).await;
+    assert_eq!(endpoints.len(), 2);
+
+    let a = endpoints[0].tcp().expect("tcp endpoint");
+    let b = endpoints[1].tcp().expect("tcp endpoint");
+    assert!(a.port() != 0 && b.port() != 0);
+    assert_ne!(a.port(), b.port());
+}
+
+#[cfg(unix)]
+#[rocket::async_test]
+async fn binds_tcp_and_unix_addresses() {
+    let dir = tempfile::tempdir().expect("temp dir");
+    let socket = dir.path().join("rocket.sock");
+    let addresses = vec![
+        "tcp:127.0.0.1:0".to_string(),
+        format!("unix:{}", socket.display()),
+    ];
+
+    let endpoints = launch_endpoints(addresses).await;
+    assert_eq!(endpoints.len(), 2);
+    assert!(endpoints[0].tcp().is_some_and(|addr| addr.port() != 0));
+    assert!(endpoints[1].is_unix());
+}
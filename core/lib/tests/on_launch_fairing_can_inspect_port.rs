@@ -20,3 +20,25 @@ async fn on_ignite_fairing_can_inspect_port() {
     rocket::tokio::spawn(rocket.try_launch_on(TcpListener::bind(addr)));
     assert_ne!(rx.await.unwrap(), 0);
 }
+
+#[rocket::async_test]
+async fn endpoints_contain_port_assigned_to_configured_port_zero() {
+    let (tx, rx) = oneshot::channel();
+    let rocket = rocket::custom(Config::debug_default())
+        .merge_config(("port", 0))
+        .attach(AdHoc::on_liftoff("Send Endpoints -> Channel", move |rocket| {
+            Box::pin(async move {
+                let endpoints: Vec<_> = rocket.endpoints().cloned().collect();
+                rocket.shutdown().notify();
+                tx.send(endpoints).expect("send okay");
+            })
+        }));
+
+    rocket.launch().await.expect("launch okay");
+    let endpoints = rx.await.unwrap();
+    assert_eq!(endpoints.len(), 1);
+
+    let tcp = endpoints[0].tcp().expect("tcp endpoint");
+    assert!(tcp.ip().is_loopback());
+    assert_ne!(tcp.port(), 0);
+}
@@ -0,0 +1,133 @@
+#![cfg(feature = "openapi")]
+
+#[macro_use] extern crate rocket;
+
+use rocket::Route;
+use rocket::http::Method;
+use rocket::route::dummy_handler;
+
+#[get("/")]
+fn index() { }
+
+#[post("/user/<id>/<name>?<page>&<limit>&<rest..>")]
+fn user(id: i32, name: &str, page: Option<usize>, limit: f64, rest: Vec<String>) {
+    let _ = (id, name, page, limit, rest);
+}
+
+#[get("/ip/<any>/<v4>/<v6>")]
+fn ip(any: std::net::IpAddr, v4: std::net::Ipv4Addr, v6: std::net::Ipv6Addr) {
+    let _ = (any, v4, v6);
+}
+
+#[get("/files/<path..>")]
+fn files(path: std::path::PathBuf) {
+    let _ = path;
+}
+
+#[get("/ignored/<_>/<id>/<_>/<_..>")]
+fn ignored(id: usize) {
+    let _ = id;
+}
+
+/// Asserts that every template variable in every path of `doc` has exactly one
+/// matching `in: path` parameter and vice versa, as OpenAPI requires.
+fn assert_path_parameters_match(doc: &serde_json::Value) {
+    for (path, item) in doc["paths"].as_object().unwrap() {
+        let mut vars: Vec<&str> = path.split('/')
+            .filter_map(|s| s.strip_prefix('{').and_then(|s| s.strip_suffix('}')))
+            .collect();
+
+        for (method, op) in item.as_object().unwrap() {
+            let params = op.get("parameters").and_then(|p| p.as_array());
+            let mut names: Vec<&str> = params.into_iter()
+                .flatten()
+                .filter(|p| p["in"] == "path")
+                .map(|p| p["name"].as_str().unwrap())
+                .collect();
+
+            vars.sort();
+            names.sort();
+            assert_eq!(vars, names, "{} {}", method, path);
+            names.dedup();
+            assert_eq!(vars.len(), names.len(), "{} {}", method, path);
+        }
+    }
+}
+
+#[test]
+fn openapi_paths_and_parameters() {
+    let manual = Route::new(Method::Put, "/manual/<thing>", dummy_handler);
+    let rocket = rocket::build()
+        .mount("/", routes![index, files])
+        .mount("/api", routes![user])
+        .mount("/", vec![manual]);
+
+    let doc = rocket.openapi();
+    assert_eq!(doc["openapi"], "3.0.3");
+
+    let paths = doc["paths"].as_object().unwrap();
+    assert_eq!(paths.len(), 4);
+    assert_eq!(paths["/"]["get"]["operationId"], "index");
+    assert!(paths["/"]["get"].get("parameters").is_none());
+
+    let op = &paths["/api/user/{id}/{name}"]["post"];
+    let params = op["parameters"].as_array().unwrap();
+    assert_eq!(params.len(), 4);
+
+    assert_eq!(params[0]["name"], "id");
+    assert_eq!(params[0]["in"], "path");
+    assert_eq!(params[0]["schema"]["format"], "int32");
+
+    assert_eq!(params[1]["name"], "name");
+    assert_eq!(params[1]["schema"]["type"], "string");
+
+    assert_eq!(params[2]["name"], "page");
+    assert_eq!(params[2]["in"], "query");
+    assert_eq!(params[2]["required"], false);
+    assert_eq!(params[2]["schema"]["type"], "integer");
+
+    assert_eq!(params[3]["name"], "limit");
+    assert_eq!(params[3]["required"], true);
+    assert_eq!(params[3]["schema"]["type"], "number");
+
+    let op = &paths["/files/{path}"]["get"];
+    assert_eq!(op["parameters"][0]["in"], "path");
+
+    let op = &paths["/manual/{thing}"]["put"];
+    assert!(op.get("operationId").is_none());
+    assert_eq!(op["parameters"][0]["schema"]["type"], "string");
+}
+
+#[test]
+fn openapi_path_parameters_match_template() {
+    let manual = Route::new(Method::Put, "/manual/<thing>/<_>", dummy_handler);
+    let rocket = rocket::build()
+        .mount("/", routes![index, files, ignored, ip])
+        .mount("/api", routes![user])
+        .mount("/", vec![manual]);
+
+    let doc = rocket.openapi();
+    assert_path_parameters_match(&doc);
+
+    let op = &doc["paths"]["/ignored/{_1}/{id}/{_3}/{_4}"]["get"];
+    let params = op["parameters"].as_array().unwrap();
+    assert_eq!(params.len(), 4);
+    assert_eq!(params[0]["name"], "_1");
+    assert_eq!(params[0]["schema"]["type"], "string");
+    assert_eq!(params[1]["name"], "id");
+    assert_eq!(params[1]["schema"]["type"], "integer");
+    assert_eq!(params[3]["name"], "_4");
+    assert!(doc["paths"]["/manual/{thing}/{_2}"]["put"].is_object());
+}
+
+#[test]
+fn openapi_ip_address_formats() {
+    let doc = rocket::build().mount("/", routes![ip]).openapi();
+    let params = doc["paths"]["/ip/{any}/{v4}/{v6}"]["get"]["parameters"].as_array().unwrap();
+
+    let formats = &params[0]["schema"]["oneOf"];
+    assert_eq!(formats[0]["format"], "ipv4");
+    assert_eq!(formats[1]["format"], "ipv6");
+    assert_eq!(params[1]["schema"]["format"], "ipv4");
+    assert_eq!(params[2]["schema"]["format"], "ipv6");
+}
@@ -0,0 +1,165 @@
+#[macro_use] extern crate rocket;
+
+use rocket::{Config, Build, Rocket};
+use rocket::fairing::AdHoc;
+use rocket::http::Header;
+use rocket::local::blocking::Client;
+use rocket::response::Redirect;
+
+#[get("/")]
+fn relative() -> Redirect {
+    Redirect::to(uri!("/login?next=home"))
+}
+
+#[get("/absolute")]
+fn absolute() -> Redirect {
+    Redirect::to(uri!("https://rocket.rs/guide"))
+}
+
+#[get("/none")]
+fn none() -> &'static str {
+    "none"
+}
+
+fn rocket(base: &str) -> Rocket<Build> {
+    let config = Config {
+        proxy_proto_header: Some("X-Forwarded-Proto".into()),
+        ..Config::debug_default()
+    };
+
+    rocket::custom(config)
+        .mount("/", routes![relative, absolute, none])
+        .attach(AdHoc::proxy_base(base))
+}
+
+#[test]
+fn prefixes_with_configured_base() {
+    for base in ["/app", "/app/", "app"] {
+        let client = Client::debug(rocket(base)).unwrap();
+        let response = client.get("/").dispatch();
+        assert_eq!(response.headers().get_one("Location"), Some("/app/login?next=home"));
+    }
+
+    let client = Client::debug(rocket("")).unwrap();
+    let response = client.get("/").dispatch();
+    assert_eq!(response.headers().get_one("Location"), Some("/login?next=home"));
+}
+
+#[test]
+fn uses_forwarded_headers() {
+    let client = Client::debug(rocket("/app")).unwrap();
+
+    let response = client.get("/")
+        .header(Header::new("X-Forwarded-Prefix", "/public/"))
+        .dispatch();
+
+    assert_eq!(response.headers().get_one("Location"), Some("/public/login?next=home"));
+
+    let response = client.get("/")
+        .header(Header::new("X-Forwarded-Host", "example.com, proxy.local"))
+        .dispatch();
+
+    let location = response.headers().get_one("Location");
+    assert_eq!(location, Some("http://example.com/app/login?next=home"));
+
+    let response = client.get("/")
+        .header(Header::new("X-Forwarded-Host", "example.com:8443"))
+        .header(Header::new("X-Forwarded-Proto", "https"))
+        .header(Header::new("X-Forwarded-Prefix", ""))
+        .dispatch();
+
+    let location = response.headers().get_one("Location");
+    assert_eq!(location, Some("https://example.com:8443/login?next=home"));
+}
+
+#[test]
+fn ignores_absolute_and_missing_locations() {
+    let client = Client::debug(rocket("/app")).unwrap();
+
+    let response = client.get("/absolute")
+        .header(Header::new("X-Forwarded-Host", "example.com"))
+        .dispatch();
+
+    assert_eq!(response.headers().get_one("Location"), Some("https://rocket.rs/guide"));
+
+    let response = client.get("/none").dispatch();
+    assert!(response.headers().get_one("Location").is_none());
+}
+
+#[test]
+fn ignores_invalid_forwarded_host() {
+    let client = Client::debug(rocket("/app")).unwrap();
+    let response = client.get("/")
+        .header(Header::new("X-Forwarded-Host", "exa mple.com"))
+        .dispatch();
+
+    assert_eq!(response.headers().get_one("Location"), Some("/app/login?next=home"));
+}
+
+#[test]
+fn ignores_unsafe_forwarded_prefix() {
+    let client = Client::debug(rocket("/app")).unwrap();
+    for prefix in ["//evil.com", "///evil.com", "/\\evil.com", "\\\\evil.com", "/a?b", "/a#b"] {
+        let response = client.get("/")
+            .header(Header::new("X-Forwarded-Prefix", prefix))
+            .dispatch();
+
+        let location = response.headers().get_one("Location");
+        assert_eq!(location, Some("/app/login?next=home"), "prefix: {:?}", prefix);
+    }
+}
+
+#[test]
+fn ignores_unsafe_configured_base() {
+    let client = Client::debug(rocket("//evil.com")).unwrap();
+    let response = client.get("/").dispatch();
+    assert_eq!(response.headers().get_one("Location"), Some("/login?next=home"));
+}
+
+#[test]
+fn proxy_base_guard_resolves_uris() {
+    use rocket::request::ProxyBase;
+
+    #[get("/item/<id>")]
+    fn item(id: usize) -> String {
+        id.to_string()
+    }
+
+    #[get("/link")]
+    fn link(base: ProxyBase<'_>) -> String {
+        base.resolve(&uri!(item(10)))
+    }
+
+    let rocket = rocket("/app").mount("/", routes![item, link]);
+    let client = Client::debug(rocket).unwrap();
+
+    let response = client.get("/link").dispatch();
+    assert_eq!(response.into_string().unwrap(), "/app/item/10");
+
+    let response = client.get("/link")
+        .header(Header::new("X-Forwarded-Prefix", "//evil.com"))
+        .dispatch();
+
+    assert_eq!(response.into_string().unwrap(), "/app/item/10");
+
+    let response = client.get("/link")
+        .header(Header::new("X-Forwarded-Host", "example.com"))
+        .header(Header::new("X-Forwarded-Proto", "https"))
+        .dispatch();
+
+    assert_eq!(response.into_string().unwrap(), "https://example.com/app/item/10");
+}
+
+#[test]
+fn proxy_base_guard_requires_fairing() {
+    use rocket::request::ProxyBase;
+
+    #[get("/")]
+    fn index(base: ProxyBase<'_>) -> String {
+        base.prefix().to_string()
+    }
+
+    let client = Client::debug_with(routes![index]).unwrap();
+    let response = client.get("/").dispatch();
+    assert_eq!(response.status(), rocket::http::Status::InternalServerError);
+}
@@ -0,0 +1,62 @@
+#[macro_use] extern crate rocket;
+
+use rocket::form::{Query, Strict, Errors};
+use rocket::http::Status;
+use rocket::local::blocking::Client;
+
+#[derive(FromForm)]
+struct Filters<'r> {
+    color: Option<&'r str>,
+    #[field(default = 10)]
+    limit: usize,
+    tags: Vec<&'r str>,
+}
+
+#[get("/items")]
+fn items(filters: Query<Filters<'_>>) -> String {
+    format!("{:?} {} {:?}", filters.color, filters.limit, filters.tags)
+}
+
+#[get("/strict")]
+fn strict(filters: Query<Strict<Filters<'_>>>) -> String {
+    format!("{:?}", filters.color)
+}
+
+#[get("/checked")]
+fn checked(filters: Result<Query<Filters<'_>>, Errors<'_>>) -> String {
+    match filters {
+        Ok(filters) => filters.limit.to_string(),
+        Err(errors) => format!("errors: {}", errors.len()),
+    }
+}
+
+fn client() -> Client {
+    Client::debug(rocket::build().mount("/", routes![items, strict, checked])).unwrap()
+}
+
+#[test]
+fn query_guard_parses_whole_query() {
+    let client = client();
+    let body = |uri: &str| client.get(uri).dispatch().into_string().unwrap();
+
+    assert_eq!(body("/items"), "None 10 []");
+    assert_eq!(body("/items?color=red"), "Some(\"red\") 10 []");
+    assert_eq!(body("/items?tags=a&limit=5&tags=b&other=x"), "None 5 [\"a\", \"b\"]");
+    assert_eq!(body("/items?color=dark%20red"), "Some(\"dark red\") 10 []");
+}
+
+#[test]
+fn query_guard_errors() {
+    let client = client();
+    let response = client.get("/items?limit=many").dispatch();
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let response = client.get("/strict?color=red").dispatch();
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let response = client.get("/strict?color=red&limit=1&tags=a").dispatch();
+    assert_eq!(response.into_string().unwrap(), "Some(\"red\")");
+
+    let response = client.get("/checked?limit=many").dispatch();
+    assert_eq!(response.into_string().unwrap(), "errors: 1");
+}
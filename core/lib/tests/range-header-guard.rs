@@ -0,0 +1,99 @@
+#[macro_use] extern crate rocket;
+
+use rocket::http::{Header, Status};
+use rocket::request::{RangeHeader, RangeError, Unsatisfiable};
+use rocket::local::blocking::Client;
+
+const DATA: &str = "0123456789";
+
+#[derive(Responder)]
+enum Body {
+    #[response(status = 200)]
+    Full(&'static str),
+    #[response(status = 206)]
+    Partial(&'static str, Header<'static>),
+}
+
+#[get("/")]
+fn data(range: Option<RangeHeader>) -> Result<Body, Unsatisfiable> {
+    let Some(range) = range else {
+        return Ok(Body::Full(DATA));
+    };
+
+    let range = range.resolve(DATA.len() as u64)?.remove(0);
+    let content_range = format!("bytes {}-{}/{}", range.start, range.end - 1, DATA.len());
+    let slice = &DATA[range.start as usize..range.end as usize];
+    Ok(Body::Partial(slice, Header::new("Content-Range", content_range)))
+}
+
+#[get("/strict")]
+fn strict(range: RangeHeader) -> String {
+    format!("{:?}", range.ranges())
+}
+
+#[test]
+fn range_header_guard() {
+    let client = Client::debug_with(routes![data, strict]).unwrap();
+
+    let response = client.get("/").dispatch();
+    assert_eq!(response.status(), Status::Ok);
+    assert_eq!(response.into_string().unwrap(), DATA);
+
+    let response = client.get("/").header(Header::new("Range", "bytes=2-4")).dispatch();
+    assert_eq!(response.status(), Status::PartialContent);
+    assert_eq!(response.headers().get_one("Content-Range"), Some("bytes 2-4/10"));
+    assert_eq!(response.into_string().unwrap(), "234");
+
+    let response = client.get("/").header(Header::new("Range", "bytes=-3")).dispatch();
+    assert_eq!(response.status(), Status::PartialContent);
+    assert_eq!(response.into_string().unwrap(), "789");
+
+    let response = client.get("/").header(Header::new("Range", "bytes=20-")).dispatch();
+    assert_eq!(response.status(), Status::RangeNotSatisfiable);
+    assert_eq!(response.headers().get_one("Content-Range"), Some("bytes */10"));
+
+    // `Option<RangeHeader>` ignores malformed headers; `RangeHeader` doesn't.
+    let response = client.get("/").header(Header::new("Range", "bytes=5-1")).dispatch();
+    assert_eq!(response.status(), Status::Ok);
+
+    let response = client.get("/strict").header(Header::new("Range", "bytes=5-1")).dispatch();
+    assert_eq!(response.status(), Status::BadRequest);
+
+    // A missing header forwards with the usual `404`.
+    let response = client.get("/strict").dispatch();
+    assert_eq!(response.status(), Status::NotFound);
+
+    let response = client.get("/strict").header(Header::new("Range", "bytes=0-1,5-")).dispatch();
+    assert_eq!(response.into_string().unwrap(), "[Bounded(0, 1), From(5)]");
+}
+
+#[test]
+fn range_count_is_capped() {
+    let client = Client::debug_with(routes![data, strict]).unwrap();
+
+    let max = format!("bytes=0-0{}", ",0-0".repeat(RangeHeader::MAX_RANGES - 1));
+    let range = RangeHeader::parse(&max).unwrap();
+    assert_eq!(range.ranges().len(), RangeHeader::MAX_RANGES);
+
+    let many = format!("{},0-0", max);
+    assert_eq!(RangeHeader::parse(&many), Err(RangeError::TooMany));
+
+    let response = client.get("/strict").header(Header::new("Range", many.clone())).dispatch();
+    assert_eq!(response.status(), Status::RangeNotSatisfiable);
+
+    let response = client.get("/").header(Header::new("Range", many)).dispatch();
+    assert_eq!(response.status(), Status::Ok);
+    assert_eq!(response.into_string().unwrap(), DATA);
+}
+
+#[test]
+fn overlapping_ranges_are_coalesced() {
+    let range = RangeHeader::parse(&format!("bytes=0-0{}", ",0-0".repeat(20))).unwrap();
+    assert_eq!(range.resolve(10).unwrap(), vec![0..1]);
+
+    let range = RangeHeader::parse("bytes=6-7, 0-1, 1-2, 3-4, 7-, -1").unwrap();
+    assert_eq!(range.resolve(10).unwrap(), vec![6..10, 0..3, 3..5]);
+
+    let range = RangeHeader::parse("bytes=8-9, 0-1, 4-5, 1-8").unwrap();
+    assert_eq!(range.resolve(10).unwrap(), vec![0..10]);
+}
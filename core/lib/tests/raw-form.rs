@@ -0,0 +1,66 @@
+#[macro_use] extern crate rocket;
+
+use rocket::form::RawForm;
+use rocket::http::{ContentType, Status};
+use rocket::local::blocking::Client;
+
+#[derive(FromForm)]
+struct Payment<'r> {
+    id: &'r str,
+    amount: u64,
+}
+
+#[post("/fields", data = "<form>")]
+fn fields(form: RawForm<'_>) -> String {
+    form.iter()
+        .map(|(name, value)| format!("{}:{}", name, value))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+#[post("/raw", data = "<form>")]
+fn raw(form: RawForm<'_>) -> &str {
+    form.raw().as_str()
+}
+
+#[post("/parse", data = "<form>")]
+fn parse(form: RawForm<'_>) -> Option<String> {
+    let payment: Payment<'_> = form.parse().ok()?;
+    Some(format!("{}={}", payment.id, payment.amount))
+}
+
+fn client() -> Client {
+    Client::debug_with(routes![fields, raw, parse]).unwrap()
+}
+
+#[test]
+fn preserves_wire_order() {
+    let client = client();
+    let body = "z=1&a=2&z=3&&m=hello+there%21";
+    let response = client.post("/fields").header(ContentType::Form).body(body).dispatch();
+    assert_eq!(response.into_string().unwrap(), "z:1,a:2,z:3,m:hello there!");
+
+    let response = client.post("/raw").header(ContentType::Form).body(body).dispatch();
+    assert_eq!(response.into_string().unwrap(), body);
+}
+
+#[test]
+fn parses_into_structure() {
+    let client = client();
+    let response = client.post("/parse")
+        .header(ContentType::Form)
+        .body("amount=10&id=abc&extra=1")
+        .dispatch();
+
+    assert_eq!(response.into_string().unwrap(), "abc=10");
+}
+
+#[test]
+fn forwards_on_non_form() {
+    let client = client();
+    let response = client.post("/fields").header(ContentType::JSON).body("{}").dispatch();
+    assert_eq!(response.status(), Status::UnsupportedMediaType);
+
+    let response = client.post("/fields").header(ContentType::FormData).body("").dispatch();
+    assert_eq!(response.status(), Status::UnsupportedMediaType);
+}
@@ -0,0 +1,55 @@
+#[macro_use] extern crate rocket;
+
+use rocket::State;
+use rocket::http::ContentType;
+use rocket::futures::stream::{self, Stream};
+use rocket::response::stream::{stream, TextStream};
+use rocket::local::blocking::Client;
+
+#[get("/numbers")]
+fn numbers() -> impl Stream<Item = String> {
+    stream! {
+        for i in 0..5 {
+            yield i.to_string();
+        }
+    }
+}
+
+#[get("/bytes")]
+async fn bytes() -> impl Stream<Item = Vec<u8>> + Send {
+    stream::iter(vec![vec![b'a', b'b'], vec![b'c']])
+}
+
+#[get("/borrowed")]
+fn borrowed(string: &State<String>) -> impl Stream<Item = &str> {
+    stream::iter(string.split(' '))
+}
+
+#[get("/text")]
+fn text() -> TextStream<impl Stream<Item = &'static str>> {
+    TextStream(stream::iter(vec!["hi", " ", "there"]))
+}
+
+#[test]
+fn raw_streams_are_byte_streams() {
+    let rocket = rocket::build()
+        .manage(String::from("a b c"))
+        .mount("/", routes![numbers, bytes, borrowed, text]);
+
+    let client = Client::debug(rocket).unwrap();
+
+    let response = client.get("/numbers").dispatch();
+    assert_eq!(response.content_type(), Some(ContentType::Binary));
+    assert_eq!(response.into_string().unwrap(), "01234");
+
+    let response = client.get("/bytes").dispatch();
+    assert_eq!(response.content_type(), Some(ContentType::Binary));
+    assert_eq!(response.into_bytes().unwrap(), b"abc");
+
+    let response = client.get("/borrowed").dispatch();
+    assert_eq!(response.into_string().unwrap(), "abc");
+
+    let response = client.get("/text").dispatch();
+    assert_eq!(response.content_type(), Some(ContentType::Text));
+    assert_eq!(response.into_string().unwrap(), "hi there");
+}
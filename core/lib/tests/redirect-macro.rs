@@ -0,0 +1,50 @@
+#[macro_use] extern crate rocket;
+
+use rocket::http::Status;
+use rocket::local::blocking::Client;
+use rocket::response::{redirect, Redirect};
+
+#[get("/hello/<name>")]
+fn hello(name: &str) -> String {
+    format!("Hello, {}!", name)
+}
+
+#[get("/")]
+fn index() -> Redirect {
+    redirect!(hello("world"))
+}
+
+#[get("/<kind>")]
+fn kind(kind: &str) -> Option<Redirect> {
+    match kind {
+        "to" => Some(redirect!(to: hello("to"))),
+        "temporary" => Some(redirect!(temporary: hello("temporary"))),
+        "permanent" => Some(redirect!(permanent: "/v2", hello("permanent"))),
+        "found" => Some(redirect!(found: hello("found"))),
+        "moved" => Some(redirect!(moved: hello("moved"))),
+        _ => None,
+    }
+}
+
+#[test]
+fn redirect_macro_redirects_to_routes() {
+    let client = Client::debug_with(routes![hello, index, kind]).unwrap();
+
+    let response = client.get("/").dispatch();
+    assert_eq!(response.status(), Status::SeeOther);
+    assert_eq!(response.headers().get_one("Location"), Some("/hello/world"));
+
+    let cases = [
+        ("to", Status::SeeOther, "/hello/to"),
+        ("temporary", Status::TemporaryRedirect, "/hello/temporary"),
+        ("permanent", Status::PermanentRedirect, "/v2/hello/permanent"),
+        ("found", Status::Found, "/hello/found"),
+        ("moved", Status::MovedPermanently, "/hello/moved"),
+    ];
+
+    for (kind, status, location) in cases {
+        let response = client.get(format!("/{}", kind)).dispatch();
+        assert_eq!(response.status(), status);
+        assert_eq!(response.headers().get_one("Location"), Some(location));
+    }
+}
@@ -0,0 +1,75 @@
+#[macro_use] extern crate rocket;
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use rocket::State;
+use rocket::state::ReloadableState;
+use rocket::local::blocking::Client;
+use rocket::figment::Figment;
+use rocket::serde::Deserialize;
+
+#[derive(Deserialize)]
+#[serde(crate = "rocket::serde")]
+struct Settings {
+    value: usize,
+}
+
+#[get("/")]
+fn index(settings: &State<ReloadableState<Settings>>) -> String {
+    settings.get().value.to_string()
+}
+
+#[post("/reload")]
+fn reload(settings: &State<ReloadableState<Settings>>) -> Option<()> {
+    settings.reload().ok()
+}
+
+#[test]
+fn reloadable_state_reloads() {
+    let reloads = Arc::new(AtomicUsize::new(0));
+    let counter = reloads.clone();
+    let figment = Figment::from(rocket::Config::debug_default()).merge(("value", 0));
+    let rocket = rocket::custom(figment.clone())
+        .mount("/", routes![index, reload])
+        .attach(ReloadableState::<Settings>::fairing_with(move || {
+            let value = counter.fetch_add(1, Ordering::SeqCst) + 1;
+            figment.clone().merge(("value", value))
+        }));
+
+    let client = Client::debug(rocket).unwrap();
+    assert_eq!(client.get("/").dispatch().into_string().unwrap(), "0");
+    assert_eq!(client.get("/").dispatch().into_string().unwrap(), "0");
+    assert_eq!(reloads.load(Ordering::SeqCst), 0);
+
+    assert_eq!(client.post("/reload").dispatch().status(), rocket::http::Status::Ok);
+    assert_eq!(client.get("/").dispatch().into_string().unwrap(), "1");
+
+    assert_eq!(client.post("/reload").dispatch().status(), rocket::http::Status::Ok);
+    assert_eq!(client.get("/").dispatch().into_string().unwrap(), "2");
+}
+
+#[test]
+fn failed_reload_retains_value() {
+    let figment = Figment::from(rocket::Config::debug_default()).merge(("value", 7));
+    let rocket = rocket::custom(figment)
+        .mount("/", routes![index, reload])
+        .attach(ReloadableState::<Settings>::fairing_with(|| {
+            Figment::from(rocket::Config::debug_default()).merge(("value", "invalid"))
+        }));
+
+    let client = Client::debug(rocket).unwrap();
+    assert_eq!(client.get("/").dispatch().into_string().unwrap(), "7");
+    assert_eq!(client.post("/reload").dispatch().status(), rocket::http::Status::NotFound);
+    assert_eq!(client.get("/").dispatch().into_string().unwrap(), "7");
+}
+
+#[test]
+fn unextractable_state_aborts_ignite() {
+    let figment = Figment::from(rocket::Config::debug_default());
+    let rocket = rocket::custom(figment)
+        .mount("/", routes![index])
+        .attach(ReloadableState::<Settings>::fairing());
+
+    assert!(Client::debug(rocket).is_err());
+}
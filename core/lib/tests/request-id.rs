@@ -0,0 +1,61 @@
+#[macro_use] extern crate rocket;
+
+use rocket::Request;
+use rocket::http::Header;
+use rocket::local::blocking::Client;
+use rocket::request_id::RequestId;
+
+#[get("/")]
+fn index(req: &Request<'_>) -> String {
+    req.request_id().unwrap_or("none").to_string()
+}
+
+#[test]
+fn request_id_is_propagated() {
+    let client = Client::debug_with(routes![index]).unwrap();
+    let response = client.get("/").dispatch();
+    assert_eq!(response.headers().get_one("X-Request-Id"), None);
+    assert_eq!(response.into_string().unwrap(), "none");
+
+    let rocket = rocket::build().mount("/", routes![index]).attach(RequestId::new());
+    let client = Client::debug(rocket).unwrap();
+
+    let response = client.get("/").header(Header::new("X-Request-Id", "abc-123")).dispatch();
+    assert_eq!(response.headers().get_one("X-Request-Id"), Some("abc-123"));
+    assert_eq!(response.into_string().unwrap(), "abc-123");
+
+    let response = client.get("/").dispatch();
+    let id = response.headers().get_one("X-Request-Id").unwrap().to_string();
+    assert_eq!(id.len(), 32);
+    assert!(id.bytes().all(|b| b.is_ascii_hexdigit()));
+    assert_eq!(response.into_string().unwrap(), id);
+
+    let response = client.get("/").dispatch();
+    assert_ne!(response.into_string().unwrap(), id);
+}
+
+#[test]
+fn invalid_request_ids_are_replaced() {
+    let rocket = rocket::build().mount("/", routes![index]).attach(RequestId::new());
+    let client = Client::debug(rocket).unwrap();
+
+    let long = "a".repeat(RequestId::MAX_LEN + 1);
+    for invalid in ["", "has space", "tab\there", &long] {
+        let response = client.get("/").header(Header::new("X-Request-Id", invalid)).dispatch();
+        let id = response.into_string().unwrap();
+        assert_ne!(id, invalid);
+        assert_eq!(id.len(), 32);
+    }
+}
+
+#[test]
+fn custom_request_id_header() {
+    let fairing = RequestId::new().header("X-Correlation-Id");
+    let rocket = rocket::build().mount("/", routes![index]).attach(fairing);
+    let client = Client::debug(rocket).unwrap();
+
+    let response = client.get("/").header(Header::new("X-Correlation-Id", "xyz")).dispatch();
+    assert_eq!(response.headers().get_one("X-Correlation-Id"), Some("xyz"));
+    assert_eq!(response.headers().get_one("X-Request-Id"), None);
+    assert_eq!(response.into_string().unwrap(), "xyz");
+}
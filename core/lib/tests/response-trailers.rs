@@ -0,0 +1,39 @@
+#[macro_use] extern crate rocket;
+
+use rocket::http::Header;
+use rocket::local::blocking::Client;
+use rocket::response::stream::{ByteStream, TextStream, WithTrailers};
+
+#[get("/checksum")]
+fn checksum() -> WithTrailers<ByteStream![Vec<u8>]> {
+    WithTrailers::new(|trailers| ByteStream! {
+        let mut checksum = 0u8;
+        for chunk in [vec![1, 2, 3], vec![4, 5, 6]] {
+            checksum = chunk.iter().fold(checksum, |a, b| a.wrapping_add(*b));
+            yield chunk;
+        }
+
+        trailers.set(Header::new("X-Checksum", checksum.to_string()));
+    })
+}
+
+#[get("/plain")]
+fn plain() -> TextStream![&'static str] {
+    TextStream! { yield "hi"; }
+}
+
+#[test]
+fn trailers_are_set_after_body() {
+    let client = Client::debug_with(routes![checksum, plain]).unwrap();
+
+    let response = client.get("/checksum").dispatch();
+    let trailers = response.trailers().cloned().expect("trailers");
+    assert!(trailers.get_one("X-Checksum").is_none());
+    assert!(response.headers().get_one("X-Checksum").is_none());
+
+    assert_eq!(response.into_bytes().unwrap(), vec![1, 2, 3, 4, 5, 6]);
+    assert_eq!(trailers.get_one("X-Checksum").as_deref(), Some("21"));
+
+    let response = client.get("/plain").dispatch();
+    assert!(response.trailers().is_none());
+}
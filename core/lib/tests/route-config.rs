@@ -0,0 +1,121 @@
+#[macro_use] extern crate rocket;
+
+use std::collections::HashMap;
+
+use rocket::{Build, Config, Data, Request, Rocket, Route};
+use rocket::http::{Method, Status};
+use rocket::local::blocking::Client;
+use rocket::request::RouteConfig;
+use rocket::route::{Handler, Outcome};
+use rocket::serde::Deserialize;
+
+#[derive(Deserialize)]
+#[serde(crate = "rocket::serde")]
+struct Checkout {
+    max_items: usize,
+    #[serde(default)]
+    message: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(crate = "rocket::serde", default)]
+struct Defaults {
+    greeting: String,
+}
+
+impl Default for Defaults {
+    fn default() -> Self {
+        Defaults { greeting: "hello".into() }
+    }
+}
+
+#[post("/checkout")]
+fn checkout(config: RouteConfig<Checkout>) -> String {
+    format!("{} {:?}", config.max_items, config.message)
+}
+
+#[get("/greet")]
+fn greet(config: RouteConfig<Defaults>) -> String {
+    config.into_inner().greeting
+}
+
+#[get("/map")]
+fn map(config: RouteConfig<HashMap<String, usize>>) -> String {
+    let mut entries: Vec<_> = config.iter().collect();
+    entries.sort();
+    format!("{:?}", entries)
+}
+
+#[derive(Clone)]
+struct Unnamed;
+
+#[rocket::async_trait]
+impl Handler for Unnamed {
+    async fn handle<'r>(&self, req: &'r Request<'_>, _: Data<'r>) -> Outcome<'r> {
+        match req.guard::<RouteConfig<Defaults>>().await.succeeded() {
+            Some(config) => Outcome::from(req, config.into_inner().greeting),
+            None => Outcome::Error(Status::InternalServerError),
+        }
+    }
+}
+
+fn rocket() -> Rocket<Build> {
+    let figment = Config::figment()
+        .merge(("routes.checkout.max_items", 5))
+        .merge(("routes.map", HashMap::from([("a", 1), ("b", 2)])));
+
+    rocket::custom(figment)
+        .mount("/", routes![checkout, greet, map])
+        .mount("/", vec![Route::new(Method::Get, "/unnamed", Unnamed)])
+}
+
+#[test]
+fn extracts_route_section() {
+    let client = Client::debug(rocket()).unwrap();
+    let response = client.post("/checkout").dispatch();
+    assert_eq!(response.into_string().unwrap(), "5 None");
+
+    let response = client.get("/map").dispatch();
+    assert_eq!(response.into_string().unwrap(), r#"[("a", 1), ("b", 2)]"#);
+}
+
+#[test]
+fn missing_section_uses_defaults() {
+    let client = Client::debug_with(routes![greet]).unwrap();
+    let response = client.get("/greet").dispatch();
+    assert_eq!(response.into_string().unwrap(), "hello");
+
+    let client = Client::debug_with(routes![checkout]).unwrap();
+    let response = client.post("/checkout").dispatch();
+    assert_eq!(response.status(), Status::InternalServerError);
+}
+
+#[test]
+fn invalid_or_unnamed_fails() {
+    let figment = Config::figment().merge(("routes.checkout.max_items", "lots"));
+    let client = Client::debug(rocket::custom(figment).mount("/", routes![checkout])).unwrap();
+    let response = client.post("/checkout").dispatch();
+    assert_eq!(response.status(), Status::InternalServerError);
+
+    let client = Client::debug(rocket()).unwrap();
+    let response = client.get("/unnamed").dispatch();
+    assert_eq!(response.status(), Status::InternalServerError);
+}
+
+#[test]
+fn same_named_routes_share_section() {
+    let figment = Config::figment().merge(("routes.greet.greeting", "hi"));
+    let mut route = Route::new(Method::Get, "/other", Unnamed);
+    route.name = Some("greet".into());
+
+    let rocket = rocket::custom(figment)
+        .mount("/", routes![greet])
+        .mount("/", vec![route]);
+
+    let client = Client::debug(rocket).unwrap();
+    let response = client.get("/greet").dispatch();
+    assert_eq!(response.into_string().unwrap(), "hi");
+
+    let response = client.get("/other").dispatch();
+    assert_eq!(response.into_string().unwrap(), "hi");
+}
@@ -0,0 +1,50 @@
+#[macro_use] extern crate rocket;
+
+use rocket::{Rocket, Orbit};
+use rocket::local::blocking::Client;
+
+#[get("/__routes")]
+fn routes_page(rocket: &Rocket<Orbit>) -> String {
+    let mut lines: Vec<_> = rocket.routes()
+        .map(|r| format!("{} {} {} {}", r.method, r.uri, r.rank, r.name.as_deref().unwrap_or("")))
+        .collect();
+
+    lines.sort();
+    lines.join("\n")
+}
+
+#[get("/__catchers")]
+fn catchers_page(rocket: &Rocket<Orbit>) -> String {
+    let mut lines: Vec<_> = rocket.catchers()
+        .map(|c| format!("{:?} {} {}", c.code, c.base(), c.name.as_deref().unwrap_or("")))
+        .collect();
+
+    lines.sort();
+    lines.join("\n")
+}
+
+#[post("/item/<id>", rank = 3)]
+fn item(id: usize) -> String {
+    id.to_string()
+}
+
+#[catch(404)]
+fn not_found() { }
+
+#[test]
+fn runtime_route_and_catcher_introspection() {
+    let rocket = rocket::build()
+        .mount("/", routes![routes_page, catchers_page])
+        .mount("/api", routes![item])
+        .register("/api", catchers![not_found]);
+
+    let client = Client::debug(rocket).unwrap();
+    let routes = client.get("/__routes").dispatch().into_string().unwrap();
+    assert_eq!(routes.lines().count(), 3);
+    assert!(routes.lines().any(|l| l.starts_with("GET /__routes ") && l.ends_with(" routes_page")));
+    assert!(routes.lines().any(|l| l.starts_with("GET /__catchers ") && l.ends_with(" catchers_page")));
+    assert!(routes.lines().any(|l| l == "POST /api/item/<id> 3 item"));
+
+    let catchers = client.get("/__catchers").dispatch().into_string().unwrap();
+    assert_eq!(catchers, "Some(404) /api not_found");
+}
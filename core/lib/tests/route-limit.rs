@@ -0,0 +1,72 @@
+#[macro_use] extern crate rocket;
+
+use rocket::Request;
+use rocket::data::{Limits, ToByteUnit};
+use rocket::http::Status;
+use rocket::local::blocking::Client;
+
+#[post("/small", data = "<body>")]
+fn small(body: String) -> String {
+    body
+}
+
+#[post("/large", data = "<body>", limit = "32 B")]
+fn large(body: String) -> String {
+    body
+}
+
+#[post("/tiny", data = "<body>", limit = "4b")]
+fn tiny(body: &str) -> &str {
+    body
+}
+
+#[get("/limits", limit = "1MiB")]
+fn limits(req: &Request<'_>) -> String {
+    let limits = req.limits();
+    format!("{:?} {:?}", limits.get("json"), limits.get("custom"))
+}
+
+fn client() -> Client {
+    let rocket = rocket::build()
+        .merge_config(("limits", Limits::new().limit("string", 8.bytes()).limit("custom", 1.bytes())))
+        .mount("/", routes![small, large, tiny, limits]);
+
+    Client::debug(rocket).unwrap()
+}
+
+#[test]
+fn route_limit_overrides_configured_limit() {
+    let client = client();
+    let body = "a".repeat(16);
+
+    let response = client.post("/small").body(&body).dispatch();
+    assert_eq!(response.status(), Status::BadRequest);
+
+    let response = client.post("/large").body(&body).dispatch();
+    assert_eq!(response.status(), Status::Ok);
+    assert_eq!(response.into_string().unwrap(), body);
+
+    let response = client.post("/large").body("a".repeat(33)).dispatch();
+    assert_eq!(response.status(), Status::BadRequest);
+
+    let response = client.post("/tiny").body("abcd").dispatch();
+    assert_eq!(response.status(), Status::Ok);
+
+    let response = client.post("/tiny").body("abcde").dispatch();
+    assert_eq!(response.status(), Status::BadRequest);
+}
+
+#[test]
+fn route_limit_replaces_all_limits() {
+    let client = client();
+    let response = client.get("/limits").dispatch();
+    let expected = format!("{:?} {:?}", Some(1.mebibytes()), Some(1.mebibytes()));
+    assert_eq!(response.into_string().unwrap(), expected);
+}
+
+#[test]
+fn route_limit_is_set_on_route() {
+    let routes = routes![small, large];
+    assert_eq!(routes[0].limit, None);
+    assert_eq!(routes[1].limit, Some(32.bytes()));
+}
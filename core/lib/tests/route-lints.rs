@@ -0,0 +1,77 @@
+#![cfg(feature = "route-lints")]
+
+#[macro_use] extern crate rocket;
+
+use rocket::http::Method;
+use rocket::route::Declared;
+
+#[get("/")]
+fn index() -> &'static str {
+    "index"
+}
+
+#[post("/<id>?<q>")]
+fn forgotten(id: usize, q: &str) -> String {
+    format!("{} {}", id, q)
+}
+
+#[test]
+fn routes_are_declared() {
+    let declared = |name: &str| Declared::iter().find(|d| d.name == name).unwrap();
+
+    let index = declared("index");
+    assert_eq!(index.method, Method::Get);
+    assert_eq!(index.uri, "/");
+    assert_eq!(index.location.0, file!());
+
+    let forgotten = declared("forgotten");
+    assert_eq!(forgotten.method, Method::Post);
+    assert_eq!(forgotten.uri, "/<id>?<q>");
+}
+
+mod nested {
+    #[get("/nested")]
+    pub fn index() -> &'static str {
+        "nested"
+    }
+}
+
+#[get("/hidden")]
+#[allow(unmounted_route)]
+fn hidden() -> &'static str {
+    "hidden"
+}
+
+#[suppress(unmounted_route)]
+#[get("/suppressed")]
+fn suppressed() -> &'static str {
+    "suppressed"
+}
+
+#[test]
+fn routes_are_declared_by_path() {
+    let mut paths: Vec<_> = Declared::iter()
+        .filter(|d| d.name == "index")
+        .map(|d| d.path)
+        .collect();
+
+    paths.sort();
+    assert_eq!(paths, ["route_lints::index", "route_lints::nested::index"]);
+
+    let route = &routes![nested::index][0];
+    assert_eq!(route.name.as_deref(), Some("index"));
+}
+
+#[test]
+fn allowed_routes_are_not_declared() {
+    assert!(Declared::iter().all(|d| d.name != "hidden" && d.name != "suppressed"));
+
+    let client = rocket::local::blocking::Client::debug_with(routes![hidden, suppressed]).unwrap();
+    assert_eq!(client.get("/hidden").dispatch().into_string().unwrap(), "hidden");
+}
+
+#[test]
+fn unmounted_routes_do_not_prevent_launch() {
+    let client = rocket::local::blocking::Client::debug_with(routes![index]).unwrap();
+    assert_eq!(client.get("/").dispatch().into_string().unwrap(), "index");
+}
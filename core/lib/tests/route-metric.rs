@@ -0,0 +1,53 @@
+#[macro_use] extern crate rocket;
+
+use rocket::{Request, Route};
+use rocket::fairing::AdHoc;
+use rocket::http::Method;
+use rocket::local::blocking::Client;
+use rocket::route::dummy_handler;
+
+#[post("/cart/checkout", metric = "checkout")]
+fn complete_order() -> &'static str {
+    "ordered"
+}
+
+#[route(GET, uri = "/cart", metric = "cart.view")]
+fn cart() -> &'static str {
+    "cart"
+}
+
+#[get("/")]
+fn index(req: &Request<'_>) -> String {
+    format!("{:?}", req.route().and_then(|r| r.metric.as_deref()))
+}
+
+#[test]
+fn metric_label_is_stored_in_route() {
+    let routes = routes![complete_order, cart, index];
+    assert_eq!(routes[0].metric.as_deref(), Some("checkout"));
+    assert_eq!(routes[0].name.as_deref(), Some("complete_order"));
+    assert_eq!(routes[1].metric.as_deref(), Some("cart.view"));
+    assert_eq!(routes[2].metric, None);
+
+    let route = Route::new(Method::Get, "/", dummy_handler).with_metric("home");
+    assert_eq!(route.metric.as_deref(), Some("home"));
+}
+
+#[test]
+fn metric_label_is_visible_to_fairings() {
+    let rocket = rocket::build()
+        .mount("/", routes![complete_order, index])
+        .attach(AdHoc::on_response("Metrics", |req, res| Box::pin(async move {
+            if let Some(metric) = req.route().and_then(|r| r.metric.as_deref()) {
+                res.set_raw_header("X-Metric", metric.to_string());
+            }
+        })));
+
+    let client = Client::debug(rocket).unwrap();
+    let response = client.post("/cart/checkout").dispatch();
+    assert_eq!(response.headers().get_one("X-Metric"), Some("checkout"));
+
+    let response = client.get("/").dispatch();
+    assert!(response.headers().get_one("X-Metric").is_none());
+    assert_eq!(response.into_string().unwrap(), "None");
+}
@@ -0,0 +1,62 @@
+#[macro_use] extern crate rocket;
+
+use std::time::Duration;
+
+use rocket::{Request, Data, Route};
+use rocket::http::{Method, Status};
+use rocket::local::blocking::Client;
+use rocket::route::{Handler, Outcome};
+use rocket::tokio::time::sleep;
+
+#[get("/slow", timeout = "50ms")]
+async fn slow() -> &'static str {
+    sleep(Duration::from_secs(10)).await;
+    "slow"
+}
+
+#[get("/fast", timeout = "5s")]
+async fn fast() -> &'static str {
+    sleep(Duration::from_millis(10)).await;
+    "fast"
+}
+
+#[derive(Clone)]
+struct Sleepy;
+
+#[rocket::async_trait]
+impl Handler for Sleepy {
+    async fn handle<'r>(&self, req: &'r Request<'_>, _: Data<'r>) -> Outcome<'r> {
+        sleep(Duration::from_secs(10)).await;
+        Outcome::from(req, "sleepy")
+    }
+}
+
+#[test]
+fn attribute_timeout_is_recorded() {
+    let route = routes![slow].remove(0);
+    assert_eq!(route.timeout, Some(Duration::from_millis(50)));
+
+    let route = routes![fast].remove(0);
+    assert_eq!(route.timeout, Some(Duration::from_secs(5)));
+}
+
+#[test]
+fn handler_timeout_returns_503() {
+    let manual = Route::new(Method::Get, "/manual", Sleepy)
+        .with_timeout(Duration::from_millis(50));
+
+    let rocket = rocket::build()
+        .mount("/", routes![slow, fast])
+        .mount("/", vec![manual]);
+
+    let client = Client::debug(rocket).unwrap();
+    let response = client.get("/slow").dispatch();
+    assert_eq!(response.status(), Status::ServiceUnavailable);
+
+    let response = client.get("/manual").dispatch();
+    assert_eq!(response.status(), Status::ServiceUnavailable);
+
+    let response = client.get("/fast").dispatch();
+    assert_eq!(response.status(), Status::Ok);
+    assert_eq!(response.into_string().unwrap(), "fast");
+}
@@ -0,0 +1,72 @@
+#[macro_use] extern crate rocket;
+
+use rocket::http::{Header, Status};
+use rocket::http::uri::Host;
+use rocket::local::blocking::{Client, LocalResponse};
+use rocket::request::{SameOrigin, SameOriginHosts};
+
+#[post("/")]
+fn index(origin: SameOrigin<'_>) -> String {
+    origin.host().to_string()
+}
+
+fn client() -> Client {
+    let rocket = rocket::build()
+        .manage(SameOriginHosts::new([Host::new(uri!("app.example.com"))]))
+        .mount("/", routes![index]);
+
+    Client::debug(rocket).unwrap()
+}
+
+fn post<'c>(client: &'c Client, host: &'static str, headers: Vec<Header<'static>>) -> LocalResponse<'c> {
+    let mut request = client.post("/");
+    request.inner_mut().set_host(Host::parse(host).unwrap());
+    for header in headers {
+        request.add_header(header);
+    }
+
+    request.dispatch()
+}
+
+#[test]
+fn allows_same_origin() {
+    let client = client();
+    let response = post(&client, "rocket.rs", vec![Header::new("Origin", "https://rocket.rs")]);
+
+    assert_eq!(response.status(), Status::Ok);
+    assert_eq!(response.into_string().unwrap(), "rocket.rs");
+
+    let referer = Header::new("Referer", "https://ROCKET.rs/some/page?x=1");
+    let response = post(&client, "rocket.rs", vec![referer]);
+
+    assert_eq!(response.status(), Status::Ok);
+}
+
+#[test]
+fn allows_managed_hosts() {
+    let client = client();
+    let origin = Header::new("Origin", "https://app.example.com");
+    let response = post(&client, "api.example.com", vec![origin]);
+
+    assert_eq!(response.status(), Status::Ok);
+    assert_eq!(response.into_string().unwrap(), "app.example.com");
+}
+
+#[test]
+fn rejects_cross_origin() {
+    let client = client();
+    let forbidden = [
+        vec![],
+        vec![Header::new("Origin", "null")],
+        vec![Header::new("Origin", "https://evil.example")],
+        vec![Header::new("Origin", "https://rocket.rs:8443")],
+        vec![Header::new("Referer", "https://evil.example/rocket.rs")],
+        // `Origin` takes precedence over `Referer`.
+        vec![Header::new("Origin", "https://evil.example"), Header::new("Referer", "https://rocket.rs")],
+    ];
+
+    for headers in forbidden {
+        let response = post(&client, "rocket.rs", headers.clone());
+        assert_eq!(response.status(), Status::Forbidden, "{:?}", headers);
+    }
+}
@@ -0,0 +1,95 @@
+#![cfg(any(feature = "json", feature = "msgpack"))]
+
+#[macro_use] extern crate rocket;
+
+use rocket::{Rocket, Build, Config};
+use rocket::config::SerdeLimits;
+use rocket::serde::de::IgnoredAny;
+use rocket::http::{ContentType, Status};
+use rocket::local::blocking::Client;
+
+fn rocket(depth: usize, elements: usize) -> Rocket<Build> {
+    let config = Config {
+        serde_limits: SerdeLimits { depth, elements },
+        ..Config::debug_default()
+    };
+
+    rocket::custom(config)
+}
+
+#[cfg(feature = "json")]
+mod json {
+    use super::*;
+    use rocket::serde::json::Json;
+
+    #[post("/", data = "<_data>")]
+    fn json(_data: Json<IgnoredAny>) { }
+
+    fn status(client: &Client, body: &str) -> Status {
+        client.post("/").header(ContentType::JSON).body(body).dispatch().status()
+    }
+
+    #[test]
+    fn json_depth_limit() {
+        let client = Client::debug(rocket(3, 0).mount("/", routes![json])).unwrap();
+        assert_eq!(status(&client, "[[[1]]]"), Status::Ok);
+        assert_eq!(status(&client, r#"{"a": [{"b": 1}]}"#), Status::Ok);
+        assert_eq!(status(&client, r#"["[[[[", "{{{{"]"#), Status::Ok);
+        assert_eq!(status(&client, r#"["\"[[[["]"#), Status::Ok);
+        assert_eq!(status(&client, "[[[[1]]]]"), Status::BadRequest);
+        assert_eq!(status(&client, r#"{"a": [{"b": [1]}]}"#), Status::BadRequest);
+    }
+
+    #[test]
+    fn json_elements_limit() {
+        let client = Client::debug(rocket(0, 3).mount("/", routes![json])).unwrap();
+        assert_eq!(status(&client, "[1, 2, 3]"), Status::Ok);
+        assert_eq!(status(&client, r#"{"a": 1, "b": "x,y,z", "c": [1, 2, 3]}"#), Status::Ok);
+        assert_eq!(status(&client, "[[1, 2, 3], [1, 2, 3], [1, 2, 3]]"), Status::Ok);
+        assert_eq!(status(&client, "[1, 2, 3, 4]"), Status::PayloadTooLarge);
+        assert_eq!(status(&client, r#"{"a": 1, "b": 2, "c": 3, "d": 4}"#), Status::PayloadTooLarge);
+        assert_eq!(status(&client, "[[1, 2, 3, 4]]"), Status::PayloadTooLarge);
+    }
+
+    #[test]
+    fn json_unlimited() {
+        let client = Client::debug(rocket(0, 0).mount("/", routes![json])).unwrap();
+        let array = format!("[{}]", vec!["1"; 1000].join(","));
+        assert_eq!(status(&client, &array), Status::Ok);
+    }
+}
+
+#[cfg(feature = "msgpack")]
+mod msgpack {
+    use super::*;
+    use rocket::serde::Serialize;
+    use rocket::serde::msgpack::{self, MsgPack};
+
+    #[post("/", data = "<_data>")]
+    fn msgpack(_data: MsgPack<IgnoredAny>) { }
+
+    fn status<T: Serialize>(client: &Client, value: &T) -> Status {
+        let body = msgpack::to_compact_vec(value).unwrap();
+        client.post("/").header(ContentType::MsgPack).body(body).dispatch().status()
+    }
+
+    #[test]
+    fn msgpack_depth_limit() {
+        let client = Client::debug(rocket(3, 0).mount("/", routes![msgpack])).unwrap();
+        assert_eq!(status(&client, &vec![vec![vec![1u8]]]), Status::Ok);
+        assert_eq!(status(&client, &vec![vec![vec!["[[[["]]]), Status::Ok);
+        assert_eq!(status(&client, &(1u64 << 40, vec![vec![1.5f64]])), Status::Ok);
+        assert_eq!(status(&client, &vec![vec![vec![vec![1u8]]]]), Status::BadRequest);
+        assert_eq!(status(&client, &(1, (2, (3, (4,))))), Status::BadRequest);
+    }
+
+    #[test]
+    fn msgpack_elements_limit() {
+        let client = Client::debug(rocket(0, 3).mount("/", routes![msgpack])).unwrap();
+        assert_eq!(status(&client, &vec![1, 2, 3]), Status::Ok);
+        assert_eq!(status(&client, &vec![vec![1, 2, 3]; 3]), Status::Ok);
+        assert_eq!(status(&client, &vec![1, 2, 3, 4]), Status::PayloadTooLarge);
+        assert_eq!(status(&client, &vec![vec![0u8; 100]]), Status::PayloadTooLarge);
+        assert_eq!(status(&client, &vec![0u32; 100_000]), Status::PayloadTooLarge);
+    }
+}
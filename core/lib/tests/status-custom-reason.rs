@@ -0,0 +1,51 @@
+#[macro_use] extern crate rocket;
+
+use rocket::http::{Status, CustomStatus};
+use rocket::local::blocking::Client;
+
+#[get("/stale")]
+fn stale() -> (CustomStatus, &'static str) {
+    (Status::custom(299, "Stale Data"), "old news")
+}
+
+#[get("/okay")]
+fn okay() -> (CustomStatus, &'static str) {
+    (Status::custom(200, "Okey Dokey"), "fine")
+}
+
+#[get("/plain")]
+fn plain() -> Status {
+    Status::new(299)
+}
+
+#[test]
+fn custom_reason_is_preserved() {
+    let client = Client::debug_with(routes![stale, okay, plain]).unwrap();
+
+    let response = client.get("/stale").dispatch();
+    assert_eq!(response.status().code, 299);
+    assert_eq!(response.reason(), Some("Stale Data"));
+    assert_eq!(response.into_string().unwrap(), "old news");
+
+    let response = client.get("/okay").dispatch();
+    assert_eq!(response.status(), Status::Ok);
+    assert_eq!(response.status().reason(), Some("OK"));
+    assert_eq!(response.reason(), Some("Okey Dokey"));
+
+    let response = client.get("/plain").dispatch();
+    assert_eq!(response.status().code, 299);
+    assert_eq!(response.reason(), None);
+    assert_eq!(response.status().reason(), None);
+}
+
+#[test]
+fn status_remains_a_struct_literal() {
+    let status = Status { code: 299 };
+    assert_eq!(status, Status::custom(299, "Stale Data").status);
+}
+
+#[test]
+#[should_panic]
+fn invalid_reason_panics() {
+    let _ = Status::custom(299, "Bad\r\nReason");
+}
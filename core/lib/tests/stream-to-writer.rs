@@ -0,0 +1,44 @@
+#[macro_use] extern crate rocket;
+
+use rocket::data::{Data, ToByteUnit};
+use rocket::fs::TempFile;
+use rocket::local::blocking::Client;
+
+#[post("/data", data = "<data>")]
+async fn data(data: Data<'_>) -> std::io::Result<String> {
+    let mut sink = Vec::new();
+    let n = data.stream_to_writer(&mut sink, 8.bytes()).await?;
+    let contents = String::from_utf8(sink).unwrap();
+    Ok(format!("{}:{}:{}", n.written, n.complete, contents))
+}
+
+#[post("/file", data = "<file>")]
+async fn file(file: TempFile<'_>) -> std::io::Result<String> {
+    let mut sink = Vec::new();
+    let written = file.stream_to_writer(&mut sink).await?;
+    let contents = String::from_utf8(sink).unwrap();
+    Ok(format!("{}:{}", written, contents))
+}
+
+#[test]
+fn data_stream_to_writer_respects_limit() {
+    let client = Client::debug_with(routes![data]).unwrap();
+
+    let response = client.post("/data").body("hello").dispatch();
+    assert_eq!(response.into_string().unwrap(), "5:true:hello");
+
+    let response = client.post("/data").body("hello, world!").dispatch();
+    assert_eq!(response.into_string().unwrap(), "8:false:hello, w");
+}
+
+#[test]
+fn temp_file_stream_to_writer() {
+    let client = Client::debug_with(routes![file]).unwrap();
+
+    let response = client.post("/file").body("hello, world!").dispatch();
+    assert_eq!(response.into_string().unwrap(), "13:hello, world!");
+
+    let body = "a".repeat(4096);
+    let response = client.post("/file").body(&body).dispatch();
+    assert_eq!(response.into_string().unwrap(), format!("4096:{}", body));
+}
@@ -0,0 +1,60 @@
+#[macro_use] extern crate rocket;
+
+use rocket::{Rocket, Build};
+use rocket::data::{Tee, ToByteUnit};
+use rocket::fairing::AdHoc;
+use rocket::http::Status;
+use rocket::local::blocking::Client;
+
+#[post("/", data = "<body>")]
+fn echo(tee: &Tee, body: String) -> String {
+    format!("{}:{}:{}", tee.len(), tee.is_complete(), body)
+}
+
+#[post("/raw", data = "<body>")]
+fn raw(tee: &Tee, body: Vec<u8>) -> Status {
+    match tee.as_bytes() == &body[..] {
+        true => Status::Ok,
+        false => Status::Conflict,
+    }
+}
+
+#[get("/untee")]
+fn untee(_tee: &Tee) { }
+
+fn rocket() -> Rocket<Build> {
+    rocket::build()
+        .mount("/", routes![echo, raw, untee])
+        .attach(AdHoc::on_request("Tee", |req, data| Box::pin(async move {
+            if req.uri().path() != "/untee" {
+                let _ = Tee::buffer(req, data, 8.bytes()).await;
+
+                // A second call returns the cached value without reading.
+                let _ = Tee::buffer(req, data, 1.bytes()).await;
+            }
+        })))
+}
+
+#[test]
+fn tee_buffers_body_without_consuming_it() {
+    let client = Client::debug(rocket()).unwrap();
+
+    let response = client.post("/").body("hello").dispatch();
+    assert_eq!(response.into_string().unwrap(), "5:true:hello");
+
+    let response = client.post("/").body("").dispatch();
+    assert_eq!(response.into_string().unwrap(), "0:true:");
+
+    let response = client.post("/").body("hello, world!").dispatch();
+    assert_eq!(response.into_string().unwrap(), "8:false:hello, world!");
+
+    let response = client.post("/raw").body("12345678").dispatch();
+    assert_eq!(response.status(), Status::Ok);
+}
+
+#[test]
+fn tee_guard_fails_without_buffering() {
+    let client = Client::debug(rocket()).unwrap();
+    let response = client.get("/untee").dispatch();
+    assert_eq!(response.status(), Status::InternalServerError);
+}
@@ -0,0 +1,87 @@
+#[macro_use] extern crate rocket;
+
+use rocket::form::Form;
+use rocket::fs::TempFile;
+use rocket::http::{ContentType, Status};
+use rocket::local::blocking::Client;
+
+#[derive(FromForm)]
+struct Upload<'r> {
+    #[field(validate = ext(ContentType::PDF))]
+    #[field(validate = content_is(ContentType::PDF))]
+    file: TempFile<'r>,
+}
+
+#[post("/", data = "<form>")]
+fn upload(form: Form<Upload<'_>>) -> String {
+    form.file.len().to_string()
+}
+
+#[post("/sniff", data = "<file>")]
+fn sniff(file: TempFile<'_>) -> String {
+    file.sniff().map(|ct| ct.to_string()).unwrap_or_else(|| "unknown".into())
+}
+
+fn multipart(content_type: &str, contents: &str) -> String {
+    [
+        "--X-BOUNDARY",
+        r#"Content-Disposition: form-data; name="file"; filename="doc.pdf""#,
+        &format!("Content-Type: {}", content_type),
+        "",
+        contents,
+        "--X-BOUNDARY--",
+        "",
+    ].join("\r\n")
+}
+
+#[test]
+fn content_is_validates_file_contents() {
+    let client = Client::debug_with(routes![upload, sniff]).unwrap();
+    let form_type = "multipart/form-data; boundary=X-BOUNDARY".parse::<ContentType>().unwrap();
+
+    let response = client.post("/")
+        .header(form_type.clone())
+        .body(multipart("application/pdf", "%PDF-1.7 rest"))
+        .dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+    assert_eq!(response.into_string().unwrap(), "13");
+
+    // Declared as a PDF, but isn't one.
+    let response = client.post("/")
+        .header(form_type.clone())
+        .body(multipart("application/pdf", "MZ not a pdf"))
+        .dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    // Is a GIF, declared as a PDF.
+    let response = client.post("/")
+        .header(form_type)
+        .body(multipart("application/pdf", "GIF89a nope"))
+        .dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+}
+
+#[test]
+fn sniff_recognizes_magic_numbers() {
+    let client = Client::debug_with(routes![upload, sniff]).unwrap();
+
+    let cases: &[(&[u8], &str)] = &[
+        (b"%PDF-1.4", "application/pdf"),
+        (b"\x89PNG\r\n\x1a\n....", "image/png"),
+        (b"\xFF\xD8\xFF\xE0", "image/jpeg"),
+        (b"GIF89a", "image/gif"),
+        (b"RIFF\0\0\0\0WEBPVP8 ", "image/webp"),
+        (b"\x1f\x8b\x08", "application/gzip"),
+        (b"PK\x03\x04", "application/zip"),
+        (b"plain old text", "unknown"),
+        (b"", "unknown"),
+    ];
+
+    for (bytes, expected) in cases {
+        let response = client.post("/sniff").body(bytes).dispatch();
+        assert_eq!(response.into_string().unwrap(), *expected);
+    }
+}
@@ -0,0 +1,85 @@
+#[macro_use] extern crate rocket;
+
+use rocket::http::Status;
+use rocket::local::blocking::Client;
+use rocket::time::{Date, Time, PrimitiveDateTime, OffsetDateTime};
+use rocket::time::macros::{date, time, datetime};
+
+#[get("/date/<date>")]
+fn date(date: Date) -> String {
+    date.to_string()
+}
+
+#[get("/time/<time>")]
+fn time(time: Time) -> String {
+    time.to_string()
+}
+
+#[get("/datetime/<dt>")]
+fn datetime(dt: PrimitiveDateTime) -> String {
+    dt.to_string()
+}
+
+#[get("/offset/<dt>")]
+fn offset(dt: OffsetDateTime) -> String {
+    dt.unix_timestamp().to_string()
+}
+
+#[get("/checked/<date>")]
+fn checked(date: Result<Date, rocket::time::error::Parse>) -> String {
+    match date {
+        Ok(date) => date.to_string(),
+        Err(e) => format!("invalid: {}", e),
+    }
+}
+
+#[get("/query?<date>")]
+fn query(date: Date) -> String {
+    date.to_string()
+}
+
+fn client() -> Client {
+    let rocket = rocket::build()
+        .mount("/", routes![date, time, datetime, offset, checked, query]);
+
+    Client::debug(rocket).unwrap()
+}
+
+#[test]
+fn time_params_parse() {
+    let client = client();
+    let body = |uri: &str| client.get(uri).dispatch().into_string().unwrap();
+
+    assert_eq!(body("/date/2012-10-12"), "2012-10-12");
+    assert_eq!(body("/time/13:30"), "13:30:00.0");
+    assert_eq!(body("/time/13:30:05"), "13:30:05.0");
+    assert_eq!(body("/datetime/2012-10-12T13:30"), "2012-10-12 13:30:00.0");
+    assert_eq!(body("/offset/1970-01-01T00:01:00Z"), "60");
+    assert_eq!(body("/offset/1970-01-01T01:01:00+01:00"), "60");
+}
+
+#[test]
+fn malformed_time_params() {
+    let client = client();
+    assert_eq!(client.get("/date/2012-13-12").dispatch().status(), Status::UnprocessableEntity);
+    assert_eq!(client.get("/offset/2012-10-12").dispatch().status(), Status::UnprocessableEntity);
+
+    let response = client.get("/checked/2012-13-12").dispatch();
+    let body = response.into_string().unwrap();
+    assert!(body.starts_with("invalid: "));
+    assert!(body.contains("month"));
+}
+
+#[test]
+fn time_params_round_trip() {
+    let client = client();
+    let round_trip = |uri: rocket::http::uri::Origin<'_>| {
+        client.get(uri.to_string()).dispatch().into_string().unwrap()
+    };
+
+    assert_eq!(round_trip(uri!(date(date!(2012-10-12)))), "2012-10-12");
+    assert_eq!(round_trip(uri!(time(time!(13:30:05)))), "13:30:05.0");
+    assert_eq!(round_trip(uri!(datetime(datetime!(2012-10-12 13:30)))), "2012-10-12 13:30:00.0");
+    assert_eq!(round_trip(uri!(offset(datetime!(1970-01-01 00:01 UTC)))), "60");
+    assert_eq!(round_trip(uri!(query(date!(2012-10-12)))), "2012-10-12");
+}
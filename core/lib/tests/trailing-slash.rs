@@ -0,0 +1,97 @@
+#[macro_use] extern crate rocket;
+
+use rocket::{Rocket, Build, Config};
+use rocket::config::TrailingSlash;
+use rocket::http::Status;
+use rocket::local::blocking::Client;
+
+#[get("/foo")]
+fn foo() -> &'static str {
+    "foo"
+}
+
+#[get("/bar/")]
+fn bar() -> &'static str {
+    "bar/"
+}
+
+#[get("/query?<a>")]
+fn query(a: &str) -> String {
+    format!("query: {}", a)
+}
+
+fn rocket(trailing_slash: TrailingSlash) -> Rocket<Build> {
+    let config = Config { trailing_slash, ..Config::debug_default() };
+    rocket::custom(config).mount("/", routes![foo, bar, query])
+}
+
+#[test]
+fn strict_trailing_slash() {
+    let client = Client::debug(rocket(TrailingSlash::Strict)).unwrap();
+    assert_eq!(client.get("/foo").dispatch().status(), Status::Ok);
+    assert_eq!(client.get("/foo/").dispatch().status(), Status::NotFound);
+    assert_eq!(client.get("/bar/").dispatch().status(), Status::Ok);
+    assert_eq!(client.get("/bar").dispatch().status(), Status::NotFound);
+}
+
+#[test]
+fn redirect_trailing_slash() {
+    let client = Client::debug(rocket(TrailingSlash::Redirect)).unwrap();
+
+    let response = client.get("/foo/").dispatch();
+    assert_eq!(response.status(), Status::MovedPermanently);
+    assert_eq!(response.headers().get_one("Location"), Some("/foo"));
+
+    let response = client.get("/query//?a=b").dispatch();
+    assert_eq!(response.status(), Status::MovedPermanently);
+    assert_eq!(response.headers().get_one("Location"), Some("/query?a=b"));
+
+    // Routes with a trailing slash are still routed to.
+    let response = client.get("/bar/").dispatch();
+    assert_eq!(response.into_string().unwrap(), "bar/");
+
+    // Paths without a trailing slash are never redirected.
+    assert_eq!(client.get("/bar").dispatch().status(), Status::NotFound);
+    assert_eq!(client.get("/").dispatch().status(), Status::NotFound);
+
+    let response = client.head("/foo/").dispatch();
+    assert_eq!(response.status(), Status::MovedPermanently);
+}
+
+#[test]
+fn redirect_trailing_slash_is_never_protocol_relative() {
+    let client = Client::debug(rocket(TrailingSlash::Redirect)).unwrap();
+
+    let response = client.get("//evil.com/").dispatch();
+    assert_eq!(response.status(), Status::MovedPermanently);
+    assert_eq!(response.headers().get_one("Location"), Some("/evil.com"));
+
+    let response = client.get("///evil.com//path//?a=b").dispatch();
+    assert_eq!(response.headers().get_one("Location"), Some("/evil.com/path?a=b"));
+
+    let response = client.get("//").dispatch();
+    assert_eq!(response.headers().get_one("Location"), Some("/"));
+}
+
+#[test]
+fn ignore_trailing_slash() {
+    let client = Client::debug(rocket(TrailingSlash::Ignore)).unwrap();
+    assert_eq!(client.get("/foo").dispatch().into_string().unwrap(), "foo");
+    assert_eq!(client.get("/foo/").dispatch().into_string().unwrap(), "foo");
+    assert_eq!(client.get("/bar").dispatch().into_string().unwrap(), "bar/");
+    assert_eq!(client.get("/bar/").dispatch().into_string().unwrap(), "bar/");
+    assert_eq!(client.get("/query/?a=b").dispatch().into_string().unwrap(), "query: b");
+    assert_eq!(client.get("/foo/bar").dispatch().status(), Status::NotFound);
+}
+
+#[test]
+fn trailing_slash_config() {
+    use rocket::figment::Figment;
+
+    let figment = Figment::from(Config::debug_default()).merge(("trailing_slash", "Redirect"));
+    let config = Config::from(figment);
+    assert_eq!(config.trailing_slash, TrailingSlash::Redirect);
+
+    let figment = Figment::from(Config::debug_default()).merge(("trailing_slash", "nope"));
+    assert!(Config::try_from(figment).is_err());
+}
@@ -0,0 +1,39 @@
+#[macro_use] extern crate rocket;
+
+use rocket::http::Status;
+use rocket::local::blocking::Client;
+
+#[get("/sum/<nums..>")]
+fn sum(nums: Vec<u32>) -> String {
+    nums.iter().sum::<u32>().to_string()
+}
+
+#[get("/names/<names..>")]
+fn names(names: Vec<&str>) -> String {
+    names.join(",")
+}
+
+#[get("/maybe/<nums..>")]
+fn maybe(nums: Vec<Option<u8>>) -> String {
+    format!("{:?}", nums)
+}
+
+#[test]
+fn typed_trailing_segments() {
+    let client = Client::debug_with(routes![sum, names, maybe]).unwrap();
+
+    let response = client.get("/sum/1/2/3").dispatch();
+    assert_eq!(response.into_string().unwrap(), "6");
+
+    let response = client.get("/sum").dispatch();
+    assert_eq!(response.into_string().unwrap(), "0");
+
+    let response = client.get("/sum/1/two/3").dispatch();
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let response = client.get("/names/a%20b/c").dispatch();
+    assert_eq!(response.into_string().unwrap(), "a b,c");
+
+    let response = client.get("/maybe/1/x/300").dispatch();
+    assert_eq!(response.into_string().unwrap(), "[Some(1), None, None]");
+}
@@ -0,0 +1,97 @@
+#[macro_use] extern crate rocket;
+
+use rocket::Request;
+use rocket::http::{Accept, ContentType, Header, Status};
+use rocket::local::blocking::Client;
+
+#[get("/data", format = "json")]
+fn json() -> &'static str {
+    "json"
+}
+
+#[get("/data", format = "html", rank = 2)]
+fn html() -> &'static str {
+    "html"
+}
+
+#[get("/guard")]
+fn guard(accept: &Accept) -> String {
+    accept.preferred().to_string()
+}
+
+#[derive(Responder)]
+struct WithVary(&'static str, Header<'static>);
+
+#[get("/manual")]
+fn manual(req: &Request<'_>) -> WithVary {
+    req.add_vary("Accept-Language");
+    req.add_vary("accept-language");
+    WithVary("manual", Header::new("Vary", "Origin"))
+}
+
+#[get("/star")]
+fn star(req: &Request<'_>) -> WithVary {
+    req.add_vary("Accept-Language");
+    WithVary("star", Header::new("Vary", "*"))
+}
+
+#[get("/plain")]
+fn plain() -> &'static str {
+    "plain"
+}
+
+#[post("/data", format = "json")]
+fn post_json() -> &'static str {
+    "posted"
+}
+
+fn client() -> Client {
+    Client::debug_with(routes![json, html, guard, manual, star, plain, post_json]).unwrap()
+}
+
+#[test]
+fn negotiated_routes_vary_on_accept() {
+    let client = client();
+
+    let response = client.get("/data").header(Accept::JSON).dispatch();
+    assert_eq!(response.headers().get_one("Vary"), Some("Accept"));
+    assert_eq!(response.into_string().unwrap(), "json");
+
+    let response = client.get("/data").header(Accept::HTML).dispatch();
+    assert_eq!(response.headers().get_one("Vary"), Some("Accept"));
+    assert_eq!(response.into_string().unwrap(), "html");
+
+    let response = client.get("/data").header(Accept::XML).dispatch();
+    assert_eq!(response.status(), Status::NotFound);
+    assert_eq!(response.headers().get_one("Vary"), Some("Accept"));
+}
+
+#[test]
+fn accept_guard_varies_on_accept() {
+    let response = client().get("/guard").header(Accept::JSON).dispatch();
+    assert_eq!(response.headers().get_one("Vary"), Some("Accept"));
+}
+
+#[test]
+fn recorded_headers_merge_with_existing_vary() {
+    let client = client();
+
+    let response = client.get("/manual").dispatch();
+    let vary: Vec<_> = response.headers().get("Vary").collect();
+    assert_eq!(vary, ["Origin, Accept-Language"]);
+
+    let response = client.get("/star").dispatch();
+    let vary: Vec<_> = response.headers().get("Vary").collect();
+    assert_eq!(vary, ["*"]);
+}
+
+#[test]
+fn unnegotiated_routes_do_not_vary() {
+    let client = client();
+
+    let response = client.get("/plain").header(Accept::JSON).dispatch();
+    assert!(response.headers().get_one("Vary").is_none());
+
+    let response = client.post("/data").header(ContentType::JSON).dispatch();
+    assert_eq!(response.into_string().unwrap(), "posted");
+}
@@ -0,0 +1,54 @@
+#[macro_use] extern crate rocket;
+
+use std::path::PathBuf;
+
+use rocket::http::{Method, Status};
+use rocket::local::blocking::Client;
+
+#[route(PROPFIND, uri = "/<path..>")]
+fn propfind(path: PathBuf) -> String {
+    format!("PROPFIND {}", path.display())
+}
+
+#[route("MKCOL", uri = "/<path..>")]
+fn mkcol(path: PathBuf) -> String {
+    format!("MKCOL {}", path.display())
+}
+
+#[route("unlock", uri = "/<_..>")]
+fn unlock() -> Status {
+    Status::NoContent
+}
+
+#[get("/<path..>")]
+fn get(path: PathBuf) -> String {
+    format!("GET {}", path.display())
+}
+
+#[test]
+fn webdav_methods_route() {
+    let client = Client::debug_with(routes![propfind, mkcol, unlock, get]).unwrap();
+
+    let response = client.req(Method::PropFind, "/a/b").dispatch();
+    assert_eq!(response.into_string().unwrap(), "PROPFIND a/b");
+
+    let response = client.req(Method::MkCol, "/dir").dispatch();
+    assert_eq!(response.into_string().unwrap(), "MKCOL dir");
+
+    let response = client.req(Method::Unlock, "/dir/file").dispatch();
+    assert_eq!(response.status(), Status::NoContent);
+
+    let response = client.get("/a/b").dispatch();
+    assert_eq!(response.into_string().unwrap(), "GET a/b");
+
+    let response = client.req(Method::Copy, "/a/b").dispatch();
+    assert_eq!(response.status(), Status::NotFound);
+}
+
+#[test]
+fn webdav_methods_parse() {
+    assert_eq!("PROPFIND".parse::<Method>(), Ok(Method::PropFind));
+    assert_eq!("mkcol".parse::<Method>(), Ok(Method::MkCol));
+    assert_eq!(Method::PropPatch.as_str(), "PROPPATCH");
+    assert!("FROBNICATE".parse::<Method>().is_err());
+}
@@ -0,0 +1,72 @@
+#[macro_use] extern crate rocket;
+
+use rocket::http::{ContentType, Status};
+use rocket::local::blocking::Client;
+use rocket::response::{content, WithStatus};
+
+#[get("/<code>")]
+fn forced(code: u16) -> Option<WithStatus<content::RawJson<String>>> {
+    let body = content::RawJson(format!("{{\"code\":{}}}", code));
+    Some(WithStatus::from_code(code, body)?.raw_header("X-Forced", "yes"))
+}
+
+#[get("/override")]
+fn override_headers() -> WithStatus<content::RawJson<&'static str>> {
+    WithStatus::new(Status::Accepted, content::RawJson("{}"))
+        .header(ContentType::Plain)
+        .raw_header("X-Value", "a")
+        .raw_header("X-Value", "b")
+}
+
+#[get("/outer")]
+fn outer() -> content::RawHtml<WithStatus<&'static str>> {
+    content::RawHtml(WithStatus::new(Status::Created, "<p>made</p>"))
+}
+
+#[get("/invalid")]
+fn invalid() -> WithStatus<&'static str> {
+    WithStatus::new(Status::new(1000), "invalid")
+}
+
+fn client() -> Client {
+    Client::debug_with(routes![forced, override_headers, outer, invalid]).unwrap()
+}
+
+#[test]
+fn with_status_from_code() {
+    let client = client();
+    for code in [200, 299, 418, 503] {
+        let response = client.get(format!("/{}", code)).dispatch();
+        assert_eq!(response.status().code, code);
+        assert_eq!(response.content_type(), Some(ContentType::JSON));
+        assert_eq!(response.headers().get_one("X-Forced"), Some("yes"));
+        assert_eq!(response.into_string().unwrap(), format!("{{\"code\":{}}}", code));
+    }
+
+    for code in [0, 99, 600, 999] {
+        let response = client.get(format!("/{}", code)).dispatch();
+        assert_eq!(response.status(), Status::NotFound);
+    }
+}
+
+#[test]
+fn with_status_headers_override_inner() {
+    let response = client().get("/override").dispatch();
+    assert_eq!(response.status(), Status::Accepted);
+    assert_eq!(response.content_type(), Some(ContentType::Plain));
+    assert_eq!(response.headers().get("X-Value").collect::<Vec<_>>(), ["b"]);
+}
+
+#[test]
+fn with_status_composes_with_content() {
+    let response = client().get("/outer").dispatch();
+    assert_eq!(response.status(), Status::Created);
+    assert_eq!(response.content_type(), Some(ContentType::HTML));
+    assert_eq!(response.into_string().unwrap(), "<p>made</p>");
+}
+
+#[test]
+fn with_status_invalid_status_errors() {
+    let response = client().get("/invalid").dispatch();
+    assert_eq!(response.status(), Status::InternalServerError);
+}
@@ -13,6 +13,7 @@ async fn test_config(profile: &str) {
         "release" => {
             assert_eq!(config.workers, 12);
             assert_eq!(config.keep_alive, 5);
+            assert_eq!(config.read_timeout, 15);
             assert_eq!(config.log_level, LogLevel::Critical);
             assert!(!config.secret_key.is_zero());
         }
@@ -25,7 +25,7 @@ fn redirect_logged_out_session() {
     let client = Client::tracked(rocket()).unwrap();
     let response = client.get(session::uri!(index)).dispatch();
     assert_eq!(response.status(), Status::SeeOther);
-    assert_eq!(response.headers().get_one("Location").unwrap(), &session::uri!(login));
+    assert_eq!(response.header("Location").unwrap(), &session::uri!(login));
 
     let response = client.get(session::uri!(login_page)).dispatch();
     assert_eq!(response.status(), Status::Ok);
@@ -54,7 +54,7 @@ fn login_logout_succeeds() {
     // One more.
     let response = client.get(session::uri!(login)).cookie(login_cookie.clone()).dispatch();
     assert_eq!(response.status(), Status::SeeOther);
-    assert_eq!(response.headers().get_one("Location").unwrap(), &session::uri!(index));
+    assert_eq!(response.header("Location").unwrap(), &session::uri!(index));
 
     // Logout.
     let response = client.post(session::uri!(logout)).cookie(login_cookie).dispatch();
@@ -63,7 +63,7 @@ fn login_logout_succeeds() {
 
     // The user should be redirected back to the login page.
     assert_eq!(response.status(), Status::SeeOther);
-    assert_eq!(response.headers().get_one("Location").unwrap(), &session::uri!(login));
+    assert_eq!(response.header("Location").unwrap(), &session::uri!(login));
 
     // The page should show the success message, and no errors.
     let response = client.get(session::uri!(login)).dispatch();
@@ -87,10 +87,10 @@ fn test_message() {
         .body("message=Hello from Rocket!")
         .dispatch();
 
-    let cookie_headers: Vec<_> = response.headers().get("Set-Cookie").collect();
+    let cookie_headers = response.headers_all("Set-Cookie");
     assert_eq!(cookie_headers.len(), 1);
     assert!(cookie_headers[0].starts_with("message=Hello%20from%20Rocket!"));
-    assert_eq!(response.headers().get_one("Location").unwrap(), &message::uri!(index));
+    assert_eq!(response.header("Location").unwrap(), &message::uri!(index));
     assert_eq!(response.status(), Status::SeeOther);
 
     // Check that the message is reflected.
@@ -4,7 +4,7 @@
 
 use rocket::{Rocket, Request, Build};
 use rocket::response::{content, status};
-use rocket::http::Status;
+use rocket::http::{Status, RawStr};
 
 #[get("/hello/<name>/<age>")]
 fn hello(name: &str, age: i8) -> String {
@@ -29,7 +29,7 @@ fn hello_not_found(req: &Request<'_>) -> content::RawHtml<String> {
     content::RawHtml(format!("\
         <p>Sorry, but '{}' is not a valid path!</p>\
         <p>Try visiting /hello/&lt;name&gt;/&lt;age&gt; instead.</p>",
-        req.uri()))
+        RawStr::new(&req.uri().to_string()).html_escape()))
 }
 
 #[catch(default)]
@@ -62,6 +62,15 @@ fn test_hello_invalid_age() {
         assert_eq!(response.status(), Status::NotFound);
         assert_eq!(response.into_string().unwrap(), expected.0);
     }
+
+    {
+        let response = client.get("/hello/it's").dispatch();
+        assert_eq!(response.status(), Status::NotFound);
+
+        let html = response.into_string().unwrap();
+        assert!(html.contains("'&#x2F;hello&#x2F;it&#x27;s'"));
+        assert!(!html.contains("it's"));
+    }
 }
 
 #[test]
@@ -43,6 +43,7 @@ struct Submission<'v> {
     #[field(validate = len(1..=250))]
     r#abstract: &'v str,
     #[field(validate = ext(ContentType::PDF))]
+    #[field(validate = content_is(ContentType::PDF))]
     file: TempFile<'v>,
     #[field(validate = len(1..))]
     category: Vec<Category>,
@@ -123,6 +123,7 @@ fn maybe_redir(name: &str) -> Result<&'static str, Redirect> {
 /***************************** `content` Responders ***************************/
 
 use rocket::Request;
+use rocket::http::RawStr;
 use rocket::response::content;
 
 // NOTE: This example explicitly uses the `RawJson` type from
@@ -151,7 +152,7 @@ fn not_found(request: &Request<'_>) -> content::RawHtml<String> {
         }
         _ => format!("<p>Sorry, '{}' is an invalid path! Try \
                  /hello/&lt;name&gt;/&lt;age&gt; instead.</p>",
-                 request.uri())
+                 RawStr::new(&request.uri().to_string()).html_escape())
     };
 
     content::RawHtml(html)
@@ -12,7 +12,7 @@ fn test_root(kind: &str) {
         assert_eq!(response.status(), Status::SeeOther);
         assert!(response.body().is_none());
 
-        let location = response.headers().get_one("Location").unwrap();
+        let location = response.header("Location").unwrap();
         assert_eq!(location, format!("/{}/hello/Your%20Name", kind));
     }
 
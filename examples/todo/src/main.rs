@@ -9,7 +9,7 @@ mod task;
 use rocket::{Rocket, Build};
 use rocket::fairing::AdHoc;
 use rocket::request::FlashMessage;
-use rocket::response::{Flash, Redirect};
+use rocket::response::{redirect, Flash, Redirect};
 use rocket::serde::Serialize;
 use rocket::form::Form;
 use rocket::fs::{FileServer, relative};
@@ -54,19 +54,19 @@ impl Context {
 async fn new(todo_form: Form<Todo>, conn: DbConn) -> Flash<Redirect> {
     let todo = todo_form.into_inner();
     if todo.description.is_empty() {
-        Flash::error(Redirect::to("/"), "Description cannot be empty.")
+        Flash::error(redirect!(index), "Description cannot be empty.")
     } else if let Err(e) = Task::insert(todo, &conn).await {
         error_!("DB insertion error: {}", e);
-        Flash::error(Redirect::to("/"), "Todo could not be inserted due an internal error.")
+        Flash::error(redirect!(index), "Todo could not be inserted due an internal error.")
     } else {
-        Flash::success(Redirect::to("/"), "Todo successfully added.")
+        Flash::success(redirect!(index), "Todo successfully added.")
     }
 }
 
 #[put("/<id>")]
 async fn toggle(id: i32, conn: DbConn) -> Result<Redirect, Template> {
     match Task::toggle_with_id(id, &conn).await {
-        Ok(_) => Ok(Redirect::to("/")),
+        Ok(_) => Ok(redirect!(index)),
         Err(e) => {
             error_!("DB toggle({}) error: {}", id, e);
             Err(Template::render("index", Context::err(&conn, "Failed to toggle task.").await))
@@ -77,7 +77,7 @@ async fn toggle(id: i32, conn: DbConn) -> Result<Redirect, Template> {
 #[delete("/<id>")]
 async fn delete(id: i32, conn: DbConn) -> Result<Flash<Redirect>, Template> {
     match Task::delete_with_id(id, &conn).await {
-        Ok(_) => Ok(Flash::success(Redirect::to("/"), "Todo was deleted.")),
+        Ok(_) => Ok(Flash::success(redirect!(index), "Todo was deleted.")),
         Err(e) => {
             error_!("DB deletion({}) error: {}", id, e);
             Err(Template::render("index", Context::err(&conn, "Failed to delete task.").await))
@@ -1,4 +1,5 @@
-use std::net::{Ipv4Addr, SocketAddr};
+use std::io::{Read, Write};
+use std::net::{Ipv4Addr, SocketAddr, TcpStream};
 use std::process::ExitCode;
 use std::time::Duration;
 
@@ -49,13 +50,10 @@ impl RocketExt for Rocket<Build> {
     }
 
     fn reconfigure_with_toml(self, toml: &str) -> Self {
-        use rocket::figment::{Figment, providers::{Format, Toml}};
+        use rocket::figment::providers::{Format, Toml};
 
         let toml = toml.replace("{ROCKET}", rocket::fs::relative!("../"));
-        let config = Figment::from(self.figment())
-            .merge(Toml::string(&toml).nested());
-
-        self.reconfigure(config)
+        self.merge_config(Toml::string(&toml).nested())
     }
 }
 
@@ -346,6 +344,145 @@ fn sni_resolver() -> Result<()> {
     Ok(())
 }
 
+fn test_h2c(enabled: bool) -> Result<()> {
+    let server = spawn!(enabled: bool => {
+        #[get("/")]
+        fn hello() -> &'static str {
+            "Hello, h2c!"
+        }
+
+        Rocket::default()
+            .reconfigure_with_toml(&format!("[default]\nh2c = {enabled}"))
+            .mount("/", routes![hello])
+    })?;
+
+    let client: Client = Client::build().http2_prior_knowledge().try_into()?;
+    let response = client.get(&server, "/")?.send();
+    if enabled {
+        let response = response?;
+        assert_eq!(response.version(), reqwest::Version::HTTP_2);
+        assert_eq!(response.text()?, "Hello, h2c!");
+    } else {
+        assert!(response.is_err());
+    }
+
+    // HTTP/1 is always available.
+    let client = Client::default();
+    let response = client.get(&server, "/")?.send()?;
+    assert_eq!(response.version(), reqwest::Version::HTTP_11);
+    assert_eq!(response.text()?, "Hello, h2c!");
+    Ok(())
+}
+
+fn h2c() -> Result<()> {
+    test_h2c(true)?;
+    test_h2c(false)
+}
+
+fn max_uri_length() -> Result<()> {
+    let server = spawn! {
+        #[get("/<path..>")]
+        fn echo(path: std::path::PathBuf) -> String {
+            path.display().to_string()
+        }
+
+        Rocket::default()
+            .reconfigure_with_toml("[default]\nmax_uri_length = 32")
+            .mount("/", routes![echo])
+    }?;
+
+    let client = Client::default();
+    let response = client.get(&server, "/0123456789")?.send()?;
+    assert_eq!(response.status(), 200);
+    assert_eq!(response.text()?, "0123456789");
+
+    let path = format!("/{}", "a".repeat(32));
+    let response = client.get(&server, &path)?.send()?;
+    assert_eq!(response.status(), 414);
+
+    let path = format!("/a?{}", "b".repeat(32));
+    let response = client.get(&server, &path)?.send()?;
+    assert_eq!(response.status(), 414);
+    Ok(())
+}
+
+/// Sends a raw HTTP/1.1 `GET` request for `path` to `server` and returns the
+/// raw response, including the status line, headers, and any trailers.
+fn raw_get(server: &Server, path: &str) -> Result<String> {
+    let mut stream = TcpStream::connect(server.socket_addr())?;
+    stream.set_read_timeout(Some(Duration::from_secs(5)))?;
+    write!(stream, "GET {path} HTTP/1.1\r\nHost: localhost\r\n")?;
+    write!(stream, "TE: trailers\r\nConnection: close\r\n\r\n")?;
+
+    let mut response = String::new();
+    stream.read_to_string(&mut response)?;
+    Ok(response)
+}
+
+fn response_trailers() -> Result<()> {
+    let server = spawn! {
+        use rocket::http::Header;
+        use rocket::response::stream::{ByteStream, WithTrailers};
+
+        #[get("/")]
+        fn checksum() -> WithTrailers<ByteStream![&'static [u8]]> {
+            WithTrailers::new(|trailers| ByteStream! {
+                yield &b"Hello, "[..];
+                yield &b"trailers!"[..];
+                trailers.set(Header::new("X-Checksum", "8843d7f9"));
+            })
+        }
+
+        Rocket::default().mount("/", routes![checksum])
+    }?;
+
+    let response = raw_get(&server, "/")?;
+    let (head, body) = response.split_once("\r\n\r\n").expect("response head");
+    assert!(head.starts_with("HTTP/1.1 200 OK"));
+    assert!(head.to_ascii_lowercase().contains("transfer-encoding: chunked"));
+    assert!(!head.to_ascii_lowercase().contains("x-checksum"));
+
+    // The trailers follow the terminating, zero-length chunk.
+    let (chunks, trailers) = body.split_once("\r\n0\r\n").expect("last chunk");
+    assert!(chunks.contains("Hello, ") && chunks.contains("trailers!"));
+    assert!(trailers.to_ascii_lowercase().starts_with("x-checksum: 8843d7f9\r\n"));
+    Ok(())
+}
+
+fn custom_reason_phrase() -> Result<()> {
+    let server = spawn! {
+        use rocket::http::{Status, CustomStatus};
+
+        #[get("/stale")]
+        fn stale() -> (CustomStatus, &'static str) {
+            (Status::custom(299, "Stale Data"), "old news")
+        }
+
+        #[get("/okay")]
+        fn okay() -> (CustomStatus, &'static str) {
+            (Status::custom(200, "Okey Dokey"), "fine")
+        }
+
+        #[get("/plain")]
+        fn plain() -> &'static str {
+            "plain"
+        }
+
+        Rocket::default().mount("/", routes![stale, okay, plain])
+    }?;
+
+    let response = raw_get(&server, "/stale")?;
+    assert!(response.starts_with("HTTP/1.1 299 Stale Data\r\n"), "{response:?}");
+    assert!(response.ends_with("old news"), "{response:?}");
+
+    let response = raw_get(&server, "/okay")?;
+    assert!(response.starts_with("HTTP/1.1 200 Okey Dokey\r\n"), "{response:?}");
+
+    let response = raw_get(&server, "/plain")?;
+    assert!(response.starts_with("HTTP/1.1 200 OK\r\n"), "{response:?}");
+    Ok(())
+}
+
 fn tcp_unix_listener_fail() -> Result<()> {
     let server = spawn! {
         Rocket::default().reconfigure_with_toml("[default]\naddress = 123")
@@ -399,7 +536,8 @@ struct Test {
 
 static TESTS: &[Test] = &tests![
     run_fail, infinite, tls_info, tls_resolver, tls_mtls, sni_resolver,
-    tcp_unix_listener_fail
+    tcp_unix_listener_fail, h2c, max_uri_length, response_trailers,
+    custom_reason_phrase,
 ];
 
 fn main() -> ExitCode {